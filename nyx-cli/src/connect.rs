@@ -0,0 +1,77 @@
+#![forbid(unsafe_code)]
+
+//! `nyx-cli connect`: end-to-end reachability check through `nyx-transport`.
+//!
+//! The transport is chosen by [`TransportManager`] from default requirements,
+//! then probed once: UDP sends a random nonce and waits for it to be echoed
+//! back, TCP times the handshake.
+
+use anyhow::Context;
+use nyx_transport::{TransportKind, TransportManager, TransportRequirements, UdpEndpoint};
+use rand::RngCore;
+use serde_json::{json, Value};
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+
+/// Connect to `endpoint` and report the selected transport and round-trip time.
+pub fn probe(endpoint: SocketAddr, timeout: Duration) -> anyhow::Result<Value> {
+    let manager = TransportManager::new();
+    let kind = manager
+        .select_transport(&TransportRequirements::default())
+        .context("no transport satisfies the default requirements")?;
+    let rtt = match kind {
+        TransportKind::Udp => udp_round_trip(endpoint, timeout)?,
+        TransportKind::Tcp => {
+            let started = Instant::now();
+            std::net::TcpStream::connect_timeout(&endpoint, timeout)
+                .with_context(|| format!("tcp connect to {endpoint} failed"))?;
+            started.elapsed()
+        }
+        other => anyhow::bail!("probing over {other:?} is not supported"),
+    };
+    Ok(json!({
+        "endpoint": endpoint.to_string(),
+        "transport": transport_name(kind),
+        "rtt_ms": rtt.as_secs_f64() * 1000.0,
+    }))
+}
+
+fn transport_name(kind: TransportKind) -> &'static str {
+    match kind {
+        TransportKind::Udp => "udp",
+        TransportKind::Quic => "quic",
+        TransportKind::Tcp => "tcp",
+        TransportKind::Ice => "ice",
+    }
+}
+
+/// Send a nonce and wait until the same bytes come back from `endpoint`.
+fn udp_round_trip(endpoint: SocketAddr, timeout: Duration) -> anyhow::Result<Duration> {
+    let local: SocketAddr = if endpoint.is_ipv4() {
+        "0.0.0.0:0".parse()?
+    } else {
+        "[::]:0".parse()?
+    };
+    let mut sock = UdpEndpoint::bind(local)?;
+    let mut nonce = [0u8; 16];
+    rand::thread_rng().fill_bytes(&mut nonce);
+    let probe = format!("nyx-connect {}", hex::encode(nonce));
+
+    let started = Instant::now();
+    sock.send_to(probe.as_bytes(), endpoint)?;
+    let mut buf = [0u8; 512];
+    loop {
+        let remaining = timeout
+            .checked_sub(started.elapsed())
+            .filter(|d| !d.is_zero())
+            .with_context(|| format!("no reply from {endpoint} within {timeout:?}"))?;
+        sock.set_read_timeout(Some(remaining))?;
+        let (n, from) = sock
+            .recv_from(&mut buf)
+            .with_context(|| format!("no reply from {endpoint} within {timeout:?}"))?;
+        // Ignore stray datagrams; only the echoed nonce completes the probe.
+        if from == endpoint && &buf[..n] == probe.as_bytes() {
+            return Ok(started.elapsed());
+        }
+    }
+}
@@ -1,8 +1,10 @@
 #![forbid(unsafe_code)]
 
-use clap::{Parser, Subcommand};
-use nyx_sdk::{daemon::DaemonClient, SdkConfig};
+use clap::{CommandFactory, Parser, Subcommand};
+use nyx_sdk::daemon::DaemonClient;
+use output::OutputFormat;
 use rand::RngCore;
+use resolve::{resolve_config, ConfigLayer, ConfigSources};
 use serde_json::json;
 use std::path::PathBuf;
 
@@ -23,6 +25,12 @@ struct Cli {
     /// Auth token
     #[arg(long)]
     token: Option<String>,
+    /// Output format for every command. When given, every command renders
+    /// through the same formatter (`plain` is always `key=value`). When
+    /// omitted, each command keeps its historical output (pretty JSON, or raw
+    /// text for frame-limit and prometheus-*).
+    #[arg(long, global = true, value_enum)]
+    output: Option<OutputFormat>,
 
     #[command(subcommand)]
     command: Commands,
@@ -36,6 +44,8 @@ enum Commands {
     ReloadConfig,
     /// List config versions
     ListVersions,
+    /// Show daemon health and per-component status
+    Health,
     /// Update configuration from inline JSON key=val or a JSON file
     UpdateConfig {
         /// Inline key=value pairs (JSON values). Example: log_level="debug"
@@ -58,7 +68,19 @@ enum Commands {
         types: Vec<String>,
     },
     /// Fetch Prometheus metrics from a URL (http only)
-    PrometheusGet { url: String },
+    PrometheusGet {
+        url: String,
+        /// Only print metric families whose names start with this prefix
+        #[arg(long, value_name = "PREFIX")]
+        filter: Option<String>,
+    },
+    /// Scrape a Prometheus URL twice and print counter deltas/rates and latest gauge values
+    PrometheusDiff {
+        url: String,
+        /// Delay between the two scrapes in milliseconds
+        #[arg(long, default_value_t = 5000)]
+        interval_ms: u64,
+    },
     /// Config helpers
     Config {
         #[command(subcommand)]
@@ -82,12 +104,42 @@ enum Commands {
         #[arg(long, default_value_t = 32)]
         length: usize,
     },
+    /// Measure daemon round-trip latency with repeated info requests
+    Ping {
+        /// Number of requests to send
+        #[arg(long, default_value_t = 5)]
+        count: u32,
+        /// Delay between requests in milliseconds
+        #[arg(long, default_value_t = 200)]
+        interval_ms: u64,
+    },
+    /// Poll daemon health and print one status line per poll; press Ctrl-C to stop
+    Watch {
+        /// Delay between polls in milliseconds
+        #[arg(long, default_value_t = 1000)]
+        interval_ms: u64,
+        /// Stop after this many polls (default: run until interrupted)
+        #[arg(long)]
+        count: Option<u64>,
+    },
+    /// Check connectivity to a peer (host:port) and report the transport and round-trip time
+    Connect { endpoint: std::net::SocketAddr },
+    /// Print a shell completion script (bash, zsh, fish, powershell, elvish)
+    Completions {
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
+    },
 }
 
 #[derive(Debug, Subcommand)]
 enum ConfigCmd {
     /// Show effective CLI config (resolved from env/files)
     Show,
+    /// Validate a nyx.toml offline (syntax, schema and cross-field checks)
+    Validate {
+        /// Path to the configuration file
+        path: PathBuf,
+    },
     /// Write a nyx.toml template with CLI section
     WriteTemplate {
         /// Destination path (default: ./nyx.toml)
@@ -99,6 +151,31 @@ enum ConfigCmd {
     },
 }
 
+impl Commands {
+    /// Whether the command talks to the daemon over the configured endpoint.
+    fn uses_daemon(&self) -> bool {
+        match self {
+            Commands::Info
+            | Commands::ReloadConfig
+            | Commands::ListVersions
+            | Commands::Health
+            | Commands::UpdateConfig { .. }
+            | Commands::Rollback { .. }
+            | Commands::Snapshot { .. }
+            | Commands::Events { .. }
+            | Commands::Ping { .. }
+            | Commands::Watch { .. } => true,
+            Commands::FrameLimit { set } => set.is_some(),
+            Commands::PrometheusGet { .. }
+            | Commands::PrometheusDiff { .. }
+            | Commands::Config { .. }
+            | Commands::GenCookie { .. }
+            | Commands::Connect { .. }
+            | Commands::Completions { .. } => false,
+        }
+    }
+}
+
 fn parse_kv(s: &str) -> Result<(String, serde_json::Value), String> {
     let (k, v) = s
         .split_once('=')
@@ -111,37 +188,46 @@ fn parse_kv(s: &str) -> Result<(String, serde_json::Value), String> {
 #[tokio::main(flavor = "multi_thread")]
 async fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
+    let output = cli.output;
 
-    // Start with defaults, then apply auto-discovery (env/config), then override by CLI args
-    let (mut cfg, mut token) = auto_discover().await;
-    if let Some(ep) = cli.endpoint {
-        cfg.daemon_endpoint = ep;
-    }
-    if let Some(t) = cli.timeout_ms {
-        cfg.request_timeout_ms = t;
-    }
-    if let Some(tok) = cli.token {
-        token = Some(tok);
-    }
-    let mut client = DaemonClient::new(cfg);
-    if let Some(tok) = token {
-        client = client.with_token(tok);
+    // CLI > env > cookie > file > default; see resolve.rs
+    let resolved = resolve_config(
+        &gather_sources(ConfigLayer {
+            endpoint: cli.endpoint,
+            token: cli.token,
+            timeout_ms: cli.timeout_ms,
+        })
+        .await,
+    );
+    let cfg = resolved.sdk_config();
+    let request_timeout = std::time::Duration::from_millis(cfg.request_timeout_ms);
+    if cli.command.uses_daemon() {
+        if let Err(e) = cfg.validate_endpoint() {
+            eprintln!("error: {e}");
+            std::process::exit(2);
+        }
     }
+    let client = DaemonClient::new(cfg);
 
     let res: anyhow::Result<()> = match cli.command {
         Commands::Info => {
             let v = client.get_info().await;
-            print_result(v.map(|j| json!({"ok":true, "data": j})));
+            print_data(output, v, |data| data.clone());
             Ok(())
         }
         Commands::ReloadConfig => {
             let v = client.reload_config().await;
-            print_result(v.map(|j| json!({"ok":true, "data": j})));
+            print_result(output, v.map(|j| json!({"ok":true, "data": j})));
             Ok(())
         }
         Commands::ListVersions => {
             let v = client.list_versions().await;
-            print_result(v.map(|j| json!({"ok":true, "data": j})));
+            print_data(output, v, |data| data.clone());
+            Ok(())
+        }
+        Commands::Health => {
+            let v = client.health().await;
+            print_data(output, v, health_rows);
             Ok(())
         }
         Commands::UpdateConfig { set, file } => {
@@ -172,17 +258,17 @@ async fn main() -> anyhow::Result<()> {
                 }
             }
             let v = client.update_config(map).await;
-            print_result(v.map(|r| serde_json::to_value(r).unwrap()));
+            print_result(output, v.map(|r| serde_json::to_value(r).unwrap()));
             Ok(())
         }
         Commands::Rollback { version } => {
             let v = client.rollback_config(version).await;
-            print_result(v.map(|r| serde_json::to_value(r).unwrap()));
+            print_result(output, v.map(|r| serde_json::to_value(r).unwrap()));
             Ok(())
         }
         Commands::Snapshot { description } => {
             let v = client.create_config_snapshot(description).await;
-            print_result(v.map(|j| json!({"ok":true, "data": j})));
+            print_result(output, v.map(|j| json!({"ok":true, "data": j})));
             Ok(())
         }
         Commands::Events { types } => {
@@ -196,12 +282,17 @@ async fn main() -> anyhow::Result<()> {
                     let _ = ctrlc::set_handler(move || {
                         let _ = tx_stop.try_send(());
                     });
+                    let mut header_written = false;
                     loop {
                         tokio::select! {
                             _ = rx_stop.recv() => { break; }
                             ev = rx.recv() => {
                                 match ev {
-                                    Ok(ev) => println!("{}", serde_json::to_string(&ev).unwrap()),
+                                    Ok(ev) => {
+                                        let ev = serde_json::to_value(&ev).unwrap();
+                                        let fmt = output.unwrap_or(OutputFormat::Json);
+                                        println!("{}", output::render_line(fmt, &ev, &mut header_written));
+                                    }
                                     Err(_) => break,
                                 }
                             }
@@ -212,22 +303,101 @@ async fn main() -> anyhow::Result<()> {
                 Err(e) => Err(anyhow::anyhow!(format!("subscribe error: {e}"))),
             }
         }
-        Commands::PrometheusGet { url } => match prometheus_client::scrape_text(url).await {
-            Ok(body) => {
-                print!("{body}");
-                Ok(())
+        Commands::PrometheusGet { url, filter } => {
+            match prometheus_client::scrape_text(url).await {
+                Ok(body) => {
+                    let body = match filter {
+                        Some(prefix) => prometheus_client::filter_families(&body, &prefix),
+                        None => body,
+                    };
+                    match output {
+                        None => print!("{body}"),
+                        Some(fmt) => {
+                            let metrics = prometheus_client::parse_exposition(&body);
+                            emit(fmt, &serde_json::to_value(metrics)?);
+                        }
+                    }
+                    Ok(())
+                }
+                Err(e) => Err(anyhow::anyhow!(format!("prometheus fetch failed: {e}"))),
             }
-            Err(e) => Err(anyhow::anyhow!(format!("prometheus fetch failed: {e}"))),
-        },
+        }
+        Commands::PrometheusDiff { url, interval_ms } => {
+            let first = scrape_metrics(url.clone()).await?;
+            let started = std::time::Instant::now();
+            tokio::time::sleep(std::time::Duration::from_millis(interval_ms)).await;
+            let second = scrape_metrics(url).await?;
+            let diffs = prometheus_client::diff_metrics(first, second, started.elapsed());
+            match output {
+                None => {
+                    for d in diffs {
+                        println!("{d}");
+                    }
+                }
+                Some(fmt) => emit(fmt, &serde_json::to_value(diffs)?),
+            }
+            Ok(())
+        }
         Commands::Config { action } => match action {
             ConfigCmd::Show => {
-                let (cfg, tok) = auto_discover().await;
                 let out = json!({
-                    "daemon_endpoint": cfg.daemon_endpoint,
-                    "request_timeout_ms": cfg.request_timeout_ms,
-                    "token_present": tok.is_some(),
+                    "daemon_endpoint": resolved.daemon_endpoint.value,
+                    "request_timeout_ms": resolved.request_timeout_ms.value,
+                    "token_present": resolved.token.is_some(),
+                    "sources": resolved.sources_json(),
                 });
-                println!("{}", serde_json::to_string_pretty(&out).unwrap());
+                emit(output.unwrap_or(OutputFormat::Json), &out);
+                Ok(())
+            }
+            ConfigCmd::Validate { path } => {
+                let input = match tokio::fs::read_to_string(&path).await {
+                    Ok(s) => s,
+                    Err(e) => {
+                        eprintln!("failed to read {}: {e}", path.display());
+                        std::process::exit(2);
+                    }
+                };
+                let doc = match nyx_core::config::parse_config(&input) {
+                    Ok(doc) => doc,
+                    Err(e) => {
+                        eprintln!("{}: {e}", path.display());
+                        std::process::exit(2);
+                    }
+                };
+                let violations = nyx_core::config::validate_all(&doc);
+                match output {
+                    None => {
+                        for v in &violations {
+                            match v.line_in(&input) {
+                                Some(line) => println!("{}:{line}: {v}", path.display()),
+                                None => println!("{}: {v}", path.display()),
+                            }
+                        }
+                    }
+                    Some(fmt) => {
+                        let items: Vec<_> = violations
+                            .iter()
+                            .map(|v| {
+                                json!({
+                                    "key": v.key,
+                                    "message": v.message,
+                                    "line": v.line_in(&input),
+                                })
+                            })
+                            .collect();
+                        let out = json!({
+                            "path": path.display().to_string(),
+                            "valid": violations.is_empty(),
+                            "violations": items,
+                        });
+                        emit(fmt, &out);
+                    }
+                }
+                if !violations.is_empty() {
+                    eprintln!("{} violation(s) in {}", violations.len(), path.display());
+                    std::process::exit(1);
+                }
+                eprintln!("{} is valid", path.display());
                 Ok(())
             }
             ConfigCmd::WriteTemplate { path, force } => {
@@ -245,24 +415,34 @@ async fn main() -> anyhow::Result<()> {
                     return Err(anyhow::anyhow!(e));
                 }
                 eprintln!("wrote {}", pathbuf.display());
+                if let Some(fmt) = output {
+                    emit(fmt, &json!({ "path": pathbuf.display().to_string() }));
+                }
                 Ok(())
             }
         },
         Commands::FrameLimit { set } => {
             if let Some(n) = set {
                 // Validate conservative bounds to protect memory usage.
-                const MIN: u64 = 1024; // 1 KiB
-                const MAX: u64 = 64 * 1024 * 1024; // 64 MiB
-                if !(MIN..=MAX).contains(&n) {
-                    anyhow::bail!("invalid frame limit: {} (allowed {}..={})", n, MIN, MAX);
+                let range = nyx_core::config::MAX_FRAME_LEN_RANGE;
+                if !range.contains(&n) {
+                    anyhow::bail!(
+                        "invalid frame limit: {} (allowed {}..={})",
+                        n,
+                        range.start(),
+                        range.end()
+                    );
                 }
                 let mut map = serde_json::Map::new();
                 map.insert("max_frame_len_bytes".into(), serde_json::Value::from(n));
                 let v = client.update_config(map).await;
-                print_result(v.map(|r| serde_json::to_value(r).unwrap()));
+                print_result(output, v.map(|r| serde_json::to_value(r).unwrap()));
             } else {
                 let current = nyx_stream::FrameCodec::default_limit() as u64;
-                println!("{current}");
+                match output {
+                    None => println!("{current}"),
+                    Some(fmt) => emit(fmt, &json!({ "max_frame_len_bytes": current })),
+                }
             }
             Ok(())
         }
@@ -304,6 +484,76 @@ async fn main() -> anyhow::Result<()> {
                 }
             }
             eprintln!("wrote {}", pathbuf.display());
+            if let Some(fmt) = output {
+                emit(fmt, &json!({ "path": pathbuf.display().to_string() }));
+            }
+            Ok(())
+        }
+        Commands::Ping { count, interval_ms } => {
+            if count == 0 {
+                anyhow::bail!("invalid count: 0");
+            }
+            let mut samples = Vec::with_capacity(count as usize);
+            for i in 0..count {
+                if i > 0 {
+                    tokio::time::sleep(std::time::Duration::from_millis(interval_ms)).await;
+                }
+                // Each request is bounded by the client's request timeout.
+                let started = std::time::Instant::now();
+                match client.get_info().await {
+                    Ok(_) => samples.push(started.elapsed()),
+                    Err(e) => eprintln!("request {} failed: {e}", i + 1),
+                }
+            }
+            emit(
+                output.unwrap_or(OutputFormat::Json),
+                &ping_summary(count, &mut samples),
+            );
+            if samples.is_empty() {
+                std::process::exit(1);
+            }
+            Ok(())
+        }
+        Commands::Watch { interval_ms, count } => {
+            if interval_ms == 0 || count == Some(0) {
+                anyhow::bail!("interval and count must be positive");
+            }
+            let (tx_stop, mut rx_stop) = tokio::sync::mpsc::channel::<()>(1);
+            // Ctrl-C handler (best-effort). Ignore errors if handler already set.
+            let _ = ctrlc::set_handler(move || {
+                let _ = tx_stop.try_send(());
+            });
+            let fmt = output.unwrap_or(OutputFormat::Plain);
+            let mut header_written = false;
+            let summary = watch::run_watch(
+                &client,
+                std::time::Duration::from_millis(interval_ms),
+                count,
+                &mut rx_stop,
+                |status| println!("{}", output::render_line(fmt, status, &mut header_written)),
+            )
+            .await;
+            if summary.failures > 0 {
+                anyhow::bail!(
+                    "{} of {} health checks failed",
+                    summary.failures,
+                    summary.polls
+                );
+            }
+            Ok(())
+        }
+        Commands::Connect { endpoint } => {
+            let report =
+                tokio::task::spawn_blocking(move || connect::probe(endpoint, request_timeout))
+                    .await??;
+            emit(output.unwrap_or(OutputFormat::Json), &report);
+            Ok(())
+        }
+        Commands::Completions { shell } => {
+            // The script itself is the output; --output does not apply.
+            let mut cmd = Cli::command();
+            let name = cmd.get_name().to_string();
+            clap_complete::generate(shell, &mut cmd, name, &mut std::io::stdout());
             Ok(())
         }
     };
@@ -311,9 +561,9 @@ async fn main() -> anyhow::Result<()> {
     res
 }
 
-fn print_result(res: Result<serde_json::Value, nyx_sdk::Error>) {
+fn print_result(output: Option<OutputFormat>, res: Result<serde_json::Value, nyx_sdk::Error>) {
     match res {
-        Ok(v) => println!("{}", serde_json::to_string_pretty(&v).unwrap()),
+        Ok(v) => emit(output.unwrap_or(OutputFormat::Json), &v),
         Err(e) => {
             eprintln!("error: {e}");
             std::process::exit(1);
@@ -321,73 +571,130 @@ fn print_result(res: Result<serde_json::Value, nyx_sdk::Error>) {
     }
 }
 
-mod prometheus_client;
+/// Print a daemon query result. Table mode shows `table_view(data)` instead
+/// of the `{"ok":true,"data":...}` envelope the other formats keep.
+fn print_data(
+    output: Option<OutputFormat>,
+    res: Result<serde_json::Value, nyx_sdk::Error>,
+    table_view: impl FnOnce(&serde_json::Value) -> serde_json::Value,
+) {
+    match output {
+        Some(OutputFormat::Table) => print_result(output, res.map(|j| table_view(&j))),
+        _ => print_result(output, res.map(|j| json!({"ok":true, "data": j}))),
+    }
+}
 
-// ---------------- helper: auto-discovery -----------------
+/// One row per health component, after an `overall` row, sorted by name.
+fn health_rows(health: &serde_json::Value) -> serde_json::Value {
+    let overall = match health["healthy"].as_bool() {
+        Some(true) => "healthy",
+        Some(false) => "unhealthy",
+        None => "unknown",
+    };
+    let mut rows = vec![json!({"component": "overall", "status": overall})];
+    if let Some(components) = health["components"].as_object() {
+        let mut names: Vec<&String> = components.keys().collect();
+        names.sort();
+        rows.extend(
+            names
+                .into_iter()
+                .map(|n| json!({"component": n, "status": components[n]})),
+        );
+    }
+    serde_json::Value::Array(rows)
+}
 
-#[derive(Debug, Default)]
-struct CliFileConfig {
-    endpoint: Option<String>,
-    token: Option<String>,
-    timeout_ms: Option<u64>,
+/// Summarize ping round-trip times in milliseconds (p95 by nearest rank).
+fn ping_summary(sent: u32, samples: &mut [std::time::Duration]) -> serde_json::Value {
+    samples.sort();
+    let ms = |d: &std::time::Duration| d.as_secs_f64() * 1000.0;
+    let received = samples.len() as u32;
+    let failure_rate = f64::from(sent - received) / f64::from(sent);
+    let latency = if samples.is_empty() {
+        serde_json::Value::Null
+    } else {
+        let avg = samples.iter().map(ms).sum::<f64>() / samples.len() as f64;
+        let p95_rank = (samples.len() * 95).div_ceil(100).max(1);
+        json!({
+            "min": ms(&samples[0]),
+            "avg": avg,
+            "p95": ms(&samples[p95_rank - 1]),
+            "max": ms(&samples[samples.len() - 1]),
+        })
+    };
+    json!({
+        "sent": sent,
+        "received": received,
+        "failure_rate": failure_rate,
+        "rtt_ms": latency,
+    })
 }
 
-async fn auto_discover() -> (SdkConfig, Option<String>) {
-    let mut cfg = SdkConfig::default();
-    let mut token: Option<String> = None;
+fn emit(format: OutputFormat, value: &serde_json::Value) {
+    let text = output::render(format, value);
+    if !text.is_empty() {
+        println!("{text}");
+    }
+}
 
-    // 1) Env vars
+mod connect;
+mod output;
+mod prometheus_client;
+mod resolve;
+mod watch;
+
+/// Scrape and parse a metrics endpoint without buffering the raw body.
+async fn scrape_metrics(url: String) -> anyhow::Result<Vec<prometheus_client::Metric>> {
+    let metrics = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let sink = std::sync::Arc::clone(&metrics);
+    prometheus_client::scrape_streaming(url, move |m| {
+        if let Ok(mut v) = sink.lock() {
+            v.push(m);
+        }
+    })
+    .await
+    .map_err(|e| anyhow::anyhow!(format!("prometheus fetch failed: {e}")))?;
+    let collected = std::mem::take(&mut *metrics.lock().map_err(|_| anyhow::anyhow!("poisoned"))?);
+    Ok(collected)
+}
+
+// ---------------- helper: auto-discovery -----------------
+
+/// Collect every configuration source; precedence is applied by `resolve_config`.
+async fn gather_sources(cli: ConfigLayer) -> ConfigSources {
+    let mut env = ConfigLayer::default();
     if let Ok(ep) = std::env::var("NYX_DAEMON_ENDPOINT") {
         let e = ep.trim();
         if !e.is_empty() {
-            cfg.daemon_endpoint = e.to_string();
+            env.endpoint = Some(e.to_string());
         }
     }
     if let Ok(t) = std::env::var("NYX_REQUEST_TIMEOUT_MS") {
-        if let Ok(v) = t.parse::<u64>() {
-            cfg.request_timeout_ms = v;
-        }
+        env.timeout_ms = t.parse::<u64>().ok();
     }
     // Prefer NYX_CONTROL_TOKEN (charts/values.yaml hint) then NYX_TOKEN
-    if let Ok(tok) = std::env::var("NYX_CONTROL_TOKEN") {
-        if !tok.trim().is_empty() {
-            token = Some(tok.trim().to_string());
-        }
-    }
-    if token.is_none() {
-        if let Ok(tok) = std::env::var("NYX_TOKEN") {
-            if !tok.trim().is_empty() {
-                token = Some(tok.trim().to_string());
-            }
-        }
-    }
+    env.token = ["NYX_CONTROL_TOKEN", "NYX_TOKEN"].iter().find_map(|k| {
+        std::env::var(k)
+            .ok()
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+    });
 
-    // 2) Cookie file (Tor-style). If present, use it unless env already provided token
-    if token.is_none() {
-        if let Some(tok) = read_cookie_token().await {
-            token = Some(tok);
-        }
-    }
+    // Cookie file (Tor-style) only ever provides a token
+    let cookie = ConfigLayer {
+        token: read_cookie_token().await,
+        ..Default::default()
+    };
 
-    // 3) Config file (only fills missing)
-    if let Some(file_cfg) = load_cli_file_config().await {
-        if cfg.daemon_endpoint == SdkConfig::default_endpoint() {
-            if let Some(ep) = file_cfg.endpoint {
-                cfg.daemon_endpoint = ep;
-            }
-        }
-        if let Some(ms) = file_cfg.timeout_ms {
-            cfg.request_timeout_ms = ms;
-        }
-        if token.is_none() {
-            token = file_cfg.token.filter(|s| !s.trim().is_empty());
-        }
+    ConfigSources {
+        cli,
+        env,
+        cookie,
+        file: load_cli_file_config().await.unwrap_or_default(),
     }
-
-    (cfg, token)
 }
 
-async fn load_cli_file_config() -> Option<CliFileConfig> {
+async fn load_cli_file_config() -> Option<ConfigLayer> {
     // Search order: $NYX_CONFIG -> ./nyx.toml -> platform config dir
     let mut candidates: Vec<PathBuf> = Vec::new();
     if let Ok(p) = std::env::var("NYX_CONFIG") {
@@ -426,9 +733,9 @@ async fn load_cli_file_config() -> Option<CliFileConfig> {
     None
 }
 
-fn parse_cli_toml(s: &str) -> Option<CliFileConfig> {
+fn parse_cli_toml(s: &str) -> Option<ConfigLayer> {
     let v: toml::Value = toml::from_str(s).ok()?;
-    let mut out = CliFileConfig::default();
+    let mut out = ConfigLayer::default();
     if let Some(cli) = v.get("cli") {
         if let Some(ep) = cli.get("daemon_endpoint").and_then(|x| x.as_str()) {
             let ep = ep.trim();
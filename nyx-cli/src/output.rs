@@ -0,0 +1,234 @@
+#![forbid(unsafe_code)]
+
+//! Output formatting shared by all commands.
+//!
+//! Commands build a `serde_json::Value` and hand it to [`render`], so the
+//! global `--output` flag shapes every command the same way.
+
+use clap::ValueEnum;
+use serde_json::Value;
+
+/// Selected by the global `--output` flag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Pretty-printed JSON (one compact object per line for streams)
+    Json,
+    /// Aligned columns with a header row
+    Table,
+    /// Bare values and `key=value` lines
+    Plain,
+}
+
+/// Render a complete command result.
+pub fn render(format: OutputFormat, value: &Value) -> String {
+    match format {
+        OutputFormat::Json => serde_json::to_string_pretty(value).unwrap_or_default(),
+        OutputFormat::Table => render_table(value),
+        OutputFormat::Plain => render_plain(value),
+    }
+}
+
+/// Render one element of a stream (e.g. an event) as a single line.
+///
+/// Table mode prints the header before the first row only, so the caller
+/// tracks whether it has been written.
+pub fn render_line(format: OutputFormat, value: &Value, header_written: &mut bool) -> String {
+    match format {
+        OutputFormat::Json => serde_json::to_string(value).unwrap_or_default(),
+        OutputFormat::Plain => join_pairs(&flatten(value)),
+        OutputFormat::Table => {
+            let pairs = flatten(value);
+            let row: Vec<&str> = pairs.iter().map(|(_, v)| v.as_str()).collect();
+            let row = row.join("\t");
+            if *header_written {
+                row
+            } else {
+                *header_written = true;
+                let header: Vec<&str> = pairs.iter().map(|(k, _)| k.as_str()).collect();
+                format!("{}\n{row}", header.join("\t"))
+            }
+        }
+    }
+}
+
+fn render_plain(value: &Value) -> String {
+    match value {
+        Value::Array(items) => items
+            .iter()
+            .map(|item| match item {
+                Value::Object(_) | Value::Array(_) => join_pairs(&flatten(item)),
+                _ => scalar(item),
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Value::Object(_) => flatten(value)
+            .into_iter()
+            .map(|(k, v)| format!("{k}={v}"))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => scalar(value),
+    }
+}
+
+fn render_table(value: &Value) -> String {
+    match value {
+        Value::Array(items) if items.iter().all(Value::is_object) => {
+            let rows: Vec<Vec<(String, String)>> = items.iter().map(flatten).collect();
+            let mut columns: Vec<String> = Vec::new();
+            for row in &rows {
+                for (k, _) in row {
+                    if !columns.contains(k) {
+                        columns.push(k.clone());
+                    }
+                }
+            }
+            let cells = rows
+                .iter()
+                .map(|row| {
+                    columns
+                        .iter()
+                        .map(|c| {
+                            row.iter()
+                                .find(|(k, _)| k == c)
+                                .map(|(_, v)| v.clone())
+                                .unwrap_or_default()
+                        })
+                        .collect()
+                })
+                .collect();
+            align(columns, cells)
+        }
+        Value::Array(items) => align(
+            vec!["VALUE".into()],
+            items.iter().map(|v| vec![scalar(v)]).collect(),
+        ),
+        Value::Object(_) => align(
+            vec!["KEY".into(), "VALUE".into()],
+            flatten(value)
+                .into_iter()
+                .map(|(k, v)| vec![k, v])
+                .collect(),
+        ),
+        _ => align(vec!["VALUE".into()], vec![vec![scalar(value)]]),
+    }
+}
+
+/// Lay out a header and rows as space-padded columns.
+fn align(header: Vec<String>, rows: Vec<Vec<String>>) -> String {
+    let mut widths: Vec<usize> = header.iter().map(|h| h.chars().count()).collect();
+    for row in &rows {
+        for (w, cell) in widths.iter_mut().zip(row) {
+            *w = (*w).max(cell.chars().count());
+        }
+    }
+    std::iter::once(&header)
+        .chain(rows.iter())
+        .map(|row| {
+            let line: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, w)| format!("{cell:<w$}"))
+                .collect();
+            line.join("  ").trim_end().to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Flatten nested objects/arrays into dotted `key` paths with scalar values.
+fn flatten(value: &Value) -> Vec<(String, String)> {
+    fn walk(prefix: &str, value: &Value, out: &mut Vec<(String, String)>) {
+        let key = |k: &str| {
+            if prefix.is_empty() {
+                k.to_string()
+            } else {
+                format!("{prefix}.{k}")
+            }
+        };
+        match value {
+            Value::Object(map) if !map.is_empty() => {
+                for (k, v) in map {
+                    walk(&key(k), v, out);
+                }
+            }
+            Value::Array(items) if !items.is_empty() => {
+                for (i, v) in items.iter().enumerate() {
+                    walk(&key(&i.to_string()), v, out);
+                }
+            }
+            _ => out.push((prefix.to_string(), scalar(value))),
+        }
+    }
+    let mut out = Vec::new();
+    walk("", value, &mut out);
+    out
+}
+
+fn join_pairs(pairs: &[(String, String)]) -> String {
+    pairs
+        .iter()
+        .map(|(k, v)| format!("{k}={v}"))
+        .collect::<Vec<_>>()
+        .join("\t")
+}
+
+/// Strings print unquoted; empty containers keep their JSON spelling.
+fn scalar(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn plain_scalar_is_bare() {
+        assert_eq!(render(OutputFormat::Plain, &json!(8388608)), "8388608");
+        assert_eq!(render(OutputFormat::Plain, &json!("abc")), "abc");
+    }
+
+    #[test]
+    fn plain_object_flattens_nested_keys() {
+        let v = json!({"ok": true, "data": {"version": 3, "tags": ["a", "b"]}});
+        let out = render(OutputFormat::Plain, &v);
+        assert!(out.contains("ok=true"));
+        assert!(out.contains("data.version=3"));
+        assert!(out.contains("data.tags.1=b"));
+    }
+
+    #[test]
+    fn table_aligns_array_of_objects() {
+        let v = json!([
+            {"series": "a_total", "delta": 7},
+            {"series": "gauge_long_name", "value": 42}
+        ]);
+        let out = render(OutputFormat::Table, &v);
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].contains("delta") && lines[0].contains("value"));
+        // Columns start at the same offset on every row.
+        let col = lines[0].find("series").unwrap();
+        assert_eq!(&lines[1][col..col + 7], "a_total");
+        assert_eq!(&lines[2][col..col + 15], "gauge_long_name");
+    }
+
+    #[test]
+    fn table_object_is_key_value() {
+        let out = render(OutputFormat::Table, &json!({"a": 1, "bb": "x"}));
+        assert_eq!(out, "KEY  VALUE\na    1\nbb   x");
+    }
+
+    #[test]
+    fn stream_table_writes_header_once() {
+        let mut header = false;
+        let first = render_line(OutputFormat::Table, &json!({"t": "x", "n": 1}), &mut header);
+        let second = render_line(OutputFormat::Table, &json!({"t": "y", "n": 2}), &mut header);
+        assert_eq!(first, "n\tt\n1\tx");
+        assert_eq!(second, "2\ty");
+    }
+}
@@ -1,6 +1,9 @@
 #![forbid(unsafe_code)]
 
 use anyhow::Result;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::io::BufRead;
 
 /// Fetch Prometheus text exposition format over HTTP (no TLS).
 pub async fn scrape_text(url: String) -> Result<String> {
@@ -22,3 +25,517 @@ pub async fn scrape_text(url: String) -> Result<String> {
     .map_err(|e| anyhow::anyhow!("join error: {e}"))??;
     Ok(body)
 }
+
+/// Metric family type declared by a `# TYPE` line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MetricType {
+    Counter,
+    Gauge,
+    Histogram,
+    Summary,
+    Untyped,
+}
+
+impl MetricType {
+    fn parse(s: &str) -> Self {
+        match s {
+            "counter" => MetricType::Counter,
+            "gauge" => MetricType::Gauge,
+            "histogram" => MetricType::Histogram,
+            "summary" => MetricType::Summary,
+            _ => MetricType::Untyped,
+        }
+    }
+
+    /// Whether samples only ever grow (until a reset).
+    pub fn is_cumulative(self) -> bool {
+        matches!(
+            self,
+            MetricType::Counter | MetricType::Histogram | MetricType::Summary
+        )
+    }
+}
+
+/// One sample from a Prometheus text exposition.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Metric {
+    pub name: String,
+    pub labels: BTreeMap<String, String>,
+    pub value: f64,
+    #[serde(rename = "type")]
+    pub metric_type: MetricType,
+}
+
+impl Metric {
+    /// Series identity in exposition syntax, e.g. `reqs{op="a"}`.
+    pub fn series(&self) -> String {
+        if self.labels.is_empty() {
+            return self.name.clone();
+        }
+        let labels: Vec<String> = self
+            .labels
+            .iter()
+            .map(|(k, v)| format!("{k}=\"{}\"", escape_label(v)))
+            .collect();
+        format!("{}{{{}}}", self.name, labels.join(","))
+    }
+}
+
+/// Line-oriented exposition parser that remembers `# TYPE` declarations.
+#[derive(Debug, Default)]
+pub struct ExpositionParser {
+    types: HashMap<String, MetricType>,
+}
+
+impl ExpositionParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed one line; returns a metric for sample lines and `None` for
+    /// comments, blank lines, and lines that fail to parse.
+    pub fn parse_line(&mut self, line: &str) -> Option<Metric> {
+        let line = line.trim();
+        if let Some(rest) = line.strip_prefix("# TYPE ") {
+            let mut it = rest.split_whitespace();
+            if let (Some(name), Some(ty)) = (it.next(), it.next()) {
+                self.types.insert(name.to_string(), MetricType::parse(ty));
+            }
+            return None;
+        }
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+        let (name, labels, rest) = split_sample(line)?;
+        let value = parse_value(rest.split_whitespace().next()?)?;
+        let metric_type = self.type_of(name);
+        Some(Metric {
+            name: name.to_string(),
+            labels,
+            value,
+            metric_type,
+        })
+    }
+
+    fn type_of(&self, name: &str) -> MetricType {
+        if let Some(t) = self.types.get(name) {
+            return *t;
+        }
+        // Histogram/summary samples and `_total` counters carry a suffix on the family name
+        ["_total", "_bucket", "_sum", "_count"]
+            .iter()
+            .filter_map(|suffix| name.strip_suffix(suffix))
+            .find_map(|base| self.types.get(base).copied())
+            .unwrap_or(MetricType::Untyped)
+    }
+}
+
+/// Parse a full exposition into structured samples.
+///
+/// `# HELP` lines are skipped, `# TYPE` lines assign types to the samples that
+/// follow, and malformed sample lines are ignored.
+pub fn parse_exposition(text: &str) -> Vec<Metric> {
+    let mut parser = ExpositionParser::new();
+    text.lines().filter_map(|l| parser.parse_line(l)).collect()
+}
+
+/// Keep only the metric families whose names start with `prefix`.
+///
+/// Works line by line on the exposition text: `# HELP`/`# TYPE` lines and
+/// samples are kept when their metric name matches, everything else
+/// (other comments, blank lines, other families) is dropped. Lines are
+/// returned unchanged, newline-terminated.
+pub fn filter_families(text: &str, prefix: &str) -> String {
+    let mut out = String::new();
+    for line in text.lines() {
+        let trimmed = line.trim();
+        let name = match trimmed
+            .strip_prefix("# HELP ")
+            .or_else(|| trimmed.strip_prefix("# TYPE "))
+        {
+            Some(rest) => rest.split_whitespace().next(),
+            None if trimmed.is_empty() || trimmed.starts_with('#') => None,
+            None => split_sample(trimmed).map(|(name, _, _)| name),
+        };
+        if name.is_some_and(|n| n.starts_with(prefix)) {
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Split a sample line into name, labels, and the remainder (value + optional timestamp).
+fn split_sample(line: &str) -> Option<(&str, BTreeMap<String, String>, &str)> {
+    let name_end = line
+        .find(|c: char| c == '{' || c.is_whitespace())
+        .unwrap_or(line.len());
+    let (name, rest) = line.split_at(name_end);
+    if name.is_empty() {
+        return None;
+    }
+    let Some(inner) = rest.strip_prefix('{') else {
+        return Some((name, BTreeMap::new(), rest));
+    };
+
+    let mut labels = BTreeMap::new();
+    let mut chars = inner.char_indices().peekable();
+    loop {
+        // skip separators
+        while let Some((_, c)) = chars.peek() {
+            if *c == ',' || c.is_whitespace() {
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        let (start, c) = chars.next()?;
+        if c == '}' {
+            return Some((name, labels, &inner[start + 1..]));
+        }
+        let mut key_end = start;
+        for (i, c) in chars.by_ref() {
+            if c == '=' {
+                key_end = i;
+                break;
+            }
+        }
+        let key = inner[start..key_end].trim().to_string();
+        if chars.next().map(|(_, c)| c) != Some('"') {
+            return None;
+        }
+        let mut value = String::new();
+        loop {
+            match chars.next()? {
+                (_, '\\') => match chars.next()?.1 {
+                    'n' => value.push('\n'),
+                    other => value.push(other),
+                },
+                (_, '"') => break,
+                (_, c) => value.push(c),
+            }
+        }
+        labels.insert(key, value);
+    }
+}
+
+fn parse_value(s: &str) -> Option<f64> {
+    match s {
+        "+Inf" => Some(f64::INFINITY),
+        "-Inf" => Some(f64::NEG_INFINITY),
+        "NaN" => Some(f64::NAN),
+        _ => s.parse().ok(),
+    }
+}
+
+fn escape_label(v: &str) -> String {
+    v.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Scrape a URL and parse the body line-by-line as it arrives.
+///
+/// Unlike [`scrape_text`], the body is never held in memory as a whole; only
+/// the current line and the parsed samples handed to `on_metric` are kept.
+/// Returns the number of samples parsed.
+pub async fn scrape_streaming<F>(url: String, on_metric: F) -> Result<usize>
+where
+    F: FnMut(Metric) + Send + 'static,
+{
+    if url.starts_with("https://") {
+        anyhow::bail!("https is not supported by nyx-cli prometheus-get (TLS disabled)");
+    }
+    tokio::task::spawn_blocking(move || {
+        let agent = ureq::AgentBuilder::new()
+            .timeout_connect(std::time::Duration::from_secs(3))
+            .timeout(std::time::Duration::from_secs(8))
+            .build();
+        let resp = agent.get(&url).call()?;
+        let reader = std::io::BufReader::with_capacity(STREAM_BUF_CAPACITY, resp.into_reader());
+        let n = stream_exposition(reader, on_metric)?;
+        anyhow::Ok(n)
+    })
+    .await
+    .map_err(|e| anyhow::anyhow!("join error: {e}"))?
+}
+
+/// Read buffer size used by [`scrape_streaming`].
+const STREAM_BUF_CAPACITY: usize = 8 * 1024;
+
+/// Upper bound on a single exposition line; longer lines are an error rather
+/// than an unbounded allocation.
+const MAX_LINE_LEN: usize = 64 * 1024;
+
+/// Parse exposition text from `reader` one line at a time, reusing a single
+/// line buffer. Returns the number of samples passed to `on_metric`.
+pub fn stream_exposition<R, F>(mut reader: R, mut on_metric: F) -> std::io::Result<usize>
+where
+    R: BufRead,
+    F: FnMut(Metric),
+{
+    let mut parser = ExpositionParser::new();
+    let mut line = String::new();
+    let mut count = 0;
+    loop {
+        line.clear();
+        let n = std::io::Read::take(&mut reader, MAX_LINE_LEN as u64 + 1).read_line(&mut line)?;
+        if n == 0 {
+            break;
+        }
+        if n > MAX_LINE_LEN {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("exposition line exceeds {MAX_LINE_LEN} bytes"),
+            ));
+        }
+        if let Some(m) = parser.parse_line(&line) {
+            count += 1;
+            on_metric(m);
+        }
+    }
+    Ok(count)
+}
+
+/// Per-series change between two scrapes.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum SeriesDiff {
+    /// Monotonic series (counter, histogram/summary components): delta and per-second rate.
+    Counter {
+        series: String,
+        delta: f64,
+        rate: f64,
+    },
+    /// Point-in-time series (gauge/untyped): latest observed value.
+    Gauge { series: String, value: f64 },
+}
+
+impl std::fmt::Display for SeriesDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SeriesDiff::Counter {
+                series,
+                delta,
+                rate,
+            } => write!(f, "{series}\tcounter\tdelta={delta}\trate={rate:.3}/s"),
+            SeriesDiff::Gauge { series, value } => write!(f, "{series}\tgauge\tvalue={value}"),
+        }
+    }
+}
+
+/// Compare two parsed scrapes taken `elapsed` apart.
+///
+/// Counters that went backwards are treated as resets, so the delta is the
+/// post-reset value (Prometheus `increase()` semantics). Series present only
+/// in the first scrape are dropped.
+pub fn diff_metrics(
+    before: Vec<Metric>,
+    after: Vec<Metric>,
+    elapsed: std::time::Duration,
+) -> Vec<SeriesDiff> {
+    let prev: HashMap<String, f64> = before.into_iter().map(|m| (m.series(), m.value)).collect();
+    let secs = elapsed.as_secs_f64();
+
+    after
+        .into_iter()
+        .map(|m| {
+            let series = m.series();
+            if m.metric_type.is_cumulative() {
+                let old = prev.get(&series).copied().unwrap_or(0.0);
+                let delta = if m.value >= old {
+                    m.value - old
+                } else {
+                    m.value
+                };
+                let rate = if secs > 0.0 { delta / secs } else { 0.0 };
+                SeriesDiff::Counter {
+                    series,
+                    delta,
+                    rate,
+                }
+            } else {
+                SeriesDiff::Gauge {
+                    series,
+                    value: m.value,
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn diff_scrapes(before: &str, after: &str, elapsed: Duration) -> Vec<SeriesDiff> {
+        diff_metrics(parse_exposition(before), parse_exposition(after), elapsed)
+    }
+
+    #[test]
+    fn parse_exposition_structures_samples() {
+        let text = concat!(
+            "# HELP nyx_requests_total Requests processed\n",
+            "# TYPE nyx_requests_total counter\n",
+            "nyx_requests_total{op=\"get_info\",code=\"0\"} 12\n",
+            "nyx_requests_total{op=\"health\"} 3 1700000000000\n",
+            "# HELP nyx_temp Temperature\n",
+            "# TYPE nyx_temp gauge\n",
+            "nyx_temp -1.5\n",
+            "# TYPE nyx_latency histogram\n",
+            "nyx_latency_bucket{le=\"+Inf\"} 9\n",
+            "untyped_thing{path=\"a\\\"b\"} 1e3\n",
+            "\n",
+        );
+        let m = parse_exposition(text);
+        assert_eq!(m.len(), 5);
+
+        assert_eq!(m[0].name, "nyx_requests_total");
+        assert_eq!(m[0].metric_type, MetricType::Counter);
+        assert_eq!(m[0].value, 12.0);
+        assert_eq!(m[0].labels.get("op").map(String::as_str), Some("get_info"));
+        assert_eq!(m[0].labels.get("code").map(String::as_str), Some("0"));
+
+        assert_eq!(m[1].value, 3.0);
+        assert_eq!(m[1].labels.len(), 1);
+
+        assert_eq!(m[2].name, "nyx_temp");
+        assert_eq!(m[2].metric_type, MetricType::Gauge);
+        assert!(m[2].labels.is_empty());
+        assert_eq!(m[2].value, -1.5);
+
+        assert_eq!(m[3].metric_type, MetricType::Histogram);
+        assert_eq!(m[3].labels.get("le").map(String::as_str), Some("+Inf"));
+
+        assert_eq!(m[4].metric_type, MetricType::Untyped);
+        assert_eq!(m[4].labels.get("path").map(String::as_str), Some("a\"b"));
+        assert_eq!(m[4].value, 1000.0);
+        assert_eq!(m[4].series(), "untyped_thing{path=\"a\\\"b\"}");
+    }
+
+    #[test]
+    fn filter_keeps_only_matching_families() {
+        let text = concat!(
+            "# HELP nyx_requests_total Requests processed\n",
+            "# TYPE nyx_requests_total counter\n",
+            "nyx_requests_total{op=\"get_info\"} 12\n",
+            "# HELP process_cpu_seconds_total CPU time\n",
+            "# TYPE process_cpu_seconds_total counter\n",
+            "process_cpu_seconds_total 1.5\n",
+            "# TYPE nyx_latency histogram\n",
+            "nyx_latency_bucket{le=\"+Inf\"} 9\n",
+            "nyx_latency_sum 4.2\n",
+            "# some other comment\n",
+            "\n",
+        );
+        let out = filter_families(text, "nyx_");
+        assert_eq!(
+            out,
+            concat!(
+                "# HELP nyx_requests_total Requests processed\n",
+                "# TYPE nyx_requests_total counter\n",
+                "nyx_requests_total{op=\"get_info\"} 12\n",
+                "# TYPE nyx_latency histogram\n",
+                "nyx_latency_bucket{le=\"+Inf\"} 9\n",
+                "nyx_latency_sum 4.2\n",
+            )
+        );
+        // Types still resolve after filtering
+        let m = parse_exposition(&out);
+        assert_eq!(m.len(), 3);
+        assert_eq!(m[1].metric_type, MetricType::Histogram);
+        assert!(filter_families(text, "absent_").is_empty());
+    }
+
+    #[test]
+    fn diff_reports_counter_delta_and_gauge_value() {
+        let before = "# TYPE reqs counter\nreqs{op=\"a\"} 10\n# TYPE temp gauge\ntemp 3\n";
+        let after = "# TYPE reqs counter\nreqs{op=\"a\"} 15\n# TYPE temp gauge\ntemp 1.5\n";
+        let d = diff_scrapes(before, after, Duration::from_secs(5));
+        assert_eq!(
+            d,
+            vec![
+                SeriesDiff::Counter {
+                    series: "reqs{op=\"a\"}".into(),
+                    delta: 5.0,
+                    rate: 1.0
+                },
+                SeriesDiff::Gauge {
+                    series: "temp".into(),
+                    value: 1.5
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_treats_counter_decrease_as_reset() {
+        let before = "# TYPE c counter\nc 100\n";
+        let after = "# TYPE c counter\nc 4\n";
+        let d = diff_scrapes(before, after, Duration::from_secs(1));
+        assert!(matches!(&d[0], SeriesDiff::Counter { delta, .. } if *delta == 4.0));
+    }
+
+    /// Generates a synthetic exposition lazily so the test never holds it all.
+    struct SyntheticExposition {
+        series: usize,
+        next: usize,
+        pending: Vec<u8>,
+        pos: usize,
+    }
+
+    impl std::io::Read for SyntheticExposition {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if self.pos == self.pending.len() {
+                if self.next > self.series {
+                    return Ok(0);
+                }
+                self.pending = if self.next == 0 {
+                    b"# HELP big_total Many series\n# TYPE big_total counter\n".to_vec()
+                } else {
+                    format!("big_total{{shard=\"{}\"}} {}\n", self.next, self.next).into_bytes()
+                };
+                self.pos = 0;
+                self.next += 1;
+            }
+            let n = buf.len().min(self.pending.len() - self.pos);
+            buf[..n].copy_from_slice(&self.pending[self.pos..self.pos + n]);
+            self.pos += n;
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn stream_exposition_handles_large_input_with_bounded_buffer() {
+        const SERIES: usize = 200_000;
+        let source = SyntheticExposition {
+            series: SERIES,
+            next: 0,
+            pending: Vec::new(),
+            pos: 0,
+        };
+        let reader = std::io::BufReader::with_capacity(256, source);
+        assert_eq!(reader.capacity(), 256);
+
+        let mut sum = 0.0;
+        let mut counters = 0usize;
+        let n = stream_exposition(reader, |m| {
+            if m.metric_type == MetricType::Counter {
+                counters += 1;
+            }
+            sum += m.value;
+        })
+        .unwrap();
+        assert_eq!(n, SERIES);
+        assert_eq!(counters, SERIES);
+        assert_eq!(sum, (SERIES * (SERIES + 1) / 2) as f64);
+    }
+
+    #[test]
+    fn stream_exposition_rejects_oversized_line() {
+        let line = format!("x {}\n", "1".repeat(MAX_LINE_LEN + 10));
+        assert!(stream_exposition(line.as_bytes(), |_| {}).is_err());
+    }
+}
@@ -0,0 +1,218 @@
+#![forbid(unsafe_code)]
+
+//! Client configuration resolution.
+//!
+//! Each setting is taken from the highest-precedence source that provides it:
+//!
+//! 1. CLI flags (`--endpoint`, `--timeout-ms`, `--token`)
+//! 2. Environment (`NYX_DAEMON_ENDPOINT`, `NYX_REQUEST_TIMEOUT_MS`,
+//!    `NYX_CONTROL_TOKEN`, then `NYX_TOKEN`)
+//! 3. Cookie file (token only)
+//! 4. Config file (`[cli]` section of `nyx.toml`)
+//! 5. Built-in defaults
+//!
+//! Sources are gathered by the caller; [`resolve_config`] is pure so the
+//! precedence can be tested without touching the environment.
+
+use nyx_sdk::SdkConfig;
+use serde::Serialize;
+
+/// Where a resolved value came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Source {
+    Cli,
+    Env,
+    Cookie,
+    File,
+    Default,
+}
+
+/// Settings provided by a single source; `None` means "not set here".
+#[derive(Debug, Clone, Default)]
+pub struct ConfigLayer {
+    pub endpoint: Option<String>,
+    pub token: Option<String>,
+    pub timeout_ms: Option<u64>,
+}
+
+/// All sources that can contribute to the client configuration.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigSources {
+    pub cli: ConfigLayer,
+    pub env: ConfigLayer,
+    pub cookie: ConfigLayer,
+    pub file: ConfigLayer,
+}
+
+/// A resolved value annotated with its origin.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sourced<T> {
+    pub value: T,
+    pub source: Source,
+}
+
+#[derive(Debug, Clone)]
+pub struct ResolvedConfig {
+    pub daemon_endpoint: Sourced<String>,
+    pub request_timeout_ms: Sourced<u64>,
+    /// No default exists for the token, so it may be absent entirely.
+    pub token: Option<Sourced<String>>,
+}
+
+impl ConfigSources {
+    /// Layers in descending precedence.
+    fn ordered(&self) -> [(Source, &ConfigLayer); 4] {
+        [
+            (Source::Cli, &self.cli),
+            (Source::Env, &self.env),
+            (Source::Cookie, &self.cookie),
+            (Source::File, &self.file),
+        ]
+    }
+
+    fn pick<T: Clone>(&self, field: impl Fn(&ConfigLayer) -> Option<&T>) -> Option<Sourced<T>> {
+        self.ordered().into_iter().find_map(|(source, layer)| {
+            field(layer).map(|value| Sourced {
+                value: value.clone(),
+                source,
+            })
+        })
+    }
+}
+
+/// Merge `sources` field by field using the documented precedence.
+pub fn resolve_config(sources: &ConfigSources) -> ResolvedConfig {
+    let defaults = SdkConfig::default();
+    ResolvedConfig {
+        daemon_endpoint: sources.pick(|l| l.endpoint.as_ref()).unwrap_or(Sourced {
+            value: defaults.daemon_endpoint,
+            source: Source::Default,
+        }),
+        request_timeout_ms: sources.pick(|l| l.timeout_ms.as_ref()).unwrap_or(Sourced {
+            value: defaults.request_timeout_ms,
+            source: Source::Default,
+        }),
+        token: sources.pick(|l| l.token.as_ref()),
+    }
+}
+
+impl ResolvedConfig {
+    pub fn sdk_config(&self) -> SdkConfig {
+        SdkConfig {
+            daemon_endpoint: self.daemon_endpoint.value.clone(),
+            request_timeout_ms: self.request_timeout_ms.value,
+            token: self.token.as_ref().map(|t| t.value.clone()),
+            ..SdkConfig::default()
+        }
+    }
+
+    /// Source of each field, keyed like `config show` output.
+    pub fn sources_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "daemon_endpoint": self.daemon_endpoint.source,
+            "request_timeout_ms": self.request_timeout_ms.source,
+            "token": self.token.as_ref().map(|t| t.source),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layer(endpoint: &str, token: &str, timeout_ms: u64) -> ConfigLayer {
+        ConfigLayer {
+            endpoint: Some(endpoint.into()),
+            token: Some(token.into()),
+            timeout_ms: Some(timeout_ms),
+        }
+    }
+
+    #[test]
+    fn empty_sources_fall_back_to_defaults() {
+        let r = resolve_config(&ConfigSources::default());
+        assert_eq!(r.daemon_endpoint.source, Source::Default);
+        assert_eq!(r.daemon_endpoint.value, SdkConfig::default_endpoint());
+        assert_eq!(r.request_timeout_ms.source, Source::Default);
+        assert!(r.token.is_none());
+    }
+
+    #[test]
+    fn cli_beats_every_other_source() {
+        let sources = ConfigSources {
+            cli: layer("cli-ep", "cli-tok", 1),
+            env: layer("env-ep", "env-tok", 2),
+            cookie: ConfigLayer {
+                token: Some("cookie-tok".into()),
+                ..Default::default()
+            },
+            file: layer("file-ep", "file-tok", 4),
+        };
+        let r = resolve_config(&sources);
+        assert_eq!(r.daemon_endpoint.value, "cli-ep");
+        assert_eq!(r.request_timeout_ms.value, 1);
+        assert_eq!(r.token.map(|t| t.value).as_deref(), Some("cli-tok"));
+    }
+
+    #[test]
+    fn env_beats_cookie_and_file() {
+        let sources = ConfigSources {
+            env: layer("env-ep", "env-tok", 2),
+            cookie: ConfigLayer {
+                token: Some("cookie-tok".into()),
+                ..Default::default()
+            },
+            file: layer("file-ep", "file-tok", 4),
+            ..Default::default()
+        };
+        let r = resolve_config(&sources);
+        assert_eq!(r.daemon_endpoint.source, Source::Env);
+        // The file used to win for the timeout; env now takes precedence.
+        assert_eq!(r.request_timeout_ms.value, 2);
+        assert_eq!(r.request_timeout_ms.source, Source::Env);
+        assert_eq!(r.token.map(|t| t.source), Some(Source::Env));
+    }
+
+    #[test]
+    fn cookie_beats_file_for_token_only() {
+        let sources = ConfigSources {
+            cookie: ConfigLayer {
+                token: Some("cookie-tok".into()),
+                ..Default::default()
+            },
+            file: layer("file-ep", "file-tok", 4),
+            ..Default::default()
+        };
+        let r = resolve_config(&sources);
+        assert_eq!(r.token.map(|t| t.source), Some(Source::Cookie));
+        assert_eq!(r.daemon_endpoint.source, Source::File);
+        assert_eq!(r.request_timeout_ms.source, Source::File);
+    }
+
+    #[test]
+    fn fields_resolve_independently() {
+        let sources = ConfigSources {
+            cli: ConfigLayer {
+                timeout_ms: Some(9),
+                ..Default::default()
+            },
+            env: ConfigLayer {
+                endpoint: Some("env-ep".into()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let r = resolve_config(&sources);
+        assert_eq!(r.request_timeout_ms.source, Source::Cli);
+        assert_eq!(r.daemon_endpoint.source, Source::Env);
+        assert_eq!(
+            r.sources_json(),
+            serde_json::json!({
+                "daemon_endpoint": "env",
+                "request_timeout_ms": "cli",
+                "token": null,
+            })
+        );
+    }
+}
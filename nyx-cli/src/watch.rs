@@ -0,0 +1,143 @@
+#![forbid(unsafe_code)]
+
+//! `nyx-cli watch`: poll daemon health on an interval.
+//!
+//! The loop is generic over [`HealthSource`] so it can be driven without a
+//! running daemon.
+
+use nyx_sdk::daemon::DaemonClient;
+use serde_json::{json, Value};
+use std::future::Future;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+
+/// Anything that can answer a health probe.
+pub trait HealthSource {
+    fn health(&self) -> impl Future<Output = nyx_sdk::Result<Value>>;
+}
+
+impl HealthSource for DaemonClient {
+    fn health(&self) -> impl Future<Output = nyx_sdk::Result<Value>> {
+        DaemonClient::health(self)
+    }
+}
+
+/// Totals reported when the loop stops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WatchSummary {
+    pub polls: u64,
+    pub failures: u64,
+}
+
+/// Poll `source` every `interval` until `count` polls have run (forever if
+/// `None`) or `stop` fires. Each poll produces one status object for `report`;
+/// a poll fails on an RPC error or when the daemon reports `healthy: false`.
+pub async fn run_watch<H: HealthSource>(
+    source: &H,
+    interval: Duration,
+    count: Option<u64>,
+    stop: &mut mpsc::Receiver<()>,
+    mut report: impl FnMut(&Value),
+) -> WatchSummary {
+    let mut summary = WatchSummary {
+        polls: 0,
+        failures: 0,
+    };
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    while count.is_none_or(|n| summary.polls < n) {
+        tokio::select! {
+            _ = stop.recv() => break,
+            _ = ticker.tick() => {}
+        }
+        summary.polls += 1;
+        let started = Instant::now();
+        let res = source.health().await;
+        let latency_ms = started.elapsed().as_secs_f64() * 1000.0;
+        let status = match res {
+            Ok(v) if v.get("healthy").and_then(Value::as_bool) != Some(false) => json!({
+                "seq": summary.polls,
+                "ok": true,
+                "latency_ms": latency_ms,
+            }),
+            Ok(v) => json!({
+                "seq": summary.polls,
+                "ok": false,
+                "latency_ms": latency_ms,
+                "components": v.get("components").cloned().unwrap_or(Value::Null),
+            }),
+            Err(e) => json!({
+                "seq": summary.polls,
+                "ok": false,
+                "error": e.to_string(),
+            }),
+        };
+        if status["ok"] == false {
+            summary.failures += 1;
+        }
+        report(&status);
+    }
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    /// Answers healthy, except for the polls listed in `fail_on` (1-based).
+    struct Mock {
+        calls: Cell<u64>,
+        fail_on: &'static [u64],
+    }
+
+    impl HealthSource for Mock {
+        async fn health(&self) -> nyx_sdk::Result<Value> {
+            let n = self.calls.get() + 1;
+            self.calls.set(n);
+            if self.fail_on.contains(&n) {
+                Err(nyx_sdk::Error::Timeout)
+            } else {
+                Ok(json!({"healthy": n != 3}))
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn stops_after_count_and_counts_failures() {
+        let mock = Mock {
+            calls: Cell::new(0),
+            fail_on: &[2],
+        };
+        let (_tx, mut stop) = mpsc::channel(1);
+        let mut lines = Vec::new();
+        let summary = run_watch(&mock, Duration::from_millis(1), Some(4), &mut stop, |s| {
+            lines.push(s.clone())
+        })
+        .await;
+
+        assert_eq!(mock.calls.get(), 4);
+        assert_eq!(
+            summary,
+            WatchSummary {
+                polls: 4,
+                failures: 2
+            }
+        );
+        let ok: Vec<bool> = lines.iter().map(|l| l["ok"] == true).collect();
+        assert_eq!(ok, [true, false, false, true]);
+        assert_eq!(lines[1]["error"], "timeout");
+    }
+
+    #[tokio::test]
+    async fn stop_signal_ends_unbounded_watch() {
+        let mock = Mock {
+            calls: Cell::new(0),
+            fail_on: &[],
+        };
+        let (tx, mut stop) = mpsc::channel(1);
+        tx.send(()).await.unwrap();
+        let summary = run_watch(&mock, Duration::from_millis(1), None, &mut stop, |_| {}).await;
+        assert_eq!(summary.polls, 0);
+    }
+}
@@ -0,0 +1,27 @@
+#![forbid(unsafe_code)]
+
+use assert_cmd::prelude::*;
+use std::process::Command;
+
+#[test]
+fn completions_cover_subcommands_for_each_shell() -> Result<(), Box<dyn std::error::Error>> {
+    for shell in ["bash", "zsh", "fish", "powershell"] {
+        let mut cmd = Command::cargo_bin("nyx-cli")?;
+        cmd.args(["completions", shell]);
+        let out = cmd.assert().success().get_output().stdout.clone();
+        let script = String::from_utf8(out)?;
+        assert!(!script.is_empty(), "{shell}: empty completion script");
+        for sub in ["info", "events", "frame-limit"] {
+            assert!(script.contains(sub), "{shell}: missing subcommand {sub}");
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn completions_reject_unknown_shell() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("nyx-cli")?;
+    cmd.args(["completions", "tcsh"]);
+    cmd.assert().failure();
+    Ok(())
+}
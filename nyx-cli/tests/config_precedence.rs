@@ -0,0 +1,65 @@
+#![forbid(unsafe_code)]
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::process::Command;
+
+/// Isolated home with a config file and cookie, so only the given sources apply.
+fn sandbox() -> Result<tempfile::TempDir, Box<dyn std::error::Error>> {
+    let dir = tempfile::tempdir()?;
+    std::fs::write(
+        dir.path().join("nyx.toml"),
+        "[cli]\ndaemon_endpoint = \"/file/nyx.sock\"\nrequest_timeout_ms = 4000\ntoken = \"file-token\"\n",
+    )?;
+    std::fs::write(dir.path().join("cookie"), "cookie-token\n")?;
+    Ok(dir)
+}
+
+fn config_show(dir: &tempfile::TempDir) -> Result<Command, Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("nyx-cli")?;
+    cmd.current_dir(dir.path())
+        .env_clear()
+        .env("HOME", dir.path())
+        .env("APPDATA", dir.path())
+        .env("NYX_CONFIG", dir.path().join("nyx.toml"))
+        .env("NYX_DAEMON_COOKIE", dir.path().join("cookie"));
+    Ok(cmd)
+}
+
+#[test]
+fn each_field_reports_highest_precedence_source() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = sandbox()?;
+    let mut cmd = config_show(&dir)?;
+    cmd.env("NYX_REQUEST_TIMEOUT_MS", "2000").args([
+        "--endpoint",
+        "/cli/nyx.sock",
+        "config",
+        "show",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "\"daemon_endpoint\": \"/cli/nyx.sock\"",
+        ))
+        .stdout(predicate::str::contains("\"request_timeout_ms\": 2000"))
+        .stdout(predicate::str::contains("\"daemon_endpoint\": \"cli\""))
+        .stdout(predicate::str::contains("\"request_timeout_ms\": \"env\""))
+        .stdout(predicate::str::contains("\"token\": \"cookie\""));
+    Ok(())
+}
+
+#[test]
+fn file_fills_fields_no_other_source_sets() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = sandbox()?;
+    std::fs::remove_file(dir.path().join("cookie"))?;
+    let mut cmd = config_show(&dir)?;
+    cmd.args(["--output", "plain", "config", "show"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("daemon_endpoint=/file/nyx.sock"))
+        .stdout(predicate::str::contains("request_timeout_ms=4000"))
+        .stdout(predicate::str::contains("sources.daemon_endpoint=file"))
+        .stdout(predicate::str::contains("sources.request_timeout_ms=file"))
+        .stdout(predicate::str::contains("sources.token=file"));
+    Ok(())
+}
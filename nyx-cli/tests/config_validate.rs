@@ -0,0 +1,49 @@
+#![forbid(unsafe_code)]
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::process::Command;
+use tempfile::tempdir;
+
+fn validate(contents: &str) -> Result<assert_cmd::assert::Assert, Box<dyn std::error::Error>> {
+    let dir = tempdir()?;
+    let path = dir.path().join("nyx.toml");
+    std::fs::write(&path, contents)?;
+    let mut cmd = Command::cargo_bin("nyx-cli")?;
+    cmd.args(["config", "validate"]).arg(&path);
+    Ok(cmd.assert())
+}
+
+#[test]
+fn validate_accepts_valid_file() -> Result<(), Box<dyn std::error::Error>> {
+    let repo_config = include_str!("../../nyx.toml");
+    validate(repo_config)?
+        .success()
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains("is valid"));
+    Ok(())
+}
+
+#[test]
+fn validate_lists_schema_violations() -> Result<(), Box<dyn std::error::Error>> {
+    let contents = "log_level = \"loud\"\n\n[endpoints]\ngrpc_addr = \"not-an-addr\"\n\n[quic]\nidle_timeout_secs = 5\nkeep_alive_interval_secs = 10\n";
+    validate(contents)?
+        .code(1)
+        .stdout(predicate::str::contains(":1: log_level: must be one of"))
+        .stdout(predicate::str::contains(
+            ":4: endpoints.grpc_addr: invalid socket address",
+        ))
+        .stdout(predicate::str::contains(
+            ":8: quic.keep_alive_interval_secs: must be less than",
+        ))
+        .stderr(predicate::str::contains("3 violation(s)"));
+    Ok(())
+}
+
+#[test]
+fn validate_reports_malformed_toml() -> Result<(), Box<dyn std::error::Error>> {
+    validate("listen_port = 43300\n[cli\ntoken = \"x\"\n")?
+        .code(2)
+        .stderr(predicate::str::contains("line 2"));
+    Ok(())
+}
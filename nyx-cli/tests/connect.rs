@@ -0,0 +1,42 @@
+#![forbid(unsafe_code)]
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::net::UdpSocket;
+use std::process::Command;
+
+#[test]
+fn connect_reports_udp_against_loopback_echo() -> Result<(), Box<dyn std::error::Error>> {
+    let echo = UdpSocket::bind("127.0.0.1:0")?;
+    let addr = echo.local_addr()?;
+    std::thread::spawn(move || {
+        let mut buf = [0u8; 512];
+        while let Ok((n, from)) = echo.recv_from(&mut buf) {
+            let _ = echo.send_to(&buf[..n], from);
+        }
+    });
+
+    let mut cmd = Command::cargo_bin("nyx-cli")?;
+    cmd.args(["--output", "json", "connect", &addr.to_string()]);
+    let out = cmd.assert().success().get_output().stdout.clone();
+    let v: serde_json::Value = serde_json::from_slice(&out)?;
+
+    assert_eq!(v["transport"], "udp");
+    assert_eq!(v["endpoint"], addr.to_string());
+    assert!(v["rtt_ms"].as_f64().unwrap() >= 0.0);
+    Ok(())
+}
+
+#[test]
+fn connect_fails_when_peer_is_silent() -> Result<(), Box<dyn std::error::Error>> {
+    // Bound but never answers, so the probe must time out.
+    let silent = UdpSocket::bind("127.0.0.1:0")?;
+    let addr = silent.local_addr()?;
+
+    let mut cmd = Command::cargo_bin("nyx-cli")?;
+    cmd.args(["--timeout-ms", "200", "connect", &addr.to_string()]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("no reply"));
+    Ok(())
+}
@@ -0,0 +1,41 @@
+#![forbid(unsafe_code)]
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::process::Command;
+
+#[test]
+fn tcp_endpoint_is_rejected_before_connecting() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("nyx-cli")?;
+    cmd.args(["--endpoint", "127.0.0.1:43300", "info"]);
+    cmd.assert()
+        .code(2)
+        .stderr(predicate::str::contains("invalid daemon endpoint"))
+        .stderr(predicate::str::contains("TCP endpoints are not supported"))
+        .stderr(predicate::str::contains("expected e.g."));
+    Ok(())
+}
+
+#[test]
+fn platform_endpoint_passes_validation() -> Result<(), Box<dyn std::error::Error>> {
+    let endpoint = if cfg!(windows) {
+        r"\\.\pipe\nyx-endpoint-validation-test"
+    } else {
+        "/nonexistent/nyx-endpoint-validation-test.sock"
+    };
+    let mut cmd = Command::cargo_bin("nyx-cli")?;
+    cmd.args(["--endpoint", endpoint, "info"]);
+    // Nothing is listening, so the request fails, but not on endpoint format.
+    cmd.assert()
+        .code(1)
+        .stderr(predicate::str::contains("invalid daemon endpoint").not());
+    Ok(())
+}
+
+#[test]
+fn endpoint_is_not_checked_for_local_commands() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("nyx-cli")?;
+    cmd.args(["--endpoint", "127.0.0.1:43300", "completions", "bash"]);
+    cmd.assert().success();
+    Ok(())
+}
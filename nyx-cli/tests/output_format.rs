@@ -0,0 +1,167 @@
+#![forbid(unsafe_code)]
+
+use assert_cmd::prelude::*;
+use std::process::Command;
+
+fn run(args: &[&str]) -> Result<String, Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("nyx-cli")?;
+    cmd.args(args)
+        .env("NYX_DAEMON_ENDPOINT", "test-endpoint")
+        .env("NYX_REQUEST_TIMEOUT_MS", "1234")
+        .env("NYX_CONTROL_TOKEN", "secret");
+    let out = cmd.assert().success().get_output().stdout.clone();
+    Ok(String::from_utf8(out)?)
+}
+
+#[test]
+fn json_mode_emits_valid_json() -> Result<(), Box<dyn std::error::Error>> {
+    let v: serde_json::Value =
+        serde_json::from_str(&run(&["--output", "json", "config", "show"])?)?;
+    assert_eq!(v["daemon_endpoint"], "test-endpoint");
+    assert_eq!(v["request_timeout_ms"], 1234);
+
+    // The flag is global, so it may also follow the subcommand.
+    let v: serde_json::Value = serde_json::from_str(&run(&["frame-limit", "--output", "json"])?)?;
+    assert!(v["max_frame_len_bytes"].as_u64().unwrap() > 0);
+    Ok(())
+}
+
+#[test]
+fn table_mode_emits_aligned_rows() -> Result<(), Box<dyn std::error::Error>> {
+    let out = run(&["--output", "table", "config", "show"])?;
+    let lines: Vec<&str> = out.lines().collect();
+    // Header, three values and the source of each value.
+    assert_eq!(lines.len(), 7);
+    assert!(lines[0].starts_with("KEY"));
+    let col = lines[0].find("VALUE").unwrap();
+    for line in &lines[1..] {
+        assert_eq!(line.as_bytes()[col - 1], b' ');
+        assert_ne!(line.as_bytes()[col], b' ');
+    }
+    assert!(out.contains("test-endpoint"));
+
+    let out = run(&["--output", "table", "frame-limit"])?;
+    assert_eq!(out.lines().count(), 2);
+    assert!(out
+        .lines()
+        .nth(1)
+        .unwrap()
+        .starts_with("max_frame_len_bytes"));
+    Ok(())
+}
+
+#[test]
+fn plain_mode_emits_key_value_lines() -> Result<(), Box<dyn std::error::Error>> {
+    let out = run(&["--output", "plain", "config", "show"])?;
+    assert!(out.lines().any(|l| l == "daemon_endpoint=test-endpoint"));
+    assert!(out.lines().any(|l| l == "token_present=true"));
+
+    let out = run(&["--output", "plain", "frame-limit"])?;
+    assert!(out.trim().starts_with("max_frame_len_bytes="));
+    Ok(())
+}
+
+#[test]
+fn default_output_is_unchanged() -> Result<(), Box<dyn std::error::Error>> {
+    let out = run(&["frame-limit"])?;
+    assert!(out.trim().parse::<u64>().is_ok());
+    Ok(())
+}
+
+/// Serve canned daemon replies keyed by op on a fresh Unix socket.
+#[cfg(unix)]
+fn mock_daemon(dir: &tempfile::TempDir) -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
+    use std::io::{BufRead, BufReader, Write};
+    let path = dir.path().join("nyx.sock");
+    let listener = std::os::unix::net::UnixListener::bind(&path)?;
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let mut line = String::new();
+            if BufReader::new(&stream).read_line(&mut line).is_err() {
+                continue;
+            }
+            let req: serde_json::Value = serde_json::from_str(&line).unwrap_or_default();
+            let data = match req["op"].as_str() {
+                Some("get_info") => serde_json::json!({"version": "1.2.3", "uptime": 42}),
+                Some("list_config_versions") => serde_json::json!([
+                    {"version": 1, "description": "initial"},
+                    {"version": 2, "description": "tuned"}
+                ]),
+                Some("health") => serde_json::json!({
+                    "healthy": true,
+                    "timestamp": "2024-01-01T00:00:00Z",
+                    "components": {"transport": "ok", "config": "ok"}
+                }),
+                _ => serde_json::Value::Null,
+            };
+            let resp = serde_json::json!({"ok": true, "code": 0, "data": data});
+            let _ = (&stream).write_all(format!("{resp}\n").as_bytes());
+        }
+    });
+    Ok(path)
+}
+
+#[cfg(unix)]
+fn run_daemon_cmd(
+    path: &std::path::Path,
+    args: &[&str],
+) -> Result<String, Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("nyx-cli")?;
+    cmd.args(["--endpoint", path.to_str().unwrap()]).args(args);
+    let out = cmd.assert().success().get_output().stdout.clone();
+    Ok(String::from_utf8(out)?)
+}
+
+#[cfg(unix)]
+#[test]
+fn info_json_is_unchanged_and_plain_is_key_value() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = tempfile::tempdir()?;
+    let path = mock_daemon(&dir)?;
+
+    let expected = serde_json::to_string_pretty(&serde_json::json!({
+        "ok": true,
+        "data": {"version": "1.2.3", "uptime": 42}
+    }))?;
+    assert_eq!(run_daemon_cmd(&path, &["info"])?.trim_end(), expected);
+    assert_eq!(
+        run_daemon_cmd(&path, &["--output", "json", "info"])?.trim_end(),
+        expected
+    );
+
+    let out = run_daemon_cmd(&path, &["--output", "plain", "info"])?;
+    let lines: Vec<&str> = out.lines().collect();
+    assert_eq!(lines, ["data.uptime=42", "data.version=1.2.3", "ok=true"]);
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn table_mode_renders_daemon_payloads() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = tempfile::tempdir()?;
+    let path = mock_daemon(&dir)?;
+
+    let out = run_daemon_cmd(&path, &["--output", "table", "info"])?;
+    assert_eq!(
+        out.trim_end(),
+        "KEY      VALUE\nuptime   42\nversion  1.2.3"
+    );
+
+    let out = run_daemon_cmd(&path, &["--output", "table", "list-versions"])?;
+    let lines: Vec<&str> = out.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("description") && lines[0].contains("version"));
+    assert!(lines[2].starts_with("tuned"));
+
+    let out = run_daemon_cmd(&path, &["--output", "table", "health"])?;
+    let lines: Vec<&str> = out.lines().collect();
+    assert_eq!(
+        lines,
+        [
+            "component  status",
+            "overall    healthy",
+            "config     ok",
+            "transport  ok"
+        ]
+    );
+    Ok(())
+}
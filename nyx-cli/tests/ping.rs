@@ -0,0 +1,76 @@
+#![forbid(unsafe_code)]
+#![cfg(unix)]
+
+use assert_cmd::prelude::*;
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::UnixListener;
+use std::process::Command;
+
+#[test]
+fn ping_reports_latency_against_healthy_daemon() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("nyx.sock");
+    let listener = UnixListener::bind(&path)?;
+    // Minimal daemon answering every request with a successful info response
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let mut reader = BufReader::new(&stream);
+            let mut line = String::new();
+            if reader.read_line(&mut line).is_ok() {
+                let _ = (&stream).write_all(
+                    b"{\"ok\":true,\"code\":0,\"id\":null,\"data\":{\"version\":\"test\"}}\n",
+                );
+            }
+        }
+    });
+
+    let mut cmd = Command::cargo_bin("nyx-cli")?;
+    cmd.args(["--endpoint", path.to_str().unwrap()]).args([
+        "--output",
+        "json",
+        "ping",
+        "--count",
+        "4",
+        "--interval-ms",
+        "0",
+    ]);
+    let out = cmd.assert().success().get_output().stdout.clone();
+    let v: serde_json::Value = serde_json::from_slice(&out)?;
+
+    assert_eq!(v["sent"], 4);
+    assert_eq!(v["received"], 4);
+    assert_eq!(v["failure_rate"], 0.0);
+    let rtt = &v["rtt_ms"];
+    let (min, avg, p95, max) = (
+        rtt["min"].as_f64().unwrap(),
+        rtt["avg"].as_f64().unwrap(),
+        rtt["p95"].as_f64().unwrap(),
+        rtt["max"].as_f64().unwrap(),
+    );
+    assert!(min >= 0.0);
+    assert!(min <= avg && avg <= max);
+    assert!(min <= p95 && p95 <= max);
+    Ok(())
+}
+
+#[test]
+fn ping_fails_when_daemon_is_down() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("missing.sock");
+    let mut cmd = Command::cargo_bin("nyx-cli")?;
+    cmd.args(["--endpoint", path.to_str().unwrap()]).args([
+        "--output",
+        "json",
+        "ping",
+        "--count",
+        "2",
+        "--interval-ms",
+        "0",
+    ]);
+    let out = cmd.assert().code(1).get_output().stdout.clone();
+    let v: serde_json::Value = serde_json::from_slice(&out)?;
+    assert_eq!(v["received"], 0);
+    assert_eq!(v["failure_rate"], 1.0);
+    assert!(v["rtt_ms"].is_null());
+    Ok(())
+}
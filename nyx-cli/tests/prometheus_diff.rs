@@ -0,0 +1,71 @@
+#![forbid(unsafe_code)]
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::process::Command;
+
+#[test]
+fn prometheus_diff_reports_counter_delta() -> Result<(), Box<dyn std::error::Error>> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let addr = listener.local_addr()?;
+    // Minimal HTTP server whose counter grows by 7 on each scrape
+    std::thread::spawn(move || {
+        let mut counter = 10u64;
+        for mut stream in listener.incoming().flatten().take(2) {
+            let mut buf = [0u8; 1024];
+            let _ = stream.read(&mut buf);
+            let body = format!(
+                "# TYPE nyx_test_total counter\nnyx_test_total {counter}\n# TYPE nyx_gauge gauge\nnyx_gauge 42\n"
+            );
+            let resp = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            let _ = stream.write_all(resp.as_bytes());
+            counter += 7;
+        }
+    });
+
+    let mut cmd = Command::cargo_bin("nyx-cli")?;
+    cmd.arg("prometheus-diff")
+        .arg(format!("http://{addr}/metrics"))
+        .arg("--interval-ms")
+        .arg("50");
+    cmd.assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "nyx_test_total\tcounter\tdelta=7",
+        ))
+        .stdout(predicates::str::contains("nyx_gauge\tgauge\tvalue=42"));
+    Ok(())
+}
+
+#[test]
+fn prometheus_get_plain_output_is_key_value() -> Result<(), Box<dyn std::error::Error>> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let addr = listener.local_addr()?;
+    std::thread::spawn(move || {
+        if let Some(mut stream) = listener.incoming().flatten().next() {
+            let mut buf = [0u8; 1024];
+            let _ = stream.read(&mut buf);
+            let body = "# TYPE nyx_gauge gauge\nnyx_gauge 42\n";
+            let resp = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            let _ = stream.write_all(resp.as_bytes());
+        }
+    });
+
+    let mut cmd = Command::cargo_bin("nyx-cli")?;
+    cmd.args(["--output", "plain", "prometheus-get"])
+        .arg(format!("http://{addr}/metrics"));
+    cmd.assert()
+        .success()
+        .stdout(predicates::str::contains("name=nyx_gauge"))
+        .stdout(predicates::str::contains("value=42"))
+        .stdout(predicates::str::contains("# TYPE").not());
+    Ok(())
+}
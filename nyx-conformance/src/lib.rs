@@ -32,7 +32,9 @@
 pub mod network_simulator;
 pub mod property_tester;
 
-pub use network_simulator::{DeliveryEvent, MultiPathSimulator, NetworkSimulator, SimConfig};
+pub use network_simulator::{
+    DeliveryEvent, LossPreset, MultiPathSimulator, NetworkSimulator, SimConfig,
+};
 pub use property_tester::{check_monotonic_increasing, checknon_decreasing_ep_s, MonotonicError};
 
 #[cfg(test)]
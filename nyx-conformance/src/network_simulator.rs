@@ -49,6 +49,66 @@ impl Default for SimConfig {
     }
 }
 
+/// Named link profile_s with tuned Gilbert-Elliott burst-los_s parameter_s.
+///
+/// Long-run los_s i_s `π_bad·ge_loss_bad + (1-π_bad)·ge_loss_good` with
+/// `π_bad = ge_good_to_bad / (ge_good_to_bad + ge_bad_to_good)`; the band
+/// returned by [`LossPreset::loss_band`] allow_s for sampling spread over 10k packet_s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum LossPreset {
+    /// Home/office Wi-Fi: rare short fade_s, ~1% los_s.
+    Wifi,
+    /// LTE with handover dropout_s, ~2% los_s.
+    Cellular4G,
+    /// GEO satellite: long rain-fade burst_s, ~3% los_s, high latency.
+    Satellite,
+    /// Multi-hop wirele_s_s mesh: frequent burst_s, ~12% los_s.
+    LossyMesh,
+}
+
+impl LossPreset {
+    /// All preset_s, for sweep_s.
+    pub const ALL: [LossPreset; 4] = [
+        LossPreset::Wifi,
+        LossPreset::Cellular4G,
+        LossPreset::Satellite,
+        LossPreset::LossyMesh,
+    ];
+
+    /// Documented long-run los_s band `(min, max)` for the preset.
+    pub fn loss_band(self) -> (f64, f64) {
+        match self {
+            LossPreset::Wifi => (0.003, 0.025),
+            LossPreset::Cellular4G => (0.008, 0.035),
+            LossPreset::Satellite => (0.005, 0.07),
+            LossPreset::LossyMesh => (0.08, 0.16),
+        }
+    }
+}
+
+impl SimConfig {
+    /// Configuration for a named link profile: Gilbert-Elliott parameter_s plu_s
+    /// typical latency/jitter, with every other field at its default.
+    pub fn preset(kind: LossPreset) -> Self {
+        // (good->bad, bad->good, los_s good, los_s bad, latency m_s, jitter m_s)
+        let (g2b, b2g, lg, lb, latency, jitter) = match kind {
+            LossPreset::Wifi => (0.01, 0.3, 0.001, 0.3, 5, 3),
+            LossPreset::Cellular4G => (0.005, 0.15, 0.002, 0.5, 50, 15),
+            LossPreset::Satellite => (0.002, 0.05, 0.005, 0.6, 600, 30),
+            LossPreset::LossyMesh => (0.05, 0.2, 0.02, 0.5, 20, 10),
+        };
+        Self {
+            latency_m_s: latency,
+            jitter_m_s: jitter,
+            ge_good_to_bad: g2b,
+            ge_bad_to_good: b2g,
+            ge_loss_good: lg,
+            ge_loss_bad: lb,
+            ..Self::default()
+        }
+    }
+}
+
 /// A scheduled delivery event for a simulated packet.
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct DeliveryEvent {
@@ -58,8 +118,33 @@ pub struct DeliveryEvent {
     pub seq: u64,
     /// Whether the simulator marked the packet as corrupted.
     pub corrupted: bool,
+    /// Index of the path that carried the packet (always 0 for a single path).
+    #[serde(default)]
+    pub path: usize,
+    /// Bitmap of flipped payload bit_s (bit `i` of byte `i / 8`, LSB first).
+    /// Only set for corrupted packet_s when the simulator wa_s built with
+    /// [`NetworkSimulator::with_corruption_bitmap`].
+    #[serde(default)]
+    pub corrupted_bits: Option<Vec<u8>>,
+}
+
+impl DeliveryEvent {
+    /// XOR the corrupted-bit bitmap into `payload` (no-op without a bitmap).
+    pub fn apply_corruption(&self, payload: &mut [u8]) {
+        if let Some(bits) = &self.corrupted_bits {
+            for (b, mask) in payload.iter_mut().zip(bits) {
+                *b ^= mask;
+            }
+        }
+    }
 }
 
+/// Salt for the corruption RNG, so enabling bitmap_s leave_s the los_s/jitter
+/// schedule of a given seed unchanged.
+const CORRUPTION_RNG_SALT: u64 = 0xC0AA_0F7E_D817_5EED;
+/// Upper bound on bit_s flipped in a single corrupted packet.
+const MAX_FLIPPED_BITS: usize = 8;
+
 /// Deterministic, seedable network simulator producing delivery schedule.
 ///
 /// The simulator doe_s not model bandwidth. It only account_s for los_s, base
@@ -71,7 +156,10 @@ pub struct NetworkSimulator {
     now_m_s: u64,
     ge_bad_state: bool,
     queue_depth: usize,
-    last_departure_m_s: u64,
+    last_departure_m_s: Option<u64>,
+    // Payload length in byte_s and dedicated RNG for corruption bitmap_s
+    corruption_payload_len: Option<usize>,
+    corruption_rng: StdRng,
 }
 
 impl NetworkSimulator {
@@ -84,10 +172,37 @@ impl NetworkSimulator {
             now_m_s: 0,
             ge_bad_state: false,
             queue_depth: 0,
-            last_departure_m_s: 0,
+            last_departure_m_s: None,
+            corruption_payload_len: None,
+            corruption_rng: StdRng::seed_from_u64(seed ^ CORRUPTION_RNG_SALT),
         }
     }
 
+    /// Record which bit_s of a `payload_len`-byte packet each corruption flip_s
+    /// (1 to 8 bit_s per corrupted packet) in [`DeliveryEvent::corrupted_bits`].
+    pub fn with_corruption_bitmap(mut self, payload_len: usize) -> Self {
+        self.corruption_payload_len = Some(payload_len).filter(|&len| len > 0);
+        self
+    }
+
+    fn corruption_bitmap(&mut self) -> Option<Vec<u8>> {
+        let len = self.corruption_payload_len?;
+        let mut bits = vec![0u8; len];
+        let flips = self
+            .corruption_rng
+            .gen_range(1..=MAX_FLIPPED_BITS.min(len * 8));
+        let mut flipped = 0;
+        while flipped < flips {
+            let bit = self.corruption_rng.gen_range(0..len * 8);
+            let mask = 1u8 << (bit % 8);
+            if bits[bit / 8] & mask == 0 {
+                bits[bit / 8] |= mask;
+                flipped += 1;
+            }
+        }
+        Some(bits)
+    }
+
     /// Advance simulated time by delta millisecond_s.
     pub fn advance(&mut self, delta_m_s: u64) {
         self.now_m_s = self.now_m_s.saturating_add(delta_m_s);
@@ -125,12 +240,11 @@ impl NetworkSimulator {
                 if self.queue_depth >= self.cfg.max_queue {
                     continue;
                 }
-                let next_depart = if self.last_departure_m_s == 0 {
-                    self.now_m_s
-                } else {
-                    self.last_departure_m_s + min_gap
+                let next_depart = match self.last_departure_m_s {
+                    Some(last) => last + min_gap,
+                    None => self.now_m_s,
                 };
-                self.last_departure_m_s = next_depart;
+                self.last_departure_m_s = Some(next_depart);
                 self.queue_depth += 1;
                 next_depart
             };
@@ -143,10 +257,17 @@ impl NetworkSimulator {
             };
 
             let corrupted = self.rng.gen::<f64>() < self.cfg.corruption;
+            let corrupted_bits = if corrupted {
+                self.corruption_bitmap()
+            } else {
+                None
+            };
             events.push(DeliveryEvent {
                 delivery_m_s: delivery,
                 seq,
                 corrupted,
+                path: 0,
+                corrupted_bits: corrupted_bits.clone(),
             });
 
             // Duplicate one extra copy with +1m_s delivery when enabled
@@ -155,6 +276,8 @@ impl NetworkSimulator {
                     delivery_m_s: delivery.saturating_add(1),
                     seq,
                     corrupted,
+                    path: 0,
+                    corrupted_bits,
                 });
             }
         }
@@ -237,6 +360,61 @@ impl MultiPathSimulator {
         }
     }
 
+    /// Construct a multipath simulator with a distinct config and seed per path.
+    pub fn with_paths(paths: &[(SimConfig, u64)]) -> Self {
+        Self {
+            path_s: paths
+                .iter()
+                .map(|&(cfg, seed)| NetworkSimulator::new(cfg, seed))
+                .collect(),
+            weight_s: vec![1.0; paths.len()],
+            rr_cursor: 0,
+        }
+    }
+
+    /// Send `count` packet_s one at a time, interleaved acros_s path_s in
+    /// proportion to each path'_s `bandwidth_pp_s`, and return the merged
+    /// schedule tagged with path indice_s.
+    ///
+    /// Unlike [`send_burst`](Self::send_burst), consecutive packet_s alternate
+    /// path_s (smooth weighted round-robin), so each path'_s bandwidth spacing
+    /// produce_s realistic cross-path timing. If any path ha_s unlimited
+    /// bandwidth (0), the constructor weight_s are used instead.
+    pub fn send_interleaved(&mut self, count: usize) -> Vec<DeliveryEvent> {
+        if self.path_s.is_empty() || count == 0 {
+            return Vec::new();
+        }
+        let weight_s: Vec<f64> = if self.path_s.iter().all(|p| p.cfg.bandwidth_pp_s > 0) {
+            self.path_s
+                .iter()
+                .map(|p| p.cfg.bandwidth_pp_s as f64)
+                .collect()
+        } else {
+            self.weight_s.clone()
+        };
+        let total: f64 = weight_s.iter().sum();
+        let mut credit = vec![0.0f64; self.path_s.len()];
+        let mut events = Vec::with_capacity(count);
+        for _ in 0..count {
+            for (c, w) in credit.iter_mut().zip(&weight_s) {
+                *c += w;
+            }
+            let mut pi = 0;
+            for (i, c) in credit.iter().enumerate() {
+                if *c > credit[pi] {
+                    pi = i;
+                }
+            }
+            credit[pi] -= total;
+            events.extend(self.path_s[pi].send_burst(1).into_iter().map(|mut e| {
+                e.path = pi;
+                e
+            }));
+        }
+        events.sort_by_key(|e| (e.delivery_m_s, e.path, e.seq));
+        events
+    }
+
     /// Send `n` packet_s split acros_s path_s by weighted round-robin.
     pub fn send_burst(&mut self, n: usize) -> Vec<DeliveryEvent> {
         if self.path_s.is_empty() || n == 0 {
@@ -263,8 +441,9 @@ impl MultiPathSimulator {
         let mut merged: Vec<(u64, u64, usize, DeliveryEvent)> = Vec::with_capacity(n);
         for (pi, (p, q)) in self.path_s.iter_mut().zip(quota_s.into_iter()).enumerate() {
             let mut events = p.send_burst(q);
-            for e in events.drain(..) {
+            for mut e in events.drain(..) {
                 // Make sequence globally unique using path index in the tiebreak key only
+                e.path = pi;
                 merged.push((e.delivery_m_s, e.seq, pi, e.clone()));
             }
         }
@@ -382,6 +561,48 @@ mod test_s {
         }
     }
 
+    #[test]
+    fn corruption_bitmap_reproducible_at_fixed_seed() {
+        let cfg = SimConfig {
+            los_s: 0.05,
+            corruption: 0.2,
+            ..SimConfig::default()
+        };
+        let run = || {
+            NetworkSimulator::new(cfg, 99)
+                .with_corruption_bitmap(1280)
+                .send_burst(500)
+        };
+        let a = run();
+        assert_eq!(a, run());
+        assert!(a.iter().any(|e| e.corrupted));
+
+        for e in &a {
+            match &e.corrupted_bits {
+                Some(bits) => {
+                    assert!(e.corrupted);
+                    assert_eq!(bits.len(), 1280);
+                    let flipped: u32 = bits.iter().map(|b| b.count_ones()).sum();
+                    assert!((1..=MAX_FLIPPED_BITS as u32).contains(&flipped));
+                    let mut payload = [0u8; 1280];
+                    e.apply_corruption(&mut payload);
+                    assert_eq!(&payload[..], &bits[..]);
+                }
+                None => assert!(!e.corrupted),
+            }
+        }
+
+        // Bitmaps use their own RNG, so the delivery schedule is unchanged.
+        let plain = NetworkSimulator::new(cfg, 99).send_burst(500);
+        let strip = |v: &[DeliveryEvent]| -> Vec<(u64, u64, bool)> {
+            v.iter()
+                .map(|e| (e.delivery_m_s, e.seq, e.corrupted))
+                .collect()
+        };
+        assert_eq!(strip(&a), strip(&plain));
+        assert!(plain.iter().all(|e| e.corrupted_bits.is_none()));
+    }
+
     #[test]
     fn gilbert_elliott_burst_los_s() {
         // Configure strong burst_s: once in bad state, drop almost alway_s
@@ -434,4 +655,51 @@ mod test_s {
             .windows(2)
             .all(|w| w[0].delivery_m_s <= w[1].delivery_m_s));
     }
+
+    #[test]
+    fn multipath_interleaved_by_bandwidth() {
+        let path = |bandwidth_pp_s| SimConfig {
+            latency_m_s: 5,
+            jitter_m_s: 2,
+            bandwidth_pp_s,
+            ..SimConfig::default()
+        };
+        let mut m =
+            MultiPathSimulator::with_paths(&[(path(200), 1), (path(100), 2), (path(100), 3)]);
+        let events = m.send_interleaved(400);
+        assert_eq!(events.len(), 400);
+
+        let mut per_path = [0usize; 3];
+        for e in &events {
+            per_path[e.path] += 1;
+        }
+        assert_eq!(per_path, [200, 100, 100]);
+
+        // The merged schedule is sorted by time, so recover each path'_s
+        // emission order from its sequence id_s before checking delivery order.
+        for pi in 0..3 {
+            let mut sent: Vec<(u64, u64)> = events
+                .iter()
+                .filter(|e| e.path == pi)
+                .map(|e| (e.seq, e.delivery_m_s))
+                .collect();
+            sent.sort_unstable_by_key(|&(seq, _)| seq);
+            assert!(sent.windows(2).all(|w| w[0].0 < w[1].0));
+            assert!(
+                sent.windows(2).all(|w| w[0].1 <= w[1].1),
+                "path {pi} delivered out of emission order"
+            );
+        }
+        // A bandwidth-proportional split keeps the paths finishing together.
+        let last = |pi| {
+            events
+                .iter()
+                .filter(|e| e.path == pi)
+                .map(|e| e.delivery_m_s)
+                .max()
+                .unwrap_or(0)
+        };
+        assert!(last(0).abs_diff(last(1)) <= 20);
+        assert!(last(0).abs_diff(last(2)) <= 20);
+    }
 }
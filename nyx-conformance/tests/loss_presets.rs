@@ -0,0 +1,29 @@
+use nyx_conformance::{LossPreset, NetworkSimulator, SimConfig};
+
+#[test]
+fn presets_stay_within_documented_loss_band() {
+    const PACKETS: usize = 10_000;
+    for preset in LossPreset::ALL {
+        let (min, max) = preset.loss_band();
+        for seed in [1u64, 7, 2024] {
+            let mut sim = NetworkSimulator::new(SimConfig::preset(preset), seed);
+            let delivered = sim.send_burst(PACKETS).len();
+            let loss = 1.0 - delivered as f64 / PACKETS as f64;
+            assert!(
+                (min..=max).contains(&loss),
+                "{preset:?} seed {seed}: loss {loss:.4} outside [{min}, {max}]"
+            );
+        }
+    }
+}
+
+#[test]
+fn presets_only_change_link_fields() {
+    let cfg = SimConfig::preset(LossPreset::Satellite);
+    let default = SimConfig::default();
+    assert!(cfg.ge_good_to_bad > 0.0 && cfg.ge_bad_to_good > 0.0);
+    assert!(cfg.latency_m_s > default.latency_m_s);
+    assert_eq!(cfg.los_s, default.los_s);
+    assert_eq!(cfg.duplicate, default.duplicate);
+    assert_eq!(cfg.bandwidth_pp_s, default.bandwidth_pp_s);
+}
@@ -46,6 +46,11 @@ impl KBuckets {
         v
     }
 
+    /// Known peers, nearest to the local node first.
+    pub fn snapshot(&self) -> Vec<NodeInfo> {
+        self.peers.clone()
+    }
+
     pub fn len(&self) -> usize {
         self.peers.len()
     }
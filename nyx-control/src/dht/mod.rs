@@ -19,7 +19,7 @@ pub use kbucket::{KBuckets, K_PARAM};
 pub use message::{Rpc, RpcRequest, RpcResponse};
 pub use node::{DhtConfig, DhtNode, QueryResult};
 pub use route::{RouteEntry, RoutingTable};
-pub use storage::{DhtStorage, EntryMeta, PersistEntry};
+pub use storage::{DhtStorage, EntryMeta, PersistEntry, StorageStats};
 pub use types::{Distance, NodeId, NodeInfo, StorageKey, StorageValue};
 
 #[cfg(test)]
@@ -73,4 +73,44 @@ mod tests {
             }
         }
     }
+
+    #[tokio::test]
+    async fn lookup_reports_hops_and_stops_at_limit() {
+        let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0);
+        let mut holder = DhtNode::spawn(DhtConfig {
+            bind: addr,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+        let mut seeker = DhtNode::spawn(DhtConfig {
+            bind: addr,
+            max_lookup_hops: 1,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+        // Stored before peering, so only `holder` has the value.
+        let key = StorageKey::from_bytes(b"remote");
+        let val = StorageValue::from_bytes(b"value");
+        holder.put(key.clone(), val.clone()).await.unwrap();
+        holder.add_peer(seeker.info()).await;
+        seeker.add_peer(holder.info()).await;
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+        let found = seeker.lookup(key).await.unwrap();
+        assert_eq!(found.value, Some(val));
+        assert_eq!(found.hops, 1);
+        assert!(!found.hop_limit_reached);
+
+        let absent = seeker
+            .lookup(StorageKey::from_bytes(b"absent"))
+            .await
+            .unwrap();
+        assert!(absent.value.is_none());
+        assert_eq!(absent.hops, 1);
+        assert!(absent.hop_limit_reached);
+        assert_eq!(absent.nearest.len(), 1);
+    }
 }
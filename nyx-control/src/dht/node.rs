@@ -2,17 +2,19 @@
 
 use crate::dht::kbucket::{KBuckets, K_PARAM};
 use crate::dht::message::{NodeInfoSerializable, Rpc, RpcRequest, RpcResponse};
-use crate::dht::storage::DhtStorage;
+use crate::dht::storage::{DhtStorage, StorageStats};
 use crate::dht::types::{NodeId, NodeInfo, StorageKey, StorageValue};
 use anyhow::Result;
 use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use futures::future::join_all;
 use rand::rngs::OsRng;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::net::UdpSocket;
+use tokio::sync::broadcast;
 use tokio::sync::mpsc;
 use tokio::sync::Mutex;
 use tokio::time::timeout;
@@ -27,6 +29,9 @@ pub struct DhtConfig {
     pub persist_path: Option<std::path::PathBuf>,
     #[serde(default = "default_refresh_ms")]
     pub refresh_interval_ms: u64,
+    /// Upper bound on query rounds per lookup; bounds latency on sparse networks.
+    #[serde(default = "default_max_lookup_hops")]
+    pub max_lookup_hops: usize,
 }
 
 impl Default for DhtConfig {
@@ -37,6 +42,7 @@ impl Default for DhtConfig {
             alpha: 3,
             persist_path: None,
             refresh_interval_ms: 30_000,
+            max_lookup_hops: 8,
         }
     }
 }
@@ -47,6 +53,9 @@ const fn default_alpha() -> usize {
 const fn default_refresh_ms() -> u64 {
     30_000
 }
+const fn default_max_lookup_hops() -> usize {
+    8
+}
 
 pub struct DhtNode {
     pub local: NodeInfo,
@@ -56,6 +65,7 @@ pub struct DhtNode {
     tx: mpsc::UnboundedSender<Vec<u8>>, // internal sender to IO task
     signer: Arc<SigningKey>,
     peer_keys: Arc<Mutex<HashMap<NodeId, VerifyingKey>>>,
+    replies: broadcast::Sender<(SocketAddr, RpcResponse)>, // responses seen by the receive loop
     config: DhtConfig,
 }
 
@@ -63,9 +73,26 @@ pub struct DhtNode {
 pub struct QueryResult<T> {
     pub value: T,
     pub nearest: Vec<NodeInfo>,
+    /// Query rounds sent to peers; zero when answered from local storage.
+    pub hops: usize,
+    /// The hop budget was spent without finding the value.
+    pub hop_limit_reached: bool,
 }
 
 impl DhtNode {
+    /// Spawn with `seeds` already in the routing table, e.g. peers saved from a
+    /// previous run via [`DhtNode::routing_snapshot`]. No traffic is sent to them.
+    pub async fn spawn_with_peers(cfg: DhtConfig, seeds: Vec<NodeInfo>) -> Result<Self> {
+        let node = Self::spawn(cfg).await?;
+        {
+            let mut kb = node.kb.lock().await;
+            for s in seeds.into_iter().filter(|s| s.id != node.local.id) {
+                kb.upsert(s);
+            }
+        }
+        Ok(node)
+    }
+
     pub async fn spawn(cfg: DhtConfig) -> Result<Self> {
         // Generate keypair and derive NodeId from public key
         let signer = SigningKey::generate(&mut OsRng);
@@ -83,6 +110,7 @@ impl DhtNode {
             tx: mpsc::unbounded_channel().0,
             signer: Arc::new(signer),
             peer_keys: Arc::new(Mutex::new(HashMap::new())),
+            replies: broadcast::channel(64).0,
             config: cfg.clone(),
         };
         // load persisted snapshot if configured
@@ -111,6 +139,7 @@ impl DhtNode {
         let storage_arc = storage.clone();
         let peer_keys = node.peer_keys.clone();
         let signer = node.signer.clone();
+        let replies = node.replies.clone();
         tokio::spawn(async move {
             let mut buf = vec![0u8; 2048];
             while let Ok((n, from)) = recv_sock.recv_from(&mut buf).await {
@@ -126,8 +155,10 @@ impl DhtNode {
                                 .lock()
                                 .await
                                 .upsert(NodeInfo::new(sender.clone(), from));
-                            // if signature exists and key unknown, fetch pubkey first
-                            if sig.is_some() {
+                            // if signature exists and key unknown, fetch pubkey first;
+                            // key requests are answered directly so two unknown peers
+                            // do not block on each other's fetch
+                            if sig.is_some() && !matches!(req, RpcRequest::GetPubKey) {
                                 let known = { peer_keys.lock().await.contains_key(&sender) };
                                 if !known {
                                     let me = NodeId::from_pubkey(signer.verifying_key().as_bytes());
@@ -165,26 +196,33 @@ impl DhtNode {
                                 let _ = send_rpc(&recv_sock, &msg, to).await;
                             }
                         }
-                        Rpc::Res { .. } => { /* Ignore unsolicited responses at server loop */ }
+                        Rpc::Res { res, .. } => {
+                            // hand to in-flight lookups; dropped if nobody is waiting
+                            let _ = replies.send((from, res));
+                        }
                     }
                 }
             }
         });
 
-        // periodic snapshot persistence
+        // periodic maintenance: expiry sweep, then snapshot persistence
         {
-            let storage_for_persist = storage.clone();
+            let storage_for_maintenance = storage.clone();
             let persist_path = node.config.persist_path.clone();
             let interval_ms = node.config.refresh_interval_ms;
             tokio::spawn(async move {
-                if let Some(path) = persist_path {
-                    loop {
-                        tokio::time::sleep(Duration::from_millis(interval_ms)).await;
-                        let mut st = storage_for_persist.lock().await;
+                loop {
+                    tokio::time::sleep(Duration::from_millis(interval_ms)).await;
+                    let mut st = storage_for_maintenance.lock().await;
+                    let expired = st.sweep_expired();
+                    if expired > 0 {
+                        tracing::debug!(expired, stats = ?st.stats(), "DHT expiry sweep");
+                    }
+                    if let Some(path) = &persist_path {
                         let entries = st.export_persist();
                         let mut buf = Vec::new();
                         if ciborium::ser::into_writer(&entries, &mut buf).is_ok() {
-                            let _ = std::fs::write(&path, buf);
+                            let _ = std::fs::write(path, buf);
                         }
                     }
                 }
@@ -196,79 +234,156 @@ impl DhtNode {
     pub fn info(&self) -> NodeInfo {
         self.local.clone()
     }
+    /// Peers currently in the routing table, for persisting across restarts.
+    pub async fn routing_snapshot(&self) -> Vec<NodeInfo> {
+        self.kb.lock().await.snapshot()
+    }
+    /// Local storage entry counts, including entries removed by expiry.
+    pub async fn storage_stats(&self) -> StorageStats {
+        self.storage.lock().await.stats()
+    }
     pub async fn add_peer(&mut self, n: NodeInfo) {
         self.kb.lock().await.upsert(n.clone());
         let _ = self.fetch_and_store_peer_key(n.addr, n.id.clone()).await;
     }
 
+    /// Store locally and replicate to the K known peers closest to the key.
     pub async fn put(&mut self, key: StorageKey, value: StorageValue) -> Result<()> {
-        // Store locally and try to send to K nearest known peers
         {
             let mut st = self.storage.lock().await;
             let _ = st.put(key.clone(), value.clone());
         }
-        let peers = self
-            .kb
-            .lock()
-            .await
-            .nearest(&self.local.id, K_PARAM.min(self.config.alpha));
-        for p in peers {
-            let req_body = RpcRequest::Put {
-                key: key.clone(),
-                value: value.clone(),
-                ttl_secs: 3600,
-            };
-            let sig = self.sign_request(&req_body);
-            let req = Rpc::Req {
-                from: self.local.id.clone(),
-                sig: Some(sig),
-                req: req_body,
-            };
-            let _ = request_response(&self.sock, req, p.addr, Duration::from_millis(500)).await;
-        }
+        let peers = self.kb.lock().await.nearest(&key_id(&key), K_PARAM);
+        let req_body = RpcRequest::Put {
+            key,
+            value,
+            ttl_secs: 3600,
+        };
+        join_all(
+            peers
+                .iter()
+                .filter(|p| p.id != self.local.id)
+                .map(|p| self.query(req_body.clone(), p.addr)),
+        )
+        .await;
         Ok(())
     }
 
     pub async fn get(&self, key: StorageKey) -> Result<Option<StorageValue>> {
+        Ok(self.lookup(key).await?.value)
+    }
+
+    /// Iterative lookup bounded by [`DhtConfig::max_lookup_hops`].
+    ///
+    /// Each hop queries up to α not-yet-queried peers closest to the key in
+    /// parallel for the value, and learns closer peers from their `FindNode`
+    /// replies.
+    pub async fn lookup(&self, key: StorageKey) -> Result<QueryResult<Option<StorageValue>>> {
         {
             let mut st = self.storage.lock().await;
             if let Some(v) = st.get(&key) {
-                return Ok(Some(v));
+                return Ok(QueryResult {
+                    value: Some(v),
+                    nearest: Vec::new(),
+                    hops: 0,
+                    hop_limit_reached: false,
+                });
             }
         }
-        // iterative lookup: ask nearest peers
-        // α並列で3ピアに問い合わせ
-        let peers = self
-            .kb
-            .lock()
-            .await
-            .nearest(&self.local.id, K_PARAM.min(self.config.alpha));
-        let mut futs = Vec::new();
-        for p in peers {
-            let req_body = RpcRequest::Get { key: key.clone() };
-            let sig = self.sign_request(&req_body);
-            let req = Rpc::Req {
-                from: self.local.id.clone(),
-                sig: Some(sig),
-                req: req_body,
-            };
-            futs.push(request_response(
-                &self.sock,
-                req,
-                p.addr,
-                Duration::from_millis(500),
-            ));
-        }
-        for f in futs {
-            if let Ok(Rpc::Res {
-                res: RpcResponse::Value(v),
-                ..
-            }) = f.await
-            {
-                return Ok(v);
+        let target = key_id(&key);
+        let alpha = K_PARAM.min(self.config.alpha).max(1);
+        let mut candidates = self.kb.lock().await.nearest(&target, K_PARAM);
+        let mut queried: HashSet<NodeId> = HashSet::new();
+        let mut responded: Vec<NodeInfo> = Vec::new();
+        let mut hops = 0;
+        let mut value = None;
+        let mut hop_limit_reached = false;
+        while value.is_none() {
+            if hops >= self.config.max_lookup_hops {
+                hop_limit_reached = true;
+                break;
+            }
+            candidates.retain(|n| n.id != self.local.id && !queried.contains(&n.id));
+            if candidates.is_empty() {
+                break;
+            }
+            candidates.sort_by_key(|n| n.id.distance(&target));
+            let round: Vec<NodeInfo> = candidates.drain(..alpha.min(candidates.len())).collect();
+            hops += 1;
+            queried.extend(round.iter().map(|p| p.id.clone()));
+            let replies = join_all(round.into_iter().map(|p| async {
+                let reply = self.ask_peer(&key, &target, p.addr).await;
+                (p, reply)
+            }))
+            .await;
+            for (p, reply) in replies {
+                match reply {
+                    Some(PeerReply::Value(v)) => value = value.or(Some(v)),
+                    Some(PeerReply::Closer(list)) => candidates.extend(list),
+                    None => continue,
+                }
+                responded.push(p);
             }
         }
-        Ok(None)
+        responded.sort_by_key(|n| n.id.distance(&target));
+        responded.truncate(K_PARAM);
+        Ok(QueryResult {
+            value,
+            nearest: responded,
+            hops,
+            hop_limit_reached,
+        })
+    }
+
+    /// One lookup step against a single peer: its value if it has one,
+    /// otherwise the peers it knows closest to `target`.
+    async fn ask_peer(
+        &self,
+        key: &StorageKey,
+        target: &NodeId,
+        addr: SocketAddr,
+    ) -> Option<PeerReply> {
+        match self.query(RpcRequest::Get { key: key.clone() }, addr).await {
+            Ok(RpcResponse::Value(Some(v))) => return Some(PeerReply::Value(v)),
+            Ok(RpcResponse::Value(None)) => {}
+            _ => return None,
+        }
+        let find = RpcRequest::FindNode {
+            target: target.clone(),
+        };
+        let closer = match self.query(find, addr).await {
+            Ok(RpcResponse::Nodes(list)) => list
+                .into_iter()
+                .filter_map(|n| NodeInfo::try_from(n).ok())
+                .collect(),
+            _ => Vec::new(),
+        };
+        Some(PeerReply::Closer(closer))
+    }
+
+    /// Send `req_body` to `to` and wait for a matching reply via the receive
+    /// loop, skipping stray responses (e.g. late `PubKey` replies).
+    async fn query(&self, req_body: RpcRequest, to: SocketAddr) -> Result<RpcResponse> {
+        let mut rx = self.replies.subscribe();
+        let sig = self.sign_request(&req_body);
+        let req = Rpc::Req {
+            from: self.local.id.clone(),
+            sig: Some(sig),
+            req: req_body.clone(),
+        };
+        send_rpc(&self.sock, &req, to).await?;
+        let fut = async {
+            loop {
+                match rx.recv().await {
+                    Ok((from, res)) if from == to && answers(&req_body, &res) => return Ok(res),
+                    Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => {
+                        anyhow::bail!("DHT receive loop stopped")
+                    }
+                }
+            }
+        };
+        timeout(Duration::from_millis(500), fut).await?
     }
 }
 
@@ -298,6 +413,31 @@ async fn request_response(
     Ok(msg)
 }
 
+/// Position of a storage key in the node-id space; `put` and `lookup` both
+/// route by it so that values are found where they were stored.
+fn key_id(key: &StorageKey) -> NodeId {
+    NodeId::from_pubkey(&key.0)
+}
+
+/// Outcome of asking one peer during [`DhtNode::lookup`].
+enum PeerReply {
+    Value(StorageValue),
+    Closer(Vec<NodeInfo>),
+}
+
+/// Whether `res` is a reply to `req` rather than to some earlier request.
+fn answers(req: &RpcRequest, res: &RpcResponse) -> bool {
+    matches!(
+        (req, res),
+        (_, RpcResponse::Err(_))
+            | (RpcRequest::Ping(_), RpcResponse::Pong(_))
+            | (RpcRequest::FindNode { .. }, RpcResponse::Nodes(_))
+            | (RpcRequest::Get { .. }, RpcResponse::Value(_))
+            | (RpcRequest::Put { .. }, RpcResponse::Ok)
+            | (RpcRequest::GetPubKey, RpcResponse::PubKey(_))
+    )
+}
+
 async fn handle_request(
     kb: &Arc<Mutex<KBuckets>>,
     storage: &Arc<Mutex<DhtStorage>>,
@@ -435,18 +575,7 @@ impl DhtNode {
         addr: SocketAddr,
         peer_id: NodeId,
     ) -> Result<Option<VerifyingKey>> {
-        let req_body = RpcRequest::GetPubKey;
-        let sig = self.sign_request(&req_body);
-        let req = Rpc::Req {
-            from: self.local.id.clone(),
-            sig: Some(sig),
-            req: req_body,
-        };
-        if let Ok(Rpc::Res {
-            res: RpcResponse::PubKey(pk_bytes),
-            ..
-        }) = request_response(&self.sock, req, addr, Duration::from_millis(500)).await
-        {
+        if let Ok(RpcResponse::PubKey(pk_bytes)) = self.query(RpcRequest::GetPubKey, addr).await {
             if let Ok(vk) = VerifyingKey::from_bytes(&pk_bytes) {
                 self.peer_keys.lock().await.insert(peer_id.clone(), vk);
                 return Ok(Some(vk));
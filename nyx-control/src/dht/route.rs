@@ -43,4 +43,10 @@ impl RoutingTable {
     pub fn remove_route(&mut self, dest: &NodeId) -> bool {
         self.routes.remove(dest).is_some()
     }
+    /// All routes, ordered by destination so snapshots are stable across runs.
+    pub fn snapshot(&self) -> Vec<RouteEntry> {
+        let mut v: Vec<RouteEntry> = self.routes.values().cloned().collect();
+        v.sort_by_key(|r| r.dest.0);
+        v
+    }
 }
@@ -19,10 +19,23 @@ pub struct EntryMeta {
     pub ttl_secs: u64,
 }
 
+/// Entry counts reported by [`DhtStorage::stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageStats {
+    /// Entries whose TTL has not elapsed.
+    pub live: usize,
+    /// Entries past their TTL that have not been swept yet.
+    pub expired_pending: usize,
+    /// Entries removed by expiry since this storage was created.
+    pub expired_total: u64,
+}
+
 #[derive(Debug, Clone)]
 pub struct DhtStorage {
+    // 値, メタ情報, 失効時刻
     map: HashMap<StorageKey, (StorageValue, EntryMeta, Instant)>,
     capacity: usize,
+    expired_total: u64,
 }
 
 impl Default for DhtStorage {
@@ -30,6 +43,7 @@ impl Default for DhtStorage {
         Self {
             map: HashMap::new(),
             capacity: 4096,
+            expired_total: 0,
         }
     }
 }
@@ -60,7 +74,12 @@ impl DhtStorage {
             stored_at_ms: now_ms(),
             ttl_secs: ttl.as_secs(),
         };
-        self.map.insert(k, (v, meta, Instant::now()));
+        let now = Instant::now();
+        // 極端に長いTTLは実質無期限として扱う
+        let expires_at = now
+            .checked_add(ttl)
+            .unwrap_or_else(|| now + Duration::from_secs(u64::from(u32::MAX)));
+        self.map.insert(k, (v, meta, expires_at));
         Ok(())
     }
 
@@ -93,9 +112,32 @@ impl DhtStorage {
     }
 
     pub fn gc(&mut self) {
+        self.sweep_expired();
+    }
+
+    /// 期限切れエントリを削除し、削除件数を返す
+    pub fn sweep_expired(&mut self) -> usize {
         let now = Instant::now();
-        self.map
-            .retain(|_, (_, m, ins)| now.duration_since(*ins) < Duration::from_secs(m.ttl_secs));
+        let before = self.map.len();
+        self.map.retain(|_, (_, _, expires_at)| now < *expires_at);
+        let removed = before - self.map.len();
+        self.expired_total += removed as u64;
+        removed
+    }
+
+    /// 現在の生存/期限切れ件数と累計失効数
+    pub fn stats(&self) -> StorageStats {
+        let now = Instant::now();
+        let live = self
+            .map
+            .values()
+            .filter(|(_, _, expires_at)| now < *expires_at)
+            .count();
+        StorageStats {
+            live,
+            expired_pending: self.map.len() - live,
+            expired_total: self.expired_total,
+        }
     }
 
     // ---- Persistence helpers ----
@@ -34,17 +34,26 @@ pub struct ControlConfig {
     /// Listening port for probe_s. 0 = auto-assign ephemeral port.
     #[serde(default)]
     pub __port: u16,
+    /// Probe connections accepted per second (token bucket, burst of one
+    /// second's budget); excess connections get 429. 0 = unlimited.
+    #[serde(default = "default_probe_max_conns_per_sec")]
+    pub probe_max_conns_per_sec: u32,
 }
 
 const fn default_true() -> bool {
     true
 }
 
+const fn default_probe_max_conns_per_sec() -> u32 {
+    50
+}
+
 impl Default for ControlConfig {
     fn default() -> Self {
         Self {
             __enable_http: true,
             __port: 0,
+            probe_max_conns_per_sec: default_probe_max_conns_per_sec(),
         }
     }
 }
@@ -80,6 +89,8 @@ impl ControlHandle {
 
 /// Start control plane `tasks` according to config.
 ///
+/// The probe reports ready once every task has started.
+///
 /// # Errors
 /// Returns an error if:
 /// - Control plane initialization fails
@@ -88,9 +99,12 @@ impl ControlHandle {
 pub async fn start_control(cfg: ControlConfig) -> Result<ControlHandle> {
     let mut handle = ControlHandle { probe: None };
     if cfg.__enable_http {
-        let __ph = probe::start_probe(cfg.__port).await?;
+        let __ph = probe::start_probe(cfg.__port, cfg.probe_max_conns_per_sec).await?;
         handle.probe = Some(__ph);
     }
+    if let Some(__ph) = &handle.probe {
+        __ph.set_ready(true);
+    }
     Ok(handle)
 }
 
@@ -103,6 +117,7 @@ mod test_s {
         let c = parse_config("")?;
         assert!(c.__enable_http);
         assert_eq!(c.__port, 0);
+        assert_eq!(c.probe_max_conns_per_sec, 50);
         Ok(())
     }
 
@@ -113,4 +128,21 @@ mod test_s {
         assert_eq!(c.__port, 8080);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn started_control_plane_is_ready() -> Result<()> {
+        let __h = start_control(ControlConfig::default()).await?;
+        let __addr = __h
+            .probe
+            .as_ref()
+            .map(|p| p.addr())
+            .expect("probe enabled by default");
+        let mut __sock = tokio::net::TcpStream::connect(__addr).await?;
+        tokio::io::AsyncWriteExt::write_all(&mut __sock, b"GET /readyz HTTP/1.1\r\n\r\n").await?;
+        let mut __resp = String::new();
+        tokio::io::AsyncReadExt::read_to_string(&mut __sock, &mut __resp).await?;
+        assert!(__resp.contains("200 OK"), "{__resp}");
+        __h.shutdown().await;
+        Ok(())
+    }
 }
@@ -1,7 +1,10 @@
 use std::{
     collections::HashMap,
     net::SocketAddr,
-    sync::Arc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
     time::{Duration, Instant},
 };
 use tokio::{
@@ -15,6 +18,7 @@ pub struct ProbeHandle {
     __addr: SocketAddr,
     __stop: Arc<Notify>,
     __task: tokio::task::JoinHandle<()>,
+    __ready: Arc<AtomicBool>,
 }
 
 impl ProbeHandle {
@@ -23,6 +27,16 @@ impl ProbeHandle {
         self.__addr
     }
 
+    /// Toggle what `/readyz` report_s; the daemon sets this once dependencie_s are up.
+    pub fn set_ready(&self, ready: bool) {
+        self.__ready.store(ready, Ordering::Release);
+    }
+
+    /// Whether `/readyz` currently answer_s 200.
+    pub fn is_ready(&self) -> bool {
+        self.__ready.load(Ordering::Acquire)
+    }
+
     /// Abort background task to guarantee prompt shutdown in test_s.
     pub async fn shutdown(self) {
         // Best-effort graceful signal (ignored if no waiter yet)
@@ -33,9 +47,47 @@ impl ProbeHandle {
     }
 }
 
-/// Start_s a minimal HTTP probe server serving /healthz and /ready returning 200 OK.
+/// Token bucket limiting accepted probe connection_s per second.
+struct ConnBudget {
+    rate: f64,
+    token_s: f64,
+    last: Instant,
+}
+
+impl ConnBudget {
+    fn new(max_per_sec: u32) -> Self {
+        Self {
+            rate: f64::from(max_per_sec),
+            token_s: f64::from(max_per_sec),
+            last: Instant::now(),
+        }
+    }
+
+    /// Take one token; `false` when the budget is spent. A zero rate never limit_s.
+    fn try_take(&mut self) -> bool {
+        if self.rate == 0.0 {
+            return true;
+        }
+        let __now = Instant::now();
+        let __elapsed = __now.duration_since(self.last).as_secs_f64();
+        self.last = __now;
+        self.token_s = (self.token_s + __elapsed * self.rate).min(self.rate);
+        if self.token_s >= 1.0 {
+            self.token_s -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Start_s a minimal HTTP probe server.
+///
+/// `/healthz` (alia_s `/livez`) answer_s 200 while the proces_s is alive.
+/// `/readyz` (alia_s `/ready`) answer_s 503 until [`ProbeHandle::set_ready`] is called.
+/// Connection_s beyond `max_conns_per_sec` are answered with 429 (0 = unlimited).
 /// Return_s the bound addres_s (useful when port 0 wa_s passed) and a shutdown handle.
-pub async fn start_probe(port: u16) -> crate::Result<ProbeHandle> {
+pub async fn start_probe(port: u16, max_conns_per_sec: u32) -> crate::Result<ProbeHandle> {
     // Bind only on loopback to avoid platform-specific firewall prompt_s in test_s
     let addr: SocketAddr = format!("127.0.0.1:{port}")
         .parse()
@@ -44,6 +96,9 @@ pub async fn start_probe(port: u16) -> crate::Result<ProbeHandle> {
     let __local_addr = __listener.local_addr()?;
     let __stop = Arc::new(Notify::new());
     let __stop2 = __stop.clone();
+    let mut budget = ConnBudget::new(max_conns_per_sec);
+    let __ready = Arc::new(AtomicBool::new(false));
+    let __ready2 = __ready.clone();
 
     let __task = tokio::spawn(async move {
         loop {
@@ -53,6 +108,8 @@ pub async fn start_probe(port: u16) -> crate::Result<ProbeHandle> {
                 acc = __listener.accept() => {
                     match acc {
                         Ok((mut sock, _peer)) => {
+                            let __allowed = budget.try_take();
+                            let __ready = __ready2.load(Ordering::Acquire);
                             // Handle a single HTTP/1.1 request in-place, keep-alive not supported
                             tokio::spawn(async move {
                                 let mut buf = [0u8; 1024];
@@ -60,7 +117,10 @@ pub async fn start_probe(port: u16) -> crate::Result<ProbeHandle> {
                                 let __req = String::from_utf8_lossy(&buf[..__n]);
                                 let __path = parse_path(&__req);
                                 let (__statu_s, body) = match __path.as_deref() {
-                                    Some("/healthz") | Some("/ready") | Some("/livez") => ("200 OK", "ok"),
+                                    _ if !__allowed => ("429 Too Many Requests", "rate limited"),
+                                    Some("/healthz") | Some("/livez") => ("200 OK", "ok"),
+                                    Some("/readyz") | Some("/ready") if __ready => ("200 OK", "ready"),
+                                    Some("/readyz") | Some("/ready") => ("503 Service Unavailable", "not ready"),
                                     _ => ("404 Not Found", "not found"),
                                 };
                                 let __resp = format!(
@@ -82,6 +142,7 @@ pub async fn start_probe(port: u16) -> crate::Result<ProbeHandle> {
         __addr: __local_addr,
         __stop,
         __task,
+        __ready,
     })
 }
 
@@ -101,7 +162,7 @@ mod test_s {
 
     #[tokio::test]
     async fn probe_serves_health() -> crate::Result<()> {
-        let __h = start_probe(0).await?;
+        let __h = start_probe(0, 0).await?;
         let __addr = __h.addr();
         let __resp = tiny_http_get(__addr, "/healthz").await;
         assert!(__resp.contains("200 OK"));
@@ -109,6 +170,49 @@ mod test_s {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn readiness_follows_set_ready() -> crate::Result<()> {
+        let __h = start_probe(0, 0).await?;
+        let __addr = __h.addr();
+        assert!(tiny_http_get(__addr, "/readyz")
+            .await
+            .contains("503 Service Unavailable"));
+        assert!(tiny_http_get(__addr, "/healthz").await.contains("200 OK"));
+        __h.set_ready(true);
+        assert!(tiny_http_get(__addr, "/readyz").await.contains("200 OK"));
+        assert!(tiny_http_get(__addr, "/healthz").await.contains("200 OK"));
+        __h.set_ready(false);
+        assert!(tiny_http_get(__addr, "/readyz")
+            .await
+            .contains("503 Service Unavailable"));
+        assert!(tiny_http_get(__addr, "/healthz").await.contains("200 OK"));
+        __h.shutdown().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn probe_rejects_connections_over_budget() -> crate::Result<()> {
+        let __h = start_probe(0, 5).await?;
+        let __addr = __h.addr();
+        let (mut ok, mut limited) = (0, 0);
+        for _ in 0..20 {
+            let __resp = tiny_http_get(__addr, "/healthz").await;
+            if __resp.contains("200 OK") {
+                ok += 1;
+            } else if __resp.contains("429 Too Many Requests") {
+                limited += 1;
+            }
+        }
+        assert!((5..=7).contains(&ok), "ok={ok}");
+        assert_eq!(ok + limited, 20);
+        // A client that waits for the bucket to refill is served again.
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        let __resp = tiny_http_get(__addr, "/healthz").await;
+        assert!(__resp.contains("200 OK"));
+        __h.shutdown().await;
+        Ok(())
+    }
+
     async fn tiny_http_get(__addr: SocketAddr, path: &str) -> String {
         use tokio::net::TcpStream;
         let mut _s = match TcpStream::connect(__addr).await {
@@ -1,14 +1,19 @@
 use pasetors::{
-    claims::Claims, claims::ClaimsValidationRules, keys::SymmetricKey, local,
-    token::UntrustedToken, version4::V4,
+    claims::Claims, keys::SymmetricKey, local, token::UntrustedToken, version4::LocalToken,
+    version4::V4, Local,
 };
 use rand::RngCore;
 use serde_json::json;
+use std::time::Duration;
 
 #[derive(thiserror::Error, Debug)]
 pub enum PushError {
     #[error("crypto: {0}")]
     Crypto(String),
+    #[error("token expired")]
+    Expired,
+    #[error("audience mismatch")]
+    AudienceMismatch,
 }
 
 pub type Result<T> = std::result::Result<T, PushError>;
@@ -20,6 +25,68 @@ pub fn generate_key() -> Result<SymmetricKey<V4>> {
     SymmetricKey::<V4>::from(&bytes).map_err(|e| PushError::Crypto(e.to_string()))
 }
 
+/// Issue_s a token for `subject` bound to `audience`, expiring after `ttl`.
+pub fn issue(
+    key: &SymmetricKey<V4>,
+    subject: &str,
+    audience: &str,
+    ttl: Duration,
+) -> Result<String> {
+    let mut __claims = Claims::new().map_err(|e| PushError::Crypto(e.to_string()))?;
+    __claims
+        .subject(subject)
+        .map_err(|e| PushError::Crypto(e.to_string()))?;
+    __claims
+        .audience(audience)
+        .map_err(|e| PushError::Crypto(e.to_string()))?;
+    let __ttl = chrono::Duration::from_std(ttl).map_err(|e| PushError::Crypto(e.to_string()))?;
+    let __exp = (chrono::Utc::now() + __ttl).to_rfc3339();
+    __claims
+        .expiration(&__exp)
+        .map_err(|e| PushError::Crypto(e.to_string()))?;
+    local::encrypt(key, &__claims, None, None).map_err(|e| PushError::Crypto(e.to_string()))
+}
+
+/// Verifie_s a token from [`issue`] and return_s its subject.
+///
+/// Expired tokens fail with [`PushError::Expired`] and tokens for another
+/// audience with [`PushError::AudienceMismatch`].
+pub fn verify(key: &SymmetricKey<V4>, token: &str, expected_audience: &str) -> Result<String> {
+    let __claims = decrypt_checked(key, token, expected_audience)?;
+    let __sub = __claims
+        .get_claim("sub")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| PushError::Crypto("no sub".into()))?;
+    Ok(__sub.to_string())
+}
+
+/// Decrypt and authenticate `token`, then check `exp` and `aud` ourselve_s so
+/// each failure keep_s its own error (pasetor_s collapse_s them into one).
+fn decrypt_checked(key: &SymmetricKey<V4>, token: &str, expected_aud: &str) -> Result<Claims> {
+    let __untrusted = UntrustedToken::<Local, V4>::try_from(token)
+        .map_err(|e| PushError::Crypto(e.to_string()))?;
+    let __trusted = LocalToken::decrypt(key, &__untrusted, None, None)
+        .map_err(|e| PushError::Crypto(e.to_string()))?;
+    let __claims =
+        Claims::from_string(__trusted.payload()).map_err(|e| PushError::Crypto(e.to_string()))?;
+    let __exp = __claims
+        .get_claim("exp")
+        .and_then(|v| v.as_str())
+        .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
+        .ok_or_else(|| PushError::Crypto("no exp".into()))?;
+    if chrono::Utc::now() >= __exp {
+        return Err(PushError::Expired);
+    }
+    let __aud = __claims
+        .get_claim("aud")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| PushError::Crypto("no aud".into()))?;
+    if __aud != expected_aud {
+        return Err(PushError::AudienceMismatch);
+    }
+    Ok(__claims)
+}
+
 /// Issue_s a short-lived opaque token encoding device id and audience.
 pub fn issue_token(
     key: &SymmetricKey<V4>,
@@ -43,21 +110,7 @@ pub fn issue_token(
 
 /// Verifie_s token and return_s device_id if valid and audience matches.
 pub fn verify_token(key: &SymmetricKey<V4>, token: &str, expected_aud: &str) -> Result<String> {
-    let __untrusted =
-        UntrustedToken::try_from(token).map_err(|e| PushError::Crypto(e.to_string()))?;
-    let __rules = ClaimsValidationRules::new();
-    let __trusted = local::decrypt(key, &__untrusted, &__rules, None, None)
-        .map_err(|e| PushError::Crypto(e.to_string()))?;
-    let __payload = __trusted
-        .payload_claims()
-        .ok_or_else(|| PushError::Crypto("no payload".into()))?;
-    let __aud = __payload
-        .get_claim("aud")
-        .and_then(|v| v.as_str())
-        .ok_or_else(|| PushError::Crypto("no aud".into()))?;
-    if __aud != expected_aud {
-        return Err(PushError::Crypto("aud mismatch".into()));
-    }
+    let __payload = decrypt_checked(key, token, expected_aud)?;
     let __v = __payload
         .get_claim("device_id")
         .ok_or_else(|| PushError::Crypto("no device_id".into()))?;
@@ -84,4 +137,40 @@ mod test_s {
         assert_eq!(__dev, "dev1");
         Ok(())
     }
+
+    #[test]
+    fn issue_and_verify_subject() -> super::Result<()> {
+        let __key = generate_key()?;
+        let __t = issue(&__key, "dev1", "nyx-push", Duration::from_secs(60))?;
+        assert_eq!(verify(&__key, &__t, "nyx-push")?, "dev1");
+        Ok(())
+    }
+
+    #[test]
+    fn expired_token_rejected() -> super::Result<()> {
+        let __key = generate_key()?;
+        let __t = issue(&__key, "dev1", "nyx-push", Duration::ZERO)?;
+        assert!(matches!(
+            verify(&__key, &__t, "nyx-push"),
+            Err(PushError::Expired)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn wrong_audience_rejected() -> super::Result<()> {
+        let __key = generate_key()?;
+        let __t = issue(&__key, "dev1", "nyx-push", Duration::from_secs(60))?;
+        assert!(matches!(
+            verify(&__key, &__t, "other"),
+            Err(PushError::AudienceMismatch)
+        ));
+        // A token under another key never reaches claim checks.
+        let __other = generate_key()?;
+        assert!(matches!(
+            verify(&__other, &__t, "nyx-push"),
+            Err(PushError::Crypto(_))
+        ));
+        Ok(())
+    }
 }
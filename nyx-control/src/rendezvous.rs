@@ -1,5 +1,6 @@
 use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
 use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Registration {
@@ -7,6 +8,18 @@ pub struct Registration {
     pub __public_addr: String,
     pub __private_addr: String,
     pub __timestamp: i64,
+    /// Signed expiry; records past this instant are rejected on verify.
+    pub valid_until: SystemTime,
+}
+
+impl Registration {
+    /// Extend the expiry to `ttl` from now and sign the refreshed payload.
+    pub fn resign(&mut self, sk: &SigningKey, ttl: Duration) -> Result<Vec<u8>> {
+        self.valid_until = SystemTime::now()
+            .checked_add(ttl)
+            .ok_or_else(|| RvError::Sign("ttl overflow".into()))?;
+        sign_registration(sk, self)
+    }
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -15,6 +28,8 @@ pub enum RvError {
     Sign(String),
     #[error("verify failed")]
     Verify,
+    #[error("registration expired")]
+    Expired,
 }
 
 pub type Result<T> = std::result::Result<T, RvError>;
@@ -30,8 +45,13 @@ pub fn sign_registration(sk: &SigningKey, reg: &Registration) -> Result<Vec<u8>>
     Ok(out)
 }
 
-/// Verify signed registration, returning payload if valid.
+/// Verify signed registration, returning payload if valid and unexpired.
 pub fn verify_registration(signed: &[u8]) -> Result<Registration> {
+    verify_registration_at(signed, SystemTime::now())
+}
+
+/// Like [`verify_registration`], judging expiry against `now`.
+pub fn verify_registration_at(signed: &[u8], now: SystemTime) -> Result<Registration> {
     if signed.len() < 96 {
         return Err(RvError::Verify);
     }
@@ -44,7 +64,11 @@ pub fn verify_registration(signed: &[u8]) -> Result<Registration> {
     let __msg = &signed[96..];
     __pk.verify_strict(__msg, &__sig)
         .map_err(|_| RvError::Verify)?;
-    serde_json::from_slice(__msg).map_err(|_| RvError::Verify)
+    let reg: Registration = serde_json::from_slice(__msg).map_err(|_| RvError::Verify)?;
+    if reg.valid_until <= now {
+        return Err(RvError::Expired);
+    }
+    Ok(reg)
 }
 
 #[cfg(test)]
@@ -52,18 +76,51 @@ mod test_s {
     use super::*;
     use rand::rngs::OsRng;
 
-    #[test]
-    fn sign_and_verify() -> super::Result<()> {
-        let __sk = SigningKey::generate(&mut OsRng);
-        let __reg = Registration {
+    fn registration(valid_until: SystemTime) -> Registration {
+        Registration {
             _node_id: "n1".into(),
             __public_addr: "1.2.3.4:5".into(),
             __private_addr: "10.0.0.1:5".into(),
             __timestamp: 12345,
-        };
+            valid_until,
+        }
+    }
+
+    #[test]
+    fn sign_and_verify() -> super::Result<()> {
+        let __sk = SigningKey::generate(&mut OsRng);
+        let __reg = registration(SystemTime::now() + Duration::from_secs(60));
         let __s = sign_registration(&__sk, &__reg)?;
         let __out = verify_registration(&__s)?;
         assert_eq!(__out, __reg);
         Ok(())
     }
+
+    #[test]
+    fn expired_registration_rejected() -> super::Result<()> {
+        let __sk = SigningKey::generate(&mut OsRng);
+        let __reg = registration(SystemTime::now() - Duration::from_secs(1));
+        let __s = sign_registration(&__sk, &__reg)?;
+        assert!(matches!(verify_registration(&__s), Err(RvError::Expired)));
+        // A record valid now is rejected once its expiry has passed.
+        let __later = registration(SystemTime::now() + Duration::from_secs(60));
+        let __s = sign_registration(&__sk, &__later)?;
+        let __past_expiry = __later.valid_until + Duration::from_secs(1);
+        assert!(matches!(
+            verify_registration_at(&__s, __past_expiry),
+            Err(RvError::Expired)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn resigned_registration_accepted() -> super::Result<()> {
+        let __sk = SigningKey::generate(&mut OsRng);
+        let mut __reg = registration(SystemTime::now() - Duration::from_secs(1));
+        let __s = __reg.resign(&__sk, Duration::from_secs(60))?;
+        let __out = verify_registration(&__s)?;
+        assert_eq!(__out, __reg);
+        assert!(__out.valid_until > SystemTime::now());
+        Ok(())
+    }
 }
@@ -1,5 +1,18 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Version number shared by the settings schema and [`VersionedSettings`].
+pub type Version = u64;
+
+/// Current version of the [`AppSettings`] schema.
+pub const SCHEMA_VERSION: Version = 2;
+
+/// Version that [`validate_and_parse`] expect_s; older blob_s go through [`migrate`] first.
+pub fn schema_version() -> Version {
+    SCHEMA_VERSION
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
 pub struct AppSettings {
@@ -30,6 +43,8 @@ pub enum SettingsError {
     InvalidJson(String),
     #[error("schema violation: {0}")]
     Schema(String),
+    #[error("migration: {0}")]
+    Migration(String),
 }
 
 pub type Result<T> = std::result::Result<T, SettingsError>;
@@ -53,15 +68,147 @@ pub fn validate_and_parse(json: &str) -> Result<AppSettings> {
     serde_json::from_value(v).map_err(|e| SettingsError::InvalidJson(e.to_string()))
 }
 
+type MigrationStep = Box<dyn Fn(Value) -> Result<Value> + Send + Sync>;
+
+/// Chain of single-version upgrade step_s, keyed by the version they upgrade from.
+#[derive(Default)]
+pub struct Migrations {
+    step_s: BTreeMap<Version, MigrationStep>,
+}
+
+impl Migrations {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the step upgrading `from` to `from + 1`, replacing any previous one.
+    pub fn register<F>(&mut self, from: Version, step: F) -> &mut Self
+    where
+        F: Fn(Value) -> Result<Value> + Send + Sync + 'static,
+    {
+        self.step_s.insert(from, Box::new(step));
+        self
+    }
+
+    /// Apply each step from `from_version` up to `to_version` in order.
+    pub fn migrate(&self, old: Value, from_version: Version, to_version: Version) -> Result<Value> {
+        if from_version > to_version {
+            return Err(SettingsError::Migration(format!(
+                "cannot downgrade v{from_version} to v{to_version}"
+            )));
+        }
+        (from_version..to_version).try_fold(old, |v, ver| {
+            let step = self.step_s.get(&ver).ok_or_else(|| {
+                SettingsError::Migration(format!("no migration from v{ver} to v{}", ver + 1))
+            })?;
+            step(v)
+        })
+    }
+}
+
+/// Upgrade step_s for the built-in [`AppSettings`] schema.
+pub fn builtin_migrations() -> Migrations {
+    let mut m = Migrations::new();
+    // v1 stored the level a_s `log_level`; v2 renamed it and introduced `rendezvous_url`.
+    m.register(1, |mut v| {
+        let obj = v
+            .as_object_mut()
+            .ok_or_else(|| SettingsError::Migration("v1 settings must be an object".into()))?;
+        if let Some(level) = obj.remove("log_level") {
+            if !level.is_string() {
+                return Err(SettingsError::Migration(
+                    "v1 `log_level` must be a string".into(),
+                ));
+            }
+            obj.entry("_____log_level").or_insert(level);
+        }
+        obj.entry("rendezvous_url").or_insert(Value::Null);
+        Ok(v)
+    });
+    m
+}
+
+/// Upgrade a settings blob written under `from_version` to `to_version`.
+pub fn migrate(old: Value, from_version: Version, to_version: Version) -> Result<Value> {
+    builtin_migrations().migrate(old, from_version, to_version)
+}
+
 /// Versioned setting_s blob to support sync/merge policie_s.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct VersionedSettings<T> {
-    pub __version: u64,
+    pub __version: Version,
     pub __data: T,
 }
 
 impl<T> VersionedSettings<T> {
-    pub fn new(__version: u64, __data: T) -> Self {
+    pub fn new(__version: Version, __data: T) -> Self {
         Self { __version, __data }
     }
 }
+
+#[cfg(test)]
+mod test_s {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn migrate_v1_defaults_new_field() -> Result<()> {
+        let __v1 = json!({"log_level": "debug"});
+        let __v2 = migrate(__v1.clone(), 1, schema_version())?;
+        assert_eq!(
+            __v2,
+            json!({"_____log_level": "debug", "rendezvous_url": null})
+        );
+        let __s = validate_and_parse(&__v2.to_string())?;
+        assert_eq!(__s._____log_level, "debug");
+        assert_eq!(__s.rendezvous_url, None);
+        // Unmigrated, the v1 level would silently fall back to the default
+        assert_eq!(
+            validate_and_parse(&__v1.to_string())?._____log_level,
+            "info"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn migrate_v1_rejects_non_string_level() {
+        assert!(matches!(
+            migrate(json!({"log_level": 3}), 1, 2),
+            Err(SettingsError::Migration(_))
+        ));
+    }
+
+    #[test]
+    fn unknown_migration_path_errors() {
+        let __v = json!({});
+        assert!(matches!(
+            migrate(__v.clone(), 2, 5),
+            Err(SettingsError::Migration(_))
+        ));
+        assert!(matches!(
+            migrate(__v.clone(), 0, 2),
+            Err(SettingsError::Migration(_))
+        ));
+        assert!(matches!(
+            migrate(__v, 2, 1),
+            Err(SettingsError::Migration(_))
+        ));
+    }
+
+    #[test]
+    fn registered_steps_run_in_order() -> Result<()> {
+        let mut __m = Migrations::new();
+        __m.register(1, |mut v| {
+            v["trail"] = json!("1");
+            Ok(v)
+        })
+        .register(2, |mut v| {
+            let t = format!("{}2", v["trail"].as_str().unwrap_or_default());
+            v["trail"] = json!(t);
+            Ok(v)
+        });
+        assert_eq!(__m.migrate(json!({}), 1, 3)?["trail"], "12");
+        assert_eq!(__m.migrate(json!({"k": 1}), 3, 3)?, json!({"k": 1}));
+        Ok(())
+    }
+}
@@ -92,6 +92,45 @@ fn test_dht_kv_store() {
     assert_eq!(storage.get(&k3).unwrap(), v3);
 }
 
+/// Test that TTL'd entries are swept and counted once they expire
+#[test]
+fn storage_sweeps_expired_entries() {
+    use nyx_control::dht::{DhtStorage, StorageKey, StorageValue};
+    use std::time::Duration;
+
+    let mut storage = DhtStorage::new();
+    let short = Duration::from_millis(50);
+    for key in [b"a", b"b", b"c"] {
+        storage
+            .put_with_ttl(
+                StorageKey::from_bytes(key),
+                StorageValue::from_bytes(b"v"),
+                short,
+            )
+            .unwrap();
+    }
+    let keep = StorageKey::from_bytes(b"keep");
+    storage
+        .put(keep.clone(), StorageValue::from_bytes(b"v"))
+        .unwrap();
+    assert_eq!(storage.stats().live, 4);
+    assert_eq!(storage.sweep_expired(), 0);
+
+    std::thread::sleep(Duration::from_millis(80));
+    let stats = storage.stats();
+    assert_eq!((stats.live, stats.expired_pending), (1, 3));
+
+    assert_eq!(storage.sweep_expired(), 3);
+    assert_eq!(storage.sweep_expired(), 0);
+    let stats = storage.stats();
+    assert_eq!(
+        (stats.live, stats.expired_pending, stats.expired_total),
+        (1, 0, 3)
+    );
+    assert!(storage.get(&StorageKey::from_bytes(b"a")).is_none());
+    assert!(storage.get(&keep).is_some());
+}
+
 /// Test that the control module builds without DHT dependencies
 #[test]
 fn control_module_builds_without_dht() {
@@ -99,3 +138,72 @@ fn control_module_builds_without_dht() {
     // without requiring DHT functionality
     // The module compiles successfully, which proves this works
 }
+
+/// Restarting from a routing snapshot restores peers without contacting them.
+#[tokio::test]
+async fn respawn_with_routing_snapshot() {
+    use nyx_control::dht::{DhtConfig, DhtNode, NodeId, RouteEntry, RoutingTable};
+    let cfg = DhtConfig {
+        bind: "127.0.0.1:0".parse().unwrap(),
+        ..Default::default()
+    };
+    let peer = DhtNode::spawn(cfg.clone()).await.unwrap();
+    let mut first = DhtNode::spawn(cfg.clone()).await.unwrap();
+    first.add_peer(peer.info()).await;
+    let saved = first.routing_snapshot().await;
+    assert_eq!(saved.len(), 1);
+    drop(first);
+
+    let restarted = DhtNode::spawn_with_peers(cfg, saved).await.unwrap();
+    let peers = restarted.routing_snapshot().await;
+    assert_eq!(peers.len(), 1);
+    assert_eq!(peers[0].id, peer.info().id);
+    assert_eq!(peers[0].addr, peer.info().addr);
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    // The peer never heard from the restarted node.
+    assert!(peer
+        .routing_snapshot()
+        .await
+        .iter()
+        .all(|n| n.id != restarted.info().id));
+
+    let mut rt = RoutingTable::new();
+    let (a, b) = (NodeId::generate(), NodeId::generate());
+    rt.add_route(RouteEntry::new(a.clone(), 2, 40));
+    rt.add_route(RouteEntry::new(b.clone(), 1, 10));
+    let snap = rt.snapshot();
+    assert_eq!(snap.len(), 2);
+    assert!(snap.windows(2).all(|w| w[0].dest.0 < w[1].dest.0));
+    let mut restored = RoutingTable::new();
+    snap.into_iter().for_each(|r| restored.add_route(r));
+    assert_eq!(restored.find_route(&b).map(|r| r.hops), Some(1));
+}
+
+/// A value put on one node is found by a lookup started from another.
+#[tokio::test]
+async fn put_on_one_node_lookup_from_another() {
+    use nyx_control::dht::{DhtConfig, DhtNode, StorageKey, StorageValue};
+    let cfg = DhtConfig {
+        bind: "127.0.0.1:0".parse().unwrap(),
+        ..Default::default()
+    };
+    let mut relays = Vec::new();
+    for _ in 0..4 {
+        relays.push(DhtNode::spawn(cfg.clone()).await.unwrap());
+    }
+    let mut writer = DhtNode::spawn(cfg.clone()).await.unwrap();
+    let mut reader = DhtNode::spawn(cfg).await.unwrap();
+    for relay in &relays {
+        writer.add_peer(relay.info()).await;
+        reader.add_peer(relay.info()).await;
+    }
+
+    let key = StorageKey::from_bytes(b"rendezvous/alice");
+    let value = StorageValue::from_bytes(b"descriptor");
+    writer.put(key.clone(), value.clone()).await.unwrap();
+
+    let found = reader.lookup(key).await.unwrap();
+    assert_eq!(found.value, Some(value));
+    assert_eq!(found.hops, 1);
+    assert!(!found.hop_limit_reached);
+}
@@ -1,6 +1,16 @@
 use crate::error::{Error, Result};
 use serde::{Deserialize, Serialize};
-use std::{fs, net::SocketAddr, path::Path};
+use std::{fmt, fs, net::SocketAddr, ops::RangeInclusive, path::Path};
+
+/// Accepted values for `log_level`.
+pub const LOG_LEVELS: [&str; 5] = ["trace", "debug", "info", "warn", "error"];
+
+/// Accepted non-zero `listen_port` values; 0 lets the OS choose and
+/// privileged ports are refused.
+pub const LISTEN_PORT_RANGE: RangeInclusive<u16> = 1024..=65535;
+
+/// Accepted `max_frame_len_bytes` values (1 KiB to 64 MiB).
+pub const MAX_FRAME_LEN_RANGE: RangeInclusive<u64> = 1024..=64 * 1024 * 1024;
 
 /// Core configuration shared across Nyx components.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -48,8 +58,7 @@ impl CoreConfig {
 
     /// Validate logical consistency of fields.
     pub fn validate(&self) -> Result<()> {
-        let allowed = ["trace", "debug", "info", "warn", "error"];
-        if !allowed.contains(&self.log_level.as_str()) {
+        if !LOG_LEVELS.contains(&self.log_level.as_str()) {
             return Err(Error::config(format!(
                 "invalid log_level: {}",
                 self.log_level
@@ -153,3 +162,307 @@ impl QuicConfig {
         Ok(())
     }
 }
+
+/// A single problem reported by [`validate_all`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigViolation {
+    /// Dotted key path, e.g. `quic.idle_timeout_secs`
+    pub key: String,
+    /// Human-readable description of the problem
+    pub message: String,
+}
+
+impl ConfigViolation {
+    fn new(key: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            message: message.into(),
+        }
+    }
+
+    /// 1-based line of `input` that defines this key, when it can be found.
+    pub fn line_in(&self, input: &str) -> Option<usize> {
+        let (section, name) = match self.key.rsplit_once('.') {
+            Some((s, n)) => (Some(s), n),
+            None => (None, self.key.as_str()),
+        };
+        let mut current: Option<&str> = None;
+        for (idx, raw) in input.lines().enumerate() {
+            let line = raw.trim();
+            if let Some(header) = line.strip_prefix('[').and_then(|l| l.split(']').next()) {
+                current = Some(header.trim());
+                if current == Some(self.key.as_str()) {
+                    return Some(idx + 1);
+                }
+                continue;
+            }
+            let defines = line
+                .split_once('=')
+                .is_some_and(|(k, _)| k.trim().trim_matches('"') == name);
+            if defines && current == section {
+                return Some(idx + 1);
+            }
+        }
+        None
+    }
+}
+
+impl fmt::Display for ConfigViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.key, self.message)
+    }
+}
+
+/// Parse a `nyx.toml` document into a raw table without interpreting it.
+///
+/// Syntax errors carry the line and column reported by the TOML parser.
+pub fn parse_config(input: &str) -> Result<toml::Table> {
+    Ok(toml::from_str(input)?)
+}
+
+/// Run schema (known keys have the expected type and range) and cross-field
+/// checks over a parsed `nyx.toml`, returning every violation found.
+///
+/// Unknown keys and sections are ignored, matching the forward-compatible
+/// deserialization used by the daemon.
+pub fn validate_all(doc: &toml::Table) -> Vec<ConfigViolation> {
+    let mut c = Checks::default();
+
+    let mut core = CoreConfig::default();
+    if let Some(level) = c.string(doc, "", "log_level") {
+        core.log_level = level.to_string();
+    }
+    if let Some(enabled) = c.boolean(doc, "", "enable_multipath") {
+        core.enable_multipath = enabled;
+    }
+    // log_level is the only field CoreConfig::validate checks
+    if core.validate().is_err() {
+        c.push(
+            "log_level",
+            format!("must be one of {}", LOG_LEVELS.join(", ")),
+        );
+    }
+    if let Some(port) = c.integer(doc, "", "listen_port") {
+        let valid = u16::try_from(port).is_ok_and(|p| p == 0 || LISTEN_PORT_RANGE.contains(&p));
+        if !valid {
+            c.push(
+                "listen_port",
+                format!(
+                    "must be 0 or within {}..={}",
+                    LISTEN_PORT_RANGE.start(),
+                    LISTEN_PORT_RANGE.end()
+                ),
+            );
+        }
+    }
+    if let Some(n) = c.integer(doc, "", "max_frame_len_bytes") {
+        if !u64::try_from(n).is_ok_and(|n| MAX_FRAME_LEN_RANGE.contains(&n)) {
+            c.push(
+                "max_frame_len_bytes",
+                format!(
+                    "must be within {}..={}",
+                    MAX_FRAME_LEN_RANGE.start(),
+                    MAX_FRAME_LEN_RANGE.end()
+                ),
+            );
+        }
+    }
+
+    if let Some(quic) = c.section(doc, "quic") {
+        // Fields that fail their own checks keep the default so that
+        // QuicConfig::validate only reports the remaining problems
+        let mut cfg = QuicConfig::default();
+        if let Some(addr) = c.socket_addr(quic, "quic", "bind_addr") {
+            cfg.bind_addr = addr;
+        }
+        if let Some(n) = c.positive(quic, "quic", "idle_timeout_secs") {
+            cfg.idle_timeout_secs = n.unsigned_abs();
+        }
+        if let Some(n) = c.positive(quic, "quic", "keep_alive_interval_secs") {
+            cfg.keep_alive_interval_secs = n.unsigned_abs();
+        }
+        if let Some(n) = c.positive(quic, "quic", "max_concurrent_streams") {
+            cfg.max_concurrent_streams = n.unsigned_abs();
+        }
+        if let Err(e) = cfg.validate() {
+            // QuicConfig messages lead with the offending field name
+            let message = detail(e);
+            match message.split_once(' ') {
+                Some((field, rest)) => c.push(format!("quic.{field}"), rest),
+                None => c.push("quic", message),
+            }
+        }
+    }
+
+    if let Some(cli) = c.section(doc, "cli") {
+        c.string(cli, "cli", "daemon_endpoint");
+        c.string(cli, "cli", "token");
+        if let Some(ms) = c.integer(cli, "cli", "request_timeout_ms") {
+            if ms < 0 {
+                c.push("cli.request_timeout_ms", "must not be negative");
+            }
+        }
+    }
+
+    if let Some(endpoints) = c.section(doc, "endpoints") {
+        let grpc = c.socket_addr(endpoints, "endpoints", "grpc_addr");
+        let prometheus = c.socket_addr(endpoints, "endpoints", "prometheus_addr");
+        if grpc.is_some() && grpc == prometheus {
+            c.push(
+                "endpoints.prometheus_addr",
+                "must differ from endpoints.grpc_addr",
+            );
+        }
+    }
+
+    if let Some(network) = c.section(doc, "network") {
+        c.socket_addr(network, "network", "bind_addr");
+    }
+
+    c.out
+}
+
+/// Message of a validation error without the `config:` prefix.
+fn detail(e: Error) -> String {
+    match e {
+        Error::Config(message) => message,
+        other => other.to_string(),
+    }
+}
+
+/// Typed accessors for [`validate_all`] that record a violation on type mismatch.
+#[derive(Default)]
+struct Checks {
+    out: Vec<ConfigViolation>,
+}
+
+impl Checks {
+    fn push(&mut self, key: impl Into<String>, message: impl Into<String>) {
+        self.out.push(ConfigViolation::new(key, message));
+    }
+
+    fn get<'a>(
+        &mut self,
+        table: &'a toml::Table,
+        prefix: &str,
+        key: &str,
+        expected: &str,
+        f: impl FnOnce(&'a toml::Value) -> bool,
+    ) -> Option<&'a toml::Value> {
+        let value = table.get(key)?;
+        if f(value) {
+            return Some(value);
+        }
+        let path = if prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        self.push(
+            path,
+            format!("expected {expected}, found {}", value.type_str()),
+        );
+        None
+    }
+
+    fn section<'a>(&mut self, doc: &'a toml::Table, name: &str) -> Option<&'a toml::Table> {
+        self.get(doc, "", name, "table", toml::Value::is_table)?
+            .as_table()
+    }
+
+    fn string<'a>(&mut self, table: &'a toml::Table, prefix: &str, key: &str) -> Option<&'a str> {
+        self.get(table, prefix, key, "string", toml::Value::is_str)?
+            .as_str()
+    }
+
+    fn integer(&mut self, table: &toml::Table, prefix: &str, key: &str) -> Option<i64> {
+        self.get(table, prefix, key, "integer", toml::Value::is_integer)?
+            .as_integer()
+    }
+
+    fn boolean(&mut self, table: &toml::Table, prefix: &str, key: &str) -> Option<bool> {
+        self.get(table, prefix, key, "boolean", toml::Value::is_bool)?
+            .as_bool()
+    }
+
+    fn positive(&mut self, table: &toml::Table, prefix: &str, key: &str) -> Option<i64> {
+        let n = self.integer(table, prefix, key)?;
+        if n <= 0 {
+            self.push(format!("{prefix}.{key}"), "must be greater than 0");
+            return None;
+        }
+        Some(n)
+    }
+
+    fn socket_addr(&mut self, table: &toml::Table, prefix: &str, key: &str) -> Option<SocketAddr> {
+        let s = self.string(table, prefix, key)?;
+        match s.parse() {
+            Ok(addr) => Some(addr),
+            Err(_) => {
+                self.push(
+                    format!("{prefix}.{key}"),
+                    format!("invalid socket address: {s}"),
+                );
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_all_reports_every_violation() -> Result<()> {
+        let input = "log_level = \"loud\"\nlisten_port = 80\n\n[quic]\nidle_timeout_secs = 10\nkeep_alive_interval_secs = 30\n";
+        let doc = parse_config(input)?;
+        let v = validate_all(&doc);
+        let keys: Vec<&str> = v.iter().map(|v| v.key.as_str()).collect();
+        assert_eq!(
+            keys,
+            ["log_level", "listen_port", "quic.keep_alive_interval_secs"]
+        );
+        assert_eq!(v[0].line_in(input), Some(1));
+        assert_eq!(v[2].line_in(input), Some(6));
+        assert_eq!(
+            v[2].message, "must be less than idle_timeout_secs",
+            "cross-field check comes from QuicConfig::validate"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn validate_all_agrees_with_typed_validate() -> Result<()> {
+        let doc = parse_config("[quic]\nidle_timeout_secs = 0\nmax_concurrent_streams = -1\n")?;
+        let keys: Vec<String> = validate_all(&doc).into_iter().map(|v| v.key).collect();
+        assert_eq!(
+            keys,
+            ["quic.idle_timeout_secs", "quic.max_concurrent_streams"]
+        );
+
+        let doc = parse_config("max_frame_len_bytes = 67108864\nlisten_port = 70000\n")?;
+        let keys: Vec<String> = validate_all(&doc).into_iter().map(|v| v.key).collect();
+        assert_eq!(keys, ["listen_port"]);
+        Ok(())
+    }
+
+    #[test]
+    fn validate_all_reports_type_mismatch() -> Result<()> {
+        let doc = parse_config("enable_multipath = \"yes\"\ncli = 5\n")?;
+        let v = validate_all(&doc);
+        assert_eq!(v.len(), 2);
+        assert_eq!(
+            v[0].to_string(),
+            "enable_multipath: expected boolean, found string"
+        );
+        assert_eq!(v[1].to_string(), "cli: expected table, found integer");
+        Ok(())
+    }
+
+    #[test]
+    fn parse_config_reports_position() {
+        let res = parse_config("a = 1\nb = = 2\n");
+        assert!(matches!(res, Err(e) if e.to_string().contains("line 2")));
+    }
+}
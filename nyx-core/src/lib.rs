@@ -23,6 +23,7 @@ pub mod path_monitor;
 pub mod performance;
 pub mod push;
 pub mod push_gateway;
+pub mod rpc;
 pub mod sandbox;
 pub mod security; // Post-Compromise Recovery (PCR) detection and triggering
 pub mod types;
@@ -0,0 +1,109 @@
+#![cfg_attr(test, allow(clippy::unwrap_used))]
+
+//! Wire-level types shared by the daemon's JSON RPC and its clients.
+
+use serde::{Deserialize, Serialize};
+use std::{convert::TryFrom, fmt};
+
+/// Status carried in the `code` field of every daemon RPC response.
+///
+/// Serialized as the bare integer older clients already match on, so the
+/// wire format is unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "u16", try_from = "u16")]
+pub enum DaemonErrorCode {
+    /// Success (`0`).
+    Ok,
+    /// Malformed or semantically invalid request (`400`).
+    BadRequest,
+    /// Missing or wrong control token (`401`).
+    Unauthorized,
+    /// Request line exceeded the daemon's size limit (`413`).
+    PayloadTooLarge,
+    /// Unexpected failure inside the daemon (`500`).
+    Internal,
+    /// Transient condition; retrying later may succeed (`503`).
+    Unavailable,
+}
+
+impl DaemonErrorCode {
+    /// Legacy integer value of this code.
+    pub const fn as_u16(self) -> u16 {
+        match self {
+            Self::Ok => 0,
+            Self::BadRequest => 400,
+            Self::Unauthorized => 401,
+            Self::PayloadTooLarge => 413,
+            Self::Internal => 500,
+            Self::Unavailable => 503,
+        }
+    }
+
+    /// Map a legacy integer back to its variant; `None` for unknown codes.
+    pub const fn from_u16(code: u16) -> Option<Self> {
+        match code {
+            0 => Some(Self::Ok),
+            400 => Some(Self::BadRequest),
+            401 => Some(Self::Unauthorized),
+            413 => Some(Self::PayloadTooLarge),
+            500 => Some(Self::Internal),
+            503 => Some(Self::Unavailable),
+            _ => None,
+        }
+    }
+}
+
+impl From<DaemonErrorCode> for u16 {
+    fn from(code: DaemonErrorCode) -> Self {
+        code.as_u16()
+    }
+}
+
+impl TryFrom<u16> for DaemonErrorCode {
+    type Error = String;
+
+    fn try_from(code: u16) -> Result<Self, Self::Error> {
+        Self::from_u16(code).ok_or_else(|| format!("unknown daemon error code {code}"))
+    }
+}
+
+impl PartialEq<u16> for DaemonErrorCode {
+    fn eq(&self, other: &u16) -> bool {
+        self.as_u16() == *other
+    }
+}
+
+impl fmt::Display for DaemonErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_u16())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEGACY: [(DaemonErrorCode, u16); 6] = [
+        (DaemonErrorCode::Ok, 0),
+        (DaemonErrorCode::BadRequest, 400),
+        (DaemonErrorCode::Unauthorized, 401),
+        (DaemonErrorCode::PayloadTooLarge, 413),
+        (DaemonErrorCode::Internal, 500),
+        (DaemonErrorCode::Unavailable, 503),
+    ];
+
+    #[test]
+    fn serializes_to_legacy_integers() {
+        for (code, n) in LEGACY {
+            assert_eq!(serde_json::to_value(code).unwrap(), serde_json::json!(n));
+            let back: DaemonErrorCode = serde_json::from_value(serde_json::json!(n)).unwrap();
+            assert_eq!(back, code);
+        }
+    }
+
+    #[test]
+    fn unknown_integer_is_rejected() {
+        assert!(serde_json::from_str::<DaemonErrorCode>("418").is_err());
+        assert_eq!(DaemonErrorCode::from_u16(418), None);
+    }
+}
@@ -1,17 +1,6 @@
 #![allow(missing_docs)]
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-
-#[inline]
-fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
-    if a.len() != b.len() {
-        return false;
-    }
-    let mut diff = 0u8;
-    for i in 0..a.len() {
-        diff |= a[i] ^ b[i];
-    }
-    diff == 0
-}
+use nyx_crypto::ct_eq as constant_time_eq;
 
 fn benchct_eq(c: &mut Criterion) {
     let sizes = [32usize, 256, 4096];
@@ -33,6 +33,13 @@
 //! 4. Update `hybrid.rs` to support BIKE mode selection
 //! 5. Document security considerations and parameter choices
 //!
+//! ## Error Surface
+//!
+//! Every entry point ([`keygen`], [`encapsulate`], [`decapsulate`] and the
+//! [`BikeKeyPair`] methods) returns exactly `Error::NotImplemented("BIKE")`
+//! ([`NOT_IMPLEMENTED`]), so downstream code can compile against the API today
+//! and detect the missing backend at runtime without panics.
+//!
 //! ## References
 //!
 //! - BIKE Specification: https://bikesuite.org/
@@ -159,11 +166,69 @@ impl std::fmt::Debug for Ciphertext {
 /// BIKE shared secret
 pub type SharedSecret = [u8; sizes::SHARED_SECRET];
 
+/// Payload of the `Error::NotImplemented` returned by every BIKE operation.
+/// Use ML-KEM-768 (`kyber` feature) for post-quantum security instead.
+pub const NOT_IMPLEMENTED: &str = "BIKE";
+
+fn not_implemented() -> Error {
+    Error::NotImplemented(NOT_IMPLEMENTED.to_string())
+}
+
+/// BIKE-L1 key pair
+#[derive(Debug)]
+pub struct BikeKeyPair {
+    public: PublicKey,
+    secret: SecretKey,
+}
+
+impl BikeKeyPair {
+    /// Generate a fresh key pair
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotImplemented("BIKE")`.
+    pub fn generate<R: CryptoRng + RngCore>(rng: &mut R) -> Result<Self> {
+        let (public, secret) = keygen(rng)?;
+        Ok(Self { public, secret })
+    }
+
+    /// Assemble a key pair from existing key material
+    pub fn from_keys(public: PublicKey, secret: SecretKey) -> Self {
+        Self { public, secret }
+    }
+
+    /// The public half, to be sent to the encapsulating peer
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public
+    }
+
+    /// Encapsulate a shared secret to `remote`
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotImplemented("BIKE")`.
+    pub fn encapsulate<R: CryptoRng + RngCore>(
+        remote: &PublicKey,
+        rng: &mut R,
+    ) -> Result<(Ciphertext, SharedSecret)> {
+        encapsulate(remote, rng)
+    }
+
+    /// Recover the shared secret from a ciphertext addressed to this key pair
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotImplemented("BIKE")`.
+    pub fn decapsulate(&self, ct: &Ciphertext) -> Result<SharedSecret> {
+        decapsulate(&self.secret, ct)
+    }
+}
+
 /// Generate a BIKE-L1 keypair
 ///
 /// # Errors
 ///
-/// Returns `Error::NotImplemented` as BIKE is not yet implemented.
+/// Returns `Error::NotImplemented("BIKE")` as BIKE is not yet implemented.
 ///
 /// # Future Implementation
 ///
@@ -173,10 +238,7 @@ pub type SharedSecret = [u8; sizes::SHARED_SECRET];
 /// - Secure random number generation for key generation
 /// - Zeroization of intermediate values
 pub fn keygen<R: CryptoRng + RngCore>(_rng: &mut R) -> Result<(PublicKey, SecretKey)> {
-    Err(Error::NotImplemented(
-        "BIKE KEM is not implemented. Use ML-KEM-768 (kyber feature) for post-quantum security."
-            .to_string(),
-    ))
+    Err(not_implemented())
 }
 
 /// Encapsulate a shared secret to a public key
@@ -192,7 +254,7 @@ pub fn keygen<R: CryptoRng + RngCore>(_rng: &mut R) -> Result<(PublicKey, Secret
 ///
 /// # Errors
 ///
-/// Returns `Error::NotImplemented` as BIKE is not yet implemented.
+/// Returns `Error::NotImplemented("BIKE")` as BIKE is not yet implemented.
 ///
 /// # Future Implementation
 ///
@@ -205,10 +267,7 @@ pub fn encapsulate<R: CryptoRng + RngCore>(
     _pk: &PublicKey,
     _rng: &mut R,
 ) -> Result<(Ciphertext, SharedSecret)> {
-    Err(Error::NotImplemented(
-        "BIKE KEM encapsulation is not implemented. Use ML-KEM-768 (kyber feature) instead."
-            .to_string(),
-    ))
+    Err(not_implemented())
 }
 
 /// Decapsulate a shared secret from a ciphertext
@@ -224,7 +283,7 @@ pub fn encapsulate<R: CryptoRng + RngCore>(
 ///
 /// # Errors
 ///
-/// Returns `Error::NotImplemented` as BIKE is not yet implemented.
+/// Returns `Error::NotImplemented("BIKE")` as BIKE is not yet implemented.
 /// Future implementation may also return `Error::Crypto` for decoding failures.
 ///
 /// # Future Implementation
@@ -235,10 +294,7 @@ pub fn encapsulate<R: CryptoRng + RngCore>(
 /// - Side-channel resistance
 /// - Proper error propagation without leaking secret information
 pub fn decapsulate(_sk: &SecretKey, _ct: &Ciphertext) -> Result<SharedSecret> {
-    Err(Error::NotImplemented(
-        "BIKE KEM decapsulation is not implemented. Use ML-KEM-768 (kyber feature) instead."
-            .to_string(),
-    ))
+    Err(not_implemented())
 }
 
 #[cfg(test)]
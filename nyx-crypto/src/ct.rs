@@ -0,0 +1,48 @@
+#![forbid(unsafe_code)]
+
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+
+/// Compare two byte strings without data-dependent early exit.
+///
+/// Timing guarantee: for inputs of equal length the running time depends
+/// only on that length, not on where (or whether) the contents differ, so
+/// secrets such as keys, MACs and tokens cannot be recovered byte by byte.
+/// Lengths are treated as public: a length mismatch returns `false`
+/// immediately. Use [`ct_eq_secret`] when the length is secret too.
+pub fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    a.ct_eq(b).into()
+}
+
+/// Compare two variable-length secrets without revealing either length.
+///
+/// Both inputs are hashed with SHA-256 and the fixed-size digests are
+/// compared with [`ct_eq`], so a mismatch in length takes the same path as
+/// a mismatch in content. Intended for bearer tokens and passwords.
+pub fn ct_eq_secret(a: &[u8], b: &[u8]) -> bool {
+    ct_eq(&Sha256::digest(a), &Sha256::digest(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ct_eq_matches_slice_equality() {
+        assert!(ct_eq(b"", b""));
+        assert!(ct_eq(b"secret-token", b"secret-token"));
+        assert!(!ct_eq(b"secret-token", b"secret-tokeN"));
+        assert!(!ct_eq(b"Secret-token", b"secret-token"));
+        assert!(!ct_eq(b"secret", b"secret-token"));
+        assert!(!ct_eq(b"secret-token", b""));
+    }
+
+    #[test]
+    fn ct_eq_secret_matches_slice_equality() {
+        assert!(ct_eq_secret(b"", b""));
+        assert!(ct_eq_secret(b"secret-token", b"secret-token"));
+        assert!(!ct_eq_secret(b"secret-token", b"secret-tokeN"));
+        assert!(!ct_eq_secret(b"secret", b"secret-token"));
+        assert!(!ct_eq_secret(b"secret-token", b""));
+    }
+}
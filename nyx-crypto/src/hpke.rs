@@ -51,6 +51,58 @@ mod imp {
         Ok(pt)
     }
 
+    /// Sender: encapsulate to recipient'_s public key and derive `out_len` bytes
+    /// from the context'_s exporter secret (RFC 9180 §5.3)
+    pub fn export_sender(
+        pk_recip: &[u8],
+        exporter_ctx: &[u8],
+        out_len: usize,
+    ) -> Result<(Vec<u8>, Vec<u8>)> {
+        type KemType = X25519HkdfSha256;
+        let recip_pk = <KemType as Kem>::PublicKey::from_bytes(pk_recip)
+            .map_err(|_| Error::Protocol("hpke pk parse".into()))?;
+        let mut rng = OsRng;
+        let (enc, senderctx) = hpke::setup_sender::<HpkeAead, HkdfSha256, KemType, _>(
+            &OpModeS::Base,
+            &recip_pk,
+            b"nyx-hpke",
+            &mut rng,
+        )
+        .map_err(|_| Error::Protocol("hpke setup sender".into()))?;
+        let mut out = vec![0u8; out_len];
+        senderctx
+            .export(exporter_ctx, &mut out)
+            .map_err(|_| Error::Protocol("hpke export".into()))?;
+        Ok((enc.to_bytes().to_vec(), out))
+    }
+
+    /// Receiver: decapsulate `enc` with recipient'_s private key and derive the
+    /// same exported secret as [`export_sender`]
+    pub fn export_receiver(
+        sk_recip: &[u8],
+        enc: &[u8],
+        exporter_ctx: &[u8],
+        out_len: usize,
+    ) -> Result<Vec<u8>> {
+        type KemType = X25519HkdfSha256;
+        let recip_sk = <KemType as Kem>::PrivateKey::from_bytes(sk_recip)
+            .map_err(|_| Error::Protocol("hpke sk parse".into()))?;
+        let enc = <KemType as Kem>::EncappedKey::from_bytes(enc)
+            .map_err(|_| Error::Protocol("hpke enc parse".into()))?;
+        let recipctx = hpke::setup_receiver::<HpkeAead, HkdfSha256, KemType>(
+            &OpModeR::Base,
+            &recip_sk,
+            &enc,
+            b"nyx-hpke",
+        )
+        .map_err(|_| Error::Protocol("hpke setup receiver".into()))?;
+        let mut out = vec![0u8; out_len];
+        recipctx
+            .export(exporter_ctx, &mut out)
+            .map_err(|_| Error::Protocol("hpke export".into()))?;
+        Ok(out)
+    }
+
     /// Generate X25519 keypair. Caller must securely store/zeroize the secret key.
     pub fn gen_keypair() -> (Vec<u8>, Vec<u8>) {
         let mut rng = OsRng;
@@ -78,6 +130,14 @@ mod imp {
     pub fn open(_: &[u8], _: &[u8], _: &[u8], _: &[u8]) -> Result<Vec<u8>> {
         Err(Error::Protocol("hpke feature disabled".into()))
     }
+    /// HPKE export stub when `hpke` feature is disabled.
+    pub fn export_sender(_: &[u8], _: &[u8], _: usize) -> Result<(Vec<u8>, Vec<u8>)> {
+        Err(Error::Protocol("hpke feature disabled".into()))
+    }
+    /// HPKE export stub when `hpke` feature is disabled.
+    pub fn export_receiver(_: &[u8], _: &[u8], _: &[u8], _: usize) -> Result<Vec<u8>> {
+        Err(Error::Protocol("hpke feature disabled".into()))
+    }
     /// Keypair generation stub when `hpke` feature is disabled.
     pub fn gen_keypair() -> (Vec<u8>, Vec<u8>) {
         (vec![], vec![])
@@ -108,4 +168,17 @@ mod test_s {
         assert_eq!(rt, pt);
         Ok(())
     }
+
+    #[test]
+    fn hpke_export_matches_only_for_recipient() -> Result<(), Box<dyn std::error::Error>> {
+        let (sk, pk) = gen_keypair();
+        if pk.is_empty() {
+            return Ok(());
+        }
+        let (enc, secret) = export_sender(&pk, b"ctx", 32)?;
+        assert_eq!(export_receiver(&sk, &enc, b"ctx", 32)?, secret);
+        let (other_sk, _) = gen_keypair();
+        assert_ne!(export_receiver(&other_sk, &enc, b"ctx", 32)?, secret);
+        Ok(())
+    }
 }
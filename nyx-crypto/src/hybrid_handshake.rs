@@ -558,9 +558,48 @@ impl fmt::Debug for SharedSecret {
 }
 
 /// Complete hybrid handshake implementation
-pub struct HybridHandshake;
+///
+/// The associated functions drive the stateless client/server flow. An
+/// instance owns the local key pair, so a peer can [`encapsulate`] to its
+/// public key and the owner can [`decapsulate`] the resulting ciphertext.
+///
+/// [`encapsulate`]: HybridHandshake::encapsulate
+/// [`decapsulate`]: HybridHandshake::decapsulate
+pub struct HybridHandshake {
+    key_pair: HybridKeyPair,
+}
 
 impl HybridHandshake {
+    /// Create a handshake endpoint with a freshly generated key pair
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            key_pair: HybridKeyPair::generate()?,
+        })
+    }
+
+    /// Public key to send to the peer that will encapsulate
+    pub fn public_key(&self) -> HybridPublicKey {
+        self.key_pair.public_key()
+    }
+
+    /// Encapsulate a fresh 32-byte shared secret to `remote`
+    ///
+    /// The returned ciphertext is sent to the owner of `remote`, whose
+    /// [`decapsulate`](HybridHandshake::decapsulate) yields the same secret.
+    pub fn encapsulate(remote: &HybridPublicKey) -> Result<(HybridCiphertext, SharedSecret)> {
+        Self::validate_public_key(remote)?;
+        HybridKeyPair::encapsulate(remote)
+    }
+
+    /// Recover the shared secret from a ciphertext addressed to this key pair
+    ///
+    /// ML-KEM uses implicit rejection: a tampered Kyber ciphertext does not
+    /// fail here but yields an unrelated secret, which the peers detect when
+    /// their derived keys disagree.
+    pub fn decapsulate(&self, ct: &HybridCiphertext) -> Result<SharedSecret> {
+        Self::client_finalize(&self.key_pair, ct)
+    }
+
     /// Client-side: Generate key pair and create initial message
     pub fn client_init() -> Result<(HybridKeyPair, HybridPublicKey)> {
         info!("Initializing client-side hybrid handshake");
@@ -14,6 +14,40 @@ pub fn hkdf_expand(prk: &[u8], info: &[u8], out: &mut [u8]) -> Result<()> {
     Ok(())
 }
 
+/// TLS 1.3 `HKDF-Expand-Label` (RFC 8446 §7.1) over HKDF-SHA256.
+///
+/// `secret` is used as the PRK; the label is prefixed with `"tls13 "` and
+/// encoded together with `context` and `out_len` into the `HkdfLabel` info.
+pub fn expand_label(secret: &[u8], label: &str, context: &[u8], out_len: usize) -> Result<Vec<u8>> {
+    const PREFIX: &[u8] = b"tls13 ";
+    let full_len = PREFIX.len() + label.len();
+    if label.is_empty() || full_len > 255 {
+        return Err(Error::Crypto(format!(
+            "expand_label: label length {} out of range",
+            label.len()
+        )));
+    }
+    if context.len() > 255 {
+        return Err(Error::Crypto(
+            "expand_label: context longer than 255 bytes".into(),
+        ));
+    }
+    let length = u16::try_from(out_len)
+        .map_err(|_| Error::Crypto("expand_label: output length exceeds 65535".into()))?;
+
+    let mut info = Vec::with_capacity(2 + 1 + full_len + 1 + context.len());
+    info.extend_from_slice(&length.to_be_bytes());
+    info.push(full_len as u8);
+    info.extend_from_slice(PREFIX);
+    info.extend_from_slice(label.as_bytes());
+    info.push(context.len() as u8);
+    info.extend_from_slice(context);
+
+    let mut out = vec![0u8; out_len];
+    hkdf_expand(secret, &info, &mut out)?;
+    Ok(out)
+}
+
 /// Build a 96-bit nonce from base nonce and counter (RFC8439-style XOR of last 8 bytes).
 pub fn aeadnonce_xor(base: &[u8; 12], seq: u64) -> [u8; 12] {
     let mut n = [0u8; 12];
@@ -29,6 +63,8 @@ pub fn aeadnonce_xor(base: &[u8; 12], seq: u64) -> [u8; 12] {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use hex_literal::hex;
+
     #[test]
     fn nonce_xor_changes_tail() {
         let base = [0u8; 12];
@@ -37,4 +73,34 @@ mod tests {
         assert_ne!(n0, n1);
         assert_eq!(&n0[..4], &n1[..4]);
     }
+
+    // RFC 8448 §3 (Simple 1-RTT Handshake) traffic key derivation.
+    #[test]
+    fn expand_label_matches_rfc8448() -> Result<()> {
+        let server_hs_secret =
+            hex!("b67b7d690cc16c4e75e54213cb2d37b4e9c912bcded9105d42befd59d391ad38");
+        let key = expand_label(&server_hs_secret, "key", &[], 16)?;
+        assert_eq!(key, hex!("3fce516009c21727d0f2e4e86ee403bc"));
+        let iv = expand_label(&server_hs_secret, "iv", &[], 12)?;
+        assert_eq!(iv, hex!("5d313eb2671276ee13000b30"));
+
+        // Derive-Secret(early_secret, "derived", "") uses the empty transcript hash as context.
+        let early_secret = hex!("33ad0a1c607ec03b09e6cd9893680ce210adf300aa1f2660e1b22e10f170f92a");
+        let empty_hash = <Sha256 as sha2::Digest>::digest([]);
+        let derived = expand_label(&early_secret, "derived", &empty_hash, 32)?;
+        assert_eq!(
+            derived,
+            hex!("6f2615a108c702c5678f54fc9dbab69716c076189c48250cebeac3576c3611ba")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn expand_label_rejects_bad_lengths() {
+        let secret = [7u8; 32];
+        assert!(expand_label(&secret, "", &[], 16).is_err());
+        assert!(expand_label(&secret, &"x".repeat(250), &[], 16).is_err());
+        assert!(expand_label(&secret, "key", &[0u8; 256], 16).is_err());
+        assert!(expand_label(&secret, "key", &[], 70_000).is_err());
+    }
 }
@@ -5,21 +5,219 @@
 //! - Encrypt_s with AES-GCM-256 (pure Rust)
 //! - Zeroize_s key material
 //!
+//! [`Keystore`] persist_s named key_s in a versioned file: an Argon2id-derived
+//! key seal_s the entrie_s with AES-256-GCM, and the header i_s bound a_s AAD so
+//! any flipped byte fail_s authentication.
+//!
 //!   Thi_s i_s intended for developer tooling and test_s, not HSM-grade storage.
 
-use aes_gcm::aead::{Aead, KeyInit};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use aes_gcm::aead::{Aead, KeyInit, Payload};
 use aes_gcm::{Aes256Gcm, Key, Nonce};
+use argon2::{Algorithm, Argon2, Params, Version};
 use getrandom::getrandom;
 use pbkdf2::pbkdf2_hmac;
 use sha2::Sha256;
-use zeroize::Zeroize;
+use zeroize::{Zeroize, Zeroizing};
 
 use crate::{Error, Result};
 
-/// Simple password-based keystore implementation with enhanced security
-struct Keystore;
+/// Named key_s sealed on disk under a passphrase
+///
+/// File layout (all integer_s big-endian):
+///
+/// ```text
+/// magic "NYXK" | version u8 | argon2 m_cost u32 | t_cost u32 | p_cost u32
+///   | salt [16] | nonce [12] | AES-256-GCM(entrie_s) || tag [16]
+/// ```
+///
+/// Everything before the ciphertext, version byte included, i_s authenticated
+/// a_s AAD, so a wrong passphrase and a corrupted file both surface a_s
+/// [`Error::AuthenticationFailed`]. The file i_s replaced atomically and,
+/// on Unix, i_s readable by its owner only (mode 0600).
+#[derive(Default)]
+pub struct Keystore {
+    key_s: BTreeMap<String, Zeroizing<Vec<u8>>>,
+}
+
+const KEYSTORE_MAGIC: &[u8; 4] = b"NYXK";
+/// Current on-disk format version.
+pub const KEYSTORE_VERSION: u8 = 1;
+const KEYSTORE_HEADER_LEN: usize = 4 + 1 + 12 + SALT_LEN + NONCE_LEN;
+// OWASP baseline for Argon2id: 19 MiB, 2 passe_s, 1 lane
+const ARGON2_M_COST: u32 = 19 * 1024;
+const ARGON2_T_COST: u32 = 2;
+const ARGON2_P_COST: u32 = 1;
+// Upper bound_s accepted from a file header (DoS prevention)
+const ARGON2_MAX_M_COST: u32 = 1024 * 1024;
+const ARGON2_MAX_T_COST: u32 = 16;
+const ARGON2_MAX_P_COST: u32 = 16;
 
 impl Keystore {
+    /// Create an empty keystore
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Store `key` under `name`, replacing any previou_s value
+    pub fn insert(&mut self, name: impl Into<String>, key: &[u8]) {
+        self.key_s.insert(name.into(), Zeroizing::new(key.to_vec()));
+    }
+
+    /// Look up the key stored under `name`
+    pub fn get(&self, name: &str) -> Option<&[u8]> {
+        self.key_s.get(name).map(|k| k.as_slice())
+    }
+
+    /// Name_s of all stored key_s, in sorted order
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.key_s.keys().map(String::as_str)
+    }
+
+    /// Seal all key_s under `passphrase` and write them to `path`
+    ///
+    /// The sealed file i_s written to a temporary sibling and renamed over
+    /// `path`, so a crash never leaves a half-written keystore behind.
+    ///
+    /// # Errors
+    /// Return_s `Error::Protocol` for a weak passphrase or an I/O failure.
+    pub fn save_to(&self, path: impl AsRef<Path>, passphrase: &[u8]) -> Result<()> {
+        check_password(passphrase)?;
+        let mut header = Vec::with_capacity(KEYSTORE_HEADER_LEN);
+        header.extend_from_slice(KEYSTORE_MAGIC);
+        header.push(KEYSTORE_VERSION);
+        header.extend_from_slice(&ARGON2_M_COST.to_be_bytes());
+        header.extend_from_slice(&ARGON2_T_COST.to_be_bytes());
+        header.extend_from_slice(&ARGON2_P_COST.to_be_bytes());
+        let mut salt = [0u8; SALT_LEN];
+        let mut nonce = [0u8; NONCE_LEN];
+        getrandom(&mut salt).map_err(|e| {
+            Error::Protocol(format!("SECURITY: secure random generation failed: {e}"))
+        })?;
+        getrandom(&mut nonce).map_err(|e| {
+            Error::Protocol(format!("SECURITY: secure random generation failed: {e}"))
+        })?;
+        header.extend_from_slice(&salt);
+        header.extend_from_slice(&nonce);
+
+        let key = derive_keystore_key(
+            passphrase,
+            &salt,
+            ARGON2_M_COST,
+            ARGON2_T_COST,
+            ARGON2_P_COST,
+        )?;
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key.as_slice()));
+        let entries = self.encode_entries()?;
+        let ct = cipher
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: &entries,
+                    aad: &header,
+                },
+            )
+            .map_err(|_| Error::Protocol("keystore encrypt failed".into()))?;
+
+        let mut out = header;
+        out.extend_from_slice(&ct);
+        write_private_atomic(path.as_ref(), &out)
+            .map_err(|e| Error::Protocol(format!("keystore write: {e}")))
+    }
+
+    /// Read `path` and unseal it with `passphrase`
+    ///
+    /// # Errors
+    /// Return_s `Error::AuthenticationFailed` for a wrong passphrase or any
+    /// corruption (including a damaged version byte), `Error::Protocol` for
+    /// an authentic file of an unsupported version or an I/O failure.
+    pub fn load_from(path: impl AsRef<Path>, passphrase: &[u8]) -> Result<Self> {
+        let blob =
+            std::fs::read(path).map_err(|e| Error::Protocol(format!("keystore read: {e}")))?;
+        let corrupted = || Error::AuthenticationFailed("keystore file corrupted".into());
+        if blob.len() < KEYSTORE_HEADER_LEN + 16 || &blob[..4] != KEYSTORE_MAGIC {
+            return Err(corrupted());
+        }
+        let be_u32 =
+            |at: usize| u32::from_be_bytes([blob[at], blob[at + 1], blob[at + 2], blob[at + 3]]);
+        let (m_cost, t_cost, p_cost) = (be_u32(5), be_u32(9), be_u32(13));
+        if m_cost > ARGON2_MAX_M_COST || t_cost > ARGON2_MAX_T_COST || p_cost > ARGON2_MAX_P_COST {
+            return Err(corrupted());
+        }
+        let (header, ct) = blob.split_at(KEYSTORE_HEADER_LEN);
+        let salt = &header[17..17 + SALT_LEN];
+        let nonce = &header[17 + SALT_LEN..];
+
+        let key = derive_keystore_key(passphrase, salt, m_cost, t_cost, p_cost)
+            .map_err(|_| corrupted())?;
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key.as_slice()));
+        let entries = Zeroizing::new(
+            cipher
+                .decrypt(
+                    Nonce::from_slice(nonce),
+                    Payload {
+                        msg: ct,
+                        aad: header,
+                    },
+                )
+                .map_err(|_| {
+                    Error::AuthenticationFailed(
+                        "keystore authentication failed (wrong passphrase or corrupted file)"
+                            .into(),
+                    )
+                })?,
+        );
+        // The version byte i_s part of the AAD, so it i_s only trusted once the
+        // header ha_s authenticated; a flipped byte never reache_s thi_s point.
+        if header[4] != KEYSTORE_VERSION {
+            return Err(Error::Protocol(format!(
+                "unsupported keystore version {} (expected {KEYSTORE_VERSION})",
+                header[4]
+            )));
+        }
+        Self::decode_entries(&entries).ok_or_else(corrupted)
+    }
+
+    /// `count u32 | (name_len u16 | name | key_len u32 | key)*`
+    fn encode_entries(&self) -> Result<Zeroizing<Vec<u8>>> {
+        let too_large = || Error::Protocol("keystore entry too large".into());
+        let mut out = Zeroizing::new(Vec::new());
+        let count = u32::try_from(self.key_s.len()).map_err(|_| too_large())?;
+        out.extend_from_slice(&count.to_be_bytes());
+        for (name, key) in &self.key_s {
+            let name_len = u16::try_from(name.len()).map_err(|_| too_large())?;
+            let key_len = u32::try_from(key.len()).map_err(|_| too_large())?;
+            out.extend_from_slice(&name_len.to_be_bytes());
+            out.extend_from_slice(name.as_bytes());
+            out.extend_from_slice(&key_len.to_be_bytes());
+            out.extend_from_slice(key);
+        }
+        Ok(out)
+    }
+
+    fn decode_entries(mut buf: &[u8]) -> Option<Self> {
+        fn take<'a>(buf: &mut &'a [u8], n: usize) -> Option<&'a [u8]> {
+            if buf.len() < n {
+                return None;
+            }
+            let (head, rest) = buf.split_at(n);
+            *buf = rest;
+            Some(head)
+        }
+        let count = u32::from_be_bytes(take(&mut buf, 4)?.try_into().ok()?);
+        let mut store = Self::new();
+        for _ in 0..count {
+            let name_len = u16::from_be_bytes(take(&mut buf, 2)?.try_into().ok()?);
+            let name = std::str::from_utf8(take(&mut buf, name_len as usize)?).ok()?;
+            let key_len = u32::from_be_bytes(take(&mut buf, 4)?.try_into().ok()?);
+            let key = take(&mut buf, key_len as usize)?;
+            store.insert(name, key);
+        }
+        buf.is_empty().then_some(store)
+    }
+
     /// Check for common weak password patterns
     ///
     /// # Security Considerations
@@ -64,6 +262,85 @@ const PBKDF2_ITERS: u32 = 600_000; // Enhanced security against rainbow table an
 const SALT_LEN: usize = 16;
 const NONCE_LEN: usize = 12; // AES-GCM standard 96-bit
 
+/// Shared passphrase policy for password-based encryption
+fn check_password(password: &[u8]) -> Result<()> {
+    // SECURITY ENHANCEMENT: Comprehensive input validation to prevent DoS attacks
+    if password.is_empty() {
+        return Err(Error::Protocol(
+            "SECURITY: password cannot be empty (authentication bypass prevention)".into(),
+        ));
+    }
+    if password.len() < 8 {
+        return Err(Error::Protocol(
+            "SECURITY: password too short, minimum 8 bytes required (brute force prevention)"
+                .into(),
+        ));
+    }
+    if password.len() > 1024 {
+        return Err(Error::Protocol(
+            "SECURITY: password too long, maximum 1024 bytes allowed (DoS prevention)".into(),
+        ));
+    }
+
+    // SECURITY ENHANCEMENT: Check for weak passwords (basic patterns)
+    if Keystore::is_weak_password(password) {
+        return Err(Error::Protocol(
+            "SECURITY: weak password detected, please use a stronger password".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Write `data` to a fresh owner-only temp file next to `path`, then rename
+/// it into place
+fn write_private_atomic(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let name = path
+        .file_name()
+        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidInput, "no file name"))?;
+    let mut tmp_name = std::ffi::OsString::from(".");
+    tmp_name.push(name);
+    tmp_name.push(".tmp");
+    let tmp = path.with_file_name(tmp_name);
+
+    // A stale temp file may carry looser permission_s; start from scratch
+    let _ = std::fs::remove_file(&tmp);
+    let mut opts = std::fs::OpenOptions::new();
+    opts.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        opts.mode(0o600);
+    }
+    let result = opts.open(&tmp).and_then(|mut f| {
+        f.write_all(data)?;
+        f.sync_all()
+    });
+    if let Err(e) = result.and_then(|()| std::fs::rename(&tmp, path)) {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(e);
+    }
+    Ok(())
+}
+
+/// Derive the keystore file key with Argon2id
+fn derive_keystore_key(
+    passphrase: &[u8],
+    salt: &[u8],
+    m_cost: u32,
+    t_cost: u32,
+    p_cost: u32,
+) -> Result<Zeroizing<[u8; 32]>> {
+    let params = Params::new(m_cost, t_cost, p_cost, Some(32))
+        .map_err(|e| Error::Crypto(format!("invalid argon2 parameters: {e}")))?;
+    let mut key = Zeroizing::new([0u8; 32]);
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(passphrase, salt, key.as_mut())
+        .map_err(|e| Error::Crypto(format!("argon2 derivation failed: {e}")))?;
+    Ok(key)
+}
+
 /// Encrypt plaintext with password-based key derivation
 ///
 /// # Security Considerations
@@ -87,23 +364,7 @@ const NONCE_LEN: usize = 12; // AES-GCM standard 96-bit
 /// # Ok::<(), nyx_crypto::Error>(())
 /// ```
 pub fn encrypt_with_password(password: &[u8], plaintext: &[u8]) -> Result<Vec<u8>> {
-    // SECURITY ENHANCEMENT: Comprehensive input validation to prevent DoS attacks
-    if password.is_empty() {
-        return Err(Error::Protocol(
-            "SECURITY: password cannot be empty (authentication bypass prevention)".into(),
-        ));
-    }
-    if password.len() < 8 {
-        return Err(Error::Protocol(
-            "SECURITY: password too short, minimum 8 bytes required (brute force prevention)"
-                .into(),
-        ));
-    }
-    if password.len() > 1024 {
-        return Err(Error::Protocol(
-            "SECURITY: password too long, maximum 1024 bytes allowed (DoS prevention)".into(),
-        ));
-    }
+    check_password(password)?;
     if plaintext.len() > 10 * 1024 * 1024 {
         return Err(Error::Protocol(
             "SECURITY: plaintext too large, maximum 10MB allowed (memory exhaustion prevention)"
@@ -111,13 +372,6 @@ pub fn encrypt_with_password(password: &[u8], plaintext: &[u8]) -> Result<Vec<u8
         ));
     }
 
-    // SECURITY ENHANCEMENT: Check for weak passwords (basic patterns)
-    if Keystore::is_weak_password(password) {
-        return Err(Error::Protocol(
-            "SECURITY: weak password detected, please use a stronger password".into(),
-        ));
-    }
-
     let mut salt = [0u8; SALT_LEN];
     let mut nonce = [0u8; NONCE_LEN];
 
@@ -254,4 +508,97 @@ mod test_s {
         assert!(decrypt_with_password(b"DifferentStr0ngP@ss!", &blob).is_err());
         Ok(())
     }
+
+    fn keystore_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("nyx-keystore-{}-{name}", std::process::id()))
+    }
+
+    fn sample_keystore() -> Keystore {
+        let mut ks = Keystore::new();
+        ks.insert("node", &[0x11; 32]);
+        ks.insert("control", b"cookie-secret");
+        ks
+    }
+
+    #[test]
+    fn keystore_file_roundtrip() -> core::result::Result<(), Box<dyn std::error::Error>> {
+        let path = keystore_path("roundtrip");
+        sample_keystore().save_to(&path, b"StrongP@ssw0rd123!")?;
+        let blob = std::fs::read(&path)?;
+        assert_eq!(&blob[..4], KEYSTORE_MAGIC);
+        assert_eq!(blob[4], KEYSTORE_VERSION);
+
+        let loaded = Keystore::load_from(&path, b"StrongP@ssw0rd123!")?;
+        std::fs::remove_file(&path)?;
+        assert_eq!(loaded.names().collect::<Vec<_>>(), ["control", "node"]);
+        assert_eq!(loaded.get("node"), Some(&[0x11; 32][..]));
+        assert_eq!(loaded.get("control"), Some(&b"cookie-secret"[..]));
+        Ok(())
+    }
+
+    #[test]
+    fn keystore_file_wrong_passphrase() -> core::result::Result<(), Box<dyn std::error::Error>> {
+        let path = keystore_path("wrong-pass");
+        sample_keystore().save_to(&path, b"StrongP@ssw0rd123!")?;
+        let res = Keystore::load_from(&path, b"DifferentStr0ngP@ss!");
+        std::fs::remove_file(&path)?;
+        assert!(matches!(res, Err(Error::AuthenticationFailed(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn keystore_file_flipped_byte() -> core::result::Result<(), Box<dyn std::error::Error>> {
+        let path = keystore_path("flipped");
+        sample_keystore().save_to(&path, b"StrongP@ssw0rd123!")?;
+        let mut blob = std::fs::read(&path)?;
+        // One byte in the authenticated header, one in the ciphertext.
+        for at in [KEYSTORE_HEADER_LEN - 1, blob.len() - 20] {
+            blob[at] ^= 0x01;
+            std::fs::write(&path, &blob)?;
+            let res = Keystore::load_from(&path, b"StrongP@ssw0rd123!");
+            assert!(matches!(res, Err(Error::AuthenticationFailed(_))));
+            blob[at] ^= 0x01;
+        }
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn keystore_file_flipped_version_fails_authentication(
+    ) -> core::result::Result<(), Box<dyn std::error::Error>> {
+        let path = keystore_path("flipped-version");
+        sample_keystore().save_to(&path, b"StrongP@ssw0rd123!")?;
+        let mut blob = std::fs::read(&path)?;
+        blob[4] ^= 0x01;
+        std::fs::write(&path, &blob)?;
+        let res = Keystore::load_from(&path, b"StrongP@ssw0rd123!");
+        std::fs::remove_file(&path)?;
+        assert!(matches!(res, Err(Error::AuthenticationFailed(_))));
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn keystore_file_is_owner_only_and_replaced_atomically(
+    ) -> core::result::Result<(), Box<dyn std::error::Error>> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = keystore_path("atomic-dir");
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join("keys.nyxk");
+        std::fs::write(&path, b"old contents")?;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644))?;
+
+        sample_keystore().save_to(&path, b"StrongP@ssw0rd123!")?;
+        let mode = std::fs::metadata(&path)?.permissions().mode() & 0o777;
+        assert_eq!(mode, 0o600);
+        let leftover: Vec<_> = std::fs::read_dir(&dir)?
+            .map(|e| e.map(|e| e.file_name()))
+            .collect::<std::io::Result<_>>()?;
+        let loaded = Keystore::load_from(&path, b"StrongP@ssw0rd123!");
+        std::fs::remove_dir_all(&dir)?;
+        assert_eq!(leftover, ["keys.nyxk"]);
+        assert!(loaded.is_ok());
+        Ok(())
+    }
 }
@@ -29,6 +29,8 @@
 
 /// Authenticated encryption (ChaCha20-Poly1305) utilities.
 pub mod aead;
+/// Constant-time comparison helpers.
+pub mod ct;
 /// Hybrid public-key encryption (HPKE) helpers.
 pub mod hpke;
 /// Hybrid KEM scaffolding and telemetry.
@@ -81,6 +83,8 @@ pub mod kyber;
 #[cfg(feature = "bike")]
 pub mod bike;
 
+pub use ct::{ct_eq, ct_eq_secret};
+
 // Hybrid post-quantum handshake (Kyber-768 + X25519)
 #[cfg(feature = "hybrid-handshake")]
 pub use hybrid_handshake::{
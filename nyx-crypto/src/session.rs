@@ -4,6 +4,7 @@ use crate::aead::{AeadCipher, AeadKey, AeadNonce, AeadSuite};
 use crate::kdf::{aeadnonce_xor, hkdf_expand};
 use crate::{Error, Result};
 use std::sync::OnceLock;
+use std::time::{Duration, Instant};
 
 /// AEAD session (unidirectional): derive per-record nonce from base nonce + sequence,
 /// with optional 32-bit direction identifier mixed to avoid overlap acros_s direction_s.
@@ -17,8 +18,13 @@ pub struct AeadSession {
     // Total ciphertext byte_s sent for byte-_threshold rekey (0 disable_s _threshold)
     _bytes_sent: u64,
     _rekey_bytes_interval: u64,
+    // Wall-clock key lifetime (None disable_s) and when the current key epoch began
+    rekey_after: Option<Duration>,
+    epoch_started: Option<Instant>,
     // 32-bit direction identifier to be XORed into the first 4 byte_s of the nonce
     dir_id: u32,
+    // Highest sequence sealed under the current key (None until first seal / after rekey)
+    highest_sealed: Option<u64>,
     // Pre-computed cipher instance for maximum performance - eliminates allocation overhead
     cipher: OnceLock<AeadCipher>,
 }
@@ -45,7 +51,10 @@ impl AeadSession {
             __rekey_interval: 1 << 20,
             _bytes_sent: 0,
             _rekey_bytes_interval: 0,
+            rekey_after: None,
+            epoch_started: None,
             dir_id: 0,
+            highest_sealed: None,
             cipher: OnceLock::new(),
         }
     }
@@ -75,6 +84,17 @@ impl AeadSession {
         self
     }
 
+    /// Set rekey _threshold by elapsed time since the current key wa_s installed
+    ///
+    /// Bound_s key lifetime for low-volume, long-lived session_s that never
+    /// reach the record or byte _threshold_s. The clock start_s now and restart_s
+    /// on every [`rekey`](Self::rekey).
+    pub fn with_rekey_after(mut self, interval: Duration) -> Self {
+        self.rekey_after = Some(interval);
+        self.epoch_started = Some(Instant::now());
+        self
+    }
+
     /// Set 32-bit direction identifier to be mixed into nonce (first 4 byte_s XOR)
     pub fn withdirection_id(mut self, dir_id: u32) -> Self {
         self.dir_id = dir_id;
@@ -86,8 +106,13 @@ impl AeadSession {
         self.seq
     }
 
-    /// Whether rekey criteria by record_s/byte_s are met
+    /// Whether rekey criteria by record_s/byte_s/elapsed time are met
     pub fn needs_rekey(&self) -> bool {
+        if let (Some(after), Some(started)) = (self.rekey_after, self.epoch_started) {
+            if started.elapsed() >= after {
+                return true;
+            }
+        }
         if self.seq >= self.__rekey_interval {
             return true;
         }
@@ -111,6 +136,10 @@ impl AeadSession {
         self.basenonce = newnonce;
         self.seq = 0;
         self._bytes_sent = 0;
+        self.highest_sealed = None;
+        if self.rekey_after.is_some() {
+            self.epoch_started = Some(Instant::now());
+        }
         // Clear pre-computed cipher to force recreation with new key
         self.cipher = OnceLock::new();
     }
@@ -122,7 +151,21 @@ impl AeadSession {
     /// - Enforces strict input size limits to prevent DoS attacks
     /// - Implements automatic rekeying based on usage thresholds
     /// - Validates input parameters before any cryptographic operations
+    /// - Refuses to seal at a sequence not above the highest one already used
+    ///   under the current key. This is a safety net against nonce reuse
+    ///   (fatal for ChaCha20-Poly1305) caused by state bugs, not a substitute
+    ///   for correct sequencing and rekey ordering by the caller.
     pub fn sealnext(&mut self, aad: &[u8], plaintext: &[u8]) -> Result<(u64, Vec<u8>)> {
+        // SECURITY: Nonce-misuse assertion layer
+        if let Some(highest) = self.highest_sealed {
+            if self.seq <= highest {
+                return Err(Error::Crypto(format!(
+                    "SECURITY: nonce reuse detected (sequence {} not above highest sealed {})",
+                    self.seq, highest
+                )));
+            }
+        }
+
         // SECURITY ENHANCEMENT: Comprehensive sequence number validation
         if self.seq >= self.__maxseq {
             return Err(Error::Protocol(format!(
@@ -176,6 +219,7 @@ impl AeadSession {
         let cipher = self.get_cipher();
         let ct = cipher.seal(n, aad, plaintext)?;
         let used = self.seq;
+        self.highest_sealed = Some(used);
         self.seq = self.seq.saturating_add(1);
         // タグも含む暗号斁E��を加算（おおよそ�E上限としてDoS耐性に寁E��！E
         self._bytes_sent = self._bytes_sent.saturating_add(ct.len() as u64);
@@ -183,6 +227,9 @@ impl AeadSession {
     }
 
     /// Decrypt at a given sequence number (reordering/retransmit handled by caller)
+    ///
+    /// Never refuses on rekey criteria: record_s sealed before the peer rotated
+    /// must still open. Receiver_s poll [`needs_rekey`](Self::needs_rekey).
     pub fn open_at(&self, seq: u64, aad: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>> {
         if aad.len() > Self::MAX_AAD_LEN {
             return Err(Error::Protocol("aad too long".into()));
@@ -242,7 +289,10 @@ impl Drop for AeadSession {
         self.__rekey_interval = 0;
         self._bytes_sent = 0;
         self._rekey_bytes_interval = 0;
+        self.rekey_after = None;
+        self.epoch_started = None;
         self.dir_id = 0;
+        self.highest_sealed = None;
     }
 }
 
@@ -255,7 +305,9 @@ impl core::fmt::Debug for AeadSession {
             .field("rekey_interval", &self.__rekey_interval)
             .field("bytes_sent", &self._bytes_sent)
             .field("rekey_bytes_interval", &self._rekey_bytes_interval)
+            .field("rekey_after", &self.rekey_after)
             .field("dir_id", &self.dir_id)
+            .field("highest_sealed", &self.highest_sealed)
             .finish()
     }
 }
@@ -380,6 +432,28 @@ mod test_s {
         Ok(())
     }
 
+    #[test]
+    fn seal_refuses_nonce_rollback() -> core::result::Result<(), Box<dyn std::error::Error>> {
+        let key = AeadKey([5u8; 32]);
+        let base = [2u8; 12];
+        let mut tx = AeadSession::new(AeadSuite::ChaCha20Poly1305, key, base).withdirection_id(1);
+        tx.sealnext(b"aad", b"m0")?;
+        tx.sealnext(b"aad", b"m1")?;
+
+        // Simulate a state bug rolling the counter back under the same key.
+        tx.seq = 1;
+        let err = tx.sealnext(b"aad", b"m2");
+        assert!(matches!(err, Err(Error::Crypto(_))));
+        tx.seq = 0;
+        assert!(matches!(tx.sealnext(b"aad", b"m2"), Err(Error::Crypto(_))));
+
+        // A rekey starts a fresh key epoch, so sequence 0 is legitimate again.
+        tx.rekey();
+        let (s, _) = tx.sealnext(b"aad", b"m3")?;
+        assert_eq!(s, 0);
+        Ok(())
+    }
+
     #[test]
     fn rekey_by_bytes_threshold() -> core::result::Result<(), Box<dyn std::error::Error>> {
         let key = AeadKey([22u8; 32]);
@@ -397,4 +471,31 @@ mod test_s {
         assert!(!tx.needs_rekey());
         Ok(())
     }
+
+    #[test]
+    fn rekey_after_elapsed_time() -> core::result::Result<(), Box<dyn std::error::Error>> {
+        let key = AeadKey([23u8; 32]);
+        let base = [8u8; 12];
+        let mut tx = AeadSession::new(AeadSuite::ChaCha20Poly1305, key.clone(), base)
+            .with_rekey_after(Duration::from_millis(10));
+        let rx = AeadSession::new(AeadSuite::ChaCha20Poly1305, key, base)
+            .with_rekey_after(Duration::from_millis(10));
+        let (s0, c0) = tx.sealnext(b"a", b"low volume")?;
+        assert!(!tx.needs_rekey());
+
+        std::thread::sleep(Duration::from_millis(15));
+        assert!(tx.needs_rekey());
+        assert!(rx.needs_rekey());
+        assert!(matches!(
+            tx.sealnext(b"a", b"late"),
+            Err(Error::Protocol(_))
+        ));
+        // In-flight records from the expiring epoch still open.
+        assert_eq!(rx.open_at(s0, b"a", &c0)?, b"low volume");
+
+        tx.rekey();
+        assert!(!tx.needs_rekey());
+        let _ = tx.sealnext(b"a", b"fresh")?;
+        Ok(())
+    }
 }
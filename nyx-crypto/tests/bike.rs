@@ -5,20 +5,20 @@
 
 #![cfg(feature = "bike")]
 
-use nyx_crypto::bike::{decapsulate, encapsulate, keygen};
+use nyx_crypto::bike::{decapsulate, encapsulate, keygen, NOT_IMPLEMENTED};
 use nyx_crypto::Error;
 use rand::thread_rng;
 
+fn is_documented_error<T>(result: nyx_crypto::Result<T>) -> bool {
+    matches!(result, Err(Error::NotImplemented(ref what)) if what == NOT_IMPLEMENTED)
+}
+
 #[test]
 fn test_bike_keygen_not_implemented() {
     let mut rng = thread_rng();
     let result = keygen(&mut rng);
 
-    assert!(matches!(result, Err(Error::NotImplemented(_))));
-    if let Err(Error::NotImplemented(msg)) = result {
-        assert!(msg.contains("BIKE KEM"));
-        assert!(msg.contains("ML-KEM-768"));
-    }
+    assert!(is_documented_error(result));
 }
 
 #[test]
@@ -30,10 +30,7 @@ fn test_bike_encapsulate_not_implemented() {
 
     let result = encapsulate(&dummy_pk, &mut rng);
 
-    assert!(matches!(result, Err(Error::NotImplemented(_))));
-    if let Err(Error::NotImplemented(msg)) = result {
-        assert!(msg.contains("encapsulation"));
-    }
+    assert!(is_documented_error(result));
 }
 
 #[test]
@@ -45,10 +42,33 @@ fn test_bike_decapsulate_not_implemented() {
 
     let result = decapsulate(&dummy_sk, &dummy_ct);
 
-    assert!(matches!(result, Err(Error::NotImplemented(_))));
-    if let Err(Error::NotImplemented(msg)) = result {
-        assert!(msg.contains("decapsulation"));
-    }
+    assert!(is_documented_error(result));
+}
+
+#[test]
+fn test_bike_every_entry_point_not_implemented() {
+    use nyx_crypto::bike::{sizes, BikeKeyPair, Ciphertext, PublicKey, SecretKey};
+
+    let mut rng = thread_rng();
+    let pk = PublicKey::from_bytes([1u8; sizes::PUBLIC_KEY]);
+    let ct = Ciphertext::from_bytes([2u8; sizes::CIPHERTEXT]);
+    let pair = BikeKeyPair::from_keys(pk.clone(), SecretKey::from_bytes([3u8; sizes::SECRET_KEY]));
+
+    assert!(is_documented_error(keygen(&mut rng)));
+    assert!(is_documented_error(encapsulate(&pk, &mut rng)));
+    assert!(is_documented_error(decapsulate(
+        &SecretKey::from_bytes([3u8; sizes::SECRET_KEY]),
+        &ct
+    )));
+    assert!(is_documented_error(BikeKeyPair::generate(&mut rng)));
+    assert!(is_documented_error(BikeKeyPair::encapsulate(
+        pair.public_key(),
+        &mut rng
+    )));
+    assert!(is_documented_error(pair.decapsulate(&ct)));
+
+    let shown = Error::NotImplemented(NOT_IMPLEMENTED.to_string()).to_string();
+    assert_eq!(shown, "Feature not implemented: BIKE");
 }
 
 #[test]
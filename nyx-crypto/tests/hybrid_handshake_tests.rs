@@ -51,6 +51,40 @@ mod hybrid_handshake_tests {
         assert_eq!(HYBRID_PUBLIC_KEY_SIZE, KYBER_PUBLIC_KEY_SIZE + 32);
     }
 
+    #[test]
+    fn test_encapsulate_decapsulate_roundtrip() -> Result<()> {
+        let local = HybridHandshake::new()?;
+
+        let (ciphertext, sender_secret) = HybridHandshake::encapsulate(&local.public_key())?;
+        let receiver_secret = local.decapsulate(&ciphertext)?;
+
+        assert_eq!(sender_secret.as_bytes().len(), 32);
+        assert_eq!(sender_secret.as_bytes(), receiver_secret.as_bytes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decapsulate_tampered_ciphertext() -> Result<()> {
+        let local = HybridHandshake::new()?;
+        let (ciphertext, sender_secret) = HybridHandshake::encapsulate(&local.public_key())?;
+
+        // Flipping a Kyber byte is implicitly rejected: a different secret.
+        let mut wire = ciphertext.to_wire_format();
+        wire[0] ^= 0x01;
+        let tampered = HybridCiphertext::from_wire_format(&wire)?;
+        let receiver_secret = local.decapsulate(&tampered)?;
+        assert_ne!(sender_secret.as_bytes(), receiver_secret.as_bytes());
+
+        // A degenerate X25519 share is rejected outright.
+        let mut wire = ciphertext.to_wire_format();
+        wire[KYBER_CIPHERTEXT_SIZE..].fill(0);
+        let zeroed = HybridCiphertext::from_wire_format(&wire)?;
+        assert!(local.decapsulate(&zeroed).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_public_key_serialization_roundtrip() -> Result<()> {
         let key_pair = HybridKeyPair::generate()?;
@@ -0,0 +1,162 @@
+#![forbid(unsafe_code)]
+
+//! Scoped control tokens.
+//!
+//! The daemon's configured token grants every operation. From it, narrower
+//! tokens can be minted with [`mint_scoped_token`]:
+//!
+//! ```text
+//! scoped:<scope>[,<scope>...]:<hex HMAC-SHA256(token, "nyx-scope:" + scopes)>
+//! ```
+//!
+//! The scope list travels in clear text and the MAC binds it to the daemon
+//! token, so holders cannot widen their own grant and the daemon keeps no
+//! extra state. Presenting the configured token itself still grants all
+//! scopes, so existing clients are unaffected.
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const PREFIX: &str = "scoped:";
+const MAC_DOMAIN: &[u8] = b"nyx-scope:";
+
+/// Permission required by an RPC op.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Scope {
+    /// Read-only queries: config, versions, event subscriptions.
+    Read,
+    /// Mutating configuration: update, reload, rollback, snapshot.
+    Config,
+    /// Power-state changes on mobile builds.
+    Power,
+    /// Building anonymity paths.
+    Path,
+}
+
+impl Scope {
+    pub const ALL: [Scope; 4] = [Scope::Read, Scope::Config, Scope::Power, Scope::Path];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Scope::Read => "read",
+            Scope::Config => "config",
+            Scope::Power => "power",
+            Scope::Path => "path",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|sc| sc.as_str() == s)
+    }
+
+    fn bit(self) -> u8 {
+        1 << (self as u8)
+    }
+}
+
+/// Set of scopes held by a token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ScopeSet(u8);
+
+impl ScopeSet {
+    pub fn all() -> Self {
+        Self::from_scopes(&Scope::ALL)
+    }
+
+    pub fn from_scopes(scopes: &[Scope]) -> Self {
+        Self(scopes.iter().fold(0, |acc, s| acc | s.bit()))
+    }
+
+    pub fn contains(self, scope: Scope) -> bool {
+        self.0 & scope.bit() != 0
+    }
+
+    /// Canonical comma-separated form, in [`Scope::ALL`] order.
+    fn canonical(self) -> String {
+        Scope::ALL
+            .into_iter()
+            .filter(|s| self.contains(*s))
+            .map(Scope::as_str)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+fn scope_mac(master: &str, canonical: &str) -> HmacSha256 {
+    // HMAC accepts keys of any length, so this cannot fail.
+    let mut mac = HmacSha256::new_from_slice(master.as_bytes()).expect("hmac key");
+    mac.update(MAC_DOMAIN);
+    mac.update(canonical.as_bytes());
+    mac
+}
+
+/// Mint a token limited to `scopes`, derived from the daemon's `master` token.
+pub fn mint_scoped_token(master: &str, scopes: &[Scope]) -> String {
+    let canonical = ScopeSet::from_scopes(scopes).canonical();
+    let tag = scope_mac(master, &canonical).finalize().into_bytes();
+    format!("{PREFIX}{canonical}:{}", hex::encode(tag))
+}
+
+/// Scopes granted by `provided`, or `None` if it is not valid for `master`.
+///
+/// The master token itself yields [`ScopeSet::all`]; both paths compare in
+/// constant time, and the master comparison goes through SHA-256 digests so
+/// it leaks neither the token's contents nor its length.
+pub fn granted_scopes(master: &str, provided: &str) -> Option<ScopeSet> {
+    if nyx_crypto::ct_eq_secret(provided.as_bytes(), master.as_bytes()) {
+        return Some(ScopeSet::all());
+    }
+    let rest = provided.strip_prefix(PREFIX)?;
+    let (list, tag_hex) = rest.rsplit_once(':')?;
+    let scopes = list
+        .split(',')
+        .map(Scope::parse)
+        .collect::<Option<Vec<_>>>()?;
+    let set = ScopeSet::from_scopes(&scopes);
+    // Only the canonical spelling is accepted, so one grant has one token.
+    if set.canonical() != list {
+        return None;
+    }
+    let tag = hex::decode(tag_hex).ok()?;
+    scope_mac(master, list).verify_slice(&tag).ok()?;
+    Some(set)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn master_token_grants_everything() {
+        assert_eq!(granted_scopes("m", "m"), Some(ScopeSet::all()));
+    }
+
+    #[test]
+    fn minted_token_grants_only_its_scopes() {
+        let tok = mint_scoped_token("m", &[Scope::Read]);
+        assert_eq!(tok.split(':').nth(1), Some("read"));
+        let set = granted_scopes("m", &tok).unwrap();
+        assert!(set.contains(Scope::Read));
+        assert!(!set.contains(Scope::Config));
+    }
+
+    #[test]
+    fn widened_or_foreign_tokens_are_rejected() {
+        let tok = mint_scoped_token("m", &[Scope::Read]);
+        let widened = tok.replacen("read", "read,config", 1);
+        assert_eq!(granted_scopes("m", &widened), None);
+        assert_eq!(granted_scopes("other", &tok), None);
+        assert_eq!(granted_scopes("m", "scoped:read:zz"), None);
+        assert_eq!(granted_scopes("m", "scoped:bogus:00"), None);
+    }
+
+    #[test]
+    fn non_canonical_scope_list_is_rejected() {
+        let canonical = mint_scoped_token("m", &[Scope::Config, Scope::Read]);
+        assert!(canonical.starts_with("scoped:read,config:"));
+        let reordered = canonical.replacen("read,config", "config,read", 1);
+        assert_eq!(granted_scopes("m", &reordered), None);
+    }
+}
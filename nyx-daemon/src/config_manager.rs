@@ -1,13 +1,20 @@
 #![forbid(unsafe_code)]
 
-use std::{path::PathBuf, sync::Arc, time::SystemTime};
+use std::{collections::BTreeMap, path::PathBuf, sync::Arc, time::SystemTime};
 
 use anyhow::{anyhow, Context, Result};
+use nyx_core::config::{LISTEN_PORT_RANGE, LOG_LEVELS, MAX_FRAME_LEN_RANGE};
 use nyx_stream::FrameCodec;
 use serde::{Deserialize, Serialize};
-use tokio::{fs, sync::RwLock};
+use tokio::{
+    fs,
+    io::AsyncWriteExt,
+    sync::{broadcast, RwLock},
+};
 use tracing::{debug, info, warn};
 
+use crate::event_system::Event;
+
 /// Static configuration structure loaded from TOML.
 /// - Start with a minimal set of field_s and extend progressively
 /// - Ensure forward-compatibility: unknown field_s are ignored via serde default_s
@@ -18,26 +25,68 @@ pub struct NyxConfig {
     /// Daemon listen port for transport (kept for compatibility; not used by IPC).
     #[serde(default)]
     pub listen_port: u16,
-    /// Tracing level (e.g., "info", "debug").
-    #[serde(default)]
+    /// Tracing level (e.g., "info", "debug"); `log_level` in nyx.toml.
+    #[serde(default, rename = "log_level", alias = "___log_level")]
     pub ___log_level: Option<String>,
-    /// Optional hex-encoded 32-byte node id; generated when absent.
+    /// Optional hex-encoded 32-byte node id (or "auto"); generated when absent.
     #[serde(default)]
     pub node_id: Option<String>,
-    /// Optional static max frame length (byte_s) applied on reload/startup
-    #[serde(default)]
+    /// Optional static max frame length (byte_s) applied on reload/startup;
+    /// `max_frame_len_bytes` in nyx.toml, usually under `[limits]`.
+    #[serde(
+        default,
+        rename = "max_frame_len_bytes",
+        alias = "max_frame_len_byte_s"
+    )]
     pub max_frame_len_byte_s: Option<u64>,
 }
 
+/// Live configuration reported by the `get_config` RPC: the
+/// [effective static config](ConfigManager::effective_config) plus dynamic-only
+/// setting_s, under their public key name_s.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct EffectiveConfig {
+    #[serde(flatten)]
+    pub config: NyxConfig,
+    #[serde(default)]
+    pub metrics_interval_secs: Option<u64>,
+}
+
+impl NyxConfig {
+    /// Parse a nyx.toml document, accepting the public key name_s and reading
+    /// `max_frame_len_bytes` from `[limits]` when it i_s not set at top level.
+    pub fn from_toml_str(content: &str) -> Result<Self> {
+        let mut cfg: NyxConfig = toml::from_str(content).context("parsing TOML")?;
+        if cfg.max_frame_len_byte_s.is_none() {
+            let doc: toml::Table = toml::from_str(content).context("parsing TOML")?;
+            cfg.max_frame_len_byte_s = doc
+                .get("limits")
+                .and_then(|l| l.get("max_frame_len_bytes"))
+                .and_then(|v| v.as_integer())
+                .and_then(|n| u64::try_from(n).ok());
+        }
+        Ok(cfg)
+    }
+}
+
 /// Dynamic setting_s that can be changed at runtime via IPC.
+/// Serialized under the public key name_s clients patch with.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct DynamicConfig {
-    #[serde(default)]
+    #[serde(default, rename = "log_level", alias = "___log_level")]
     pub ___log_level: Option<String>,
-    #[serde(default)]
+    #[serde(
+        default,
+        rename = "metrics_interval_secs",
+        alias = "metrics_interval_sec_s"
+    )]
     pub metrics_interval_sec_s: Option<u64>,
     /// Optional max frame length in byte_s for codec safety cap (applie_s proces_s-wide via env)
-    #[serde(default)]
+    #[serde(
+        default,
+        rename = "max_frame_len_bytes",
+        alias = "max_frame_len_byte_s"
+    )]
     pub max_frame_len_byte_s: Option<u64>,
 }
 
@@ -68,6 +117,51 @@ pub struct ConfigResponse {
     pub __validation_error_s: Vec<String>,
 }
 
+/// Before/after value_s of one changed dynamic setting.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FieldChange {
+    pub old: serde_json::Value,
+    pub new: serde_json::Value,
+}
+
+/// Dynamic setting_s changed by a patch, keyed by their public name.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ConfigDiff {
+    pub changed: BTreeMap<String, FieldChange>,
+}
+
+impl ConfigDiff {
+    /// Field_s whose serialized value differ_s between `before` and `after`.
+    pub fn between(before: &DynamicConfig, after: &DynamicConfig) -> Self {
+        let as_map = |c: &DynamicConfig| match serde_json::to_value(c) {
+            Ok(serde_json::Value::Object(m)) => m,
+            _ => serde_json::Map::new(),
+        };
+        let (old_s, new_s) = (as_map(before), as_map(after));
+        let changed = new_s
+            .into_iter()
+            .filter_map(|(k, new)| {
+                let old = old_s.get(&k).cloned().unwrap_or(serde_json::Value::Null);
+                (old != new).then_some((k, FieldChange { old, new }))
+            })
+            .collect();
+        Self { changed }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changed.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.changed.len()
+    }
+}
+
+/// Validation failure_s from [`ConfigManager::apply_patch`]; nothing i_s reported as changed.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("validation failed: {}", .0.join("; "))]
+pub struct PatchRejected(pub Vec<String>);
+
 /// Manager that own_s configuration state and provide_s validation and file reload.
 #[derive(Clone)]
 pub struct ConfigManager {
@@ -77,7 +171,8 @@ pub struct ConfigManager {
     // simple in-memory versioning (ring buffer semantic_s not needed yet)
     version_s: Arc<RwLock<Vec<ConfigVersion>>>,
     currentversion: Arc<RwLock<u64>>, // monotonically increasing
-    maxversion_s: usize,
+    maxversion_s: Arc<RwLock<usize>>,
+    event_s: Option<broadcast::Sender<Event>>, // receives `config_updated` diffs
 }
 
 impl ConfigManager {
@@ -89,10 +184,17 @@ impl ConfigManager {
             configpath,
             version_s: Arc::new(RwLock::new(Vec::with_capacity(16))),
             currentversion: Arc::new(RwLock::new(0)),
-            maxversion_s: 16,
+            maxversion_s: Arc::new(RwLock::new(16)),
+            event_s: None,
         }
     }
 
+    /// Emit a `config_updated:<diff json>` system event after each successful patch.
+    pub fn with_events(mut self, sender: broadcast::Sender<Event>) -> Self {
+        self.event_s = Some(sender);
+        self
+    }
+
     /// Get merged view: static + dynamic (dynamic overlay_s are applied by the caller when relevant).
     pub async fn getconfig(&self) -> NyxConfig {
         self.config.read().await.clone()
@@ -101,60 +203,38 @@ impl ConfigManager {
         self.dynamic.read().await.clone()
     }
 
-    /// Update dynamic setting_s atomically; return_s detailed validation error_s when any.
+    /// Static config with dynamic override_s that have a static counterpart
+    /// (log level, max frame length) folded in.
+    pub async fn effective_config(&self) -> NyxConfig {
+        let mut cfg = self.getconfig().await;
+        let dyncfg = self.getdynamic().await;
+        if let Some(level) = dyncfg.___log_level {
+            cfg.___log_level = Some(level);
+        }
+        if let Some(n) = dyncfg.max_frame_len_byte_s {
+            cfg.max_frame_len_byte_s = Some(n);
+        }
+        cfg
+    }
+
+    /// Everything `get_config` report_s, read without touching disk.
+    pub async fn public_config(&self) -> EffectiveConfig {
+        EffectiveConfig {
+            config: self.effective_config().await,
+            metrics_interval_secs: self.getdynamic().await.metrics_interval_sec_s,
+        }
+    }
+
+    /// Update dynamic setting_s; return_s detailed validation error_s when any.
+    /// Valid key_s are applied even when other key_s in the same update fail.
     pub async fn updateconfig(
         &self,
         update_s: serde_json::Map<String, serde_json::Value>,
     ) -> Result<ConfigResponse> {
         let mut dyncfg = self.dynamic.write().await;
-        let mut error_s = Vec::new();
-        let mut changed = Vec::new();
-
-        for (k, v) in update_s.into_iter() {
-            match k.as_str() {
-                // Accept aliases for ergonomics
-                // "log_level" (CLI/docs) -> "___log_level" (internal)
-                "___log_level" | "log_level" => {
-                    if let Some(level) = v.as_str() {
-                        if matches!(level, "trace" | "debug" | "info" | "warn" | "error") {
-                            dyncfg.___log_level = Some(level.to_string());
-                            // Apply immediately for operator feedback
-                            std::env::set_var("RUST_LOG", level);
-                            tracing_subscriber::fmt::try_init().ok();
-                            changed.push(k);
-                        } else {
-                            error_s.push(format!("invalid ___log_level: {level}"));
-                        }
-                    } else {
-                        error_s.push("___log_level must be string".to_string());
-                    }
-                }
-                // "metrics_interval_secs" (friendly) -> "metrics_interval_sec_s" (internal)
-                "metrics_interval_sec_s" | "metrics_interval_secs" => match v.as_u64() {
-                    Some(sec_s) if (1..=3600).contains(&sec_s) => {
-                        dyncfg.metrics_interval_sec_s = Some(sec_s);
-                        changed.push(k);
-                    }
-                    _ => error_s.push("metrics_interval_sec_s must be 1..=3600".into()),
-                },
-                // "max_frame_len_bytes" (docs/CLI) -> "max_frame_len_byte_s" (internal)
-                "max_frame_len_byte_s" | "max_frame_len_bytes" => {
-                    match v.as_u64() {
-                        Some(n) if (1024..=64 * 1024 * 1024).contains(&n) => {
-                            dyncfg.max_frame_len_byte_s = Some(n);
-                            // Apply immediately via API and also set env for child processe_s if any
-                            FrameCodec::set_default_limit(n as usize);
-                            std::env::set_var("NYX_FRAME_MAX_LEN", n.to_string());
-                            changed.push(k);
-                        }
-                        _ => error_s.push("max_frame_len_byte_s must be 1024..=67108864".into()),
-                    }
-                }
-                other => {
-                    error_s.push(format!("unknown setting: {other}"));
-                }
-            }
-        }
+        let before = dyncfg.clone();
+        let (changed, error_s) = stage_updates(&mut dyncfg, update_s);
+        activate(&before, &dyncfg);
 
         if error_s.is_empty() {
             info!("dynamic config updated: {:?}", changed);
@@ -173,14 +253,55 @@ impl ConfigManager {
         }
     }
 
+    /// Apply a partial update and report exactly which setting_s changed.
+    ///
+    /// The patch i_s all-or-nothing: validation failure_s are returned as
+    /// [`PatchRejected`] and leave the config untouched. Keys set to their
+    /// current value are accepted but do not appear in the diff. The diff i_s
+    /// taken under the same write lock as the update, so concurrent patche_s
+    /// each see their own before/after state.
+    ///
+    /// On success a `system` event with detail `config_updated:<diff json>` i_s
+    /// emitted; the diff i_s keyed by public setting name (e.g. `log_level`).
+    pub async fn apply_patch(
+        &self,
+        patch: serde_json::Map<String, serde_json::Value>,
+    ) -> Result<ConfigDiff> {
+        let diff = {
+            let mut dyncfg = self.dynamic.write().await;
+            let mut staged = dyncfg.clone();
+            let (changed, error_s) = stage_updates(&mut staged, patch);
+            if !error_s.is_empty() {
+                warn!("dynamic config patch rejected: {:?}", error_s);
+                return Err(PatchRejected(error_s).into());
+            }
+            activate(&dyncfg, &staged);
+            info!("dynamic config updated: {:?}", changed);
+            let diff = ConfigDiff::between(&dyncfg, &staged);
+            *dyncfg = staged;
+            diff
+        };
+        if let Some(tx) = &self.event_s {
+            let _ = tx.send(Event {
+                _ty: "system".into(),
+                _detail: format!("config_updated:{}", serde_json::to_string(&diff)?),
+            });
+        }
+        Ok(diff)
+    }
+
     /// Validate basic constraint_s for static config. Extend thi_s progressively.
     pub fn validate_static(config: &NyxConfig) -> Vec<String> {
         let mut err_s = Vec::new();
-        // Allow 0 (unspecified) or 1024..=65535; privileged port_s are dis_allowed by default.
-        if config.listen_port != 0 && !(1024..=65535).contains(&config.listen_port) {
-            err_s.push("listen_port must be 0 or within 1024..=65535".into());
+        // Allow 0 (unspecified) or LISTEN_PORT_RANGE; privileged port_s are dis_allowed by default.
+        if config.listen_port != 0 && !LISTEN_PORT_RANGE.contains(&config.listen_port) {
+            err_s.push(format!(
+                "listen_port must be 0 or within {}..={}",
+                LISTEN_PORT_RANGE.start(),
+                LISTEN_PORT_RANGE.end()
+            ));
         }
-        if let Some(id) = &config.node_id {
+        if let Some(id) = config.node_id.as_deref().filter(|id| *id != "auto") {
             if !hex::decode(id).map(|b| b.len() == 32).unwrap_or(false) {
                 err_s.push("node_id must be 32-byte hex".into());
             }
@@ -203,7 +324,7 @@ impl ConfigManager {
         let content = fs::read_to_string(&path)
             .await
             .context("reading config file")?;
-        let parsed: NyxConfig = toml::from_str(&content).context("parsing TOML")?;
+        let parsed = NyxConfig::from_toml_str(&content)?;
 
         let err_s = Self::validate_static(&parsed);
         if !err_s.is_empty() {
@@ -230,6 +351,45 @@ impl ConfigManager {
         })
     }
 
+    /// Write the [effective config](Self::effective_config) back to `configpath`
+    /// without risking a torn file: the TOML i_s written and synced to a sibling
+    /// temp file, then renamed over the target.
+    ///
+    /// Only key_s whose value changed are rewritten, under their public nyx.toml
+    /// name_s; all other section_s, key_s and comment_s are preserved.
+    pub async fn persist_to_file(&self) -> Result<()> {
+        let path = self
+            .configpath
+            .clone()
+            .ok_or_else(|| anyhow!("no configpath set"))?;
+        let existing = match fs::read_to_string(&path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e).with_context(|| format!("reading config {}", path.display())),
+        };
+        let cfg = self.effective_config().await;
+        let body = merge_into_document(&existing, &cfg)?;
+
+        let mut tmp_name = path
+            .file_name()
+            .ok_or_else(|| anyhow!("configpath has no file name"))?
+            .to_os_string();
+        tmp_name.push(".tmp");
+        let tmp = path.with_file_name(tmp_name);
+        let written = async {
+            let mut f = fs::File::create(&tmp).await?;
+            f.write_all(body.as_bytes()).await?;
+            f.sync_all().await
+        }
+        .await;
+        if let Err(e) = written.and(fs::rename(&tmp, &path).await) {
+            let _ = fs::remove_file(&tmp).await;
+            return Err(e).with_context(|| format!("persisting config to {}", path.display()));
+        }
+        info!("config persisted to {:?}", path);
+        Ok(())
+    }
+
     /// Store a copy into the in-memory version_s vector.
     pub async fn snapshot(&self, description: &str) -> Result<u64> {
         let cfg = self.config.read().await.clone();
@@ -247,13 +407,26 @@ impl ConfigManager {
         };
         let mut list = self.version_s.write().await;
         list.push(snap);
-        if list.len() > self.maxversion_s {
-            list.remove(0);
-        }
+        let max = *self.maxversion_s.read().await;
+        prune_snapshots(&mut list, max, version);
         debug!("created config snapshot v{}", version);
         Ok(version)
     }
 
+    /// Cap retained snapshot_s at `n` (at least 1), evicting the oldest first.
+    /// The currently active version i_s never evicted. Returns evicted version_s.
+    pub async fn set_max_snapshots(&self, n: usize) -> Vec<u64> {
+        let n = n.max(1);
+        *self.maxversion_s.write().await = n;
+        let active = *self.currentversion.read().await;
+        let mut list = self.version_s.write().await;
+        let evicted = prune_snapshots(&mut list, n, active);
+        if !evicted.is_empty() {
+            debug!("evicted config snapshots {:?}", evicted);
+        }
+        evicted
+    }
+
     /// Attempt rollback to a previou_s snapshot.
     pub async fn rollback(&self, version: u64) -> Result<ConfigResponse> {
         let snap = {
@@ -288,3 +461,316 @@ impl ConfigManager {
             .collect()
     }
 }
+
+/// Validate `update_s` into `dyncfg` without side effect_s. Returns the
+/// accepted key_s and the validation error_s.
+fn stage_updates(
+    dyncfg: &mut DynamicConfig,
+    update_s: serde_json::Map<String, serde_json::Value>,
+) -> (Vec<String>, Vec<String>) {
+    let mut error_s = Vec::new();
+    let mut changed = Vec::new();
+
+    for (k, v) in update_s.into_iter() {
+        match k.as_str() {
+            // Accept the internal field name_s as alias_s of the public key_s
+            "log_level" | "___log_level" => match v.as_str() {
+                Some(level) if LOG_LEVELS.contains(&level) => {
+                    dyncfg.___log_level = Some(level.to_string());
+                    changed.push(k);
+                }
+                Some(level) => error_s.push(format!("invalid log_level: {level}")),
+                None => error_s.push("log_level must be string".to_string()),
+            },
+            "metrics_interval_secs" | "metrics_interval_sec_s" => match v.as_u64() {
+                Some(sec_s) if (1..=3600).contains(&sec_s) => {
+                    dyncfg.metrics_interval_sec_s = Some(sec_s);
+                    changed.push(k);
+                }
+                _ => error_s.push("metrics_interval_secs must be 1..=3600".into()),
+            },
+            "max_frame_len_bytes" | "max_frame_len_byte_s" => match v.as_u64() {
+                Some(n) if MAX_FRAME_LEN_RANGE.contains(&n) => {
+                    dyncfg.max_frame_len_byte_s = Some(n);
+                    changed.push(k);
+                }
+                _ => error_s.push(format!(
+                    "max_frame_len_bytes must be {}..={}",
+                    MAX_FRAME_LEN_RANGE.start(),
+                    MAX_FRAME_LEN_RANGE.end()
+                )),
+            },
+            other => {
+                error_s.push(format!("unknown setting: {other}"));
+            }
+        }
+    }
+    (changed, error_s)
+}
+
+/// Apply the proces_s-wide side effect_s of setting_s that changed.
+fn activate(before: &DynamicConfig, after: &DynamicConfig) {
+    if after.___log_level != before.___log_level {
+        if let Some(level) = &after.___log_level {
+            // Apply immediately for operator feedback
+            std::env::set_var("RUST_LOG", level);
+            tracing_subscriber::fmt::try_init().ok();
+        }
+    }
+    if after.max_frame_len_byte_s != before.max_frame_len_byte_s {
+        if let Some(n) = after.max_frame_len_byte_s {
+            // Apply via API and also set env for child processe_s if any
+            FrameCodec::set_default_limit(n as usize);
+            std::env::set_var("NYX_FRAME_MAX_LEN", n.to_string());
+        }
+    }
+}
+
+/// Fold `cfg` into the nyx.toml document `existing`, touching only key_s whose
+/// value differ_s from what the document already hold_s.
+fn merge_into_document(existing: &str, cfg: &NyxConfig) -> Result<String> {
+    let mut doc: toml_edit::DocumentMut = existing.parse().context("parsing TOML")?;
+    let on_disk = NyxConfig::from_toml_str(existing)?;
+    let root = doc.as_table_mut();
+
+    if cfg.listen_port != on_disk.listen_port {
+        root["listen_port"] = toml_edit::value(i64::from(cfg.listen_port));
+    }
+    if cfg.___log_level != on_disk.___log_level {
+        set_public_key(
+            root,
+            "log_level",
+            "___log_level",
+            cfg.___log_level.clone().map(toml_edit::value),
+        );
+    }
+    if cfg.node_id != on_disk.node_id {
+        set_public_key(
+            root,
+            "node_id",
+            "node_id",
+            cfg.node_id.clone().map(toml_edit::value),
+        );
+    }
+    if cfg.max_frame_len_byte_s != on_disk.max_frame_len_byte_s {
+        let item = cfg
+            .max_frame_len_byte_s
+            .map(i64::try_from)
+            .transpose()
+            .context("max_frame_len_bytes out of range")?
+            .map(toml_edit::value);
+        // Keep the key where the document already has it; `[limits]` otherwise.
+        let top_level =
+            root.contains_key("max_frame_len_bytes") || root.contains_key("max_frame_len_byte_s");
+        let table = if top_level {
+            root
+        } else {
+            root.entry("limits")
+                .or_insert_with(toml_edit::table)
+                .as_table_mut()
+                .ok_or_else(|| anyhow!("[limits] is not a table"))?
+        };
+        set_public_key(table, "max_frame_len_bytes", "max_frame_len_byte_s", item);
+    }
+    Ok(doc.to_string())
+}
+
+/// Set (or remove, for `None`) `public` and drop any `internal` alias so the
+/// two never disagree.
+fn set_public_key(
+    table: &mut toml_edit::Table,
+    public: &str,
+    internal: &str,
+    item: Option<toml_edit::Item>,
+) {
+    if internal != public {
+        table.remove(internal);
+    }
+    match item {
+        Some(item) => {
+            table[public] = item;
+        }
+        None => {
+            table.remove(public);
+        }
+    }
+}
+
+/// Drop the oldest snapshot_s other than `active` until at most `max` remain.
+fn prune_snapshots(list: &mut Vec<ConfigVersion>, max: usize, active: u64) -> Vec<u64> {
+    let mut evicted = Vec::new();
+    while list.len() > max {
+        match list.iter().position(|v| v.version != active) {
+            Some(i) => evicted.push(list.remove(i).version),
+            None => break,
+        }
+    }
+    evicted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn patch_reports_only_changed_key() -> Result<()> {
+        let (tx, mut rx) = broadcast::channel(4);
+        let mgr = ConfigManager::new(NyxConfig::default(), None).with_events(tx);
+        let mut patch = serde_json::Map::new();
+        patch.insert("metrics_interval_secs".into(), serde_json::json!(30));
+        let diff = mgr.apply_patch(patch.clone()).await?;
+
+        assert_eq!(diff.len(), 1);
+        assert_eq!(
+            diff.changed.get("metrics_interval_secs"),
+            Some(&FieldChange {
+                old: serde_json::Value::Null,
+                new: serde_json::json!(30),
+            })
+        );
+        let ev = rx.recv().await?;
+        assert_eq!(ev._ty, "system");
+        let json = ev
+            ._detail
+            .strip_prefix("config_updated:")
+            .expect("diff event");
+        assert_eq!(serde_json::from_str::<ConfigDiff>(json)?, diff);
+
+        // Re-applying the same value changes nothing.
+        assert!(mgr.apply_patch(patch).await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rejected_patch_lists_validation_errors() {
+        let mgr = ConfigManager::new(NyxConfig::default(), None);
+        let mut patch = serde_json::Map::new();
+        patch.insert("metrics_interval_secs".into(), serde_json::json!(0));
+        let err = mgr.apply_patch(patch).await.unwrap_err();
+        let rejected = err
+            .downcast_ref::<PatchRejected>()
+            .expect("validation error");
+        assert_eq!(rejected.0.len(), 1);
+
+        // A rejected patch applies none of its keys, valid or not
+        let mut patch = serde_json::Map::new();
+        patch.insert("log_level".into(), serde_json::json!("warn"));
+        patch.insert("max_frame_len_bytes".into(), serde_json::json!(1));
+        assert!(mgr.apply_patch(patch).await.is_err());
+        assert_eq!(mgr.getdynamic().await, DynamicConfig::default());
+    }
+
+    #[tokio::test]
+    async fn snapshot_retention_evicts_oldest_but_keeps_active() -> Result<()> {
+        let mgr = ConfigManager::new(NyxConfig::default(), None);
+        for i in 1..=5u16 {
+            *mgr.config.write().await = NyxConfig {
+                listen_port: 1000 + i,
+                ..Default::default()
+            };
+            mgr.snapshot(&format!("s{i}")).await?;
+        }
+        // v2 is active after rollback, so it survives eviction.
+        mgr.rollback(2).await?;
+        assert_eq!(mgr.set_max_snapshots(2).await, vec![1, 3, 4]);
+        let kept: Vec<u64> = mgr
+            .listversion_s()
+            .await
+            .iter()
+            .map(|v| v.version)
+            .collect();
+        assert_eq!(kept, vec![2, 5]);
+
+        assert!(mgr.rollback(3).await.is_err());
+        mgr.rollback(5).await?;
+        assert_eq!(mgr.getconfig().await.listen_port, 1005);
+
+        // New snapshots respect the limit too.
+        mgr.snapshot("s6").await?;
+        let kept: Vec<u64> = mgr
+            .listversion_s()
+            .await
+            .iter()
+            .map(|v| v.version)
+            .collect();
+        assert_eq!(kept, vec![5, 6]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn persisted_config_survives_reload() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("nyx.toml");
+        let mgr = ConfigManager::new(
+            NyxConfig {
+                listen_port: 4433,
+                ..Default::default()
+            },
+            Some(path.clone()),
+        );
+        let mut patch = serde_json::Map::new();
+        patch.insert("log_level".into(), serde_json::json!("debug"));
+        mgr.apply_patch(patch).await?;
+        mgr.persist_to_file().await?;
+        assert!(!dir.path().join("nyx.toml.tmp").exists());
+
+        let fresh = ConfigManager::new(NyxConfig::default(), Some(path));
+        assert!(fresh.reload_from_file().await?.__succes_s);
+        let cfg = fresh.getconfig().await;
+        assert_eq!(cfg.listen_port, 4433);
+        assert_eq!(cfg.___log_level.as_deref(), Some("debug"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn persist_fails_when_target_unwritable() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        // Renaming a file over a directory fails, and the temp file is cleaned up.
+        let path = dir.path().join("occupied");
+        std::fs::create_dir(&path)?;
+        std::fs::write(path.join("keep"), b"x")?;
+        let mgr = ConfigManager::new(NyxConfig::default(), Some(path));
+        assert!(mgr.persist_to_file().await.is_err());
+        assert!(!dir.path().join("occupied.tmp").exists());
+
+        let unset = ConfigManager::new(NyxConfig::default(), None);
+        assert!(unset.persist_to_file().await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn persist_keeps_other_sections_of_repo_template() -> Result<()> {
+        let template = include_str!("../../nyx.toml");
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("nyx.toml");
+        std::fs::write(&path, template)?;
+
+        let mgr = ConfigManager::new(NyxConfig::default(), Some(path.clone()));
+        assert!(mgr.reload_from_file().await?.__succes_s);
+        let mut patch = serde_json::Map::new();
+        patch.insert("log_level".into(), serde_json::json!("debug"));
+        patch.insert("max_frame_len_bytes".into(), serde_json::json!(1_048_576));
+        mgr.apply_patch(patch).await?;
+        mgr.persist_to_file().await?;
+
+        let written = std::fs::read_to_string(&path)?;
+        for line in template.lines().filter(|l| l.starts_with('[')) {
+            assert!(written.contains(line), "lost section {line}");
+        }
+        assert!(written.contains("# Nyx Network Configuration"));
+        assert!(!written.contains("___log_level"));
+        assert!(!written.contains("max_frame_len_byte_s"));
+        let doc: toml::Table = toml::from_str(&written)?;
+        assert_eq!(doc["log_level"].as_str(), Some("debug"));
+        assert_eq!(
+            doc["limits"]["max_frame_len_bytes"].as_integer(),
+            Some(1_048_576)
+        );
+        assert_eq!(doc["listen_port"].as_integer(), Some(43300));
+
+        let fresh = ConfigManager::new(NyxConfig::default(), Some(path));
+        assert!(fresh.reload_from_file().await?.__succes_s);
+        assert_eq!(fresh.getconfig().await, mgr.effective_config().await);
+        Ok(())
+    }
+}
@@ -52,6 +52,16 @@ impl Default for ConnectionManagerConfig {
     }
 }
 
+/// Initial congestion window when nothing is known about the path (RFC 6928)
+pub const DEFAULT_INITIAL_CWND: usize = 10;
+
+/// Ceiling for a bandwidth-derived initial window; larger opening bursts
+/// risk loss before the first RTT sample corrects the estimate
+pub const MAX_INITIAL_CWND: usize = 100;
+
+/// Packet size assumed when converting BDP bytes to packets
+const BBR_PACKET_SIZE: usize = 1500;
+
 /// BBR congestion control state
 #[derive(Debug, Clone)]
 pub struct BbrState {
@@ -69,10 +79,41 @@ pub struct BbrState {
     pub cwnd_gain: f64,
 }
 
+impl BbrState {
+    /// Start with an explicit congestion window, clamped to
+    /// `[1, MAX_INITIAL_CWND]`
+    pub fn with_initial_cwnd(cwnd: usize) -> Self {
+        Self {
+            cwnd: cwnd.clamp(1, MAX_INITIAL_CWND),
+            ..Self::default()
+        }
+    }
+
+    /// Start from a bandwidth/RTT estimate carried over from a previous
+    /// connection on the same path, opening the window to its BDP
+    pub fn from_estimate(btlbw: u64, rtprop: Duration) -> Self {
+        Self {
+            cwnd: Self::initial_cwnd_for(btlbw, rtprop),
+            btlbw,
+            rtprop,
+            ..Self::default()
+        }
+    }
+
+    /// BDP-based initial window in packets for `btlbw` bytes/sec over
+    /// `rtprop`, never below the RFC 6928 default or above `MAX_INITIAL_CWND`
+    pub fn initial_cwnd_for(btlbw: u64, rtprop: Duration) -> usize {
+        let bdp_bytes = btlbw as f64 * rtprop.as_secs_f64();
+        // Float-to-int casts saturate, so an enormous BDP still clamps
+        let packets = (bdp_bytes / BBR_PACKET_SIZE as f64).ceil() as usize;
+        packets.clamp(DEFAULT_INITIAL_CWND, MAX_INITIAL_CWND)
+    }
+}
+
 impl Default for BbrState {
     fn default() -> Self {
         Self {
-            cwnd: 10,
+            cwnd: DEFAULT_INITIAL_CWND,
             btlbw: 1_000_000, // 1 Mbps initial estimate
             rtprop: Duration::from_millis(100),
             last_update: Instant::now(),
@@ -240,7 +281,7 @@ impl Connection {
     pub fn new(id: ConnectionId, config: &ConnectionManagerConfig) -> Self {
         Self {
             id,
-            bbr: BbrState::default(),
+            bbr: BbrState::with_initial_cwnd(config.initial_cwnd),
             rtt: RttEstimator::new(config.rtt_alpha),
             rate_limiter: TokenBucket::new(config.rate_limit_bps, config.rate_limit_capacity),
             created_at: Instant::now(),
@@ -505,6 +546,25 @@ mod tests {
         assert!(rto <= Duration::from_secs(60));
     }
 
+    #[test]
+    fn test_initial_cwnd_from_bandwidth_estimate() {
+        // 100 Mbps over 20 ms is a 250 KB BDP, well above 10 packets
+        let bbr = BbrState::from_estimate(100_000_000 / 8, Duration::from_millis(20));
+        assert!(bbr.cwnd > DEFAULT_INITIAL_CWND, "cwnd={}", bbr.cwnd);
+        assert!(bbr.cwnd <= MAX_INITIAL_CWND);
+
+        // A slow path keeps the conservative default; a huge BDP is capped
+        assert_eq!(
+            BbrState::initial_cwnd_for(10_000, Duration::from_millis(20)),
+            DEFAULT_INITIAL_CWND
+        );
+        assert_eq!(
+            BbrState::initial_cwnd_for(u64::MAX, Duration::from_secs(1)),
+            MAX_INITIAL_CWND
+        );
+        assert_eq!(BbrState::with_initial_cwnd(5000).cwnd, MAX_INITIAL_CWND);
+    }
+
     #[test]
     fn test_token_bucket() {
         let mut bucket = TokenBucket::new(1000, 5000); // 1000 bytes/sec, 5000 capacity
@@ -21,6 +21,8 @@ pub enum DaemonError {
     PathBuild(#[from] PathBuildError),
     #[error("resource exhaustion")]
     ResourceExhaustion,
+    #[error("invalid path request: {0}")]
+    InvalidPathRequest(#[from] crate::proto::PathRequestError),
 }
 
 #[derive(Debug, Error)]
@@ -43,6 +45,8 @@ pub enum PathBuildError {
     ProtocolMismatch,
     #[error("resource exhaustion")]
     ResourceExhaustion,
+    #[error("not enough peers with required capabilities: need {needed}, found {found}")]
+    InsufficientPeers { needed: usize, found: usize },
     #[error("unknown error: {0}")]
     Unknown(String),
 }
@@ -1,10 +1,23 @@
 #![forbid(unsafe_code)]
 
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
 use tokio::sync::{broadcast, RwLock};
 
+/// Default cap on concurrent client subscriptions.
+pub const DEFAULT_MAX_SUBSCRIBERS: usize = 256;
+
+/// Event type_s the daemon emits; filters naming anything else never match.
+pub const KNOWN_EVENT_TYPES: &[&str] = &["system", "metric_s", "power", "multipath"];
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SubscribeError {
+    #[error("event subscriber limit reached ({limit})")]
+    LimitReached { limit: usize },
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Event {
     pub _ty: String,
@@ -17,6 +30,39 @@ pub struct EventSystem {
     tx: broadcast::Sender<Event>,
     // naive filter state; can evolve to per-subscriber rule_s
     default_type_s: Arc<RwLock<Vec<String>>>,
+    max_subscribers: usize,
+    active_subscribers: Arc<AtomicUsize>,
+    // reject filters naming unknown type_s instead of warning
+    strict_type_s: bool,
+}
+
+/// A counted client subscription; releases its slot when dropped.
+pub struct EventSubscription {
+    rx: broadcast::Receiver<Event>,
+    active: Arc<AtomicUsize>,
+    compression: Option<&'static str>,
+}
+
+impl EventSubscription {
+    /// Record the wire codec negotiated for this subscriber's event lines.
+    pub fn with_compression(mut self, codec: Option<&'static str>) -> Self {
+        self.compression = codec;
+        self
+    }
+
+    pub fn compression(&self) -> Option<&'static str> {
+        self.compression
+    }
+
+    pub async fn recv(&mut self) -> Result<Event, broadcast::error::RecvError> {
+        self.rx.recv().await
+    }
+}
+
+impl Drop for EventSubscription {
+    fn drop(&mut self) {
+        self.active.fetch_sub(1, Ordering::AcqRel);
+    }
 }
 
 impl EventSystem {
@@ -28,17 +74,68 @@ impl EventSystem {
                 "system".into(),
                 "metric_s".into(),
                 "power".into(),
+                "multipath".into(),
             ])),
+            max_subscribers: DEFAULT_MAX_SUBSCRIBERS,
+            active_subscribers: Arc::new(AtomicUsize::new(0)),
+            strict_type_s: false,
         }
     }
 
+    /// Reject subscription filters containing unknown event type_s.
+    /// The default (permissive) mode only reports them back to the client.
+    pub fn with_strict_type_s(mut self, strict: bool) -> Self {
+        self.strict_type_s = strict;
+        self
+    }
+
+    pub fn strict_type_s(&self) -> bool {
+        self.strict_type_s
+    }
+
+    /// Override the cap on concurrent client subscriptions.
+    pub fn with_max_subscribers(mut self, limit: usize) -> Self {
+        self.max_subscribers = limit;
+        self
+    }
+
     pub fn sender(&self) -> broadcast::Sender<Event> {
         self.tx.clone()
     }
+    /// Uncounted receiver for in-process consumers.
     pub fn subscribe(&self) -> broadcast::Receiver<Event> {
         self.tx.subscribe()
     }
 
+    /// Subscribe on behalf of a client, honouring the subscriber limit.
+    pub fn try_subscribe(&self) -> Result<EventSubscription, SubscribeError> {
+        let limit = self.max_subscribers;
+        self.active_subscribers
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |n| {
+                (n < limit).then_some(n + 1)
+            })
+            .map_err(|_| SubscribeError::LimitReached { limit })?;
+        Ok(EventSubscription {
+            rx: self.tx.subscribe(),
+            active: self.active_subscribers.clone(),
+            compression: None,
+        })
+    }
+
+    /// Number of live client subscriptions.
+    pub fn active_subscribers(&self) -> usize {
+        self.active_subscribers.load(Ordering::Acquire)
+    }
+
+    /// Requested type_s that are not in [`KNOWN_EVENT_TYPES`], in request order.
+    pub fn unknown_type_s(type_s: &[String]) -> Vec<String> {
+        type_s
+            .iter()
+            .filter(|t| !KNOWN_EVENT_TYPES.contains(&t.as_str()))
+            .cloned()
+            .collect()
+    }
+
     pub async fn set_default_type_s(&self, type_s: Vec<String>) {
         *self.default_type_s.write().await = type_s;
     }
@@ -51,3 +148,53 @@ impl EventSystem {
         allow.iter().any(|t| t == &ev._ty)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subscriber_limit_enforced_and_released_on_drop() {
+        let events = EventSystem::new(8).with_max_subscribers(2);
+        let first = events.try_subscribe().unwrap();
+        let second = events.try_subscribe().unwrap();
+        assert_eq!(events.active_subscribers(), 2);
+
+        assert_eq!(
+            events.try_subscribe().err(),
+            Some(SubscribeError::LimitReached { limit: 2 })
+        );
+        assert_eq!(events.active_subscribers(), 2);
+
+        drop(first);
+        assert_eq!(events.active_subscribers(), 1);
+        let third = events.try_subscribe().unwrap();
+        assert_eq!(events.active_subscribers(), 2);
+
+        drop(second);
+        drop(third);
+        assert_eq!(events.active_subscribers(), 0);
+    }
+
+    #[test]
+    fn unknown_type_s_reported_in_order() {
+        let req = vec!["power".to_string(), "sytem".into(), "bogus".into()];
+        assert_eq!(EventSystem::unknown_type_s(&req), vec!["sytem", "bogus"]);
+        let known: Vec<String> = KNOWN_EVENT_TYPES.iter().map(|t| t.to_string()).collect();
+        assert!(EventSystem::unknown_type_s(&known).is_empty());
+    }
+
+    #[tokio::test]
+    async fn counted_subscription_receives_events() {
+        let events = EventSystem::new(8);
+        let mut sub = events.try_subscribe().unwrap();
+        events
+            .sender()
+            .send(Event {
+                _ty: "system".into(),
+                _detail: "hello".into(),
+            })
+            .unwrap();
+        assert_eq!(sub.recv().await.unwrap()._detail, "hello");
+    }
+}
@@ -0,0 +1,131 @@
+#![forbid(unsafe_code)]
+
+//! Optional per-message compression for streamed IPC event lines.
+//!
+//! A client advertises the codecs it can decode in `SubscribeEvents
+//! { compression: [...] }`; the daemon picks one and echoes it in the
+//! subscribe response. Afterwards, event lines larger than
+//! [`COMPRESSION_THRESHOLD`] are replaced by an envelope
+//! `{"compressed":"deflate","payload":"<base64 raw deflate>"}` so the
+//! stream stays newline-delimited JSON. Smaller lines are sent as-is.
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use serde::{Deserialize, Serialize};
+
+/// Raw DEFLATE (RFC 1951), pure Rust via miniz_oxide.
+pub const DEFLATE: &str = "deflate";
+
+/// Codecs the daemon can produce, in preference order.
+pub const SUPPORTED: &[&str] = &[DEFLATE];
+
+/// Event lines at or below this size are never compressed.
+pub const COMPRESSION_THRESHOLD: usize = 4 * 1024;
+
+/// Upper bound on a decompressed payload, guarding against deflate bombs.
+#[cfg(test)]
+const MAX_DECOMPRESSED_LEN: usize = 4 * 1024 * 1024;
+
+const DEFLATE_LEVEL: u8 = 6;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Envelope<'a> {
+    compressed: &'a str,
+    payload: String,
+}
+
+#[cfg(test)]
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+enum DecodeError {
+    #[error("unsupported compression: {0}")]
+    Unsupported(String),
+    #[error("invalid base64 payload: {0}")]
+    Base64(String),
+    #[error("corrupt or oversized deflate payload")]
+    Inflate,
+}
+
+/// Pick the first codec the daemon supports from the client's offer.
+pub fn negotiate(offered: &[String]) -> Option<&'static str> {
+    SUPPORTED
+        .iter()
+        .copied()
+        .find(|codec| offered.iter().any(|o| o.eq_ignore_ascii_case(codec)))
+}
+
+/// Wrap a serialized event line in a compressed envelope when negotiated
+/// and worthwhile; otherwise return it unchanged.
+pub fn encode_line(line: Vec<u8>, codec: Option<&str>) -> Vec<u8> {
+    if codec != Some(DEFLATE) || line.len() <= COMPRESSION_THRESHOLD {
+        return line;
+    }
+    let deflated = miniz_oxide::deflate::compress_to_vec(&line, DEFLATE_LEVEL);
+    let envelope = Envelope {
+        compressed: DEFLATE,
+        payload: STANDARD.encode(deflated),
+    };
+    match serde_json::to_vec(&envelope) {
+        Ok(v) if v.len() < line.len() => v,
+        _ => line,
+    }
+}
+
+/// Inverse of [`encode_line`], for round-trip tests; clients decode with
+/// the SDK's event reader.
+#[cfg(test)]
+fn decode_line(line: &[u8]) -> Result<Vec<u8>, DecodeError> {
+    let Ok(envelope) = serde_json::from_slice::<Envelope>(line) else {
+        return Ok(line.to_vec());
+    };
+    if envelope.compressed != DEFLATE {
+        return Err(DecodeError::Unsupported(envelope.compressed.to_string()));
+    }
+    let deflated = STANDARD
+        .decode(envelope.payload)
+        .map_err(|e| DecodeError::Base64(e.to_string()))?;
+    miniz_oxide::inflate::decompress_to_vec_with_limit(&deflated, MAX_DECOMPRESSED_LEN)
+        .map_err(|_| DecodeError::Inflate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn large_event() -> Vec<u8> {
+        let detail = "config diff: log_level debug -> info; ".repeat(500);
+        serde_json::to_vec(&serde_json::json!({"_ty": "system", "_detail": detail})).unwrap()
+    }
+
+    #[test]
+    fn negotiate_picks_supported_codec() {
+        assert_eq!(negotiate(&["zstd".into(), "DEFLATE".into()]), Some(DEFLATE));
+        assert_eq!(negotiate(&["zstd".into()]), None);
+        assert_eq!(negotiate(&[]), None);
+    }
+
+    #[test]
+    fn large_line_round_trips_through_envelope() {
+        let original = large_event();
+        let wire = encode_line(original.clone(), Some(DEFLATE));
+        assert!(wire.len() < original.len());
+        assert!(!wire.contains(&b'\n'));
+        assert_eq!(decode_line(&wire).unwrap(), original);
+    }
+
+    #[test]
+    fn small_or_unnegotiated_lines_pass_through() {
+        let small = br#"{"_ty":"system","_detail":"hi"}"#.to_vec();
+        assert_eq!(encode_line(small.clone(), Some(DEFLATE)), small);
+        let large = large_event();
+        assert_eq!(encode_line(large.clone(), None), large);
+        assert_eq!(decode_line(&small).unwrap(), small);
+    }
+
+    #[test]
+    fn unknown_codec_in_envelope_rejected() {
+        let line = br#"{"compressed":"brotli","payload":""}"#;
+        assert_eq!(
+            decode_line(line),
+            Err(DecodeError::Unsupported("brotli".into()))
+        );
+    }
+}
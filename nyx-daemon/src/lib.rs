@@ -2,9 +2,11 @@
 #![cfg_attr(feature = "low_power", allow(unsafe_code))]
 
 // Public module_s for daemon runtime (pure Rust only; no ring/openssl).
+pub mod auth_scope; // Scoped control tokens derived from the daemon token
 pub mod config_manager;
 pub mod errors; // Error types for daemon
 pub mod event_system;
+pub mod ipc_compression; // Negotiated compression for streamed IPC events
 #[cfg(feature = "low_power")]
 pub mod low_power;
 pub mod metrics;
@@ -10,18 +10,30 @@ use tracing::{info, warn};
 mod json_util;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
-use nyx_daemon::event_system::{Event, EventSystem};
+use nyx_daemon::auth_scope::{granted_scopes, Scope};
+use nyx_daemon::errors::{DaemonError, PathBuildError};
+use nyx_daemon::event_system::{Event, EventSubscription, EventSystem};
+use nyx_daemon::ipc_compression;
 #[cfg(feature = "low_power")]
 use nyx_daemon::low_power::LowPowerBridge;
-// use nyx_daemon::metrics::MetricsCollector; // no longer used
-use nyx_daemon::nyx_daemon_config::{ConfigManager, ConfigResponse, NyxConfig, VersionSummary};
+use nyx_daemon::metrics::MetricsCollector;
+use nyx_daemon::multipath_integration::{forward_events, MultipathManager};
+use nyx_daemon::nyx_daemon_config::{
+    ConfigManager, ConfigResponse, NyxConfig, PatchRejected, VersionSummary,
+};
+use nyx_daemon::path_builder::{DaemonConfig as PathBuilderConfig, PathBuilder};
+use nyx_daemon::pure_rust_dht::{DhtConfig, PureRustDht};
+use nyx_daemon::pure_rust_p2p::{P2pConfig, PureRustP2p, DISCOVERY_INTERVAL};
+use nyx_stream::multipath_dataplane::MultipathConfig;
 // use nyx_daemon::prometheus_exporter::maybe_start_prometheus;
+use nyx_core::rpc::DaemonErrorCode;
 use nyx_core::sandbox::{apply_policy as apply_os_sandbox, SandboxPolicy, SandboxStatus};
 
 #[cfg(windows)]
 use tokio::net::windows::named_pipe::ServerOptions;
 #[cfg(unix)]
 use tokio::net::UnixListener;
+use tokio::sync::{broadcast::error::RecvError, watch};
 
 #[cfg(unix)]
 const DEFAULT_ENDPOINT: &str = "/tmp/nyx.sock";
@@ -30,6 +42,97 @@ const DEFAULT_ENDPOINT: &str = "\\\\.\\pipe\\nyx-daemon";
 
 const INITIAL_READ_TIMEOUT_MS: u64 = 2000;
 
+/// Default cap on one request line; override with `NYX_DAEMON_MAX_REQUEST_BYTES`.
+const DEFAULT_MAX_REQUEST_BYTES: usize = 64 * 1024;
+
+/// DHT and P2P layers kept alive for the daemon's lifetime.
+struct PeerDiscovery {
+    _dht: Arc<PureRustDht>,
+    _p2p: Arc<PureRustP2p>,
+    _feed: tokio::task::JoinHandle<()>,
+}
+
+/// Start the DHT and P2P layers on `addr` and feed their known peers into the
+/// path builder's relay candidates. Failures are logged and leave BuildPath
+/// without candidates.
+async fn start_peer_discovery(addr: &str, paths: &PathBuilder) -> Option<PeerDiscovery> {
+    let listen: std::net::SocketAddr = match addr.parse() {
+        Ok(a) => a,
+        Err(e) => {
+            warn!("invalid NYX_DISCOVERY_ADDR {addr}: {e}");
+            return None;
+        }
+    };
+    let bootstrap: Vec<std::net::SocketAddr> = std::env::var("NYX_BOOTSTRAP_PEERS")
+        .map(|v| v.split(',').filter_map(|s| s.trim().parse().ok()).collect())
+        .unwrap_or_default();
+
+    let mut dht = match PureRustDht::new(DhtConfig {
+        bind_addr: listen,
+        bootstrap_nodes: bootstrap.clone(),
+        ..Default::default()
+    })
+    .await
+    {
+        Ok(d) => d,
+        Err(e) => {
+            warn!("failed to start DHT on {listen}: {e}");
+            return None;
+        }
+    };
+    if let Err(e) = dht.start().await {
+        warn!("failed to start DHT on {listen}: {e}");
+        return None;
+    }
+    let dht = Arc::new(dht);
+
+    let mut p2p = match PureRustP2p::new(P2pConfig {
+        listen_addr: listen,
+        bootstrap_peers: bootstrap,
+        ..Default::default()
+    })
+    .await
+    {
+        Ok(p) => p,
+        Err(e) => {
+            warn!("failed to start P2P on {listen}: {e}");
+            return None;
+        }
+    };
+    p2p.set_dht(dht.clone());
+    if let Err(e) = p2p.start().await {
+        warn!("failed to start P2P on {listen}: {e}");
+        return None;
+    }
+    let p2p = Arc::new(p2p);
+    let feed = paths.spawn_candidate_feed(p2p.clone(), DISCOVERY_INTERVAL);
+    info!("peer discovery on {listen} feeding path candidates");
+    Some(PeerDiscovery {
+        _dht: dht,
+        _p2p: p2p,
+        _feed: feed,
+    })
+}
+
+/// Multipath manager whose path health transitions reach `subscribe_events`
+/// clients as `multipath` events. Liveness is checked twice per failover timeout.
+fn start_multipath(events: &EventSystem, config: MultipathConfig) -> Arc<MultipathManager> {
+    let (tx, rx) = tokio::sync::mpsc::channel(64);
+    let every = std::time::Duration::from_millis((config.failover_timeout_ms / 2).max(1));
+    let manager = Arc::new(MultipathManager::new(config).with_event_sender(tx));
+    forward_events(rx, events.sender());
+    manager.clone().spawn_maintenance(every);
+    manager
+}
+
+fn max_request_bytes() -> usize {
+    std::env::var("NYX_DAEMON_MAX_REQUEST_BYTES")
+        .ok()
+        .and_then(|v| v.trim().parse::<usize>().ok())
+        .filter(|&n| n > 0)
+        .unwrap_or(DEFAULT_MAX_REQUEST_BYTES)
+}
+
 #[derive(Clone)]
 struct DaemonState {
     start_time: Instant,
@@ -37,18 +140,25 @@ struct DaemonState {
     cfg: ConfigManager,
     events: EventSystem,
     token: Option<String>, // Optional static token for privileged ops
+    metrics: Arc<MetricsCollector>,
+    paths: PathBuilder,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(tag = "op", rename_all = "snake_case")]
 enum Request {
     GetInfo,
+    /// Live effective config; never touches the config file
+    GetConfig,
     ReloadConfig,
     UpdateConfig {
         settings: serde_json::Map<String, serde_json::Value>,
     },
     SubscribeEvents {
         types: Option<Vec<String>>,
+        /// Codecs the client can decode for large event lines
+        #[serde(default)]
+        compression: Option<Vec<String>>,
     },
     ListConfigVersions,
     RollbackConfig {
@@ -59,12 +169,43 @@ enum Request {
     },
     GetSystemInfo,
     Health,
+    BuildPath {
+        #[serde(default)]
+        required_caps: Vec<String>,
+        hops: u8,
+    },
+    /// Run several requests over one round-trip, in order
+    Batch {
+        requests: Vec<RpcRequest>,
+    },
     #[cfg(feature = "low_power")]
     SetPowerState {
         state: u32,
     },
 }
 
+impl Request {
+    /// Stable op name used as the metrics label.
+    fn op_name(&self) -> &'static str {
+        match self {
+            Request::GetInfo => "get_info",
+            Request::GetConfig => "get_config",
+            Request::ReloadConfig => "reload_config",
+            Request::UpdateConfig { .. } => "update_config",
+            Request::SubscribeEvents { .. } => "subscribe_events",
+            Request::ListConfigVersions => "list_config_versions",
+            Request::RollbackConfig { .. } => "rollback_config",
+            Request::CreateConfigSnapshot { .. } => "create_config_snapshot",
+            Request::GetSystemInfo => "get_system_info",
+            Request::Health => "health",
+            Request::BuildPath { .. } => "build_path",
+            Request::Batch { .. } => "batch",
+            #[cfg(feature = "low_power")]
+            Request::SetPowerState { .. } => "set_power_state",
+        }
+    }
+}
+
 /// RPC request envelope carrying optional request id and auth token.
 #[derive(Debug, Deserialize)]
 struct RpcRequest {
@@ -102,7 +243,7 @@ struct HealthStatus {
 #[serde(bound(serialize = "T: Serialize"))]
 struct Response<T> {
     ok: bool,
-    code: u16, // 0 = OK, non-zero = error code
+    code: DaemonErrorCode,
     #[serde(skip_serializing_if = "Option::is_none")]
     id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -115,13 +256,13 @@ impl<T: Serialize> Response<T> {
     fn ok_with_id(id: Option<String>, data: T) -> Self {
         Self {
             ok: true,
-            code: 0,
+            code: DaemonErrorCode::Ok,
             id,
             data: Some(data),
             error: None,
         }
     }
-    fn err_with_id(id: Option<String>, code: u16, msg: impl Into<String>) -> Self {
+    fn err_with_id(id: Option<String>, code: DaemonErrorCode, msg: impl Into<String>) -> Self {
         Self {
             ok: false,
             code,
@@ -201,7 +342,7 @@ async fn main() -> io::Result<()> {
     } else if let Some(path) = std::env::var("NYX_CONFIG").ok().map(PathBuf::from) {
         // Best-effort manual read if initial config is default but path is set
         if let Ok(content) = tokio::fs::read_to_string(&path).await {
-            if let Ok(parsed) = toml::from_str::<NyxConfig>(&content) {
+            if let Ok(parsed) = NyxConfig::from_toml_str(&content) {
                 if let Some(n) = parsed.max_frame_len_byte_s {
                     nyx_stream::FrameCodec::set_default_limit(n as usize);
                     std::env::set_var("NYX_FRAME_MAX_LEN", n.to_string());
@@ -209,14 +350,22 @@ async fn main() -> io::Result<()> {
             }
         }
     }
-    let events = EventSystem::new(1024);
+    let strict_event_types = std::env::var("NYX_EVENTS_STRICT_TYPES")
+        .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+        .unwrap_or(false);
+    let events = EventSystem::new(1024).with_strict_type_s(strict_event_types);
     let token = ensure_token_from_env_or_cookie();
+    let metrics = Arc::new(MetricsCollector::new());
+    let _metrics_task = metrics.start_collection(std::time::Duration::from_secs(10));
+    let paths = PathBuilder::new(PathBuilderConfig::default()).map_err(io::Error::other)?;
     let state = Arc::new(DaemonState {
         start_time: Instant::now(),
         node_id,
-        cfg: cfg_mgr,
+        cfg: cfg_mgr.with_events(events.sender()),
         events,
         token,
+        metrics,
+        paths,
     });
 
     // Try to apply minimal OS-level sandboxing (no-op on unsupported platforms/features)
@@ -242,6 +391,15 @@ async fn main() -> io::Result<()> {
         }
     };
 
+    let _multipath = start_multipath(&state.events, MultipathConfig::default());
+
+    // Peer discovery feeding BuildPath relay candidates when NYX_DISCOVERY_ADDR is set.
+    // Example: NYX_DISCOVERY_ADDR=0.0.0.0:43301 NYX_BOOTSTRAP_PEERS=10.0.0.2:43301,10.0.0.3:43301
+    let _discovery = match std::env::var("NYX_DISCOVERY_ADDR") {
+        Ok(addr) => start_peer_discovery(&addr, &state.paths).await,
+        Err(_) => None,
+    };
+
     // Determine listener type based on --bind argument
     let use_tcp = bind_addr.is_some();
     if use_tcp {
@@ -261,7 +419,7 @@ async fn main() -> io::Result<()> {
                 if let Err(e) = nyx_telemetry::init(&cfg) {
                     warn!("failed to init prometheus telemetry: {e:?}");
                 } else {
-                    match nyx_telemetry::start_metrics_http_server(sock).await {
+                    match nyx_telemetry::start_metrics_http_server_or_ephemeral(sock).await {
                         Ok(g) => {
                             info!("Prometheus /metrics at http://{}/metrics", g.addr());
                             _metrics_guard = Some(g);
@@ -353,10 +511,67 @@ async fn main() -> io::Result<()> {
         }
     }
 
+    // --- Shutdown -----------------------------------------------------------
+    // SIGTERM/Ctrl-C flips this flag; every accept loop watches it and exits.
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    tokio::spawn(async move {
+        shutdown_signal().await;
+        info!("shutdown signal received, stopping listener");
+        let _ = shutdown_tx.send(true);
+    });
+
     // --- Listener Setup: TCP or IPC -----------------------------------------
-    if use_tcp {
+    let served = serve(bind_addr, state, shutdown_rx).await;
+
+    // Stop the low-power bridge before the runtime goes away.
+    #[cfg(feature = "low_power")]
+    drop(_lp_guard);
+    info!("nyx-daemon stopped");
+    served
+}
+
+/// Resolve once the process is asked to terminate (Ctrl-C, or SIGTERM on Unix).
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            warn!("failed to listen for ctrl-c: {e}");
+            std::future::pending::<()>().await;
+        }
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        use tokio::signal::unix::{signal, SignalKind};
+        match signal(SignalKind::terminate()) {
+            Ok(mut s) => {
+                s.recv().await;
+            }
+            Err(e) => {
+                warn!("failed to listen for SIGTERM: {e}");
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+    }
+}
+
+/// Wait until `shutdown` is set; a dropped sender counts as shutdown.
+async fn shutdown_requested(shutdown: &mut watch::Receiver<bool>) {
+    let _ = shutdown.wait_for(|stop| *stop).await;
+}
+
+/// Run the TCP or IPC accept loop until `shutdown` is set.
+async fn serve(
+    bind_addr: Option<String>,
+    state: Arc<DaemonState>,
+    mut shutdown: watch::Receiver<bool>,
+) -> io::Result<()> {
+    if let Some(bind_addr_str) = bind_addr {
         // TCP listener for integration tests and remote access
-        let bind_addr_str = bind_addr.clone().unwrap();
         let addr: std::net::SocketAddr = bind_addr_str.parse().map_err(|e| {
             io::Error::new(io::ErrorKind::InvalidInput, format!("Invalid bind address: {}", e))
         })?;
@@ -365,7 +580,11 @@ async fn main() -> io::Result<()> {
         info!("TCP listener bound to {}", listener.local_addr()?);
         
         loop {
-            match listener.accept().await {
+            let accepted = tokio::select! {
+                r = listener.accept() => r,
+                _ = shutdown_requested(&mut shutdown) => return Ok(()),
+            };
+            match accepted {
                 Ok((stream, peer_addr)) => {
                     info!("Accepted TCP connection from {}", peer_addr);
                     let st = state.clone();
@@ -382,21 +601,7 @@ async fn main() -> io::Result<()> {
         // IPC listener (Unix socket or Windows named pipe)
         #[cfg(unix)]
         {
-            let _ = std::fs::remove_file(DEFAULT_ENDPOINT);
-            let listener = UnixListener::bind(DEFAULT_ENDPOINT)?;
-            loop {
-                match listener.accept().await {
-                    Ok((stream, _addr)) => {
-                        let st = state.clone();
-                        tokio::spawn(async move {
-                            if let Err(e) = handle_unix_client(stream, st).await {
-                                warn!("client error: {}", e);
-                            }
-                        });
-                    }
-                    Err(e) => warn!("accept error: {}", e),
-                }
-            }
+            serve_unix(std::path::Path::new(DEFAULT_ENDPOINT), state, shutdown).await
         }
 
         #[cfg(windows)]
@@ -413,7 +618,11 @@ async fn main() -> io::Result<()> {
             };
 
             // Await connection before spawning handler to avoid unbounded instance creation
-            match server.connect().await {
+            let connected = tokio::select! {
+                r = server.connect() => r,
+                _ = shutdown_requested(&mut shutdown) => return Ok(()),
+            };
+            match connected {
                 Ok(()) => {
                     let st = state.clone();
                     // Move the connected server into a task to handle this client
@@ -435,6 +644,40 @@ async fn main() -> io::Result<()> {
     }
 }
 
+/// Accept Unix socket clients at `path` until `shutdown` is set, then remove
+/// the socket file so the next start does not trip over a stale one.
+#[cfg(unix)]
+async fn serve_unix(
+    path: &std::path::Path,
+    state: Arc<DaemonState>,
+    mut shutdown: watch::Receiver<bool>,
+) -> io::Result<()> {
+    let _ = std::fs::remove_file(path);
+    let listener = UnixListener::bind(path)?;
+    loop {
+        let accepted = tokio::select! {
+            r = listener.accept() => r,
+            _ = shutdown_requested(&mut shutdown) => break,
+        };
+        match accepted {
+            Ok((stream, _addr)) => {
+                let st = state.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = handle_unix_client(stream, st).await {
+                        warn!("client error: {}", e);
+                    }
+                });
+            }
+            Err(e) => warn!("accept error: {}", e),
+        }
+    }
+    drop(listener);
+    if let Err(e) = std::fs::remove_file(path) {
+        warn!("failed to remove socket {}: {e}", path.display());
+    }
+    Ok(())
+}
+
 fn ensure_token_from_env_or_cookie() -> Option<String> {
     // 1) Environment variable takes precedence (non-empty)
     if let Ok(t) = std::env::var("NYX_DAEMON_TOKEN") {
@@ -529,8 +772,12 @@ async fn handle_tcp_client(
     state: Arc<DaemonState>,
 ) -> io::Result<()> {
     let mut buf = Vec::with_capacity(1024);
-    match read_one_line_with_timeout(&mut stream, &mut buf, INITIAL_READ_TIMEOUT_MS).await {
-        Ok(_) => {}
+    let limit = max_request_bytes();
+    match read_one_line_with_timeout(&mut stream, &mut buf, INITIAL_READ_TIMEOUT_MS, limit).await {
+        Ok(LineRead::Complete) => {}
+        Ok(LineRead::TooLarge) => {
+            return write_request_too_large(&mut stream, limit, &state).await;
+        }
         Err(_) => {
             return Ok(());
         } // drop slow/idle client silently
@@ -541,35 +788,19 @@ async fn handle_tcp_client(
     let json = json_util::encode_to_vec(&resp).unwrap_or_else(|e| {
         #[cfg(feature = "telemetry")]
         nyx_telemetry::record_counter("nyx_daemon_serde_error", 1);
-        serde_json::to_vec(&Response::<serde_json::Value>::err_with_id(resp_id, 500, e))
-            .unwrap_or_default()
+        serde_json::to_vec(&Response::<serde_json::Value>::err_with_id(
+            resp_id,
+            DaemonErrorCode::Internal,
+            e,
+        ))
+        .unwrap_or_default()
     });
     stream.write_all(&json).await?;
     stream.write_all(b"\n").await?;
     stream.flush().await?;
     // If client requested subscription, stream events until client disconnects
-    if let Some(mut rx) = stream_back {
-        while let Ok(ev) = rx.recv().await {
-            if !state.events.matches(&ev, &filter).await {
-                continue;
-            }
-            let line = match json_util::encode_to_vec(&ev) {
-                Ok(v) => v,
-                Err(e) => {
-                    warn!("failed to serialize event: {}", e);
-                    continue;
-                }
-            };
-            if stream.write_all(&line).await.is_err() {
-                break;
-            }
-            if stream.write_all(&b"\n"[..]).await.is_err() {
-                break;
-            }
-            if stream.flush().await.is_err() {
-                break;
-            }
-        }
+    if let Some(rx) = stream_back {
+        stream_events(&mut stream, rx, &filter, &state).await;
     }
     Ok(())
 }
@@ -580,8 +811,12 @@ async fn handle_unix_client(
     state: Arc<DaemonState>,
 ) -> io::Result<()> {
     let mut buf = Vec::with_capacity(1024);
-    match read_one_line_with_timeout(&mut stream, &mut buf, INITIAL_READ_TIMEOUT_MS).await {
-        Ok(_) => {}
+    let limit = max_request_bytes();
+    match read_one_line_with_timeout(&mut stream, &mut buf, INITIAL_READ_TIMEOUT_MS, limit).await {
+        Ok(LineRead::Complete) => {}
+        Ok(LineRead::TooLarge) => {
+            return write_request_too_large(&mut stream, limit, &state).await;
+        }
         Err(_) => {
             return Ok(());
         } // drop slow/idle client silently
@@ -593,35 +828,19 @@ async fn handle_unix_client(
         #[cfg(feature = "telemetry")]
         nyx_telemetry::record_counter("nyx_daemon_serde_error", 1);
         // Fixed: Use unwrap_or_default instead of ? in closure
-        serde_json::to_vec(&Response::<serde_json::Value>::err_with_id(resp_id, 500, e))
-            .unwrap_or_default()
+        serde_json::to_vec(&Response::<serde_json::Value>::err_with_id(
+            resp_id,
+            DaemonErrorCode::Internal,
+            e,
+        ))
+        .unwrap_or_default()
     });
     stream.write_all(&json).await?;
     stream.write_all(b"\n").await?;
     stream.flush().await?;
     // If client requested subscription, stream events until client disconnects
-    if let Some(mut rx) = stream_back {
-        while let Ok(ev) = rx.recv().await {
-            if !state.events.matches(&ev, &filter).await {
-                continue;
-            }
-            let line = match json_util::encode_to_vec(&ev) {
-                Ok(v) => v,
-                Err(e) => {
-                    warn!("failed to serialize event: {}", e);
-                    continue;
-                }
-            };
-            if stream.write_all(&line).await.is_err() {
-                break;
-            }
-            if stream.write_all(b"\n").await.is_err() {
-                break;
-            }
-            if stream.flush().await.is_err() {
-                break;
-            }
-        }
+    if let Some(rx) = stream_back {
+        stream_events(&mut stream, rx, &filter, &state).await;
     }
     Ok(())
 }
@@ -633,8 +852,12 @@ async fn handle_pipe_client(
 ) -> io::Result<()> {
     // Named pipes on windows are byte streams; read until newline or timeout
     let mut buf = Vec::with_capacity(1024);
-    match read_one_line_with_timeout(stream, &mut buf, INITIAL_READ_TIMEOUT_MS).await {
-        Ok(_) => {}
+    let limit = max_request_bytes();
+    match read_one_line_with_timeout(stream, &mut buf, INITIAL_READ_TIMEOUT_MS, limit).await {
+        Ok(LineRead::Complete) => {}
+        Ok(LineRead::TooLarge) => {
+            return write_request_too_large(stream, limit, &state).await;
+        }
         Err(_) => {
             return Ok(());
         }
@@ -647,7 +870,7 @@ async fn handle_pipe_client(
         nyx_telemetry::record_counter("nyx_daemon_serde_error", 1);
         serde_json::to_vec(&Response::<serde_json::Value>::err_with_id(
             resp_id,
-            500,
+            DaemonErrorCode::Internal,
             e.to_string(),
         ))
         .unwrap_or_default()
@@ -656,27 +879,89 @@ async fn handle_pipe_client(
     stream.write_all(b"\n").await?;
     stream.flush().await?;
     // Stream events if subscribed until client disconnects
-    if let Some(mut rx) = stream_back {
-        while let Ok(ev) = rx.recv().await {
-            if !state.events.matches(&ev, &filter).await {
-                continue;
-            }
-            let line = match json_util::encode_to_vec(&ev) {
-                Ok(v) => v,
-                Err(_) => continue,
-            };
-            if stream.write_all(&line).await.is_err() {
-                break;
-            }
-            if stream.write_all(b"\n").await.is_err() {
-                break;
+    if let Some(rx) = stream_back {
+        stream_events(stream, rx, &filter, &state).await;
+    }
+    Ok(())
+}
+
+/// Write matching events to a subscribed client until it disconnects.
+/// Lines the client sends meanwhile are keepalive pings; each one is
+/// answered with an empty line, which subscribers skip.
+///
+/// A subscriber too slow to keep up with the broadcast buffer loses the
+/// oldest events; it is told how many via an ordinary event of type
+/// `events_dropped` whose detail is the count, so the gap is visible
+/// instead of silent.
+async fn stream_events<S>(
+    stream: &mut S,
+    mut rx: EventSubscription,
+    filter: &Option<Vec<String>>,
+    state: &DaemonState,
+) where
+    S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+{
+    let mut inbound = [0u8; 256];
+    let mut client_open = true;
+    loop {
+        tokio::select! {
+            ev = rx.recv() => {
+                let encoded = match ev {
+                    Ok(ev) => {
+                        if !state.events.matches(&ev, filter).await {
+                            continue;
+                        }
+                        json_util::encode_to_vec(&ev)
+                    }
+                    Err(RecvError::Lagged(count)) => {
+                        warn!("event subscriber lagged, {count} events dropped");
+                        json_util::encode_to_vec(&Event {
+                            _ty: "events_dropped".into(),
+                            _detail: count.to_string(),
+                        })
+                    }
+                    Err(RecvError::Closed) => break,
+                };
+                let line = match encoded {
+                    Ok(v) => ipc_compression::encode_line(v, rx.compression()),
+                    Err(e) => {
+                        warn!("failed to serialize event: {}", e);
+                        continue;
+                    }
+                };
+                if stream.write_all(&line).await.is_err()
+                    || stream.write_all(b"\n").await.is_err()
+                    || stream.flush().await.is_err()
+                {
+                    break;
+                }
             }
-            if stream.flush().await.is_err() {
-                break;
+            read = stream.read(&mut inbound), if client_open => {
+                let n = match read {
+                    // Half-closed client: keep streaming until a write fails
+                    Ok(0) | Err(_) => {
+                        client_open = false;
+                        continue;
+                    }
+                    Ok(n) => n,
+                };
+                let pings = memchr::memchr_iter(b'\n', &inbound[..n]).count();
+                let pongs = vec![b'\n'; pings];
+                if stream.write_all(&pongs).await.is_err() || stream.flush().await.is_err() {
+                    break;
+                }
             }
         }
     }
-    Ok(())
+    state.metrics.subscription_closed();
+}
+
+/// Outcome of reading the request line.
+#[derive(Debug, PartialEq, Eq)]
+enum LineRead {
+    Complete,
+    /// The line exceeded the limit; it was discarded rather than truncated.
+    TooLarge,
 }
 
 // Minimal 1-line reader with timeout and CRLF handling (mirrors SDK behavior)
@@ -684,12 +969,14 @@ async fn read_one_line_with_timeout<R: tokio::io::AsyncRead + Unpin>(
     reader: &mut R,
     out: &mut Vec<u8>,
     timeout_ms: u64,
-) -> io::Result<()> {
+    max_len: usize,
+) -> io::Result<LineRead> {
     use tokio::time::{timeout, Duration, Instant};
     let deadline = Duration::from_millis(timeout_ms);
     let start = Instant::now();
     out.clear();
     let mut tmp = [0u8; 256];
+    let mut too_large = false;
     loop {
         let remain = deadline.saturating_sub(start.elapsed());
         if remain.is_zero() {
@@ -703,21 +990,46 @@ async fn read_one_line_with_timeout<R: tokio::io::AsyncRead + Unpin>(
         if n == 0 {
             break;
         }
-        out.extend_from_slice(&tmp[..n]);
-        if out.contains(&b'\n') {
-            break;
+        let newline = memchr::memchr(b'\n', &tmp[..n]);
+        if !too_large {
+            out.extend_from_slice(&tmp[..newline.unwrap_or(n)]);
+            // The trailing '\r' of a CRLF line does not count toward the limit
+            let len = out.len() - usize::from(newline.is_some() && out.last() == Some(&b'\r'));
+            if len > max_len {
+                // Keep draining to the newline so the client can read our reply
+                too_large = true;
+                out.clear();
+            }
         }
-        if out.len() > 64 * 1024 {
+        if newline.is_some() {
             break;
         }
     }
-    if let Some(pos) = memchr::memchr(b'\n', out) {
-        out.truncate(pos);
+    if too_large {
+        return Ok(LineRead::TooLarge);
     }
     if out.last().copied() == Some(b'\r') {
         out.pop();
     }
-    Ok(())
+    Ok(LineRead::Complete)
+}
+
+/// Answer an oversized request with 413 instead of parsing a truncated line.
+async fn write_request_too_large<S: tokio::io::AsyncWrite + Unpin>(
+    stream: &mut S,
+    limit: usize,
+    state: &DaemonState,
+) -> io::Result<()> {
+    state.metrics.record_bad_request();
+    let resp = Response::<serde_json::Value>::err_with_id(
+        None,
+        DaemonErrorCode::PayloadTooLarge,
+        format!("request too large: limit is {limit} bytes"),
+    );
+    let json = json_util::encode_to_vec(&resp).map_err(io::Error::other)?;
+    stream.write_all(&json).await?;
+    stream.write_all(b"\n").await?;
+    stream.flush().await
 }
 
 async fn process_request(
@@ -725,15 +1037,85 @@ async fn process_request(
     state: &DaemonState,
 ) -> (
     Response<serde_json::Value>,
-    Option<tokio::sync::broadcast::Receiver<Event>>,
+    Option<EventSubscription>,
     Option<Vec<String>>,
 ) {
-    match json_util::decode_from_str::<RpcRequest>(req_line) {
+    let parsed = json_util::decode_from_str::<RpcRequest>(req_line);
+    if let Ok(rpc) = &parsed {
+        state.metrics.record_request(rpc.req.op_name());
+    }
+    match parsed {
         Ok(RpcRequest {
+            id,
+            auth: _,
+            req: Request::Batch { requests },
+        }) => (process_batch(id, requests, state).await, None, None),
+        Ok(rpc) => dispatch(rpc, state).await,
+        Err(e) => {
+            state.metrics.record_bad_request();
+            #[cfg(feature = "telemetry")]
+            nyx_telemetry::record_counter("nyx_daemon_bad_request", 1);
+            (
+                Response::err_with_id(
+                    None,
+                    DaemonErrorCode::BadRequest,
+                    format!("invalid request: {e}"),
+                ),
+                None,
+                None,
+            )
+        }
+    }
+}
+
+/// Upper bound on sub-requests in one `batch` call.
+const MAX_BATCH_REQUESTS: usize = 64;
+
+/// Run the sub-requests of a batch in order. Each one is authorized with its
+/// own `auth` field, so a batch mixes successes and 401s rather than failing
+/// as a whole; subscriptions and nested batches are refused per entry.
+async fn process_batch(
+    id: Option<String>,
+    requests: Vec<RpcRequest>,
+    state: &DaemonState,
+) -> Response<serde_json::Value> {
+    if requests.len() > MAX_BATCH_REQUESTS {
+        let msg = format!("batch too large: {} > {MAX_BATCH_REQUESTS}", requests.len());
+        return Response::err_with_id(id, DaemonErrorCode::BadRequest, msg);
+    }
+    let mut results = Vec::with_capacity(requests.len());
+    for rpc in requests {
+        state.metrics.record_request(rpc.req.op_name());
+        let resp = match rpc.req {
+            Request::Batch { .. } | Request::SubscribeEvents { .. } => {
+                let msg = format!("{} not allowed in batch", rpc.req.op_name());
+                Response::err_with_id(rpc.id, DaemonErrorCode::BadRequest, msg)
+            }
+            _ => dispatch(rpc, state).await.0,
+        };
+        results.push(resp);
+    }
+    match serde_json::to_value(results) {
+        Ok(v) => Response::ok_with_id(id, v),
+        Err(e) => Response::err_with_id(id, DaemonErrorCode::Internal, e.to_string()),
+    }
+}
+
+/// Execute one parsed request. `Batch` is handled by [`process_request`].
+async fn dispatch(
+    rpc: RpcRequest,
+    state: &DaemonState,
+) -> (
+    Response<serde_json::Value>,
+    Option<EventSubscription>,
+    Option<Vec<String>>,
+) {
+    match rpc {
+        RpcRequest {
             id,
             auth: _,
             req: Request::GetInfo,
-        }) => {
+        } => {
             let info = Info {
                 node_id: hex::encode(state.node_id),
                 version: env!("CARGO_PKG_VERSION").to_string(),
@@ -741,16 +1123,24 @@ async fn process_request(
             };
             match serde_json::to_value(info) {
                 Ok(v) => (Response::ok_with_id(id, v), None, None),
-                Err(e) => (Response::err_with_id(id, 500, e.to_string()), None, None),
+                Err(e) => (
+                    Response::err_with_id(id, DaemonErrorCode::Internal, e.to_string()),
+                    None,
+                    None,
+                ),
             }
         }
-        Ok(RpcRequest {
+        RpcRequest {
             id,
             auth,
             req: Request::ReloadConfig,
-        }) => {
-            if !is_authorized(state, auth.as_deref()) {
-                return (Response::err_with_id(id, 401, "unauthorized"), None, None);
+        } => {
+            if !is_authorized(state, auth.as_deref(), Scope::Config) {
+                return (
+                    Response::err_with_id(id, DaemonErrorCode::Unauthorized, "unauthorized"),
+                    None,
+                    None,
+                );
             }
             let res = state
                 .cfg
@@ -766,6 +1156,7 @@ async fn process_request(
                 nyx_telemetry::record_counter("nyx_daemon_reload_fail", 1);
             }
             if res.__succes_s {
+                state.metrics.record_config_reload();
                 let _ = state.events.sender().send(Event {
                     _ty: "system".into(),
                     _detail: "config_reloaded".into(),
@@ -773,77 +1164,159 @@ async fn process_request(
             }
             match serde_json::to_value(res) {
                 Ok(v) => (Response::ok_with_id(id, v), None, None),
-                Err(e) => (Response::err_with_id(id, 500, e.to_string()), None, None),
+                Err(e) => (
+                    Response::err_with_id(id, DaemonErrorCode::Internal, e.to_string()),
+                    None,
+                    None,
+                ),
             }
         }
-        Ok(RpcRequest {
+        RpcRequest {
+            id,
+            auth,
+            req: Request::GetConfig,
+        } => {
+            if !is_authorized(state, auth.as_deref(), Scope::Read) {
+                return (
+                    Response::err_with_id(id, DaemonErrorCode::Unauthorized, "unauthorized"),
+                    None,
+                    None,
+                );
+            }
+            match serde_json::to_value(state.cfg.public_config().await) {
+                Ok(v) => (Response::ok_with_id(id, v), None, None),
+                Err(e) => (
+                    Response::err_with_id(id, DaemonErrorCode::Internal, e.to_string()),
+                    None,
+                    None,
+                ),
+            }
+        }
+        RpcRequest {
             id,
             auth,
             req: Request::UpdateConfig { settings },
-        }) => {
-            if !is_authorized(state, auth.as_deref()) {
-                return (Response::err_with_id(id, 401, "unauthorized"), None, None);
+        } => {
+            if !is_authorized(state, auth.as_deref(), Scope::Config) {
+                return (
+                    Response::err_with_id(id, DaemonErrorCode::Unauthorized, "unauthorized"),
+                    None,
+                    None,
+                );
             }
-            let res = state
-                .cfg
-                .updateconfig(settings)
-                .await
-                .unwrap_or_else(|e| ConfigResponse {
-                    __succes_s: false,
-                    _message: e.to_string(),
+            // apply_patch emits the `config_updated` event carrying the diff
+            let res = match state.cfg.apply_patch(settings).await {
+                Ok(diff) => ConfigResponse {
+                    __succes_s: true,
+                    _message: format!("updated {} field(_s)", diff.len()),
                     __validation_error_s: vec![],
-                });
+                },
+                Err(e) => match e.downcast::<PatchRejected>() {
+                    Ok(PatchRejected(error_s)) => ConfigResponse {
+                        __succes_s: false,
+                        _message: "validation failed".into(),
+                        __validation_error_s: error_s,
+                    },
+                    Err(e) => ConfigResponse {
+                        __succes_s: false,
+                        _message: e.to_string(),
+                        __validation_error_s: vec![],
+                    },
+                },
+            };
             #[cfg(feature = "telemetry")]
             if !res.__succes_s {
                 nyx_telemetry::record_counter("nyx_daemon_update_fail", 1);
             }
-            if res.__succes_s {
-                let _ = state.events.sender().send(Event {
-                    _ty: "system".into(),
-                    _detail: "config_updated".into(),
-                });
-            }
             match serde_json::to_value(res) {
                 Ok(v) => (Response::ok_with_id(id, v), None, None),
-                Err(e) => (Response::err_with_id(id, 500, e.to_string()), None, None),
+                Err(e) => (
+                    Response::err_with_id(id, DaemonErrorCode::Internal, e.to_string()),
+                    None,
+                    None,
+                ),
             }
         }
-        Ok(RpcRequest {
+        RpcRequest {
             id,
             auth,
-            req: Request::SubscribeEvents { types },
-        }) => {
-            if !is_authorized(state, auth.as_deref()) {
-                return (Response::err_with_id(id, 401, "unauthorized"), None, None);
+            req: Request::SubscribeEvents { types, compression },
+        } => {
+            if !is_authorized(state, auth.as_deref(), Scope::Read) {
+                return (
+                    Response::err_with_id(id, DaemonErrorCode::Unauthorized, "unauthorized"),
+                    None,
+                    None,
+                );
             }
-            let rx = state.events.subscribe();
-            (
-                Response::ok_with_id(id, serde_json::json!({"subscribed": true})),
-                Some(rx),
-                types,
-            )
+            let unknown = types
+                .as_deref()
+                .map(EventSystem::unknown_type_s)
+                .unwrap_or_default();
+            if !unknown.is_empty() && state.events.strict_type_s() {
+                let msg = format!("unknown event types: {}", unknown.join(", "));
+                return (
+                    Response::err_with_id(id, DaemonErrorCode::BadRequest, msg),
+                    None,
+                    None,
+                );
+            }
+            let rx = match state.events.try_subscribe() {
+                Ok(rx) => rx,
+                Err(e) => {
+                    return (
+                        Response::err_with_id(id, DaemonErrorCode::Unavailable, e.to_string()),
+                        None,
+                        None,
+                    )
+                }
+            };
+            let codec = compression.as_deref().and_then(ipc_compression::negotiate);
+            let rx = rx.with_compression(codec);
+            state.metrics.subscription_opened();
+            let mut data = serde_json::json!({"subscribed": true});
+            if let Some(codec) = codec {
+                data["compression"] = serde_json::json!(codec);
+            }
+            if !unknown.is_empty() {
+                warn!(unknown = ?unknown, "subscription filter names unknown event types");
+                data["unknown_types"] = serde_json::json!(unknown);
+            }
+            (Response::ok_with_id(id, data), Some(rx), types)
         }
-        Ok(RpcRequest {
+        RpcRequest {
             id,
             auth,
             req: Request::ListConfigVersions,
-        }) => {
-            if !is_authorized(state, auth.as_deref()) {
-                return (Response::err_with_id(id, 401, "unauthorized"), None, None);
+        } => {
+            if !is_authorized(state, auth.as_deref(), Scope::Read) {
+                return (
+                    Response::err_with_id(id, DaemonErrorCode::Unauthorized, "unauthorized"),
+                    None,
+                    None,
+                );
             }
             let list: Vec<VersionSummary> = state.cfg.listversion_s().await;
             match serde_json::to_value(list) {
                 Ok(v) => (Response::ok_with_id(id, v), None, None),
-                Err(e) => (Response::err_with_id(id, 500, e.to_string()), None, None),
+                Err(e) => (
+                    Response::err_with_id(id, DaemonErrorCode::Internal, e.to_string()),
+                    None,
+                    None,
+                ),
             }
         }
-        Ok(RpcRequest {
+        RpcRequest {
             id,
             auth,
             req: Request::RollbackConfig { version },
-        }) => {
-            if !is_authorized(state, auth.as_deref()) {
-                return (Response::err_with_id(id, 401, "unauthorized"), None, None);
+        } => {
+            if !is_authorized(state, auth.as_deref(), Scope::Config) {
+                return (
+                    Response::err_with_id(id, DaemonErrorCode::Unauthorized, "unauthorized"),
+                    None,
+                    None,
+                );
             }
             let res = state
                 .cfg
@@ -866,16 +1339,24 @@ async fn process_request(
             }
             match serde_json::to_value(res) {
                 Ok(v) => (Response::ok_with_id(id, v), None, None),
-                Err(e) => (Response::err_with_id(id, 500, e.to_string()), None, None),
+                Err(e) => (
+                    Response::err_with_id(id, DaemonErrorCode::Internal, e.to_string()),
+                    None,
+                    None,
+                ),
             }
         }
-        Ok(RpcRequest {
+        RpcRequest {
             id,
             auth,
             req: Request::CreateConfigSnapshot { description },
-        }) => {
-            if !is_authorized(state, auth.as_deref()) {
-                return (Response::err_with_id(id, 401, "unauthorized"), None, None);
+        } => {
+            if !is_authorized(state, auth.as_deref(), Scope::Config) {
+                return (
+                    Response::err_with_id(id, DaemonErrorCode::Unauthorized, "unauthorized"),
+                    None,
+                    None,
+                );
             }
             match state
                 .cfg
@@ -890,18 +1371,59 @@ async fn process_request(
                 Err(e) => {
                     #[cfg(feature = "telemetry")]
                     nyx_telemetry::record_counter("nyx_daemon_snapshot_fail", 1);
-                    (Response::err_with_id(id, 500, e.to_string()), None, None)
+                    (
+                        Response::err_with_id(id, DaemonErrorCode::Internal, e.to_string()),
+                        None,
+                        None,
+                    )
+                }
+            }
+        }
+        RpcRequest {
+            id,
+            auth,
+            req:
+                Request::BuildPath {
+                    required_caps,
+                    hops,
+                },
+        } => {
+            if !is_authorized(state, auth.as_deref(), Scope::Path) {
+                return (
+                    Response::err_with_id(id, DaemonErrorCode::Unauthorized, "unauthorized"),
+                    None,
+                    None,
+                );
+            }
+            let built = state
+                .paths
+                .build_path_with_capabilities(&required_caps, hops)
+                .await;
+            match built.map(serde_json::to_value) {
+                Ok(Ok(v)) => (Response::ok_with_id(id, v), None, None),
+                Ok(Err(e)) => (
+                    Response::err_with_id(id, DaemonErrorCode::Internal, e.to_string()),
+                    None,
+                    None,
+                ),
+                Err(e) => {
+                    let code = build_path_error_code(&e);
+                    (Response::err_with_id(id, code, e.to_string()), None, None)
                 }
             }
         }
         #[cfg(feature = "low_power")]
-        Ok(RpcRequest {
+        RpcRequest {
             id,
             auth,
             req: Request::SetPowerState { state: s },
-        }) => {
-            if !is_authorized(state, auth.as_deref()) {
-                return (Response::err_with_id(id, 401, "unauthorized"), None, None);
+        } => {
+            if !is_authorized(state, auth.as_deref(), Scope::Power) {
+                return (
+                    Response::err_with_id(id, DaemonErrorCode::Unauthorized, "unauthorized"),
+                    None,
+                    None,
+                );
             }
             let rc = nyx_mobile_ffi::nyx_power_set_state(s);
             if rc == nyx_mobile_ffi::NyxStatus::Ok as i32 {
@@ -912,17 +1434,21 @@ async fn process_request(
                 )
             } else {
                 (
-                    Response::err_with_id(id, 400, format!("ffi_error:{rc}")),
+                    Response::err_with_id(
+                        id,
+                        DaemonErrorCode::BadRequest,
+                        format!("ffi_error:{rc}"),
+                    ),
                     None,
                     None,
                 )
             }
         }
-        Ok(RpcRequest {
+        RpcRequest {
             id,
             req: Request::GetSystemInfo,
             ..
-        }) => {
+        } => {
             let info = SystemInfo {
                 uptime: state.start_time.elapsed().as_secs(),
                 version: env!("CARGO_PKG_VERSION").to_string(),
@@ -931,14 +1457,18 @@ async fn process_request(
             };
             match serde_json::to_value(info) {
                 Ok(v) => (Response::ok_with_id(id, v), None, None),
-                Err(e) => (Response::err_with_id(id, 500, e.to_string()), None, None),
+                Err(e) => (
+                    Response::err_with_id(id, DaemonErrorCode::Internal, e.to_string()),
+                    None,
+                    None,
+                ),
             }
         }
-        Ok(RpcRequest {
+        RpcRequest {
             id,
             req: Request::Health,
             ..
-        }) => {
+        } => {
             let status = HealthStatus {
                 healthy: true,
                 timestamp: chrono::Utc::now(),
@@ -946,22 +1476,48 @@ async fn process_request(
             };
             match serde_json::to_value(status) {
                 Ok(v) => (Response::ok_with_id(id, v), None, None),
-                Err(e) => (Response::err_with_id(id, 500, e.to_string()), None, None),
+                Err(e) => (
+                    Response::err_with_id(id, DaemonErrorCode::Internal, e.to_string()),
+                    None,
+                    None,
+                ),
             }
         }
-        Err(e) => {
-            #[cfg(feature = "telemetry")]
-            nyx_telemetry::record_counter("nyx_daemon_bad_request", 1);
-            (
-                Response::err_with_id(None, 400, format!("invalid request: {e}")),
-                None,
-                None,
-            )
+        RpcRequest {
+            id,
+            req: Request::Batch { .. },
+            ..
+        } => (
+            Response::err_with_id(id, DaemonErrorCode::BadRequest, "nested batch not allowed"),
+            None,
+            None,
+        ),
+    }
+}
+
+/// Map path-build failures to RPC codes: bad input is the caller's fault,
+/// too few capable peers is a transient condition.
+fn build_path_error_code(e: &DaemonError) -> DaemonErrorCode {
+    match e {
+        DaemonError::InvalidPathRequest(_) => DaemonErrorCode::BadRequest,
+        DaemonError::PathBuild(PathBuildError::InsufficientPeers { .. }) => {
+            DaemonErrorCode::Unavailable
         }
+        _ => DaemonErrorCode::Internal,
     }
 }
 
-fn is_authorized(state: &DaemonState, auth: Option<&str>) -> bool {
+fn is_authorized(state: &DaemonState, auth: Option<&str>, scope: Scope) -> bool {
+    let ok = check_token(state, auth, scope);
+    if !ok {
+        state.metrics.record_auth_failure();
+    }
+    ok
+}
+
+/// A token passes if it is the configured token or a scoped token derived
+/// from it that includes `scope`.
+fn check_token(state: &DaemonState, auth: Option<&str>, scope: Scope) -> bool {
     // Auth mode: require token by default, allow disable via NYX_DAEMON_DISABLE_AUTH=1
     let auth_disabled = std::env::var("NYX_DAEMON_DISABLE_AUTH")
         .ok()
@@ -991,31 +1547,19 @@ fn is_authorized(state: &DaemonState, auth: Option<&str>) -> bool {
     let expected = effective.unwrap();
     match auth {
         Some(provided) => {
-            // SECURITY ENHANCEMENT: Use constant-time comparison to prevent timing attacks
-            // This prevents attackers from deducing token characters through timing analysis
-
-            let provided_bytes = provided.as_bytes();
-            let expected_bytes = expected.as_bytes();
-
-            // Ensure both strings are compared in constant time regardless of length
-            let max_len = std::cmp::max(provided_bytes.len(), expected_bytes.len());
-            let mut result = 0u8;
-
-            // Always compare the full length to prevent early termination timing attacks
-            for i in 0..max_len {
-                let p_byte = provided_bytes.get(i).copied().unwrap_or(0);
-                let e_byte = expected_bytes.get(i).copied().unwrap_or(0);
-                result |= p_byte ^ e_byte;
-            }
-
-            // Additional length check in constant time
-            let length_match = provided_bytes.len() == expected_bytes.len();
-            let ok = result == 0 && length_match;
-
-            if !ok {
-                warn!("authorization failed: invalid token");
+            // Digest comparison/MAC check (see auth_scope) so neither the
+            // token's characters nor its length can be deduced by timing
+            match granted_scopes(expected, provided) {
+                Some(granted) if granted.contains(scope) => true,
+                Some(_) => {
+                    warn!("authorization failed: token lacks scope {}", scope.as_str());
+                    false
+                }
+                None => {
+                    warn!("authorization failed: invalid token");
+                    false
+                }
             }
-            ok
         }
         None => {
             warn!("authorization failed: missing token");
@@ -1071,9 +1615,11 @@ mod tests {
         DaemonState {
             start_time: Instant::now(),
             node_id,
-            cfg: cfg_mgr,
+            cfg: cfg_mgr.with_events(events.sender()),
             events,
             token: token.map(|s| s.to_string()),
+            metrics: Arc::new(MetricsCollector::new()),
+            paths: PathBuilder::new(PathBuilderConfig::default()).unwrap(),
         }
     }
 
@@ -1092,6 +1638,199 @@ mod tests {
         assert!(filter.is_none());
     }
 
+    #[tokio::test]
+    async fn oversized_request_gets_413_not_parse_error() -> io::Result<()> {
+        use tokio::io::AsyncBufReadExt;
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let state = Arc::new(make_state_with_token(None));
+        tokio::spawn(async move {
+            while let Ok((sock, _)) = listener.accept().await {
+                tokio::spawn(handle_tcp_client(sock, state.clone()));
+            }
+        });
+
+        let send = |line: Vec<u8>| async move {
+            let mut c = tokio::net::TcpStream::connect(addr).await?;
+            c.write_all(&line).await?;
+            let mut resp = String::new();
+            tokio::io::BufReader::new(c).read_line(&mut resp).await?;
+            Ok::<_, io::Error>(serde_json::from_str::<serde_json::Value>(&resp)?)
+        };
+
+        // Valid JSON padded past the limit: previously truncated into a 400.
+        let pad = "x".repeat(DEFAULT_MAX_REQUEST_BYTES);
+        let big = format!("{{\"op\":\"get_info\",\"id\":\"{pad}\"}}\n");
+        let resp = send(big.into_bytes()).await?;
+        assert_eq!(resp["code"], 413);
+        assert!(resp["error"].as_str().unwrap().contains("request too large"));
+
+        let small = b"{\"op\":\"get_info\"}\r\n".to_vec();
+        let resp = send(small).await?;
+        assert_eq!(resp["ok"], true);
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn unix_listener_removes_socket_on_shutdown() -> io::Result<()> {
+        use tokio::io::AsyncBufReadExt;
+        let dir = tempdir()?;
+        let path = dir.path().join("nyx.sock");
+        let state = Arc::new(make_state_with_token(None));
+        let (tx, rx) = watch::channel(false);
+        let server = tokio::spawn({
+            let path = path.clone();
+            async move { serve_unix(&path, state, rx).await }
+        });
+
+        // Wait for the listener, then prove it serves requests.
+        let mut conn = None;
+        for _ in 0..100 {
+            match tokio::net::UnixStream::connect(&path).await {
+                Ok(c) => {
+                    conn = Some(c);
+                    break;
+                }
+                Err(_) => tokio::time::sleep(std::time::Duration::from_millis(10)).await,
+            }
+        }
+        let mut c = conn.expect("listener never came up");
+        c.write_all(b"{\"op\":\"get_info\"}\n").await?;
+        let mut resp = String::new();
+        tokio::io::BufReader::new(c).read_line(&mut resp).await?;
+        assert!(resp.contains("\"ok\":true"));
+
+        tx.send(true).expect("server still listening");
+        tokio::time::timeout(std::time::Duration::from_secs(5), server)
+            .await
+            .expect("accept loop did not stop")
+            .expect("server task panicked")?;
+        assert!(!path.exists());
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn sdk_subscriber_receives_multipath_events() -> Result<(), Box<dyn std::error::Error>> {
+        use nyx_stream::multipath_dataplane::{PathInfo, PathMetrics, PathState};
+        let dir = tempdir()?;
+        let path = dir.path().join("nyx.sock");
+        let state = Arc::new(make_state_with_token(Some("tok")));
+        let multipath = start_multipath(
+            &state.events,
+            MultipathConfig {
+                failover_timeout_ms: 50,
+                ..Default::default()
+            },
+        );
+        let (_tx, rx) = watch::channel(false);
+        tokio::spawn({
+            let path = path.clone();
+            async move { serve_unix(&path, state, rx).await }
+        });
+        for _ in 0..100 {
+            if path.exists() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+
+        let cfg = nyx_sdk::SdkConfig {
+            daemon_endpoint: path.to_string_lossy().into_owned(),
+            request_timeout_ms: 2000,
+            ..Default::default()
+        };
+        let mut events = nyx_sdk::DaemonClient::new(cfg)
+            .with_token("tok")
+            .subscribe_events(Some(vec!["multipath".into()]))
+            .await?;
+
+        // A path that never carries traffic goes silent past the failover timeout.
+        multipath.register_connection(7).await;
+        let now = std::time::Instant::now();
+        let info = PathInfo {
+            path_id: 0,
+            connection_id: 7,
+            state: PathState::Active,
+            weight: 1.0,
+            metrics: PathMetrics::default(),
+            created_at: now,
+            last_activity: now,
+        };
+        multipath.add_path(7, 0, info).await?;
+
+        let ev = tokio::time::timeout(std::time::Duration::from_secs(5), events.recv()).await??;
+        assert_eq!(ev.event_type, "multipath");
+        assert_eq!(ev.detail, "path_deactivated:conn=7,path=0");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn line_at_limit_is_read_whole() -> io::Result<()> {
+        let line = [vec![b'a'; 16], b"\r\n".to_vec()].concat();
+        let mut buf = Vec::new();
+        let r = read_one_line_with_timeout(&mut &line[..], &mut buf, 1000, 16).await?;
+        assert_eq!(r, LineRead::Complete);
+        assert_eq!(buf.len(), 16);
+        let r = read_one_line_with_timeout(&mut &line[..], &mut buf, 1000, 15).await?;
+        assert_eq!(r, LineRead::TooLarge);
+        assert!(buf.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn max_request_bytes_env_override() {
+        with_env_lock(|| {
+            std::env::set_var("NYX_DAEMON_MAX_REQUEST_BYTES", "1024");
+            assert_eq!(max_request_bytes(), 1024);
+            std::env::set_var("NYX_DAEMON_MAX_REQUEST_BYTES", "0");
+            assert_eq!(max_request_bytes(), DEFAULT_MAX_REQUEST_BYTES);
+            std::env::remove_var("NYX_DAEMON_MAX_REQUEST_BYTES");
+            assert_eq!(max_request_bytes(), DEFAULT_MAX_REQUEST_BYTES);
+        });
+    }
+
+    #[tokio::test]
+    async fn get_config_returns_defaults_then_reflects_update() {
+        let state = make_state_with_token(Some("tok"));
+        let get = serde_json::json!({"auth": "tok", "op": "get_config"}).to_string();
+        let (resp, _rx, _filter) = process_request(&get, &state).await;
+        assert!(resp.ok);
+        assert_eq!(
+            resp.data,
+            Some(serde_json::json!({
+                "listen_port": 0,
+                "log_level": null,
+                "node_id": null,
+                "max_frame_len_bytes": null,
+                "metrics_interval_secs": null
+            }))
+        );
+
+        let update = serde_json::json!({
+            "auth": "tok",
+            "op": "update_config",
+            "settings": {"log_level": "debug", "metrics_interval_secs": 15}
+        })
+        .to_string();
+        let (resp, _rx, _filter) = process_request(&update, &state).await;
+        assert!(resp.ok);
+        let (resp, _rx, _filter) = process_request(&get, &state).await;
+        let data = resp.data.unwrap();
+        assert_eq!(data["log_level"], "debug");
+        assert_eq!(data["metrics_interval_secs"], 15);
+        assert!(data
+            .as_object()
+            .unwrap()
+            .keys()
+            .all(|k| !k.starts_with('_') && !k.ends_with("_s")));
+
+        let anon = serde_json::json!({"op": "get_config"}).to_string();
+        let (resp, _rx, _filter) = process_request(&anon, &state).await;
+        assert_eq!(resp.code, 401);
+    }
+
     #[tokio::test]
     async fn update_config_unauthorized_without_token() {
         let state = make_state_with_token(Some("secret"));
@@ -1107,6 +1846,31 @@ mod tests {
         assert_eq!(resp.id.as_deref(), Some("u1"));
     }
 
+    #[tokio::test]
+    async fn read_scoped_token_cannot_update_config() {
+        let state = make_state_with_token(Some("secret"));
+        let read = nyx_daemon::auth_scope::mint_scoped_token("secret", &[Scope::Read]);
+        for op in ["get_info", "get_config", "list_config_versions"] {
+            let req = serde_json::json!({"auth": read, "op": op}).to_string();
+            let (resp, _rx, _filter) = process_request(&req, &state).await;
+            assert!(resp.ok, "{op} should accept a read token");
+        }
+
+        let update = serde_json::json!({
+            "auth": read,
+            "op": "update_config",
+            "settings": {"log_level": "debug"}
+        })
+        .to_string();
+        let (resp, _rx, _filter) = process_request(&update, &state).await;
+        assert_eq!(resp.code, DaemonErrorCode::Unauthorized);
+
+        // The unscoped daemon token keeps full access.
+        let update = update.replace(&read, "secret");
+        let (resp, _rx, _filter) = process_request(&update, &state).await;
+        assert!(resp.ok);
+    }
+
     #[tokio::test]
     async fn subscribe_events_authorized_and_filters_attached() {
         let state = make_state_with_token(Some("tok"));
@@ -1123,6 +1887,214 @@ mod tests {
         assert_eq!(filter, Some(vec!["system".to_string()]));
     }
 
+    #[tokio::test]
+    async fn subscribe_events_reports_unknown_types() {
+        let mut state = make_state_with_token(Some("tok"));
+        let req = serde_json::json!({
+            "auth": "tok",
+            "op": "subscribe_events",
+            "types": ["system", "bogus"]
+        })
+        .to_string();
+
+        let (resp, rx, filter) = process_request(&req, &state).await;
+        assert!(resp.ok);
+        assert!(rx.is_some());
+        assert_eq!(filter, Some(vec!["system".to_string(), "bogus".to_string()]));
+        let data = resp.data.unwrap();
+        assert_eq!(data["subscribed"], true);
+        assert_eq!(data["unknown_types"], serde_json::json!(["bogus"]));
+
+        state.events = EventSystem::new(16).with_strict_type_s(true);
+        let (resp, rx, _) = process_request(&req, &state).await;
+        assert!(!resp.ok);
+        assert_eq!(resp.code, 400);
+        assert!(resp.error.unwrap().contains("bogus"));
+        assert!(rx.is_none());
+    }
+
+    #[tokio::test]
+    async fn build_path_returns_only_capable_hops() {
+        use nyx_daemon::pure_rust_dht::NodeId;
+        use nyx_daemon::pure_rust_p2p::PeerInfo;
+
+        let state = make_state_with_token(Some("tok"));
+        for i in 1..=6u8 {
+            let addr = format!("10.1.0.{i}:4433").parse().unwrap();
+            let mut peer = PeerInfo::new(NodeId::random(), addr);
+            if i <= 3 {
+                peer.capabilities.push("pq".into());
+            }
+            state.paths.add_candidate(peer).await.unwrap();
+        }
+        let req = serde_json::json!({
+            "auth": "tok",
+            "op": "build_path",
+            "required_caps": ["pq"],
+            "hops": 3
+        })
+        .to_string();
+
+        let (resp, _, _) = process_request(&req, &state).await;
+        assert!(resp.ok, "{:?}", resp.error);
+        let data = resp.data.unwrap();
+        let hops = data["hops"].as_array().unwrap();
+        assert_eq!(hops.len(), 3);
+        for hop in hops {
+            assert_eq!(hop["capabilities"], serde_json::json!(["pq"]));
+        }
+        assert!(data["path_id"].as_str().is_some());
+
+        let req = req.replace("\"hops\":3", "\"hops\":4");
+        let (resp, _, _) = process_request(&req, &state).await;
+        assert_eq!(resp.code, 503);
+    }
+
+    #[tokio::test]
+    async fn batch_runs_in_order_with_per_request_auth() {
+        let state = make_state_with_token(Some("tok"));
+        let req = serde_json::json!({
+            "id": "b1",
+            "op": "batch",
+            "requests": [
+                {"id": "info", "op": "get_info"},
+                {"id": "snap", "auth": "tok", "op": "create_config_snapshot", "description": "batched"},
+                {"id": "denied", "auth": "wrong", "op": "create_config_snapshot", "description": null},
+                {"id": "sub", "auth": "tok", "op": "subscribe_events", "types": null}
+            ]
+        })
+        .to_string();
+
+        let (resp, rx, _) = process_request(&req, &state).await;
+        assert!(resp.ok, "{:?}", resp.error);
+        assert!(rx.is_none());
+        assert_eq!(resp.id.as_deref(), Some("b1"));
+        let results = resp.data.unwrap();
+        let results = results.as_array().unwrap();
+        assert_eq!(results.len(), 4);
+
+        assert_eq!(results[0]["id"], "info");
+        assert_eq!(results[0]["ok"], true);
+        assert!(results[0]["data"]["node_id"].is_string());
+
+        assert_eq!(results[1]["id"], "snap");
+        assert_eq!(results[1]["ok"], true, "{}", results[1]);
+        assert!(results[1]["data"]["version"].is_u64());
+
+        assert_eq!(results[2]["id"], "denied");
+        assert_eq!(results[2]["code"], 401);
+
+        assert_eq!(results[3]["code"], 400);
+    }
+
+    #[tokio::test]
+    async fn subscription_answers_keepalive_pings() {
+        let state = make_state_with_token(None);
+        let rx = state.events.try_subscribe().unwrap();
+        let (mut client, mut server) = tokio::io::duplex(1024);
+        let task = tokio::spawn(async move {
+            stream_events(&mut server, rx, &None, &state).await;
+        });
+
+        client
+            .write_all(b"{\"op\":\"ping\"}\n{\"op\":\"ping\"}\n")
+            .await
+            .unwrap();
+        let mut pongs = [0u8; 2];
+        tokio::time::timeout(
+            std::time::Duration::from_secs(1),
+            client.read_exact(&mut pongs),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(&pongs, b"\n\n");
+
+        drop(client);
+        task.abort();
+    }
+
+    #[tokio::test]
+    async fn lagging_subscriber_gets_dropped_marker() {
+        use tokio::io::AsyncBufReadExt;
+        let mut state = make_state_with_token(None);
+        state.events = EventSystem::new(4);
+        let rx = state.events.try_subscribe().unwrap();
+        // Overrun the 4-slot buffer before the subscriber reads anything.
+        for i in 0..10 {
+            let _ = state.events.sender().send(Event {
+                _ty: "system".into(),
+                _detail: format!("e{i}"),
+            });
+        }
+        let (client, mut server) = tokio::io::duplex(4096);
+        let task = tokio::spawn(async move {
+            stream_events(&mut server, rx, &None, &state).await;
+        });
+
+        let mut lines = tokio::io::BufReader::new(client).lines();
+        let mut got = Vec::new();
+        while got.len() < 2 {
+            let line = tokio::time::timeout(std::time::Duration::from_secs(1), lines.next_line())
+                .await
+                .unwrap()
+                .unwrap()
+                .unwrap();
+            got.push(serde_json::from_str::<serde_json::Value>(&line).unwrap());
+        }
+        assert_eq!(
+            got[0],
+            serde_json::json!({"_ty": "events_dropped", "_detail": "6"})
+        );
+        // Delivery resumes with the oldest event still buffered.
+        assert_eq!(got[1]["_detail"], "e6");
+
+        task.abort();
+    }
+
+    #[tokio::test]
+    async fn subscribe_events_negotiates_compression() {
+        let state = make_state_with_token(Some("tok"));
+        let req = serde_json::json!({
+            "auth": "tok",
+            "op": "subscribe_events",
+            "types": null,
+            "compression": ["zstd", "deflate"]
+        })
+        .to_string();
+        let (resp, rx, _) = process_request(&req, &state).await;
+        assert_eq!(resp.data.unwrap()["compression"], "deflate");
+        assert_eq!(rx.unwrap().compression(), Some(ipc_compression::DEFLATE));
+
+        // Clients that don't advertise support keep plain event lines
+        let req = serde_json::json!({"auth": "tok", "op": "subscribe_events", "types": null})
+            .to_string();
+        let (resp, rx, _) = process_request(&req, &state).await;
+        assert!(resp.data.unwrap().get("compression").is_none());
+        assert_eq!(rx.unwrap().compression(), None);
+    }
+
+    #[tokio::test]
+    async fn subscribe_events_rejected_when_busy() {
+        let mut state = make_state_with_token(Some("tok"));
+        state.events = EventSystem::new(16).with_max_subscribers(1);
+        let req = serde_json::json!({"auth": "tok", "op": "subscribe_events", "types": null})
+            .to_string();
+
+        let (first, held, _) = process_request(&req, &state).await;
+        assert!(first.ok);
+        let (second, rx, _) = process_request(&req, &state).await;
+        assert!(!second.ok);
+        assert_eq!(second.code, 503);
+        assert!(rx.is_none());
+        // nyx_daemon_active_subscriptions counts only the accepted subscriber
+        assert_eq!(state.metrics.snapshot().active_subscriptions, 1);
+
+        drop(held);
+        let (third, _rx, _) = process_request(&req, &state).await;
+        assert!(third.ok);
+    }
+
     #[tokio::test]
     async fn list_versions_after_snapshot() -> Result<(), Box<dyn std::error::Error>> {
         let state = make_state_with_token(Some("test_token"));
@@ -1186,6 +2158,37 @@ mod tests {
         assert!(resp.error.unwrap().contains("invalid request"));
     }
 
+    #[tokio::test]
+    async fn metrics_collector_tracks_processed_requests() {
+        let state = make_state_with_token(Some("tok"));
+        let reqs = [
+            serde_json::json!({"op": "get_info"}).to_string(),
+            serde_json::json!({"op": "get_info"}).to_string(),
+            serde_json::json!({"op": "health"}).to_string(),
+            serde_json::json!({"op": "reload_config", "auth": "wrong"}).to_string(),
+            serde_json::json!({"op": "subscribe_events", "auth": "tok", "types": null})
+                .to_string(),
+            "{ not_json }".to_string(),
+        ];
+        for r in &reqs {
+            let _ = process_request(r, &state).await;
+        }
+
+        let m = state.metrics.snapshot();
+        assert_eq!(m.requests_by_op.get("get_info"), Some(&2));
+        assert_eq!(m.requests_by_op.get("health"), Some(&1));
+        assert_eq!(m.requests_by_op.get("reload_config"), Some(&1));
+        assert_eq!(m.total_requests(), 5);
+        assert_eq!(m.bad_requests, 1);
+        assert_eq!(m.auth_failures, 1);
+        assert_eq!(m.active_subscriptions, 1);
+        assert_eq!(m.config_reloads, 0);
+
+        let text = state.metrics.render_prometheus();
+        assert!(text.contains("nyx_daemon_requests_total{op=\"get_info\"} 2"));
+        assert!(text.contains("nyx_daemon_bad_requests_total 1"));
+    }
+
     #[test]
     fn empty_env_token_is_treated_as_disabled() -> Result<(), Box<dyn std::error::Error>> {
         // Use environment lock to prevent test interference
@@ -1239,7 +2242,7 @@ mod tests {
             // Remove disable auth flag to ensure default behavior (auth required)
             std::env::remove_var("NYX_DAEMON_DISABLE_AUTH");
             let st = make_state_with_token(None);
-            let ok = is_authorized(&st, None);
+            let ok = is_authorized(&st, None, Scope::Read);
             assert!(!ok, "Should block access when no token is configured and auth is not explicitly disabled");
         });
     }
@@ -1251,7 +2254,7 @@ mod tests {
             std::env::remove_var("NYX_DAEMON_DISABLE_AUTH");
 
             let state = make_state_with_token(None); // No token set
-            let is_auth = is_authorized(&state, None);
+            let is_auth = is_authorized(&state, None, Scope::Read);
             assert!(
                 !is_auth,
                 "Auth should be enabled by default when no token configured"
@@ -1265,7 +2268,7 @@ mod tests {
             std::env::set_var("NYX_DAEMON_DISABLE_AUTH", "1");
 
             let state = make_state_with_token(None); // No token set
-            let is_auth = is_authorized(&state, None);
+            let is_auth = is_authorized(&state, None, Scope::Read);
 
             // Cleanup
             std::env::remove_var("NYX_DAEMON_DISABLE_AUTH");
@@ -1283,7 +2286,7 @@ mod tests {
             std::env::remove_var("NYX_DAEMON_DISABLE_AUTH");
 
             let state = make_state_with_token(Some("valid_token"));
-            let is_auth = is_authorized(&state, Some("valid_token"));
+            let is_auth = is_authorized(&state, Some("valid_token"), Scope::Config);
             assert!(is_auth, "Auth should succeed with valid token");
         });
     }
@@ -1,5 +1,6 @@
 #![forbid(unsafe_code)]
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -13,6 +14,23 @@ pub struct DaemonMetrics {
     pub total_memory: u64,
     pub used_memory: u64,
     pub thread_count: usize,
+    /// Successfully parsed requests, keyed by op name.
+    pub requests_by_op: BTreeMap<String, u64>,
+    /// Requests that failed to parse.
+    pub bad_requests: u64,
+    /// Privileged requests rejected by token check.
+    pub auth_failures: u64,
+    /// Currently open event subscriptions.
+    pub active_subscriptions: u64,
+    /// Successful configuration reloads.
+    pub config_reloads: u64,
+}
+
+impl DaemonMetrics {
+    /// Total requests processed across all ops.
+    pub fn total_requests(&self) -> u64 {
+        self.requests_by_op.values().sum()
+    }
 }
 
 #[derive(Clone)]
@@ -37,9 +55,41 @@ impl MetricsCollector {
         self.inner.read().clone()
     }
 
+    /// Count a successfully parsed request for `op`.
+    pub fn record_request(&self, op: &str) {
+        let mut w = self.inner.write();
+        *w.requests_by_op.entry(op.to_string()).or_insert(0) += 1;
+    }
+
+    /// Count a request that could not be parsed.
+    pub fn record_bad_request(&self) {
+        self.inner.write().bad_requests += 1;
+    }
+
+    /// Count a rejected authorization attempt.
+    pub fn record_auth_failure(&self) {
+        self.inner.write().auth_failures += 1;
+    }
+
+    /// Count a successful config reload.
+    pub fn record_config_reload(&self) {
+        self.inner.write().config_reloads += 1;
+    }
+
+    /// Mark an event subscription as opened.
+    pub fn subscription_opened(&self) {
+        self.inner.write().active_subscriptions += 1;
+    }
+
+    /// Mark an event subscription as closed.
+    pub fn subscription_closed(&self) {
+        let mut w = self.inner.write();
+        w.active_subscriptions = w.active_subscriptions.saturating_sub(1);
+    }
+
     pub fn render_prometheus(&self) -> String {
         let m = self.snapshot();
-        format!(
+        let mut out = format!(
             concat!(
                 "# HELP nyx_daemon_cpu_usage_pct CPU usage percent\n",
                 "# TYPE nyx_daemon_cpu_usage_pct gauge\n",
@@ -55,7 +105,30 @@ impl MetricsCollector {
                 "nyx_daemon_thread_count {}\n"
             ),
             m.cpu_usage_pct, m.total_memory, m.used_memory, m.thread_count
-        )
+        );
+        out.push_str("# HELP nyx_daemon_requests_total Requests processed per op\n");
+        out.push_str("# TYPE nyx_daemon_requests_total counter\n");
+        for (op, n) in &m.requests_by_op {
+            out.push_str(&format!("nyx_daemon_requests_total{{op=\"{op}\"}} {n}\n"));
+        }
+        out.push_str(&format!(
+            concat!(
+                "# HELP nyx_daemon_bad_requests_total Malformed requests\n",
+                "# TYPE nyx_daemon_bad_requests_total counter\n",
+                "nyx_daemon_bad_requests_total {}\n",
+                "# HELP nyx_daemon_auth_failures_total Rejected authorization attempts\n",
+                "# TYPE nyx_daemon_auth_failures_total counter\n",
+                "nyx_daemon_auth_failures_total {}\n",
+                "# HELP nyx_daemon_active_subscriptions Open event subscriptions\n",
+                "# TYPE nyx_daemon_active_subscriptions gauge\n",
+                "nyx_daemon_active_subscriptions {}\n",
+                "# HELP nyx_daemon_config_reloads_total Successful config reloads\n",
+                "# TYPE nyx_daemon_config_reloads_total counter\n",
+                "nyx_daemon_config_reloads_total {}\n"
+            ),
+            m.bad_requests, m.auth_failures, m.active_subscriptions, m.config_reloads
+        ));
+        out
     }
 
     /// Spawn a background task to periodically refresh metrics.
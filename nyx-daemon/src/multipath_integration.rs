@@ -14,23 +14,35 @@
 
 #![forbid(unsafe_code)]
 
+use crate::event_system::Event;
+use nyx_stream::frame::Frame;
 use nyx_stream::multipath_dataplane::{
     MultipathConfig, PathId, PathInfo, PathMetrics, PathScheduler, PathState,
     ReorderingBuffer,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::sync::RwLock;
-use tracing::{debug, info};
+use tokio::sync::{broadcast, mpsc, RwLock};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
 
 /// Connection identifier
 pub type ConnectionId = u32;
 
+/// Default reordering buffer capacity per connection (packets)
+const DEFAULT_REORDER_CAPACITY: usize = 1000;
+
+/// Gauge reporting the highest reorder-buffer peak among registered
+/// connections; recomputed when one is unregistered so it can fall again
+const REORDER_PEAK_GAUGE: &str = "nyx_daemon_reorder_peak_occupancy";
+
 /// Multipath manager for daemon-level integration
 pub struct MultipathManager {
     connections: Arc<RwLock<HashMap<ConnectionId, ConnectionMultipath>>>,
     config: MultipathConfig,
+    reorder_capacity: usize,
+    event_tx: Option<mpsc::Sender<MultipathEvent>>,
 }
 
 impl MultipathManager {
@@ -39,13 +51,27 @@ impl MultipathManager {
         Self {
             connections: Arc::new(RwLock::new(HashMap::new())),
             config,
+            reorder_capacity: DEFAULT_REORDER_CAPACITY,
+            event_tx: None,
         }
     }
 
+    /// Deliver path health transitions found by `periodic_maintenance` to `tx`
+    pub fn with_event_sender(mut self, tx: mpsc::Sender<MultipathEvent>) -> Self {
+        self.event_tx = Some(tx);
+        self
+    }
+
+    /// Set the reordering buffer capacity for connections registered afterwards
+    pub fn with_reorder_capacity(mut self, packets: usize) -> Self {
+        self.reorder_capacity = packets;
+        self
+    }
+
     /// Register connection for multipath support
     pub async fn register_connection(&self, conn_id: ConnectionId) {
         let mut conns = self.connections.write().await;
-        let multipath = ConnectionMultipath::new(self.config.clone());
+        let multipath = ConnectionMultipath::new(self.config.clone(), self.reorder_capacity);
         conns.insert(conn_id, multipath);
         info!("Registered connection {} for multipath", conn_id);
     }
@@ -55,6 +81,7 @@ impl MultipathManager {
         let mut conns = self.connections.write().await;
         
         if conns.remove(&conn_id).is_some() {
+            publish_reorder_peak(&conns);
             info!("Unregistered connection {} from multipath", conn_id);
             Ok(())
         } else {
@@ -148,6 +175,108 @@ impl MultipathManager {
             .unwrap_or_default()
     }
 
+    /// Feed a received frame through the connection's reordering buffer and
+    /// return the frames that are now deliverable in order
+    pub async fn receive_frame(
+        &self,
+        conn_id: ConnectionId,
+        frame: Frame,
+        seq: u64,
+    ) -> Result<Vec<Frame>, MultipathError> {
+        let mut conns = self.connections.write().await;
+
+        let multipath = conns
+            .get_mut(&conn_id)
+            .ok_or(MultipathError::ConnectionNotFound)?;
+
+        let previous_peak = multipath.reorder_buffer.peak_occupancy();
+        let delivered = multipath.reorder_buffer.add_frame(frame, seq);
+        if multipath.reorder_buffer.peak_occupancy() > previous_peak {
+            publish_reorder_peak(&conns);
+        }
+        Ok(delivered)
+    }
+
+    /// Highest reordering buffer occupancy seen on a connection.
+    ///
+    /// Reordering is per connection (all paths feed one sequence space), so
+    /// the peak is reported per connection. A value equal to the configured
+    /// capacity means the buffer overflowed and delivered frames early.
+    pub async fn reorder_peak(&self, conn_id: ConnectionId) -> Option<usize> {
+        let conns = self.connections.read().await;
+        conns
+            .get(&conn_id)
+            .map(|multipath| multipath.reorder_buffer.peak_occupancy())
+    }
+
+    /// Highest reorder peak among registered connections, as published on
+    /// the `nyx_daemon_reorder_peak_occupancy` gauge
+    pub async fn max_reorder_peak(&self) -> usize {
+        max_reorder_peak(&*self.connections.read().await)
+    }
+
+    /// Reordering buffer drop counters for every registered connection
+    pub async fn drop_stats(&self) -> HashMap<ConnectionId, ReorderDropStats> {
+        let conns = self.connections.read().await;
+        conns
+            .iter()
+            .map(|(conn_id, multipath)| {
+                let buffer = &multipath.reorder_buffer;
+                (
+                    *conn_id,
+                    ReorderDropStats {
+                        dropped_full: buffer.dropped_full(),
+                        dropped_stale: buffer.dropped_stale(),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Deactivate paths silent for longer than `failover_timeout_ms` and
+    /// reactivate them once traffic resumes, emitting one event per transition
+    pub async fn periodic_maintenance(&self) {
+        let timeout = Duration::from_millis(self.config.failover_timeout_ms);
+        let events: Vec<MultipathEvent> = {
+            let mut conns = self.connections.write().await;
+            conns
+                .iter_mut()
+                .flat_map(|(conn_id, multipath)| multipath.check_liveness(*conn_id, timeout))
+                .collect()
+        };
+
+        let Some(tx) = &self.event_tx else { return };
+        for event in events {
+            if let Err(e) = tx.try_send(event) {
+                warn!("dropping multipath event: {}", e);
+            }
+        }
+    }
+
+    /// Run `periodic_maintenance` every `every` until the task is aborted
+    pub fn spawn_maintenance(self: Arc<Self>, every: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(every);
+            loop {
+                ticker.tick().await;
+                self.periodic_maintenance().await;
+            }
+        })
+    }
+
+    /// Flush every connection's reordering buffer in sequence order, for
+    /// clean teardown. Connections with nothing buffered are omitted.
+    pub async fn drain_all(&self) -> HashMap<ConnectionId, Vec<Frame>> {
+        let mut conns = self.connections.write().await;
+        conns
+            .iter_mut()
+            .filter_map(|(conn_id, multipath)| {
+                let frames = multipath.reorder_buffer.drain_in_order();
+                (!frames.is_empty()).then_some((*conn_id, frames))
+            })
+            .collect()
+    }
+
     /// Get reordering buffer status
     pub async fn get_reorder_status(&self, conn_id: ConnectionId) -> Option<ReorderStatus> {
         let conns = self.connections.read().await;
@@ -170,16 +299,19 @@ struct ConnectionMultipath {
     config: MultipathConfig,
     next_sequence: u64,
     last_probe: Instant,
+    // paths deactivated by liveness checks, awaiting fresh activity
+    silenced: HashSet<PathId>,
 }
 
 impl ConnectionMultipath {
-    fn new(config: MultipathConfig) -> Self {
+    fn new(config: MultipathConfig, reorder_capacity: usize) -> Self {
         Self {
             scheduler: PathScheduler::new(config.clone()),
-            reorder_buffer: ReorderingBuffer::new(config.reorder_timeout_ms, 1000), // max 1000 packets
+            reorder_buffer: ReorderingBuffer::new(config.reorder_timeout_ms, reorder_capacity),
             config,
             next_sequence: 0,
             last_probe: Instant::now(),
+            silenced: HashSet::new(),
         }
     }
 
@@ -191,6 +323,7 @@ impl ConnectionMultipath {
 
     fn remove_path(&mut self, path_id: PathId) -> Result<(), MultipathError> {
         if self.scheduler.remove_path(path_id) {
+            self.silenced.remove(&path_id);
             Ok(())
         } else {
             Err(MultipathError::PathNotFound)
@@ -213,6 +346,37 @@ impl ConnectionMultipath {
             .map_err(|e| MultipathError::SchedulerError(e.to_string()))
     }
 
+    fn check_liveness(&mut self, conn_id: ConnectionId, timeout: Duration) -> Vec<MultipathEvent> {
+        let mut silent = Vec::new();
+        let mut revived = Vec::new();
+        for (path_id, info) in self.scheduler.get_all_paths() {
+            let is_silent = info.last_activity.elapsed() > timeout;
+            match (is_silent, self.silenced.contains(path_id)) {
+                (true, false) => silent.push(*path_id),
+                (false, true) => revived.push(*path_id),
+                _ => {}
+            }
+        }
+
+        let mut events = Vec::with_capacity(silent.len() + revived.len());
+        for path_id in silent {
+            self.scheduler.set_path_state(path_id, PathState::Failed);
+            self.silenced.insert(path_id);
+            info!(
+                "Path {} on connection {} deactivated after silence",
+                path_id, conn_id
+            );
+            events.push(MultipathEvent::PathDeactivated { conn_id, path_id });
+        }
+        for path_id in revived {
+            // Fresh metrics have already recomputed the path state
+            self.silenced.remove(&path_id);
+            info!("Path {} on connection {} reactivated", path_id, conn_id);
+            events.push(MultipathEvent::PathReactivated { conn_id, path_id });
+        }
+        events
+    }
+
     fn probe_paths(&mut self) {
         // Automatic path health check
         let timeout = Duration::from_millis(self.config.failover_timeout_ms);
@@ -241,6 +405,62 @@ impl ConnectionMultipath {
     }
 }
 
+/// Path health transitions reported by `MultipathManager::periodic_maintenance`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MultipathEvent {
+    PathDeactivated {
+        conn_id: ConnectionId,
+        path_id: PathId,
+    },
+    PathReactivated {
+        conn_id: ConnectionId,
+        path_id: PathId,
+    },
+}
+
+fn max_reorder_peak(conns: &HashMap<ConnectionId, ConnectionMultipath>) -> usize {
+    conns
+        .values()
+        .map(|m| m.reorder_buffer.peak_occupancy())
+        .max()
+        .unwrap_or(0)
+}
+
+fn publish_reorder_peak(conns: &HashMap<ConnectionId, ConnectionMultipath>) {
+    nyx_telemetry::set_gauge(REORDER_PEAK_GAUGE, max_reorder_peak(conns) as i64);
+}
+
+impl From<MultipathEvent> for Event {
+    fn from(event: MultipathEvent) -> Self {
+        let (what, conn_id, path_id) = match event {
+            MultipathEvent::PathDeactivated { conn_id, path_id } => {
+                ("path_deactivated", conn_id, path_id)
+            }
+            MultipathEvent::PathReactivated { conn_id, path_id } => {
+                ("path_reactivated", conn_id, path_id)
+            }
+        };
+        Event {
+            _ty: "multipath".into(),
+            _detail: format!("{what}:conn={conn_id},path={path_id}"),
+        }
+    }
+}
+
+/// Republish path health transitions on the daemon event bus, where
+/// `subscribe_events` clients receive them as `multipath` events
+pub fn forward_events(
+    mut rx: mpsc::Receiver<MultipathEvent>,
+    events: broadcast::Sender<Event>,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        while let Some(event) = rx.recv().await {
+            // No subscriber listening is not an error
+            let _ = events.send(event.into());
+        }
+    })
+}
+
 /// Reordering buffer status
 #[derive(Debug, Clone)]
 pub struct ReorderStatus {
@@ -249,6 +469,15 @@ pub struct ReorderStatus {
     pub timeout_ms: u64,
 }
 
+/// Frames lost to, or forced out of order by, a connection's reordering buffer
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReorderDropStats {
+    /// Frames evicted out of sequence because the buffer was full
+    pub dropped_full: u64,
+    /// Duplicate or already-delivered frames that were discarded
+    pub dropped_stale: u64,
+}
+
 /// Multipath errors
 #[derive(Debug, thiserror::Error)]
 pub enum MultipathError {
@@ -413,4 +642,154 @@ mod tests {
         assert_eq!(status.buffered_packets, 0);
         assert_eq!(status.next_sequence, 0);
     }
+
+    #[tokio::test]
+    async fn test_reorder_peak_reaches_capacity_on_overflow() {
+        use nyx_stream::frame::{FrameHeader, FrameType};
+
+        let manager = MultipathManager::new(MultipathConfig::default()).with_reorder_capacity(8);
+        let conn_id = 1;
+        manager.register_connection(conn_id).await;
+        assert_eq!(manager.reorder_peak(conn_id).await, Some(0));
+
+        // Sequence 0 is missing, so every frame is buffered until overflow.
+        for seq in 1..=20u64 {
+            let frame = Frame {
+                header: FrameHeader {
+                    stream_id: 1,
+                    seq,
+                    ty: FrameType::Data,
+                },
+                payload: vec![0u8; 4],
+            };
+            manager.receive_frame(conn_id, frame, seq).await.unwrap();
+        }
+
+        assert_eq!(manager.reorder_peak(conn_id).await, Some(8));
+        assert_eq!(manager.max_reorder_peak().await, 8);
+        assert!(nyx_telemetry::dump_prometheus().contains(REORDER_PEAK_GAUGE));
+        assert_eq!(manager.reorder_peak(99).await, None);
+
+        // The published peak falls back once its connection is gone
+        manager.register_connection(2).await;
+        manager.unregister_connection(conn_id).await.unwrap();
+        assert_eq!(manager.max_reorder_peak().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_drop_stats_count_overflow_and_stale_frames() {
+        use nyx_stream::frame::{FrameHeader, FrameType};
+
+        let frame = |seq: u64| Frame {
+            header: FrameHeader {
+                stream_id: 1,
+                seq,
+                ty: FrameType::Data,
+            },
+            payload: vec![],
+        };
+        let manager = MultipathManager::new(MultipathConfig::default()).with_reorder_capacity(4);
+        manager.register_connection(1).await;
+        manager.register_connection(2).await;
+
+        // In-order frames followed by replays of them are stale.
+        for seq in 0..3 {
+            manager.receive_frame(1, frame(seq), seq).await.unwrap();
+        }
+        for seq in 0..3 {
+            manager.receive_frame(1, frame(seq), seq).await.unwrap();
+        }
+        // A duplicate of a frame still waiting in the buffer is stale too.
+        manager.receive_frame(1, frame(5), 5).await.unwrap();
+        manager.receive_frame(1, frame(5), 5).await.unwrap();
+        // Seq 3 never arrives: 5..=10 overflow a 4-slot buffer twice.
+        for seq in 6..=10 {
+            manager.receive_frame(1, frame(seq), seq).await.unwrap();
+        }
+
+        let stats = manager.drop_stats().await;
+        assert_eq!(
+            stats[&1],
+            ReorderDropStats {
+                dropped_full: 2,
+                dropped_stale: 4,
+            }
+        );
+        assert_eq!(stats[&2], ReorderDropStats::default());
+    }
+
+    #[tokio::test]
+    async fn test_drain_all_flushes_buffered_frames_in_order() {
+        use nyx_stream::frame::{FrameHeader, FrameType};
+
+        let frame = |seq: u64| Frame {
+            header: FrameHeader {
+                stream_id: 1,
+                seq,
+                ty: FrameType::Data,
+            },
+            payload: vec![],
+        };
+        let manager = MultipathManager::new(MultipathConfig::default());
+        manager.register_connection(1).await;
+        manager.register_connection(2).await;
+
+        // Seq 0 is missing on connection 1, so everything stays buffered
+        for seq in [4, 1, 3] {
+            assert!(manager.receive_frame(1, frame(seq), seq).await.unwrap().is_empty());
+        }
+        manager.receive_frame(2, frame(0), 0).await.unwrap();
+
+        let drained = manager.drain_all().await;
+        assert_eq!(drained.len(), 1);
+        let seqs: Vec<u64> = drained[&1].iter().map(|f| f.header.seq).collect();
+        assert_eq!(seqs, vec![1, 3, 4]);
+        assert_eq!(manager.get_reorder_status(1).await.unwrap().buffered_packets, 0);
+        assert!(manager.drain_all().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_maintenance_emits_deactivation_once() {
+        let config = MultipathConfig {
+            failover_timeout_ms: 20,
+            ..Default::default()
+        };
+        let (tx, mut rx) = mpsc::channel(8);
+        let manager = MultipathManager::new(config).with_event_sender(tx);
+        let conn_id = 1;
+        let path_id = 0;
+        manager.register_connection(conn_id).await;
+        manager
+            .add_path(conn_id, path_id, create_test_path_info(path_id))
+            .await
+            .unwrap();
+
+        manager.periodic_maintenance().await;
+        assert!(rx.try_recv().is_err());
+
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        manager.periodic_maintenance().await;
+        manager.periodic_maintenance().await;
+        assert_eq!(
+            rx.try_recv().unwrap(),
+            MultipathEvent::PathDeactivated { conn_id, path_id }
+        );
+        assert!(rx.try_recv().is_err());
+        assert!(matches!(
+            manager.select_path(conn_id).await,
+            Err(MultipathError::NoActivePath)
+        ));
+
+        let metrics = create_test_path_info(path_id).metrics;
+        manager
+            .update_path_metrics(conn_id, path_id, metrics)
+            .await
+            .unwrap();
+        manager.periodic_maintenance().await;
+        assert_eq!(
+            rx.try_recv().unwrap(),
+            MultipathEvent::PathReactivated { conn_id, path_id }
+        );
+        assert!(rx.try_recv().is_err());
+    }
 }
@@ -1,10 +1,15 @@
-use crate::errors::{DaemonError, Result};
+use crate::errors::{DaemonError, PathBuildError, Result};
+use crate::proto::{PathRequest, PathRequestError, PathResponse, MAX_HOPS, MIN_HOPS};
+use crate::pure_rust_dht::NodeId;
+use crate::pure_rust_p2p::{P2pError, PeerInfo, PureRustP2p};
+use serde::Serialize;
 use std::collections::HashMap;
 use std::net::{IpAddr, SocketAddr};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant, SystemTime};
 use tokio::sync::RwLock;
+use tracing::debug;
 
 /// Path degradation thresholds for automatic rebuilding
 #[derive(Debug, Clone)]
@@ -57,6 +62,24 @@ impl PathQuality {
     }
 }
 
+/// One relay chosen for a capability-filtered path.
+#[derive(Debug, Clone, Serialize)]
+pub struct PathHop {
+    pub node_id: String,
+    pub address: SocketAddr,
+    pub capabilities: Vec<String>,
+    pub quality: f64,
+}
+
+/// Result of [`PathBuilder::build_path_with_capabilities`].
+#[derive(Debug, Clone, Serialize)]
+pub struct CapabilityPath {
+    pub path_id: String,
+    pub hops: Vec<PathHop>,
+    /// Mean quality score of the chosen hops (0.0-1.0).
+    pub quality: f64,
+}
+
 #[cfg(test)]
 pub mod integration_tests;
 
@@ -64,6 +87,8 @@ pub mod integration_tests;
 struct PathInfo {
     id: String,
     endpoint: SocketAddr,
+    /// Mix hops the path was built with; 0 for a direct path
+    hops: u32,
     quality: PathQuality,
     created_at: SystemTime,
     last_ping: Option<Instant>,
@@ -81,6 +106,7 @@ pub struct PathBuilder {
     config: DaemonConfig,
     active_paths: Arc<RwLock<HashMap<String, PathInfo>>>,
     path_counter: Arc<AtomicU64>,
+    candidates: Arc<RwLock<HashMap<NodeId, PeerInfo>>>,
 }
 
 impl PathBuilder {
@@ -89,10 +115,112 @@ impl PathBuilder {
             config,
             active_paths: Arc::new(RwLock::new(HashMap::new())),
             path_counter: Arc::new(AtomicU64::new(0)),
+            candidates: Arc::new(RwLock::new(HashMap::new())),
+        })
+    }
+
+    /// Offer a discovered peer as a relay candidate. The record is normalized
+    /// first; peers with undialable addresses are rejected.
+    pub async fn add_candidate(&self, mut peer: PeerInfo) -> core::result::Result<(), P2pError> {
+        peer.validate_and_normalize()?;
+        self.candidates.write().await.insert(peer.id, peer);
+        Ok(())
+    }
+
+    /// Replace the candidate set with a fresh discovery snapshot, dropping
+    /// peers that are no longer known. Returns how many peers were accepted.
+    pub async fn refresh_candidates(&self, peers: impl IntoIterator<Item = PeerInfo>) -> usize {
+        let fresh: HashMap<NodeId, PeerInfo> = peers
+            .into_iter()
+            .filter_map(|mut peer| peer.validate_and_normalize().ok().map(|_| (peer.id, peer)))
+            .collect();
+        let accepted = fresh.len();
+        *self.candidates.write().await = fresh;
+        accepted
+    }
+
+    /// Keep the candidate set in sync with the P2P layer'_s known peers.
+    pub fn spawn_candidate_feed(
+        &self,
+        p2p: Arc<PureRustP2p>,
+        every: Duration,
+    ) -> tokio::task::JoinHandle<()> {
+        let builder = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(every);
+            loop {
+                ticker.tick().await;
+                let n = builder.refresh_candidates(p2p.known_peers().await).await;
+                debug!("path candidates refreshed: {n} peers");
+            }
+        })
+    }
+
+    /// Build a path of `hops` relays that all advertise every capability in
+    /// `required_caps` (case-insensitive), preferring the highest quality scores.
+    /// The path i_s tracked under its exit hop, where traffic leave_s the mix.
+    pub async fn build_path_with_capabilities(
+        &self,
+        required_caps: &[String],
+        hops: u8,
+    ) -> Result<CapabilityPath> {
+        if !(MIN_HOPS..=MAX_HOPS).contains(&u32::from(hops)) {
+            return Err(PathRequestError::HopCountOutOfRange(u32::from(hops)).into());
+        }
+        let needed = usize::from(hops);
+
+        let mut eligible: Vec<PeerInfo> = self
+            .candidates
+            .read()
+            .await
+            .values()
+            .filter(|peer| {
+                required_caps.iter().all(|req| {
+                    peer.capabilities
+                        .iter()
+                        .any(|cap| cap.eq_ignore_ascii_case(req.trim()))
+                })
+            })
+            .cloned()
+            .collect();
+        if eligible.len() < needed {
+            return Err(PathBuildError::InsufficientPeers {
+                needed,
+                found: eligible.len(),
+            }
+            .into());
+        }
+        eligible.sort_by(|a, b| b.quality_score.total_cmp(&a.quality_score));
+        eligible.truncate(needed);
+
+        let quality = eligible.iter().map(|p| p.quality_score).sum::<f64>() / needed as f64;
+        let exit = eligible[needed - 1].address;
+        let path_id = self.insert_path(exit, u32::from(hops)).await?;
+        if let Some(info) = self.active_paths.write().await.get_mut(&path_id) {
+            info.quality.reliability = quality;
+        }
+        let hops = eligible
+            .into_iter()
+            .map(|peer| PathHop {
+                node_id: peer.id.to_string(),
+                address: peer.address,
+                capabilities: peer.capabilities,
+                quality: peer.quality_score,
+            })
+            .collect();
+        Ok(CapabilityPath {
+            path_id,
+            hops,
+            quality,
         })
     }
 
+    /// Build a direct path (no mix hops) to `endpoint`.
     pub async fn build_path(&self, endpoint: SocketAddr) -> Result<String> {
+        self.insert_path(endpoint, 0).await
+    }
+
+    async fn insert_path(&self, endpoint: SocketAddr, hops: u32) -> Result<String> {
         // Basic endpoint validation and a simple heuristic for unreachable test-nets
         if endpoint.port() == 0 || is_unreachable_endpoint(endpoint.ip()) {
             return Err(DaemonError::transport("Network unreachable"));
@@ -112,6 +240,7 @@ impl PathBuilder {
         let info = PathInfo {
             id: path_id.clone(),
             endpoint,
+            hops,
             quality: PathQuality {
                 latency: 0.6,
                 bandwidth: 0.6,
@@ -132,6 +261,19 @@ impl PathBuilder {
         Ok(path_id)
     }
 
+    /// Validate a `PathRequest` and build the path it describes.
+    /// Malformed requests fail fast, before any discovery work.
+    pub async fn build_path_for_request(&self, request: &PathRequest) -> Result<PathResponse> {
+        let target = request.validate()?;
+        let path_id = self.insert_path(target, request.hops).await?;
+        let hops = self.get_path_hops(&path_id).await?;
+        Ok(PathResponse {
+            path_id,
+            target,
+            hops,
+        })
+    }
+
     pub async fn destroy_path(&self, path_id: &str) -> Result<()> {
         let mut write = self.active_paths.write().await;
         write.remove(path_id);
@@ -146,6 +288,15 @@ impl PathBuilder {
         Ok(info.quality.clone())
     }
 
+    /// Number of mix hops recorded for `path_id` when it was built.
+    pub async fn get_path_hops(&self, path_id: &str) -> Result<u32> {
+        let read = self.active_paths.read().await;
+        let info = read
+            .get(path_id)
+            .ok_or_else(|| DaemonError::internal("Path not found"))?;
+        Ok(info.hops)
+    }
+
     pub async fn get_available_paths(&self) -> Result<Vec<(String, SocketAddr)>> {
         let read = self.active_paths.read().await;
         Ok(read.values().map(|p| (p.id.clone(), p.endpoint)).collect())
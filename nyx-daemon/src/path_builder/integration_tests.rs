@@ -1077,4 +1077,122 @@ mod tests {
         assert_eq!(thresholds.max_build_time, Duration::from_secs(1));
         assert_eq!(thresholds.min_success_rate, 0.9);
     }
+
+    #[tokio::test]
+    async fn test_path_request_hop_count_rejected_before_build() -> Result<()> {
+        use crate::proto::{PathRequest, PathRequestError, MAX_HOPS};
+
+        let builder = PathBuilder::new(DaemonConfig::default())?;
+        let request = PathRequest {
+            target: "127.0.0.1:8080".into(),
+            hops: MAX_HOPS + 1,
+        };
+        let err = builder.build_path_for_request(&request).await.unwrap_err();
+        assert!(matches!(
+            err,
+            DaemonError::InvalidPathRequest(PathRequestError::HopCountOutOfRange(_))
+        ));
+        assert!(builder.get_available_paths().await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_valid_path_request_builds_path() -> Result<()> {
+        use crate::proto::PathRequest;
+
+        let builder = PathBuilder::new(DaemonConfig::default())?;
+        let request = PathRequest {
+            target: "127.0.0.1:8080".into(),
+            hops: 5,
+        };
+        let response = builder.build_path_for_request(&request).await?;
+        assert_eq!(response.hops, 5);
+        assert_eq!(builder.get_path_hops(&response.path_id).await?, 5);
+        assert_eq!(response.target, "127.0.0.1:8080".parse().unwrap());
+        assert!(builder.path_exists(&response.path_id).await?);
+
+        let direct = builder
+            .build_path("127.0.0.1:8081".parse().unwrap())
+            .await?;
+        assert_eq!(builder.get_path_hops(&direct).await?, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_capability_filtered_path_only_uses_capable_peers() -> Result<()> {
+        use crate::errors::PathBuildError;
+        use crate::pure_rust_dht::NodeId;
+        use crate::pure_rust_p2p::PeerInfo;
+
+        let builder = PathBuilder::new(DaemonConfig::default())?;
+        for i in 1..=8u8 {
+            let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(10, 0, 0, i)), 4433);
+            let mut peer = PeerInfo::new(NodeId::random(), addr);
+            peer.capabilities.push("mix".into());
+            if i % 2 == 0 {
+                peer.capabilities.push("pq".into());
+            }
+            peer.quality_score = f64::from(i) / 10.0;
+            builder.add_candidate(peer).await.unwrap();
+        }
+
+        let required = vec!["PQ".to_string()];
+        let path = builder.build_path_with_capabilities(&required, 3).await?;
+        assert_eq!(path.hops.len(), 3);
+        for hop in &path.hops {
+            assert!(hop.capabilities.iter().any(|c| c == "pq"), "{hop:?}");
+        }
+        // Highest-quality capable peers win: 10.0.0.8, .6, .4
+        assert_eq!(
+            path.hops[0].address.ip(),
+            IpAddr::V4(Ipv4Addr::new(10, 0, 0, 8))
+        );
+        assert!((path.quality - 0.6).abs() < 1e-9);
+        assert!(builder.path_exists(&path.path_id).await?);
+        assert_eq!(builder.get_path_hops(&path.path_id).await?, 3);
+        // The path is tracked under its exit hop, not the best-scored relay
+        let exit = path.hops[2].address;
+        assert_eq!(exit.ip(), IpAddr::V4(Ipv4Addr::new(10, 0, 0, 4)));
+        assert!(builder
+            .get_available_paths()
+            .await?
+            .contains(&(path.path_id.clone(), exit)));
+
+        let err = builder
+            .build_path_with_capabilities(&required, 5)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            DaemonError::PathBuild(PathBuildError::InsufficientPeers {
+                needed: 5,
+                found: 4
+            })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_refresh_candidates_replaces_discovered_set() -> Result<()> {
+        use crate::pure_rust_dht::NodeId;
+        use crate::pure_rust_p2p::PeerInfo;
+
+        let builder = PathBuilder::new(DaemonConfig::default())?;
+        let peer = |i: u8| {
+            let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(10, 2, 0, i)), 4433);
+            PeerInfo::new(NodeId::random(), addr)
+        };
+        assert_eq!(builder.refresh_candidates((1..=4).map(peer)).await, 4);
+        assert!(builder.build_path_with_capabilities(&[], 4).await.is_ok());
+
+        // Stale peers are dropped and undialable ones skipped
+        let undialable = PeerInfo::new(NodeId::random(), "0.0.0.0:0".parse().unwrap());
+        let accepted = builder
+            .refresh_candidates([peer(5), peer(6), peer(7), undialable])
+            .await;
+        assert_eq!(accepted, 3);
+        assert!(builder.build_path_with_capabilities(&[], 4).await.is_err());
+        assert!(builder.build_path_with_capabilities(&[], 3).await.is_ok());
+        Ok(())
+    }
 }
@@ -265,7 +265,7 @@ impl ProofDistributor {
         signature: &[u8],
     ) -> bool {
         let expected_sig = self.sign_proof(batch_id, acc_value, timestamp).await;
-        signature == expected_sig.as_slice()
+        nyx_crypto::ct_eq(signature, &expected_sig)
     }
 
     /// Distribute proof to DHT
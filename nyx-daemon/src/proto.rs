@@ -4,6 +4,7 @@
 //! for the Nyx daemon, including message type conversions and re-exports.
 
 use std::collections::HashMap;
+use std::net::SocketAddr;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use anyhow::{Result, Context};
 use serde::{Deserialize, Serialize};
@@ -175,6 +176,76 @@ pub enum NotificationPriority {
     High,
 }
 
+/// Earliest Timestamp the protobuf spec allows (0001-01-01T00:00:00Z)
+pub const MIN_TIMESTAMP_SECONDS: i64 = -62_135_596_800;
+/// Latest Timestamp the protobuf spec allows (9999-12-31T23:59:59Z)
+pub const MAX_TIMESTAMP_SECONDS: i64 = 253_402_300_799;
+
+/// Check a Timestamp is well-formed: seconds within the spec range and
+/// nanos a non-negative fraction of a second
+pub fn validate_timestamp(timestamp: &Timestamp) -> Result<()> {
+    if !(0..1_000_000_000).contains(&timestamp.nanos) {
+        return Err(anyhow::anyhow!(
+            "Invalid timestamp: nanos {} outside 0..1000000000",
+            timestamp.nanos
+        ));
+    }
+    if !(MIN_TIMESTAMP_SECONDS..=MAX_TIMESTAMP_SECONDS).contains(&timestamp.seconds) {
+        return Err(anyhow::anyhow!(
+            "Invalid timestamp: seconds {} outside supported range",
+            timestamp.seconds
+        ));
+    }
+    Ok(())
+}
+
+/// Fewest mix hops a path request may ask for
+pub const MIN_HOPS: u32 = 3;
+/// Most mix hops a path request may ask for
+pub const MAX_HOPS: u32 = 7;
+
+/// Request to build a path to a target endpoint
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PathRequest {
+    /// Target endpoint as `ip:port`
+    pub target: String,
+    /// Requested number of mix hops
+    pub hops: u32,
+}
+
+/// Result of a successful path build
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PathResponse {
+    /// Identifier of the built path
+    pub path_id: String,
+    /// Resolved target endpoint
+    pub target: SocketAddr,
+    /// Number of mix hops in the path
+    pub hops: u32,
+}
+
+/// Reasons a `PathRequest` is rejected before any discovery work
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PathRequestError {
+    #[error("hop count {0} outside {MIN_HOPS}..={MAX_HOPS}")]
+    HopCountOutOfRange(u32),
+    #[error("malformed target {0:?}: expected ip:port with a non-zero port")]
+    MalformedTarget(String),
+}
+
+impl PathRequest {
+    /// Check hop bounds and target syntax, returning the parsed target
+    pub fn validate(&self) -> std::result::Result<SocketAddr, PathRequestError> {
+        if !(MIN_HOPS..=MAX_HOPS).contains(&self.hops) {
+            return Err(PathRequestError::HopCountOutOfRange(self.hops));
+        }
+        match self.target.trim().parse::<SocketAddr>() {
+            Ok(addr) if addr.port() != 0 => Ok(addr),
+            _ => Err(PathRequestError::MalformedTarget(self.target.clone())),
+        }
+    }
+}
+
 /// Proto message manager for centralized message handling
 pub struct ProtoManager {
     /// Message type registry
@@ -297,25 +368,46 @@ impl ProtoManager {
         bincode::deserialize(data).context("Failed to deserialize message")
     }
 
-    /// Convert SystemTime to protobuf Timestamp
+    /// Convert SystemTime to protobuf Timestamp.
+    /// Fails for times outside the range a Timestamp can represent.
     pub fn system_time_to_proto(&self, time: SystemTime) -> Result<Timestamp> {
-        let duration = time.duration_since(UNIX_EPOCH)
-            .context("Time is before UNIX epoch")?;
-        
-        Ok(Timestamp {
-            seconds: duration.as_secs() as i64,
-            nanos: duration.subsec_nanos() as i32,
-        })
+        let timestamp = match time.duration_since(UNIX_EPOCH) {
+            Ok(after) => Timestamp {
+                seconds: i64::try_from(after.as_secs()).unwrap_or(i64::MAX),
+                nanos: after.subsec_nanos() as i32,
+            },
+            Err(e) => {
+                // Timestamp nanos always count forward, so borrow a second
+                let before = e.duration();
+                let secs = i64::try_from(before.as_secs()).unwrap_or(i64::MAX);
+                match before.subsec_nanos() {
+                    0 => Timestamp {
+                        seconds: -secs,
+                        nanos: 0,
+                    },
+                    n => Timestamp {
+                        seconds: -secs - 1,
+                        nanos: (1_000_000_000 - n) as i32,
+                    },
+                }
+            }
+        };
+        validate_timestamp(&timestamp)?;
+        Ok(timestamp)
     }
 
-    /// Convert protobuf Timestamp to SystemTime
+    /// Convert protobuf Timestamp to SystemTime, rejecting values outside
+    /// the spec range or with `nanos` outside `0..1_000_000_000`
     pub fn proto_to_system_time(&self, timestamp: &Timestamp) -> Result<SystemTime> {
-        let duration = Duration::new(
-            timestamp.seconds as u64,
-            timestamp.nanos as u32,
-        );
-        
-        Ok(UNIX_EPOCH + duration)
+        validate_timestamp(timestamp)?;
+        let nanos = Duration::from_nanos(timestamp.nanos as u64);
+        let time = if timestamp.seconds >= 0 {
+            UNIX_EPOCH.checked_add(Duration::from_secs(timestamp.seconds as u64))
+        } else {
+            UNIX_EPOCH.checked_sub(Duration::from_secs(timestamp.seconds.unsigned_abs()))
+        };
+        time.and_then(|t| t.checked_add(nanos))
+            .ok_or_else(|| anyhow::anyhow!("Timestamp not representable on this platform"))
     }
 
     /// Convert Duration to protobuf Duration
@@ -461,6 +553,32 @@ pub mod utils {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_path_request_validation() {
+        let req = |target: &str, hops| PathRequest {
+            target: target.to_string(),
+            hops,
+        };
+        assert_eq!(
+            req("127.0.0.1:4433", MIN_HOPS).validate(),
+            Ok("127.0.0.1:4433".parse().unwrap())
+        );
+        assert!(req("[::1]:4433", MAX_HOPS).validate().is_ok());
+
+        for hops in [0, MIN_HOPS - 1, MAX_HOPS + 1] {
+            assert_eq!(
+                req("127.0.0.1:4433", hops).validate(),
+                Err(PathRequestError::HopCountOutOfRange(hops))
+            );
+        }
+        for target in ["", "example", "127.0.0.1", "127.0.0.1:0", "host:4433"] {
+            assert_eq!(
+                req(target, 5).validate(),
+                Err(PathRequestError::MalformedTarget(target.to_string()))
+            );
+        }
+    }
+
     #[test]
     fn test_proto_manager_creation() {
         let manager = ProtoManager::new();
@@ -552,6 +670,34 @@ mod tests {
         assert!(diff < Duration::from_secs(1));
     }
 
+    #[test]
+    fn test_timestamp_round_trip_exact() {
+        let manager = ProtoManager::new();
+        for (seconds, nanos) in [(1_700_000_000, 123_456_789), (0, 0), (-1, 999_999_999)] {
+            let ts = Timestamp { seconds, nanos };
+            let time = manager.proto_to_system_time(&ts).unwrap();
+            assert_eq!(manager.system_time_to_proto(time).unwrap(), ts);
+        }
+        let before_epoch = UNIX_EPOCH - Duration::from_millis(1500);
+        let ts = manager.system_time_to_proto(before_epoch).unwrap();
+        assert_eq!((ts.seconds, ts.nanos), (-2, 500_000_000));
+        assert_eq!(manager.proto_to_system_time(&ts).unwrap(), before_epoch);
+    }
+
+    #[test]
+    fn test_malformed_timestamp_rejected() {
+        let manager = ProtoManager::new();
+        for (seconds, nanos) in [
+            (1_700_000_000, 1_000_000_000),
+            (1_700_000_000, -1),
+            (MAX_TIMESTAMP_SECONDS + 1, 0),
+            (i64::MIN, 0),
+        ] {
+            let ts = Timestamp { seconds, nanos };
+            assert!(manager.proto_to_system_time(&ts).is_err(), "{ts:?}");
+        }
+    }
+
     #[test]
     fn test_duration_conversion() {
         let manager = ProtoManager::new();
@@ -37,6 +37,10 @@ pub const K_BUCKET_SIZE: usize = 20;
 /// Number of bits in node ID
 pub const NODE_ID_BITS: usize = NODE_ID_SIZE * 8;
 
+/// BLAKE3 key-derivation context for deterministic node IDs. Changing it
+/// changes every derived ID, so bump the version suffix if it ever must.
+pub const NODE_ID_DERIVATION_CONTEXT: &str = "nyx-daemon dht node-id v1";
+
 /// Default RPC timeout
 pub const DEFAULT_RPC_TIMEOUT: Duration = Duration::from_secs(5);
 
@@ -108,6 +112,24 @@ impl NodeId {
     }
 }
 
+/// Derive a node ID from stable peer material (public key or address bytes).
+///
+/// Uses BLAKE3 in key-derivation mode under [`NODE_ID_DERIVATION_CONTEXT`],
+/// truncated to [`NODE_ID_SIZE`], so the same input always maps to the same ID
+/// and IDs cannot collide with other BLAKE3 uses in the daemon.
+pub fn derive_node_id(input: &[u8]) -> NodeId {
+    let mut hasher = blake3::Hasher::new_derive_key(NODE_ID_DERIVATION_CONTEXT);
+    hasher.update(input);
+    let mut id = [0u8; NODE_ID_SIZE];
+    hasher.finalize_xof().fill(&mut id);
+    NodeId(id)
+}
+
+/// Check a peer's advertised node ID against the material it claims to derive from.
+pub fn verify_node_id(input: &[u8], claimed: &NodeId) -> bool {
+    derive_node_id(input) == *claimed
+}
+
 impl std::fmt::Display for NodeId {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for byte in &self.0 {
@@ -977,6 +999,23 @@ mod tests {
         assert_eq!(distance.as_bytes(), &[0xFF; NODE_ID_SIZE]);
     }
 
+    #[test]
+    fn test_derive_node_id_is_deterministic() {
+        let a = derive_node_id(b"127.0.0.1:4433");
+        assert_eq!(a, derive_node_id(b"127.0.0.1:4433"));
+        assert_ne!(a, derive_node_id(b"127.0.0.1:4434"));
+        assert_ne!(a, derive_node_id(b""));
+    }
+
+    #[test]
+    fn test_verify_node_id_rejects_mismatched_claim() {
+        let key = [7u8; 32];
+        let id = derive_node_id(&key);
+        assert!(verify_node_id(&key, &id));
+        assert!(!verify_node_id(&[8u8; 32], &id));
+        assert!(!verify_node_id(&key, &NodeId::random()));
+    }
+
     #[test]
     fn test_node_id_bucket_index() {
         let id1 = NodeId::from_bytes([0x00; NODE_ID_SIZE]);
@@ -128,6 +128,44 @@ impl PeerInfo {
             quality_score: 0.5,
         }
     }
+
+    /// Sanitize a peer record before it enters the known-peer cache.
+    ///
+    /// Records arrive from the DHT and from remote `PeerResponse` messages, so
+    /// fields are not trusted: the quality score is clamped to [0, 1] (NaN
+    /// falls back to the neutral 0.5), a `last_seen` in the future is pulled
+    /// back to now, and duplicate or blank capabilities are dropped. An
+    /// address nobody can dial (unspecified, port 0, multicast, broadcast)
+    /// cannot be repaired and is rejected.
+    pub fn validate_and_normalize(&mut self) -> Result<(), P2pError> {
+        let ip = self.address.ip();
+        let undialable = ip.is_unspecified()
+            || ip.is_multicast()
+            || self.address.port() == 0
+            || matches!(ip, std::net::IpAddr::V4(v4) if v4.is_broadcast());
+        if undialable {
+            return Err(P2pError::InvalidPeerAddress(self.address));
+        }
+
+        self.quality_score = if self.quality_score.is_nan() {
+            0.5
+        } else {
+            self.quality_score.clamp(0.0, 1.0)
+        };
+
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        self.last_seen = self.last_seen.min(now);
+
+        let mut seen = HashSet::new();
+        self.capabilities.retain(|c| {
+            let c = c.trim();
+            !c.is_empty() && seen.insert(c.to_string())
+        });
+        Ok(())
+    }
     
     /// Update peer quality based on connection metrics
     pub fn update_quality(&mut self, rtt: Duration, success_rate: f64) {
@@ -443,6 +481,11 @@ impl PureRustP2p {
         handlers.insert(channel, handler_tx);
     }
     
+    /// Snapshot of peers learned through discovery
+    pub async fn known_peers(&self) -> Vec<PeerInfo> {
+        self.known_peers.read().await.values().cloned().collect()
+    }
+    
     /// Get connected peers
     pub async fn get_connected_peers(&self) -> Vec<NodeId> {
         let connections = self.connections.read().await;
@@ -746,7 +789,11 @@ impl PureRustP2p {
                 
                 for node in nodes {
                     if !connections.contains_key(&node.id) {
-                        let peer_info = PeerInfo::new(node.id, node.addr);
+                        let mut peer_info = PeerInfo::new(node.id, node.addr);
+                        if let Err(e) = peer_info.validate_and_normalize() {
+                            warn!("Discarding discovered peer {:?}: {}", node.id, e);
+                            continue;
+                        }
                         known.insert(node.id, peer_info);
                     }
                 }
@@ -823,6 +870,9 @@ pub enum P2pError {
     
     #[error("Handshake failed")]
     HandshakeFailed,
+    
+    #[error("Invalid peer address: {0}")]
+    InvalidPeerAddress(SocketAddr),
 }
 
 #[cfg(test)]
@@ -898,6 +948,45 @@ mod tests {
         assert!(peer_info.quality_score < 0.5);
     }
 
+    #[test]
+    fn test_peer_info_normalized_before_caching() {
+        let mut peer_info = PeerInfo::new(NodeId::random(), "10.0.0.7:4433".parse().unwrap());
+        peer_info.quality_score = 3.5;
+        peer_info.last_seen = u64::MAX;
+        peer_info.capabilities = vec!["mix".into(), " ".into(), "mix".into(), "relay".into()];
+
+        peer_info.validate_and_normalize().unwrap();
+        assert_eq!(peer_info.quality_score, 1.0);
+        assert!(peer_info.last_seen < u64::MAX);
+        assert_eq!(
+            peer_info.capabilities,
+            vec!["mix".to_string(), "relay".to_string()]
+        );
+
+        peer_info.quality_score = f64::NAN;
+        peer_info.validate_and_normalize().unwrap();
+        assert_eq!(peer_info.quality_score, 0.5);
+    }
+
+    #[test]
+    fn test_peer_info_undialable_address_rejected() {
+        for addr in [
+            "0.0.0.0:4433",
+            "10.0.0.7:0",
+            "224.0.0.1:4433",
+            "255.255.255.255:4433",
+        ] {
+            let mut peer_info = PeerInfo::new(NodeId::random(), addr.parse().unwrap());
+            assert!(
+                matches!(
+                    peer_info.validate_and_normalize(),
+                    Err(P2pError::InvalidPeerAddress(_))
+                ),
+                "{addr} should be rejected"
+            );
+        }
+    }
+
     #[tokio::test]
     async fn test_connection_stats() {
         let (tx, _rx) = mpsc::unbounded_channel();
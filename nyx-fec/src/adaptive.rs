@@ -0,0 +1,127 @@
+#![forbid(unsafe_code)]
+
+//! Loss-driven parity sizing for [`rs1280`](crate::rs1280) blocks.
+//!
+//! Shards are assumed to be lost independently with the smoothed observed
+//! loss rate `p`. For `k` data shards the controller picks the smallest parity
+//! count `m` such that a block of `k + m` shards loses at most `m` of them with
+//! probability at least `target_recovery`.
+
+use crate::timing::Ema;
+
+/// GF(2^8) Reed-Solomon limit on data + parity shards.
+const MAX_TOTAL_SHARDS: usize = 256;
+/// Smoothing factor applied to loss observations.
+const LOSS_EMA_ALPHA: f32 = 0.3;
+
+#[derive(Debug, Clone, Copy)]
+pub struct AdaptiveRedundancy {
+    target_recovery: f64,
+    loss: Ema,
+    max_parity_ratio: f64,
+}
+
+impl AdaptiveRedundancy {
+    /// `target_recovery` is the desired probability that a block is
+    /// recoverable, clamped to `[0.0, 0.999999]`.
+    pub fn new(target_recovery: f64) -> Self {
+        Self {
+            target_recovery: target_recovery.clamp(0.0, 0.999_999),
+            loss: Ema::new(LOSS_EMA_ALPHA),
+            max_parity_ratio: 1.0,
+        }
+    }
+
+    /// Cap parity at `ratio` × data shards (default 1.0, i.e. 100% overhead).
+    pub fn with_max_parity_ratio(mut self, ratio: f64) -> Self {
+        self.max_parity_ratio = ratio.max(0.0);
+        self
+    }
+
+    /// Feed a measured loss rate in `[0.0, 1.0]`; non-finite samples are ignored.
+    pub fn observe_loss(&mut self, rate: f64) {
+        if rate.is_finite() {
+            self.loss.observe(rate.clamp(0.0, 1.0) as f32);
+        }
+    }
+
+    /// Smoothed loss estimate (0.0 before any observation).
+    pub fn loss_estimate(&self) -> f64 {
+        self.loss.get().map_or(0.0, f64::from)
+    }
+
+    /// Maximum parity this controller recommends for `data_shards`.
+    pub fn max_parity(&self, data_shards: usize) -> usize {
+        let by_ratio = (data_shards as f64 * self.max_parity_ratio).ceil() as usize;
+        by_ratio.min(MAX_TOTAL_SHARDS.saturating_sub(data_shards))
+    }
+
+    /// Parity shards needed to meet the recovery target at the current loss
+    /// estimate, clamped to [`max_parity`](Self::max_parity).
+    pub fn recommended_parity(&self, data_shards: usize) -> usize {
+        let max = self.max_parity(data_shards);
+        let p = self.loss_estimate();
+        if data_shards == 0 || p <= 0.0 {
+            return 0;
+        }
+        if p >= 1.0 {
+            return max;
+        }
+        (0..=max)
+            .find(|&m| recovery_probability(data_shards + m, m, p) >= self.target_recovery)
+            .unwrap_or(max)
+    }
+}
+
+/// P(at most `tolerated` of `n` independent shards are lost), loss rate `p`.
+fn recovery_probability(n: usize, tolerated: usize, p: f64) -> f64 {
+    let ratio = p / (1.0 - p);
+    let mut term = (1.0 - p).powi(n as i32);
+    let mut sum = term;
+    for i in 0..tolerated.min(n) {
+        term *= (n - i) as f64 / (i + 1) as f64 * ratio;
+        sum += term;
+    }
+    sum.min(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parity_grows_monotonically_with_loss() {
+        let mut prev = 0;
+        for step in 0..=50 {
+            let mut ar = AdaptiveRedundancy::new(0.999);
+            ar.observe_loss(step as f64 / 100.0);
+            let m = ar.recommended_parity(10);
+            assert!(m >= prev, "loss {step}%: parity {m} < {prev}");
+            prev = m;
+        }
+        assert!(prev > 0);
+    }
+
+    #[test]
+    fn parity_is_clamped_to_maximum() {
+        let mut ar = AdaptiveRedundancy::new(0.999_999).with_max_parity_ratio(0.5);
+        ar.observe_loss(0.9);
+        assert_eq!(ar.recommended_parity(10), 5);
+        ar.observe_loss(f64::NAN);
+        assert_eq!(ar.recommended_parity(10), 5);
+        // The GF(2^8) shard limit applies on top of the ratio.
+        let mut ar = AdaptiveRedundancy::new(0.99);
+        ar.observe_loss(1.0);
+        assert_eq!(ar.recommended_parity(200), 56);
+    }
+
+    #[test]
+    fn lossless_link_needs_no_parity_and_target_is_met() {
+        let mut ar = AdaptiveRedundancy::new(0.99);
+        assert_eq!(ar.recommended_parity(10), 0);
+        ar.observe_loss(0.05);
+        let m = ar.recommended_parity(10);
+        assert!(recovery_probability(10 + m, m, 0.05) >= 0.99);
+        assert!(m == 0 || recovery_probability(10 + m - 1, m - 1, 0.05) < 0.99);
+    }
+}
@@ -3,17 +3,21 @@
 //! - Reed-Solomon (GF(2^8)) erasure coding wrapper_s specialized for 1280B shard_s.
 //! - Lightweight timing helper_s.
 //! - Optional adaptive redundancy helper behind the `raptorq` feature.
+//! - Loss-driven parity sizing for Reed-Solomon block_s ([`AdaptiveRedundancy`]).
 //!
 //! Thi_s crate avoid_s unsafe code and external C/C++ backend_s by default.
 
 #![forbid(unsafe_code)]
 
+pub mod adaptive;
 pub mod padding;
 #[cfg(feature = "raptorq")]
 pub mod raptorq;
 pub mod rs1280;
 pub mod timing;
 
+pub use adaptive::AdaptiveRedundancy;
+
 /// Error type for FEC operation_s in thi_s crate.
 #[derive(Debug)]
 pub enum Error {
@@ -70,6 +70,55 @@ pub fn try_unpack_from_shard(shard: &[u8; SHARD_SIZE]) -> Option<&[u8]> {
     }
 }
 
+/// Length prefix of a stream packed by [`pack`].
+const STREAM_LEN_PREFIX: usize = 8;
+
+/// Split an arbitrary byte stream into zero-padded fixed-size shards.
+/// Layout (concatenated over all shards): [len: u64 LE][data...][zero padding]
+///
+/// Always yields at least one shard, so an empty stream round-trips too.
+pub fn pack(data: &[u8]) -> Vec<[u8; SHARD_SIZE]> {
+    let framed_len = STREAM_LEN_PREFIX + data.len();
+    let mut shards = vec![[0u8; SHARD_SIZE]; framed_len.div_ceil(SHARD_SIZE)];
+    let prefix = (data.len() as u64).to_le_bytes();
+    let framed = prefix.iter().chain(data);
+    for (dst, src) in shards.iter_mut().flatten().zip(framed) {
+        *dst = *src;
+    }
+    shards
+}
+
+/// Reassemble a stream produced by [`pack`], stripping the trailing padding.
+///
+/// # Errors
+/// Returns `Error::Protocol` if the embedded length does not fit the shards
+/// or the shard count is not exactly what that length requires.
+pub fn unpack(shards: &[[u8; SHARD_SIZE]]) -> crate::Result<Vec<u8>> {
+    let first = shards
+        .first()
+        .ok_or_else(|| crate::Error::Protocol("no shards to unpack".into()))?;
+    let mut prefix = [0u8; STREAM_LEN_PREFIX];
+    prefix.copy_from_slice(&first[..STREAM_LEN_PREFIX]);
+    let len = u64::from_le_bytes(prefix);
+    let expected = usize::try_from(len)
+        .ok()
+        .and_then(|len| len.checked_add(STREAM_LEN_PREFIX))
+        .map(|framed| framed.div_ceil(SHARD_SIZE));
+    if expected != Some(shards.len()) {
+        return Err(crate::Error::Protocol(format!(
+            "stream length {len} does not match {} shards",
+            shards.len()
+        )));
+    }
+    Ok(shards
+        .iter()
+        .flatten()
+        .skip(STREAM_LEN_PREFIX)
+        .take(len as usize)
+        .copied()
+        .collect())
+}
+
 /// Validate that a shard uses a sane length prefix and returns the payload length.
 pub fn validate_shard_header(shard: &[u8; SHARD_SIZE]) -> Option<usize> {
     let len = u16::from_le_bytes([shard[0], shard[1]]) as usize;
@@ -141,6 +190,37 @@ mod tests {
         assert!(try_unpack_from_shard(&shard).is_none());
     }
 
+    #[test]
+    fn stream_pack_unpack_roundtrip() -> Result<(), Box<dyn std::error::Error>> {
+        let sizes = [
+            0,
+            1,
+            SHARD_SIZE - STREAM_LEN_PREFIX,
+            SHARD_SIZE - STREAM_LEN_PREFIX + 1,
+            SHARD_SIZE,
+            3 * SHARD_SIZE + 17,
+        ];
+        for size in sizes {
+            let data: Vec<u8> = (0..size).map(|i| (i * 7 + 1) as u8).collect();
+            let shards = pack(&data);
+            let framed = STREAM_LEN_PREFIX + size;
+            assert_eq!(shards.len(), framed.div_ceil(SHARD_SIZE), "size {size}");
+            assert_eq!(unpack(&shards)?, data, "size {size}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn stream_unpack_rejects_mismatched_length() {
+        assert!(unpack(&[]).is_err());
+        let mut shards = pack(&[5u8; 100]);
+        shards.push([0u8; SHARD_SIZE]);
+        assert!(unpack(&shards).is_err());
+        let mut shards = pack(&[5u8; 100]);
+        shards[0][..STREAM_LEN_PREFIX].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(unpack(&shards).is_err());
+    }
+
     #[test]
     fn try_unpack_matches_unpack_for_valid_input() -> Result<(), Box<dyn std::error::Error>> {
         let data = b"abc";
@@ -89,10 +89,93 @@ impl Rs1280 {
     }
 }
 
+/// Compute `parity` parity shards for `data`.
+///
+/// Returns only the parity shards; transmit them after the data shards so
+/// the slice handed to [`decode`] is `data ++ parity`.
+pub fn encode(data: &[[u8; SHARD_SIZE]], parity: usize) -> Result<Vec<[u8; SHARD_SIZE]>> {
+    let rs = Rs1280::new(RsConfig {
+        data_shards: data.len(),
+        parity_shards: parity,
+    })?;
+    let mut out = vec![[0u8; SHARD_SIZE]; parity];
+    let data_refs: Vec<&[u8; SHARD_SIZE]> = data.iter().collect();
+    let mut parity_refs: Vec<&mut [u8; SHARD_SIZE]> = out.iter_mut().collect();
+    rs.encode_parity(&data_refs, &mut parity_refs)?;
+    Ok(out)
+}
+
+/// Recover erased (`None`) shards in place from `data ++ parity` shards.
+///
+/// The last `parity` entries are parity shards. Fails when more than
+/// `parity` shards are missing.
+pub fn decode(shards: &mut [Option<[u8; SHARD_SIZE]>], parity: usize) -> Result<()> {
+    let data_shards = shards
+        .len()
+        .checked_sub(parity)
+        .ok_or_else(|| Error::Protocol("fewer shards than parity count".into()))?;
+    Rs1280::new(RsConfig {
+        data_shards,
+        parity_shards: parity,
+    })?
+    .reconstruct(shards)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn sample_data(n: usize) -> Vec<[u8; SHARD_SIZE]> {
+        (0..n)
+            .map(|i| {
+                let mut a = [0u8; SHARD_SIZE];
+                for (j, b) in a.iter_mut().enumerate() {
+                    *b = (i * 31 + j * 7) as u8;
+                }
+                a
+            })
+            .collect()
+    }
+
+    #[test]
+    fn encode_decode_recovers_any_four_of_fourteen(
+    ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let data = sample_data(10);
+        let parity = encode(&data, 4)?;
+        assert_eq!(parity.len(), 4);
+        let full: Vec<[u8; SHARD_SIZE]> = data.iter().chain(&parity).copied().collect();
+
+        for a in 0..14 {
+            for b in a + 1..14 {
+                for c in b + 1..14 {
+                    for d in c + 1..14 {
+                        let mut shards: Vec<Option<[u8; SHARD_SIZE]>> =
+                            full.iter().copied().map(Some).collect();
+                        for i in [a, b, c, d] {
+                            shards[i] = None;
+                        }
+                        decode(&mut shards, 4)?;
+                        assert!(shards.iter().zip(&full).all(|(s, f)| s.as_ref() == Some(f)));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn decode_fails_with_five_erasures() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let data = sample_data(10);
+        let parity = encode(&data, 4)?;
+        let mut shards: Vec<Option<[u8; SHARD_SIZE]>> =
+            data.iter().chain(&parity).copied().map(Some).collect();
+        for i in [0, 3, 6, 9, 12] {
+            shards[i] = None;
+        }
+        assert!(decode(&mut shards, 4).is_err());
+        Ok(())
+    }
+
     #[test]
     fn rs_roundtrip_one_loss() -> std::result::Result<(), Box<dyn std::error::Error>> {
         let cfg = RsConfig {
@@ -79,13 +79,19 @@ pub struct Accumulator {
     pub stats: AccumulatorStats,
     /// Random generator base for RSA operations
     pub generator: BigInt,
+    /// Leaf hashes of the hash-based membership set, in insertion order
+    members: Vec<[u8; 32]>,
+    /// Leaf hash -> position in `members`
+    member_index: HashMap<[u8; 32], usize>,
 }
 
 /// Statistics for accumulator operations with cryptographic metrics
 #[derive(Debug, Clone, Default)]
 pub struct AccumulatorStats {
-    /// Number of elements added
+    /// Number of elements added to the RSA accumulator
     pub elements_added: usize,
+    /// Number of distinct elements added to the hash-based membership set
+    pub members_added: usize,
     /// Number of witnesses generated
     pub witnesses_generated: usize,
     /// Number of verification operations
@@ -130,6 +136,8 @@ impl Accumulator {
             prime_cache: HashMap::new(),
             stats: AccumulatorStats::default(),
             generator,
+            members: Vec::new(),
+            member_index: HashMap::new(),
         }
     }
 
@@ -388,6 +396,166 @@ impl Accumulator {
     }
 }
 
+/// Domain tags for the hash-based membership set. Leaves, interior nodes and
+/// the size-bound root are hashed under distinct prefixes so that no value of
+/// one kind can be reinterpreted as another.
+const LEAF_TAG: u8 = 0x00;
+const NODE_TAG: u8 = 0x01;
+const ROOT_TAG: u8 = 0x02;
+
+/// Membership proof for the hash-based accumulator (a Merkle authentication path).
+///
+/// A witness is bound to the set as it was when it was issued: adding further
+/// elements changes the root, and older witnesses stop verifying. Callers must
+/// re-request a witness after the set changes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Witness {
+    /// Position of the element in insertion order
+    pub leaf_index: usize,
+    /// Number of elements in the set when the witness was issued
+    pub leaf_count: usize,
+    /// Sibling hashes from the leaf level up to (excluding) the root
+    pub path: Vec<[u8; 32]>,
+}
+
+/// Hash-based membership API.
+///
+/// Security assumptions:
+/// - SHA-256 is collision and second-preimage resistant. Membership of an
+///   absent element requires a second preimage of some leaf or a collision on
+///   the path to the root.
+/// - Leaves (`0x00 || element`), interior nodes (`0x01 || left || right`) and the
+///   root (`0x02 || count || tree_root`) are domain separated, so an interior
+///   node cannot be presented as a leaf (the classic Merkle second-preimage
+///   attack) and a tree cannot be passed off as one of a different size.
+/// - An unpaired node is promoted unchanged rather than duplicated, so distinct
+///   sets never share a root.
+/// - The verifier must obtain the root ([`Accumulator::root`]) over an
+///   authenticated channel; the scheme proves membership relative to a root,
+///   not the honesty of whoever published it.
+///
+/// This set is independent of the RSA `value`/`add_element` state, which relies
+/// on a deterministic modulus and unproven primes and is not suitable for
+/// adversarial membership proofs.
+impl Accumulator {
+    /// Add an element to the membership set. Adding an element twice is a no-op.
+    pub fn add(&mut self, element: &[u8]) {
+        let leaf = leaf_hash(element);
+        if self.member_index.contains_key(&leaf) {
+            return;
+        }
+        self.member_index.insert(leaf, self.members.len());
+        self.members.push(leaf);
+        self.stats.members_added += 1;
+    }
+
+    /// Produce a membership witness, or `None` if the element was never added.
+    pub fn witness(&self, element: &[u8]) -> Option<Witness> {
+        let leaf_index = *self.member_index.get(&leaf_hash(element))?;
+        let mut path = Vec::new();
+        let mut level = self.members.clone();
+        let mut idx = leaf_index;
+        while level.len() > 1 {
+            if let Some(sibling) = level.get(idx ^ 1) {
+                path.push(*sibling);
+            }
+            level = next_level(&level);
+            idx /= 2;
+        }
+        Some(Witness {
+            leaf_index,
+            leaf_count: self.members.len(),
+            path,
+        })
+    }
+
+    /// Verify a witness for `element` against the current root.
+    pub fn verify(&self, element: &[u8], w: &Witness) -> bool {
+        verify_witness(&self.root(), element, w)
+    }
+
+    /// Size-bound root of the membership set, to be published to verifiers.
+    pub fn root(&self) -> [u8; 32] {
+        let mut level = self.members.clone();
+        while level.len() > 1 {
+            level = next_level(&level);
+        }
+        let tree_root = level.first().copied().unwrap_or([0u8; 32]);
+        bind_root(self.members.len(), &tree_root)
+    }
+}
+
+/// Verify a hash-based membership witness against a published root.
+pub fn verify_witness(root: &[u8; 32], element: &[u8], w: &Witness) -> bool {
+    if w.leaf_index >= w.leaf_count {
+        return false;
+    }
+    let mut node = leaf_hash(element);
+    let mut idx = w.leaf_index;
+    let mut width = w.leaf_count;
+    let mut siblings = w.path.iter();
+    while width > 1 {
+        if (idx ^ 1) < width {
+            let Some(sibling) = siblings.next() else {
+                return false;
+            };
+            node = if idx.is_multiple_of(2) {
+                node_hash(&node, sibling)
+            } else {
+                node_hash(sibling, &node)
+            };
+        }
+        idx /= 2;
+        width = width.div_ceil(2);
+    }
+    if siblings.next().is_some() {
+        return false;
+    }
+    bind_root(w.leaf_count, &node) == *root
+}
+
+fn leaf_hash(element: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([LEAF_TAG]);
+    hasher.update(element);
+    hasher.finalize().into()
+}
+
+fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([NODE_TAG]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+fn bind_root(count: usize, tree_root: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([ROOT_TAG]);
+    hasher.update((count as u64).to_be_bytes());
+    hasher.update(tree_root);
+    hasher.finalize().into()
+}
+
+fn next_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    let mut next = Vec::with_capacity(level.len().div_ceil(2));
+    let mut rest = level;
+    loop {
+        match rest {
+            [left, right, tail @ ..] => {
+                next.push(node_hash(left, right));
+                rest = tail;
+            }
+            [single] => {
+                next.push(*single);
+                break;
+            }
+            [] => break,
+        }
+    }
+    next
+}
+
 /// Errors that can occur during accumulator operations
 #[derive(Debug, Clone, PartialEq)]
 pub enum AccumulatorError {
@@ -686,4 +854,74 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn hash_accumulator_proves_membership() {
+        let mut acc = Accumulator::new();
+        let elements: Vec<Vec<u8>> = (0..7u8).map(|i| vec![b'e', i]).collect();
+        for e in &elements {
+            acc.add(e);
+        }
+        for e in &elements {
+            let w = acc.witness(e).expect("added element has a witness");
+            assert!(acc.verify(e, &w));
+            assert!(verify_witness(&acc.root(), e, &w));
+        }
+    }
+
+    #[test]
+    fn hash_accumulator_rejects_absent_elements() {
+        let mut acc = Accumulator::new();
+        acc.add(b"alpha");
+        acc.add(b"beta");
+        acc.add(b"gamma");
+
+        assert!(acc.witness(b"delta").is_none());
+        // A witness for a present element must not vouch for any other element.
+        let w = acc.witness(b"alpha").expect("witness");
+        assert!(!acc.verify(b"delta", &w));
+        assert!(!acc.verify(b"beta", &w));
+
+        let mut tampered = w.clone();
+        tampered.path[0][0] ^= 1;
+        assert!(!acc.verify(b"alpha", &tampered));
+        let mut resized = w;
+        resized.leaf_count = 2;
+        assert!(!acc.verify(b"alpha", &resized));
+    }
+
+    #[test]
+    fn hash_accumulator_rejects_interior_node_as_leaf() {
+        let mut acc = Accumulator::new();
+        for e in [b"a", b"b", b"c", b"d"] {
+            acc.add(e);
+        }
+        // Present the concatenated children of an interior node as an element
+        // together with the upper half of a genuine path.
+        let w = acc.witness(b"a").expect("witness");
+        let forged_element = [leaf_hash(b"a"), leaf_hash(b"b")].concat();
+        let forged = Witness {
+            leaf_index: 0,
+            leaf_count: 2,
+            path: w.path[1..].to_vec(),
+        };
+        assert!(!acc.verify(&forged_element, &forged));
+    }
+
+    #[test]
+    fn hash_accumulator_witness_is_bound_to_root() {
+        let mut acc = Accumulator::new();
+        acc.add(b"first");
+        acc.add(b"first");
+        assert_eq!(acc.stats.members_added, 1);
+        assert_eq!(acc.stats.elements_added, 0);
+        let w = acc.witness(b"first").expect("witness");
+        assert_eq!(w.leaf_count, 1);
+        assert!(acc.verify(b"first", &w));
+
+        acc.add(b"second");
+        assert!(!acc.verify(b"first", &w));
+        let fresh = acc.witness(b"first").expect("witness");
+        assert!(acc.verify(b"first", &fresh));
+    }
 }
@@ -2,9 +2,9 @@
 
 use crate::errors::Result;
 use rand::Rng;
-use rand_distr::{Distribution, Poisson};
+use rand_distr::{Distribution, Exp, Poisson};
 use serde::{Deserialize, Serialize};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CoverTrafficConfig {
@@ -58,6 +58,40 @@ pub fn poisson_rate(lambda: f32, rng: &mut impl Rng) -> u32 {
     dist.sample(rng) as u32
 }
 
+/// Sample an inter-packet delay for a Poisson process with rate `lambda` (packets/sec)
+///
+/// Delays are exponentially distributed with mean `1/lambda`. A non-positive
+/// or non-finite `lambda` means no cover traffic and yields `Duration::MAX`.
+pub fn sample_interval(lambda: f32, rng: &mut impl Rng) -> Duration {
+    if !(lambda.is_finite() && lambda > 0.0) {
+        return Duration::MAX;
+    }
+    match Exp::new(lambda as f64) {
+        Ok(dist) => Duration::try_from_secs_f64(dist.sample(rng)).unwrap_or(Duration::MAX),
+        Err(_) => Duration::MAX,
+    }
+}
+
+/// Empirical packet rate (packets/sec) of consecutive `samples` within `window`
+///
+/// Intervals are accumulated from the start of the window; only packets that
+/// fall inside it are counted. Returns 0.0 for an empty window.
+pub fn expected_rate_over(window: Duration, samples: &[Duration]) -> f32 {
+    if window.is_zero() {
+        return 0.0;
+    }
+    let mut elapsed = Duration::ZERO;
+    let mut count = 0u64;
+    for s in samples {
+        elapsed = elapsed.saturating_add(*s);
+        if elapsed > window {
+            break;
+        }
+        count += 1;
+    }
+    (count as f64 / window.as_secs_f64()) as f32
+}
+
 /// Enhanced cover traffic with adaptive rate control
 pub fn adaptive_cover_rate(base_lambda: f32, load_factor: f32, rng: &mut impl Rng) -> u32 {
     // Adjust rate based on current network load
@@ -93,4 +127,37 @@ mod tests {
         assert!(high_load <= 50); // Sanity check
         assert!(low_load <= 50); // Sanity check
     }
+
+    #[test]
+    fn test_sample_interval_matches_lambda() {
+        use rand::{rngs::StdRng, SeedableRng};
+        let mut rng = StdRng::seed_from_u64(0x5eed);
+        let lambda = 5.0f32;
+        let samples: Vec<Duration> = (0..10_000)
+            .map(|_| sample_interval(lambda, &mut rng))
+            .collect();
+
+        let total: Duration = samples.iter().sum();
+        let mean = total.as_secs_f64() / samples.len() as f64;
+        let expected = 1.0 / lambda as f64;
+        assert!(
+            (mean - expected).abs() / expected < 0.05,
+            "mean interval {mean} not within 5% of {expected}"
+        );
+
+        let rate = expected_rate_over(total, &samples);
+        assert!((rate - lambda).abs() / lambda < 0.05, "rate {rate}");
+    }
+
+    #[test]
+    fn test_sample_interval_degenerate_lambda() {
+        let mut rng = thread_rng();
+        assert_eq!(sample_interval(0.0, &mut rng), Duration::MAX);
+        assert_eq!(sample_interval(-2.0, &mut rng), Duration::MAX);
+        assert_eq!(sample_interval(f32::NAN, &mut rng), Duration::MAX);
+        assert_eq!(
+            expected_rate_over(Duration::ZERO, &[Duration::from_millis(1)]),
+            0.0
+        );
+    }
 }
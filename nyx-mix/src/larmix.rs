@@ -66,6 +66,41 @@ pub fn build_mix_path(candidates: &[Candidate], hops: usize, rng: &mut impl Rng)
     path
 }
 
+/// Latency below which a path is considered good enough for the minimum hop count
+pub const GOOD_LATENCY_MS: f64 = 100.0;
+/// Loss rate below which a path is considered good enough for the minimum hop count
+pub const GOOD_LOSS_RATE: f64 = 0.01;
+/// Latency above which a path warrants the maximum hop count
+pub const POOR_LATENCY_MS: f64 = 500.0;
+/// Loss rate above which a path warrants the maximum hop count
+pub const POOR_LOSS_RATE: f64 = 0.1;
+
+/// Target hop count for a path with the given measured latency and loss.
+///
+/// Paths with latency below 100 ms and loss below 1% get `min` hops; latency
+/// above 500 ms or loss above 10% gets `max`. In between, the hop count is
+/// interpolated from whichever of the two metrics is worse. Non-finite inputs
+/// are treated as the worst case. If `min > max` the bounds are swapped.
+///
+/// This is the hop-count policy for the stream layer as well: the multipath
+/// scheduler steps each path toward this target.
+pub fn optimal_hops(latency_ms: f64, loss_rate: f64, min: u8, max: u8) -> u8 {
+    let (min, max) = if min <= max { (min, max) } else { (max, min) };
+    let badness = |value: f64, good: f64, poor: f64| {
+        if value.is_finite() {
+            ((value - good) / (poor - good)).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    };
+    let score = badness(latency_ms, GOOD_LATENCY_MS, POOR_LATENCY_MS).max(badness(
+        loss_rate,
+        GOOD_LOSS_RATE,
+        POOR_LOSS_RATE,
+    ));
+    min + (score * f64::from(max - min)).round() as u8
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -122,4 +157,29 @@ mod tests {
         let path = build_mix_path(&candidates, 5, &mut rng);
         assert_eq!(path.len(), 1); // Can't build path longer than available nodes
     }
+
+    #[test]
+    fn test_optimal_hops_bounds() {
+        // Poor conditions push to the maximum, good ones to the minimum.
+        assert_eq!(optimal_hops(600.0, 0.15, 3, 7), 7);
+        assert_eq!(optimal_hops(80.0, 0.005, 3, 7), 3);
+        // Either metric alone is enough to mark a path as poor.
+        assert_eq!(optimal_hops(50.0, 0.2, 3, 7), 7);
+        assert_eq!(optimal_hops(900.0, 0.0, 3, 7), 7);
+        assert_eq!(optimal_hops(f64::NAN, 0.0, 3, 7), 7);
+        // Swapped bounds are tolerated.
+        assert_eq!(optimal_hops(80.0, 0.005, 7, 3), 3);
+    }
+
+    #[test]
+    fn test_optimal_hops_interpolates() {
+        assert_eq!(optimal_hops(300.0, 0.0, 3, 7), 5);
+        let mut last = 0;
+        for latency in (0..=600).step_by(50) {
+            let hops = optimal_hops(latency as f64, 0.0, 3, 7);
+            assert!((3..=7).contains(&hops));
+            assert!(hops >= last, "hop count must not decrease as latency grows");
+            last = hops;
+        }
+    }
 }
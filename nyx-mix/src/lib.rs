@@ -25,7 +25,10 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 // Re-export enhanced cover traffic types
-pub use cover::{adaptive_cover_rate, poisson_rate, CoverTrafficConfig, CoverTrafficGenerator};
+pub use cover::{
+    adaptive_cover_rate, expected_rate_over, poisson_rate, sample_interval, CoverTrafficConfig,
+    CoverTrafficGenerator,
+};
 pub use cover_adaptive::{AdaptiveCoverManager, CoverConfig, NetworkMetrics};
 pub use enhanced_cover_traffic::{
     AnonymityMetrics, CoverPacket, CoverPriority, CrossLayerMetrics, EnhancedCoverConfig,
@@ -89,6 +92,10 @@ impl Default for MixConfig {
 }
 
 impl MixConfig {
+    /// Documented bounds for `base_cover_lambda` (packets per second)
+    pub const MIN_COVER_LAMBDA: f32 = 0.1;
+    pub const MAX_COVER_LAMBDA: f32 = 100.0;
+
     fn default_lambda() -> f32 {
         5.0
     }
@@ -101,11 +108,32 @@ impl MixConfig {
         if !(0.0..=1.0).contains(&self.low_power_ratio) {
             return Err("low_power_ratio must be within [0,1]".into());
         }
-        if !(0.0..=50_000.0).contains(&self.base_cover_lambda) {
-            return Err("base_cover_lambda out of reasonable range".into());
+        if !(Self::MIN_COVER_LAMBDA..=Self::MAX_COVER_LAMBDA).contains(&self.base_cover_lambda) {
+            return Err(format!(
+                "base_cover_lambda must be within [{}, {}] pps, got {}",
+                Self::MIN_COVER_LAMBDA,
+                Self::MAX_COVER_LAMBDA,
+                self.base_cover_lambda
+            ));
         }
         Ok(())
     }
+
+    /// Best-effort alternative to `validate_range_s`: pull out-of-range values
+    /// to the nearest bound instead of erroring. NaN falls back to the default.
+    pub fn clamp(&mut self) {
+        self.base_cover_lambda = if self.base_cover_lambda.is_nan() {
+            Self::default_lambda()
+        } else {
+            self.base_cover_lambda
+                .clamp(Self::MIN_COVER_LAMBDA, Self::MAX_COVER_LAMBDA)
+        };
+        self.low_power_ratio = if self.low_power_ratio.is_nan() {
+            Self::default_low_power_ratio()
+        } else {
+            self.low_power_ratio.clamp(0.0, 1.0)
+        };
+    }
 }
 
 /// 目標カバートラフィック係数の参考値
@@ -129,4 +157,39 @@ mod test_s {
     fn config_validate_range_s() {
         MixConfig::default().validate_range_s().unwrap();
     }
+    #[test]
+    fn cover_lambda_range_matches_documentation() {
+        let with = |lambda| MixConfig {
+            base_cover_lambda: lambda,
+            ..Default::default()
+        };
+        assert!(with(0.0).validate_range_s().is_err());
+        assert!(with(0.05).validate_range_s().is_err());
+        assert!(with(5.0).validate_range_s().is_ok());
+        let err = with(200.0).validate_range_s().unwrap_err();
+        assert!(
+            err.contains("base_cover_lambda") && err.contains("200"),
+            "{err}"
+        );
+    }
+    #[test]
+    fn clamp_brings_config_into_range() {
+        for (input, expected) in [(0.0, 0.1), (0.05, 0.1), (5.0, 5.0), (200.0, 100.0)] {
+            let mut cfg = MixConfig {
+                base_cover_lambda: input,
+                low_power_ratio: 1.5,
+                ..Default::default()
+            };
+            cfg.clamp();
+            assert_eq!(cfg.base_cover_lambda, expected);
+            assert_eq!(cfg.low_power_ratio, 1.0);
+            cfg.validate_range_s().unwrap();
+        }
+        let mut cfg = MixConfig {
+            base_cover_lambda: f32::NAN,
+            ..Default::default()
+        };
+        cfg.clamp();
+        assert_eq!(cfg.base_cover_lambda, 5.0);
+    }
 }
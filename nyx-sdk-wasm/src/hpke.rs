@@ -7,10 +7,16 @@ use wasm_bindgen::prelude::*;
 
 use crate::errors::{NyxWasmError, WasmResult};
 use base64::{engine::general_purpose, Engine};
+use nyx_crypto::aead::{AeadCipher, AeadKey, AeadNonce, AeadSuite};
+use nyx_crypto::kdf::aeadnonce_xor;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Mutex, OnceLock};
 
 /// HPKE cipher suite configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct HpkeConfig {
     /// KEM (Key Encapsulation Mechanism) algorithm
     pub kem: String,
@@ -18,6 +24,62 @@ pub struct HpkeConfig {
     pub kdf: String,
     /// AEAD (Authenticated Encryption with Associated Data) algorithm
     pub aead: String,
+    /// Short AEAD suite selector ("aes128gcm", "aes256gcm", "chacha20poly1305").
+    /// When present, overrides `aead`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suite: Option<String>,
+}
+
+/// AEAD suites selectable via [`HpkeConfig::suite`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HpkeAeadSuite {
+    /// AES-128-GCM
+    Aes128Gcm,
+    /// AES-256-GCM
+    Aes256Gcm,
+    /// ChaCha20-Poly1305
+    ChaCha20Poly1305,
+}
+
+impl HpkeAeadSuite {
+    /// Parse a suite selector string
+    pub fn from_selector(s: &str) -> Result<Self, NyxWasmError> {
+        match s {
+            "aes128gcm" => Ok(Self::Aes128Gcm),
+            "aes256gcm" => Ok(Self::Aes256Gcm),
+            "chacha20poly1305" => Ok(Self::ChaCha20Poly1305),
+            other => Err(NyxWasmError::ConfigurationError(format!(
+                "Unsupported HPKE suite: {other}"
+            ))),
+        }
+    }
+
+    /// AEAD algorithm name as used in [`HpkeConfig::aead`]
+    pub fn aead_name(&self) -> &'static str {
+        match self {
+            Self::Aes128Gcm => "AES-128-GCM",
+            Self::Aes256Gcm => "AES-256-GCM",
+            Self::ChaCha20Poly1305 => "ChaCha20Poly1305",
+        }
+    }
+}
+
+impl HpkeConfig {
+    /// Parse optional config JSON, resolving the `suite` selector into `aead`
+    fn from_json(config_json: Option<String>) -> Result<Self, NyxWasmError> {
+        let mut config = match config_json {
+            Some(cfg) => serde_json::from_str::<HpkeConfig>(&cfg).map_err(|e| {
+                NyxWasmError::ConfigurationError(format!("Invalid HPKE config: {e}"))
+            })?,
+            None => HpkeConfig::default(),
+        };
+        if let Some(selector) = config.suite.as_deref() {
+            config.aead = HpkeAeadSuite::from_selector(selector)?
+                .aead_name()
+                .to_string();
+        }
+        Ok(config)
+    }
 }
 
 /// HPKE encryption result
@@ -29,6 +91,9 @@ pub struct HpkeEncryptionResult {
     pub ciphertext: String,
     /// Authentication tag (if separate)
     pub auth_tag: Option<String>,
+    /// AEAD algorithm used for this ciphertext
+    #[serde(default)]
+    pub aead: String,
 }
 
 /// HPKE decryption result
@@ -47,6 +112,7 @@ impl Default for HpkeConfig {
             kem: "DHKEM(X25519, HKDF-SHA256)".to_string(),
             kdf: "HKDF-SHA256".to_string(),
             aead: "ChaCha20Poly1305".to_string(),
+            suite: None,
         }
     }
 }
@@ -57,12 +123,7 @@ pub fn hpke_encrypt_internal(
     plaintext: &str,
     config_json: Option<String>,
 ) -> WasmResult<String> {
-    let config = if let Some(cfg) = config_json {
-        serde_json::from_str::<HpkeConfig>(&cfg)
-            .map_err(|e| NyxWasmError::ConfigurationError(format!("Invalid HPKE config: {e}")))?
-    } else {
-        HpkeConfig::default()
-    };
+    let config = HpkeConfig::from_json(config_json)?;
 
     #[cfg(feature = "hpke")]
     {
@@ -82,12 +143,7 @@ pub fn hpke_decrypt_internal(
     ciphertext: &str,
     config_json: Option<String>,
 ) -> WasmResult<String> {
-    let config = if let Some(cfg) = config_json {
-        serde_json::from_str::<HpkeConfig>(&cfg)
-            .map_err(|e| NyxWasmError::ConfigurationError(format!("Invalid HPKE config: {e}")))?
-    } else {
-        HpkeConfig::default()
-    };
+    let config = HpkeConfig::from_json(config_json)?;
 
     #[cfg(feature = "hpke")]
     {
@@ -117,7 +173,7 @@ pub fn hpke_generate_keypair_internal() -> WasmResult<String> {
 fn hpke_encrypt_impl(
     _recipient_public_key: &str,
     plaintext: &str,
-    _config: &HpkeConfig,
+    config: &HpkeConfig,
 ) -> WasmResult<String> {
     // For now, return a demonstration encryption result
     // In a full implementation, this would use actual HPKE from nyx-crypto
@@ -125,6 +181,7 @@ fn hpke_encrypt_impl(
         encapsulated_key: generate_demo_key()?,
         ciphertext: general_purpose::STANDARD.encode(plaintext.as_bytes()),
         auth_tag: Some("demo_auth_tag".to_string()),
+        aead: config.aead.clone(),
     };
 
     serde_json::to_string(&demo_result)
@@ -155,12 +212,11 @@ fn hpke_decrypt_impl(
 
 #[cfg(feature = "hpke")]
 fn hpke_generate_keypair_impl() -> WasmResult<String> {
-    let private_key = generate_demo_key()?;
-    let public_key = generate_demo_key()?;
+    let (private_key, public_key) = nyx_crypto::hpke::gen_keypair();
 
     let keypair = serde_json::json!({
-        "private_key": private_key,
-        "public_key": public_key,
+        "private_key": hex::encode(private_key),
+        "public_key": hex::encode(public_key),
         "algorithm": "X25519"
     });
 
@@ -188,6 +244,186 @@ fn generate_demo_key() -> Result<String, NyxWasmError> {
     Ok(hex::encode(key))
 }
 
+/// Role of a streaming HPKE context
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StreamRole {
+    Sender,
+    Receiver,
+}
+
+/// Streaming context: AEAD key, base nonce and next sequence number
+struct StreamContext {
+    role: StreamRole,
+    cipher: AeadCipher,
+    base_nonce: [u8; 12],
+    seq: u64,
+}
+
+/// Result of creating a sender stream context
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HpkeStreamSender {
+    /// Opaque context handle for [`hpke_seal_chunk_internal`]
+    pub context: u32,
+    /// Encapsulated key (hex) the receiver needs to open the stream
+    pub encapsulated_key: String,
+}
+
+fn stream_registry() -> &'static Mutex<HashMap<u32, StreamContext>> {
+    static REGISTRY: OnceLock<Mutex<HashMap<u32, StreamContext>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn register_stream(ctx: StreamContext) -> Result<u32, NyxWasmError> {
+    static NEXT_HANDLE: AtomicU32 = AtomicU32::new(1);
+    let handle = NEXT_HANDLE.fetch_add(1, Ordering::Relaxed);
+    stream_registry()
+        .lock()
+        .map_err(|_| NyxWasmError::OperationError("HPKE context registry poisoned".into()))?
+        .insert(handle, ctx);
+    Ok(handle)
+}
+
+/// Exporter context binding the stream key schedule to this use
+const STREAM_EXPORTER_CTX: &[u8] = b"nyx-hpke-stream";
+
+fn check_stream_suite(config: &HpkeConfig) -> Result<(), NyxWasmError> {
+    if config.aead != HpkeAeadSuite::ChaCha20Poly1305.aead_name() {
+        return Err(NyxWasmError::ConfigurationError(format!(
+            "Streaming HPKE supports only ChaCha20Poly1305, got {}",
+            config.aead
+        )));
+    }
+    Ok(())
+}
+
+fn decode_hex(label: &str, value: &str) -> Result<Vec<u8>, NyxWasmError> {
+    hex::decode(value)
+        .map_err(|e| NyxWasmError::CryptographicError(format!("Invalid {label}: {e}")))
+}
+
+/// Split an exported secret into the stream key and base nonce
+fn new_stream_context(role: StreamRole, secret: &[u8]) -> StreamContext {
+    let mut key = [0u8; 32];
+    let mut base_nonce = [0u8; 12];
+    key.copy_from_slice(&secret[..32]);
+    base_nonce.copy_from_slice(&secret[32..44]);
+    StreamContext {
+        role,
+        cipher: AeadCipher::new(AeadSuite::ChaCha20Poly1305, AeadKey(key)),
+        base_nonce,
+        seq: 0,
+    }
+}
+
+/// Create a sender context for chunked encryption
+///
+/// The stream key and base nonce come from the HPKE exporter secret, so only
+/// the holder of the recipient private key can derive them from `enc`.
+pub fn hpke_create_sender_context_internal(
+    recipient_public_key: &str,
+    config_json: Option<String>,
+) -> WasmResult<String> {
+    let config = HpkeConfig::from_json(config_json)?;
+    check_stream_suite(&config)?;
+    let pk = decode_hex("recipient public key", recipient_public_key)?;
+    let (enc, secret) = nyx_crypto::hpke::export_sender(&pk, STREAM_EXPORTER_CTX, 44)
+        .map_err(|e| NyxWasmError::CryptographicError(e.to_string()))?;
+    let sender = HpkeStreamSender {
+        context: register_stream(new_stream_context(StreamRole::Sender, &secret))?,
+        encapsulated_key: hex::encode(enc),
+    };
+    serde_json::to_string(&sender)
+        .map_err(|e| NyxWasmError::SerializationError(e.to_string()).into())
+}
+
+/// Create a receiver context for chunked decryption
+pub fn hpke_create_receiver_context_internal(
+    private_key: &str,
+    encapsulated_key: &str,
+    config_json: Option<String>,
+) -> WasmResult<u32> {
+    let config = HpkeConfig::from_json(config_json)?;
+    check_stream_suite(&config)?;
+    let sk = decode_hex("private key", private_key)?;
+    let enc = decode_hex("encapsulated key", encapsulated_key)?;
+    let secret = nyx_crypto::hpke::export_receiver(&sk, &enc, STREAM_EXPORTER_CTX, 44)
+        .map_err(|e| NyxWasmError::CryptographicError(e.to_string()))?;
+    Ok(register_stream(new_stream_context(
+        StreamRole::Receiver,
+        &secret,
+    ))?)
+}
+
+fn with_stream<T>(
+    context: u32,
+    role: StreamRole,
+    f: impl FnOnce(&mut StreamContext) -> Result<T, NyxWasmError>,
+) -> WasmResult<T> {
+    let mut registry = stream_registry()
+        .lock()
+        .map_err(|_| NyxWasmError::OperationError("HPKE context registry poisoned".into()))?;
+    let ctx = registry
+        .get_mut(&context)
+        .ok_or_else(|| NyxWasmError::OperationError(format!("Unknown HPKE context: {context}")))?;
+    if ctx.role != role {
+        return Err(NyxWasmError::OperationError(format!(
+            "HPKE context {context} is not a {role:?} context"
+        ))
+        .into());
+    }
+    Ok(f(ctx)?)
+}
+
+/// Encrypt the next chunk of a stream
+///
+/// Each call consumes one sequence number; the receiver must open chunks in
+/// exactly the order they were sealed. Stream truncation is not detected, so
+/// callers must signal end-of-stream out of band.
+pub fn hpke_seal_chunk_internal(context: u32, chunk: &[u8]) -> WasmResult<Vec<u8>> {
+    with_stream(context, StreamRole::Sender, |ctx| {
+        let seq = ctx.seq;
+        let next = seq
+            .checked_add(1)
+            .ok_or_else(|| NyxWasmError::CryptographicError("HPKE sequence exhausted".into()))?;
+        let nonce = AeadNonce(aeadnonce_xor(&ctx.base_nonce, seq));
+        let ct = ctx
+            .cipher
+            .seal(nonce, &seq.to_be_bytes(), chunk)
+            .map_err(|e| NyxWasmError::CryptographicError(e.to_string()))?;
+        ctx.seq = next;
+        Ok(ct)
+    })
+}
+
+/// Decrypt the next chunk of a stream
+///
+/// A chunk that is replayed, reordered or tampered with fails to
+/// authenticate; the expected sequence number is not advanced on failure.
+pub fn hpke_open_chunk_internal(context: u32, chunk: &[u8]) -> WasmResult<Vec<u8>> {
+    with_stream(context, StreamRole::Receiver, |ctx| {
+        let seq = ctx.seq;
+        let nonce = AeadNonce(aeadnonce_xor(&ctx.base_nonce, seq));
+        let pt = ctx
+            .cipher
+            .open(nonce, &seq.to_be_bytes(), chunk)
+            .map_err(|_| {
+                NyxWasmError::CryptographicError(format!(
+                    "Chunk failed to authenticate at sequence {seq}"
+                ))
+            })?;
+        ctx.seq = seq.saturating_add(1);
+        Ok(pt)
+    })
+}
+
+/// Release a stream context; returns whether the handle existed
+pub fn hpke_destroy_context_internal(context: u32) -> bool {
+    stream_registry()
+        .lock()
+        .map(|mut r| r.remove(&context).is_some())
+        .unwrap_or(false)
+}
+
 /// Check if HPKE is available in current build
 pub fn hpke_available_internal() -> bool {
     cfg!(feature = "hpke")
@@ -207,6 +443,11 @@ pub fn hpke_supported_suites() -> String {
             "kdf": "HKDF-SHA256",
             "aead": "AES-128-GCM"
         }),
+        serde_json::json!({
+            "kem": "DHKEM(X25519, HKDF-SHA256)",
+            "kdf": "HKDF-SHA256",
+            "aead": "AES-256-GCM"
+        }),
     ];
 
     serde_json::to_string(&suites).unwrap_or_else(|_| "[]".to_string())
@@ -299,4 +540,117 @@ mod tests {
             serde_json::from_str(&decrypt_result.unwrap()).unwrap();
         assert!(decryption.success);
     }
+
+    #[cfg(feature = "hpke")]
+    #[test]
+    fn test_hpke_suite_selection_roundtrip() {
+        let keypair = hpke_generate_keypair_internal().unwrap();
+        let keypair_obj: serde_json::Value = serde_json::from_str(&keypair).unwrap();
+        let public_key = keypair_obj["public_key"].as_str().unwrap();
+        let private_key = keypair_obj["private_key"].as_str().unwrap();
+
+        for (selector, aead) in [
+            ("aes128gcm", "AES-128-GCM"),
+            ("aes256gcm", "AES-256-GCM"),
+            ("chacha20poly1305", "ChaCha20Poly1305"),
+        ] {
+            let cfg = format!(r#"{{"suite": "{selector}"}}"#);
+            let encrypted =
+                hpke_encrypt_internal(public_key, "suite test", Some(cfg.clone())).unwrap();
+            let encryption: HpkeEncryptionResult = serde_json::from_str(&encrypted).unwrap();
+            assert_eq!(encryption.aead, aead);
+
+            let decrypted = hpke_decrypt_internal(
+                private_key,
+                &encryption.encapsulated_key,
+                &encryption.ciphertext,
+                Some(cfg),
+            )
+            .unwrap();
+            let decryption: HpkeDecryptionResult = serde_json::from_str(&decrypted).unwrap();
+            assert!(decryption.success);
+            let plaintext = general_purpose::STANDARD
+                .decode(decryption.plaintext)
+                .unwrap();
+            assert_eq!(plaintext, b"suite test");
+        }
+    }
+
+    #[test]
+    fn test_hpke_unknown_suite_rejected() {
+        let err = HpkeConfig::from_json(Some(r#"{"suite": "des-cbc"}"#.to_string())).unwrap_err();
+        assert!(matches!(err, NyxWasmError::ConfigurationError(_)));
+        assert!(hpke_encrypt_internal("k", "p", Some(r#"{"suite": "rot13"}"#.into())).is_err());
+    }
+
+    fn stream_keypair() -> (String, String) {
+        let keypair = hpke_generate_keypair_internal().unwrap();
+        let keypair: serde_json::Value = serde_json::from_str(&keypair).unwrap();
+        (
+            keypair["private_key"].as_str().unwrap().to_string(),
+            keypair["public_key"].as_str().unwrap().to_string(),
+        )
+    }
+
+    fn open_stream_pair() -> (u32, u32) {
+        let (private_key, public_key) = stream_keypair();
+        let sender = hpke_create_sender_context_internal(&public_key, None).unwrap();
+        let sender: HpkeStreamSender = serde_json::from_str(&sender).unwrap();
+        let receiver =
+            hpke_create_receiver_context_internal(&private_key, &sender.encapsulated_key, None)
+                .unwrap();
+        (sender.context, receiver)
+    }
+
+    #[test]
+    fn test_hpke_stream_chunks_in_order() {
+        let (tx, rx) = open_stream_pair();
+        let chunks: [&[u8]; 3] = [b"first", b"second", b"third"];
+        let sealed: Vec<Vec<u8>> = chunks
+            .iter()
+            .map(|c| hpke_seal_chunk_internal(tx, c).unwrap())
+            .collect();
+        assert_ne!(sealed[0], sealed[1]);
+
+        for (ct, pt) in sealed.iter().zip(chunks) {
+            assert_eq!(hpke_open_chunk_internal(rx, ct).unwrap(), pt);
+        }
+        assert!(hpke_destroy_context_internal(tx));
+        assert!(hpke_destroy_context_internal(rx));
+        assert!(!hpke_destroy_context_internal(rx));
+    }
+
+    #[test]
+    fn test_hpke_stream_out_of_order_fails() {
+        let (tx, rx) = open_stream_pair();
+        let first = hpke_seal_chunk_internal(tx, b"first").unwrap();
+        let second = hpke_seal_chunk_internal(tx, b"second").unwrap();
+
+        assert!(hpke_open_chunk_internal(rx, &second).is_err());
+        // Failure does not advance the sequence, so the in-order stream still opens
+        assert_eq!(hpke_open_chunk_internal(rx, &first).unwrap(), b"first");
+        assert_eq!(hpke_open_chunk_internal(rx, &second).unwrap(), b"second");
+        // Replaying an earlier chunk is rejected
+        assert!(hpke_open_chunk_internal(rx, &first).is_err());
+        // Roles are enforced
+        assert!(hpke_seal_chunk_internal(rx, b"x").is_err());
+    }
+
+    #[test]
+    fn test_hpke_stream_wrong_private_key_fails() {
+        let (_, public_key) = stream_keypair();
+        let (other_private_key, _) = stream_keypair();
+        let sender = hpke_create_sender_context_internal(&public_key, None).unwrap();
+        let sender: HpkeStreamSender = serde_json::from_str(&sender).unwrap();
+        let sealed = hpke_seal_chunk_internal(sender.context, b"secret").unwrap();
+
+        // Knowing `enc` is not enough without the matching private key
+        let eavesdropper = hpke_create_receiver_context_internal(
+            &other_private_key,
+            &sender.encapsulated_key,
+            None,
+        )
+        .unwrap();
+        assert!(hpke_open_chunk_internal(eavesdropper, &sealed).is_err());
+    }
 }
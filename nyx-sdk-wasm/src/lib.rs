@@ -127,6 +127,53 @@ pub fn hpke_decrypt(
     hpke::hpke_decrypt_internal(private_key, encapsulated_key, ciphertext, config_json)
 }
 
+/// Create an HPKE sender context for chunked encryption (if feature enabled)
+///
+/// Returns JSON `{"context": <u32>, "encapsulated_key": <hex>}`.
+#[cfg(feature = "hpke")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn hpke_create_sender_context(
+    recipient_public_key: &str,
+    config_json: Option<String>,
+) -> WasmResult<String> {
+    hpke::hpke_create_sender_context_internal(recipient_public_key, config_json)
+}
+
+/// Create an HPKE receiver context for chunked decryption (if feature enabled)
+#[cfg(feature = "hpke")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn hpke_create_receiver_context(
+    private_key: &str,
+    encapsulated_key: &str,
+    config_json: Option<String>,
+) -> WasmResult<u32> {
+    hpke::hpke_create_receiver_context_internal(private_key, encapsulated_key, config_json)
+}
+
+/// Seal the next chunk of an HPKE stream (if feature enabled)
+///
+/// Chunks carry an implicit, incrementing sequence number and must be opened
+/// in the order they were sealed; replayed or reordered chunks are rejected.
+#[cfg(feature = "hpke")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn hpke_seal_chunk(context: u32, chunk: &[u8]) -> WasmResult<Vec<u8>> {
+    hpke::hpke_seal_chunk_internal(context, chunk)
+}
+
+/// Open the next chunk of an HPKE stream (if feature enabled)
+#[cfg(feature = "hpke")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn hpke_open_chunk(context: u32, chunk: &[u8]) -> WasmResult<Vec<u8>> {
+    hpke::hpke_open_chunk_internal(context, chunk)
+}
+
+/// Release an HPKE stream context (if feature enabled)
+#[cfg(feature = "hpke")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn hpke_destroy_context(context: u32) -> bool {
+    hpke::hpke_destroy_context_internal(context)
+}
+
 /// HPKE generate keypair function (if feature enabled)
 #[cfg(feature = "hpke")]
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
@@ -98,6 +98,21 @@ struct PathPerformanceRecord {
     jitter_ms: f64,
 }
 
+/// Per-path scheduler state exported for debugging
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PathStateExport {
+    /// Path identifier
+    pub path_id: String,
+    /// Normalized scheduling weight (0.0-1.0, sums to 1.0 across eligible paths)
+    pub weight: f64,
+    /// Last measured round-trip time in milliseconds
+    pub rtt_ms: f64,
+    /// Last measured packet loss ratio (0.0-1.0)
+    pub loss: f64,
+    /// Path status
+    pub status: PathStatus,
+}
+
 impl Default for MultipathManager {
     fn default() -> Self {
         Self::new()
@@ -308,9 +323,60 @@ impl MultipathManager {
     pub fn get_performance_history(&self) -> String {
         serde_json::to_string(&self.performance_history).unwrap_or_else(|_| "[]".to_string())
     }
+
+    /// Export per-path scheduler state (id, weight, RTT, loss) as a JSON array
+    ///
+    /// Weights are path quality scores normalized over paths the selector
+    /// would consider; ineligible paths report a weight of 0.0.
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+    pub fn export_state_json(&self) -> String {
+        serde_json::to_string(&self.export_state()).unwrap_or_else(|_| "[]".to_string())
+    }
 }
 
 impl MultipathManager {
+    /// Build the scheduler state export, sorted by path id
+    fn export_state(&self) -> Vec<PathStateExport> {
+        let eligible = |path: &PathInfo| {
+            matches!(path.status, PathStatus::Active | PathStatus::Testing)
+                && path.quality >= self.config.quality_threshold
+        };
+        let total_quality: f64 = self
+            .paths
+            .values()
+            .filter(|p| eligible(p))
+            .map(|p| p.quality)
+            .sum();
+
+        let mut state: Vec<PathStateExport> = self
+            .paths
+            .values()
+            .map(|path| {
+                let weight = if eligible(path) && total_quality > 0.0 {
+                    path.quality / total_quality
+                } else {
+                    0.0
+                };
+                let loss = self
+                    .performance_history
+                    .iter()
+                    .rev()
+                    .find(|r| r.path_id == path.path_id)
+                    .map(|r| r.packet_loss)
+                    .unwrap_or(0.0);
+                PathStateExport {
+                    path_id: path.path_id.clone(),
+                    weight,
+                    rtt_ms: path.latency_ms,
+                    loss,
+                    status: path.status.clone(),
+                }
+            })
+            .collect();
+        state.sort_by(|a, b| a.path_id.cmp(&b.path_id));
+        state
+    }
+
     /// Select path using adaptive algorithm
     fn select_adaptive_path(&self, active_paths: &[(&String, &PathInfo)]) -> String {
         // Adaptive selection considers multiple factors with dynamic weighting
@@ -571,4 +637,26 @@ mod tests {
         let jitter = manager.calculate_jitter("test_path", 52.0);
         assert!(jitter > 0.0); // Should have some jitter
     }
+
+    #[test]
+    fn test_export_state_json() {
+        let mut manager = MultipathManager::new();
+        manager.add_path("fast", 0.8).unwrap();
+        manager.add_path("slow", 0.8).unwrap();
+        manager
+            .update_path_metrics("fast", 20.0, 50.0, 0.0)
+            .unwrap();
+        manager
+            .update_path_metrics("slow", 400.0, 50.0, 0.05)
+            .unwrap();
+
+        let json = manager.export_state_json();
+        let state: Vec<PathStateExport> = serde_json::from_str(&json).unwrap();
+        assert_eq!(state.len(), 2);
+        assert_eq!(state[0].path_id, "fast");
+        assert_eq!(state[1].path_id, "slow");
+        assert_eq!(state[0].rtt_ms, 20.0);
+        assert_eq!(state[1].loss, 0.05);
+        assert!(state[0].weight > state[1].weight);
+    }
 }
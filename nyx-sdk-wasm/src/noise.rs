@@ -4,6 +4,8 @@
 //! in a browser-compatible WASM environment.
 
 use crate::errors::{NyxWasmError, WasmResult};
+use rand_chacha::rand_core::{RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
 use serde::{Deserialize, Serialize};
 
 /// Configuration for Noise handshake demonstration
@@ -17,6 +19,12 @@ pub struct NoiseConfig {
     pub static_keypair: Option<StaticKeypair>,
     /// Additional data for handshake
     pub payload: Option<String>,
+    /// RNG seed for reproducible ephemeral keys (optional)
+    ///
+    /// When both sides supply a seed, the demo output is byte-identical
+    /// across runs and timing metrics are reported as zero.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -95,12 +103,24 @@ pub fn perform_handshake_demo(
 
     let total_time = get_timestamp() - start_time;
 
+    // Wall-clock timings would make seeded transcripts non-reproducible
+    let deterministic = initiator_cfg.seed.is_some() && responder_cfg.seed.is_some();
+
     let mut result = handshake_result;
-    result.metrics = HandshakeMetrics {
-        total_time_ms: total_time,
-        key_generation_time_ms: keygen_time,
-        exchange_time_ms: exchange_time,
-        total_bytes: result.messages.iter().map(|m| m.payload_size).sum(),
+    result.metrics = if deterministic {
+        HandshakeMetrics {
+            total_time_ms: 0.0,
+            key_generation_time_ms: 0.0,
+            exchange_time_ms: 0.0,
+            total_bytes: result.messages.iter().map(|m| m.payload_size).sum(),
+        }
+    } else {
+        HandshakeMetrics {
+            total_time_ms: total_time,
+            key_generation_time_ms: keygen_time,
+            exchange_time_ms: exchange_time,
+            total_bytes: result.messages.iter().map(|m| m.payload_size).sum(),
+        }
     };
 
     serde_json::to_string(&result)
@@ -110,11 +130,15 @@ pub fn perform_handshake_demo(
 /// Simulate a Noise handshake for demonstration purposes
 fn simulate_noise_handshake(
     initiator_cfg: &NoiseConfig,
-    _responder_cfg: &NoiseConfig,
+    responder_cfg: &NoiseConfig,
 ) -> Result<HandshakeResult, NyxWasmError> {
+    // Each side draws from its own seeded RNG when a seed is configured
+    let mut initiator_rng = initiator_cfg.seed.map(ChaCha20Rng::seed_from_u64);
+    let mut responder_rng = responder_cfg.seed.map(ChaCha20Rng::seed_from_u64);
+
     // Generate ephemeral keypairs (simulated with random data)
-    let initiator_ephemeral = generate_demo_key()?;
-    let responder_ephemeral = generate_demo_key()?;
+    let initiator_ephemeral = generate_key_with(initiator_rng.as_mut())?;
+    let responder_ephemeral = generate_key_with(responder_rng.as_mut())?;
 
     let mut messages = Vec::new();
 
@@ -151,7 +175,10 @@ fn simulate_noise_handshake(
     }
 
     // Generate demonstration shared secret
-    let shared_secret = generate_demo_secret()?;
+    let shared_secret = match initiator_rng.as_mut() {
+        Some(rng) => generate_key_with(Some(rng))?,
+        None => generate_demo_secret()?,
+    };
 
     Ok(HandshakeResult {
         success: true,
@@ -189,6 +216,19 @@ fn generate_demo_key() -> Result<String, NyxWasmError> {
     Ok(hex::encode(key))
 }
 
+/// Generate a demonstration key from a seeded RNG, falling back to
+/// [`generate_demo_key`] when no RNG is supplied
+fn generate_key_with(rng: Option<&mut ChaCha20Rng>) -> Result<String, NyxWasmError> {
+    match rng {
+        Some(rng) => {
+            let mut key = [0u8; 32];
+            rng.fill_bytes(&mut key);
+            Ok(hex::encode(key))
+        }
+        None => generate_demo_key(),
+    }
+}
+
 /// Generate a demonstration shared secret
 fn generate_demo_secret() -> Result<String, NyxWasmError> {
     generate_demo_key()
@@ -232,6 +272,7 @@ mod tests {
             psk: None,
             static_keypair: None,
             payload: Some("test payload".to_string()),
+            seed: None,
         };
 
         let serialized = serde_json::to_string(&config).unwrap();
@@ -286,4 +327,35 @@ mod tests {
         assert!(hex::decode(&key1).is_ok());
         assert!(hex::decode(&key2).is_ok());
     }
+
+    #[test]
+    fn test_seeded_handshake_is_reproducible() {
+        let initiator_config = r#"{
+            "pattern": "Noise_XX_25519_ChaChaPoly_BLAKE2s",
+            "psk": null,
+            "static_keypair": null,
+            "payload": "initiator_data",
+            "seed": 42
+        }"#;
+
+        let responder_config = r#"{
+            "pattern": "Noise_XX_25519_ChaChaPoly_BLAKE2s",
+            "psk": null,
+            "static_keypair": null,
+            "payload": "responder_data",
+            "seed": 7
+        }"#;
+
+        let first = perform_handshake_demo(initiator_config, responder_config).unwrap();
+        let second = perform_handshake_demo(initiator_config, responder_config).unwrap();
+        assert_eq!(first, second);
+
+        let result: HandshakeResult = serde_json::from_str(&first).unwrap();
+        assert_ne!(result.initiator_ephemeral, result.responder_ephemeral);
+
+        // A different seed must yield a different transcript
+        let other_initiator = initiator_config.replace("\"seed\": 42", "\"seed\": 43");
+        let third = perform_handshake_demo(&other_initiator, responder_config).unwrap();
+        assert_ne!(first, third);
+    }
 }
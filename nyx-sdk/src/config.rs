@@ -1,6 +1,8 @@
 #![forbid(unsafe_code)]
 
+use crate::error::{Error, Result};
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SdkConfig {
@@ -8,6 +10,23 @@ pub struct SdkConfig {
     pub daemon_endpoint: String,
     #[serde(default = "SdkConfig::default_timeout_ms")]
     pub request_timeout_ms: u64,
+    /// Minimum delay before reconnecting after a failed daemon connection.
+    /// Grows with repeated failures; while it runs, calls fail fast with
+    /// `Error::Backoff`. Off by default (0), so every call tries to connect.
+    /// Also the starting delay for event resubscription when non-zero.
+    #[serde(default = "SdkConfig::default_reconnect_min_interval_ms")]
+    pub reconnect_min_interval_ms: u64,
+    /// Ping an event subscription after this long without traffic
+    /// (0 disables keepalive and automatic resubscription).
+    #[serde(default = "SdkConfig::default_keepalive_interval_ms")]
+    pub keepalive_interval_ms: u64,
+    /// Treat a subscription as dead and reconnect when nothing, not even a
+    /// ping reply, arrives for this long (0 only reconnects on I/O errors).
+    #[serde(default = "SdkConfig::default_keepalive_idle_timeout_ms")]
+    pub keepalive_idle_timeout_ms: u64,
+    /// Auth token sent with requests; never serialized back out.
+    #[serde(default, skip_serializing)]
+    pub token: Option<String>,
 }
 
 impl Default for SdkConfig {
@@ -15,11 +34,20 @@ impl Default for SdkConfig {
         Self {
             daemon_endpoint: Self::default_endpoint(),
             request_timeout_ms: Self::default_timeout_ms(),
+            reconnect_min_interval_ms: Self::default_reconnect_min_interval_ms(),
+            keepalive_interval_ms: Self::default_keepalive_interval_ms(),
+            keepalive_idle_timeout_ms: Self::default_keepalive_idle_timeout_ms(),
+            token: None,
         }
     }
 }
 
 impl SdkConfig {
+    /// Create a builder that validates the result.
+    pub fn builder() -> SdkConfigBuilder {
+        SdkConfigBuilder::default()
+    }
+
     pub fn default_endpoint() -> String {
         if cfg!(windows) {
             "\\\\.\\pipe\\nyx-daemon".to_string()
@@ -30,4 +58,185 @@ impl SdkConfig {
     fn default_timeout_ms() -> u64 {
         10000
     }
+    fn default_reconnect_min_interval_ms() -> u64 {
+        0
+    }
+    fn default_keepalive_interval_ms() -> u64 {
+        30_000
+    }
+    fn default_keepalive_idle_timeout_ms() -> u64 {
+        90_000
+    }
+
+    /// Check that `daemon_endpoint` has the form this platform connects to:
+    /// a Unix socket path, or a `\\.\pipe\<name>` named pipe on Windows.
+    ///
+    /// Returns `Error::Config` with a hint for the expected form, so a wrong
+    /// value fails early instead of as an opaque connect error.
+    pub fn validate_endpoint(&self) -> Result<()> {
+        check_endpoint(&self.daemon_endpoint, cfg!(windows))
+    }
+}
+
+/// Builder for `SdkConfig`; unset fields keep their defaults.
+#[derive(Debug, Default)]
+pub struct SdkConfigBuilder {
+    endpoint: Option<String>,
+    timeout: Option<Duration>,
+    token: Option<String>,
+}
+
+impl SdkConfigBuilder {
+    pub fn endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoint = Some(endpoint.into());
+        self
+    }
+    /// Per-request timeout; rounded down to whole milliseconds.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+    /// Auth token; whitespace-only tokens are treated as absent.
+    pub fn token(mut self, token: impl Into<String>) -> Self {
+        let token = token.into();
+        let token = token.trim();
+        self.token = (!token.is_empty()).then(|| token.to_string());
+        self
+    }
+    /// Build the config, rejecting an endpoint this platform cannot connect
+    /// to and a zero timeout.
+    pub fn build(self) -> Result<SdkConfig> {
+        let mut cfg = SdkConfig::default();
+        if let Some(v) = self.endpoint {
+            cfg.daemon_endpoint = v;
+        }
+        if let Some(v) = self.timeout {
+            let ms = u64::try_from(v.as_millis()).unwrap_or(u64::MAX);
+            if ms == 0 {
+                return Err(Error::config("request timeout must be at least 1ms"));
+            }
+            cfg.request_timeout_ms = ms;
+        }
+        cfg.token = self.token;
+        cfg.validate_endpoint()?;
+        Ok(cfg)
+    }
+}
+
+/// Unix `sun_path` holds 108 bytes including the trailing NUL.
+const MAX_UNIX_SOCKET_PATH: usize = 107;
+
+fn check_endpoint(endpoint: &str, windows: bool) -> Result<()> {
+    let example = if windows {
+        r"\\.\pipe\nyx-daemon"
+    } else {
+        "/tmp/nyx.sock"
+    };
+    let fail = |why: String| {
+        Err(Error::config(format!(
+            "invalid daemon endpoint {endpoint:?}: {why} (expected e.g. {example})"
+        )))
+    };
+
+    if endpoint.trim().is_empty() {
+        return fail("endpoint is empty".into());
+    }
+    if endpoint.contains("://") || looks_like_host_port(endpoint) {
+        return fail("TCP endpoints are not supported; the daemon listens on local IPC".into());
+    }
+    let is_pipe = endpoint.starts_with(r"\\");
+    if windows {
+        let name = endpoint
+            .strip_prefix(r"\\")
+            .and_then(|rest| rest.split_once('\\'))
+            .and_then(|(_, rest)| rest.strip_prefix(r"pipe\"));
+        match name {
+            Some(name) if !name.is_empty() => Ok(()),
+            _ => fail("expected a named pipe path".into()),
+        }
+    } else if is_pipe {
+        fail("named pipes are only available on Windows; use a Unix socket path".into())
+    } else if endpoint.len() > MAX_UNIX_SOCKET_PATH {
+        fail(format!(
+            "Unix socket paths are limited to {MAX_UNIX_SOCKET_PATH} bytes"
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// `host:port` without a path separator, e.g. `127.0.0.1:9000` or `localhost:43300`.
+fn looks_like_host_port(endpoint: &str) -> bool {
+    if endpoint.contains('/') || endpoint.contains('\\') {
+        return false;
+    }
+    match endpoint.rsplit_once(':') {
+        Some((host, port)) => !host.is_empty() && port.parse::<u16>().is_ok(),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unix_endpoints() {
+        assert!(check_endpoint("/tmp/nyx.sock", false).is_ok());
+        assert!(check_endpoint("run/nyx.sock", false).is_ok());
+        let err = check_endpoint("127.0.0.1:43300", false).unwrap_err();
+        assert!(err.to_string().contains("TCP endpoints are not supported"));
+        assert!(err.to_string().contains("/tmp/nyx.sock"));
+        assert!(check_endpoint(r"\\.\pipe\nyx-daemon", false).is_err());
+        assert!(check_endpoint(&format!("/{}", "x".repeat(200)), false).is_err());
+        assert!(check_endpoint("  ", false).is_err());
+    }
+
+    #[test]
+    fn builder_sets_fields() {
+        let endpoint = if cfg!(windows) {
+            r"\\.\pipe\custom"
+        } else {
+            "/run/nyx/custom.sock"
+        };
+        let cfg = SdkConfig::builder()
+            .endpoint(endpoint)
+            .timeout(Duration::from_secs(3))
+            .token(" tok ")
+            .build()
+            .unwrap();
+        assert_eq!(cfg.daemon_endpoint, endpoint);
+        assert_eq!(cfg.request_timeout_ms, 3000);
+        assert_eq!(cfg.token.as_deref(), Some("tok"));
+        assert_eq!(
+            cfg.keepalive_interval_ms,
+            SdkConfig::default().keepalive_interval_ms
+        );
+    }
+
+    #[test]
+    fn builder_rejects_empty_endpoint_and_zero_timeout() {
+        let err = SdkConfig::builder().endpoint("").build().unwrap_err();
+        assert!(err.to_string().contains("endpoint is empty"));
+        let err = SdkConfig::builder()
+            .timeout(Duration::from_micros(500))
+            .build()
+            .unwrap_err();
+        assert!(err.to_string().contains("timeout"));
+        let err = SdkConfig::builder()
+            .endpoint("127.0.0.1:43300")
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, Error::Config(_)));
+    }
+
+    #[test]
+    fn windows_endpoints() {
+        assert!(check_endpoint(r"\\.\pipe\nyx-daemon", true).is_ok());
+        assert!(check_endpoint(r"\\server\pipe\nyx", true).is_ok());
+        assert!(check_endpoint(r"\\.\pipe\", true).is_err());
+        assert!(check_endpoint("/tmp/nyx.sock", true).is_err());
+        let err = check_endpoint("tcp://localhost:43300", true).unwrap_err();
+        assert!(err.to_string().contains(r"\\.\pipe\nyx-daemon"));
+    }
 }
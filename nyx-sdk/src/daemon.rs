@@ -4,25 +4,33 @@ use crate::{
     config::SdkConfig,
     error::{Error, Result},
     events::Event,
+    framing::{read_buffered_line, LineFramedReader},
+    reconnect::ReconnectPolicy,
 };
+use base64::{engine::general_purpose::STANDARD, Engine as _};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use std::sync::Mutex;
 use tokio::time::{timeout, Duration, Instant};
 use tokio::{
-    io::{AsyncRead, AsyncReadExt, AsyncWriteExt},
-    sync::broadcast,
+    io::{AsyncWriteExt, BufReader},
+    sync::{broadcast, mpsc},
 };
 
 #[derive(Debug, Serialize)]
 #[serde(tag = "op", rename_all = "snake_case")]
 enum Request<'a> {
     GetInfo,
+    Health,
+    GetConfig,
     ReloadConfig,
     UpdateConfig {
         settings: &'a serde_json::Map<String, serde_json::Value>,
     },
     SubscribeEvents {
         types: Option<Vec<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        compression: Option<&'a [&'a str]>,
     },
     ListConfigVersions,
     RollbackConfig {
@@ -31,6 +39,13 @@ enum Request<'a> {
     CreateConfigSnapshot {
         description: Option<String>,
     },
+    BuildPath {
+        required_caps: &'a [String],
+        hops: u8,
+    },
+    Batch {
+        requests: &'a [serde_json::Value],
+    },
 }
 
 #[derive(Debug, Serialize)]
@@ -66,16 +81,75 @@ pub struct ConfigResponse {
     pub validation_errors: Vec<String>,
 }
 
+/// Relay chosen by the daemon for a capability-filtered path
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PathHop {
+    pub node_id: String,
+    pub address: String,
+    #[serde(default)]
+    pub capabilities: Vec<String>,
+    pub quality: f64,
+}
+
+/// Path built by [`DaemonClient::build_path`]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PathResponse {
+    pub path_id: String,
+    pub hops: Vec<PathHop>,
+    /// Mean quality score of the hops (0.0-1.0)
+    pub quality: f64,
+}
+
+/// Codecs this client can decode on the event stream
+const EVENT_CODECS: &[&str] = &["deflate"];
+
+/// Upper bound on a decompressed event line
+const MAX_DECOMPRESSED_EVENT: usize = 4 * 1024 * 1024;
+
+/// Compressed event line as sent by the daemon once a codec is negotiated
+#[derive(Debug, Deserialize)]
+struct CompressedLine {
+    compressed: String,
+    payload: String,
+}
+
+/// Upper bound on the reconnect delay after repeated failures
+const MAX_RECONNECT_BACKOFF_MS: u64 = 30_000;
+
+/// First resubscribe delay when `reconnect_min_interval_ms` is 0
+const DEFAULT_RESUBSCRIBE_DELAY_MS: u64 = 500;
+
+/// Suppresses connection attempts while the daemon keeps failing, so a busy
+/// caller gets `Error::Backoff` instead of spinning on connect. Only active
+/// when `reconnect_min_interval_ms` is non-zero.
+#[derive(Debug, Default)]
+struct ConnectThrottle {
+    failures: u32,
+    next_allowed: Option<Instant>,
+}
+
 pub struct DaemonClient {
     cfg: SdkConfig,
     auth_token: Option<String>,
+    event_compression: bool,
+    throttle: Mutex<ConnectThrottle>,
+    reconnect: Option<ReconnectPolicy>,
 }
 
 impl DaemonClient {
+    /// Create a client; a token set on `cfg` is used for auth.
     pub fn new(cfg: SdkConfig) -> Self {
-        Self {
+        let token = cfg.token.clone();
+        let client = Self {
             cfg,
             auth_token: None,
+            event_compression: true,
+            throttle: Mutex::default(),
+            reconnect: None,
+        };
+        match token {
+            Some(t) => client.with_token(t),
+            None => client,
         }
     }
     /// Set an auth token; whitespace-only tokens are treated as absent.
@@ -92,13 +166,33 @@ impl DaemonClient {
 
     /// Construct a client and auto-discover token from env/cookie (non-blocking config stays as provided).
     pub async fn new_with_auto_token(cfg: SdkConfig) -> Self {
-        let tok = auto_discover_token().await;
+        let tok = match &cfg.token {
+            Some(t) => Some(t.clone()),
+            None => auto_discover_token().await,
+        };
         Self {
             cfg,
             auth_token: tok,
+            event_compression: true,
+            throttle: Mutex::default(),
+            reconnect: None,
         }
     }
 
+    /// Advertise (default) or withhold support for compressed event lines.
+    pub fn with_event_compression(mut self, enabled: bool) -> Self {
+        self.event_compression = enabled;
+        self
+    }
+
+    /// Retry failed connects per `policy` instead of failing on the first one.
+    /// The last connect error is returned once attempts or the deadline run out;
+    /// retries still count against each call's request timeout.
+    pub fn with_reconnect(mut self, policy: ReconnectPolicy) -> Self {
+        self.reconnect = Some(policy);
+        self
+    }
+
     /// Try to auto-discover an auth token from env/cookie and set it. Whitespace is ignored.
     pub async fn with_auto_token(mut self) -> Self {
         self.auth_token = auto_discover_token().await;
@@ -114,6 +208,32 @@ impl DaemonClient {
         .await
     }
 
+    /// Ask the daemon for its health status (`healthy`, per-component state).
+    /// Needs no auth token.
+    pub async fn health(&self) -> Result<serde_json::Value> {
+        self.rpc_json(&RpcRequest {
+            id: None,
+            auth: self.auth_token.as_deref(),
+            req: Request::Health,
+        })
+        .await
+    }
+
+    /// Fetch the daemon's live effective configuration without a disk reload
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Communication with daemon fails
+    /// - The client is not authorized
+    pub async fn get_config(&self) -> Result<serde_json::Value> {
+        self.rpc_json(&RpcRequest {
+            id: None,
+            auth: self.auth_token.as_deref(),
+            req: Request::GetConfig,
+        })
+        .await
+    }
+
     pub async fn reload_config(&self) -> Result<serde_json::Value> {
         self.rpc_json(&RpcRequest {
             id: None,
@@ -197,6 +317,63 @@ impl DaemonClient {
         .await
     }
 
+    /// Build a path whose relays all advertise `required_caps`
+    ///
+    /// # Arguments
+    /// * `required_caps` - Capabilities every hop must have (e.g. `"pq"`); empty accepts any relay
+    /// * `hops` - Number of relays, within the daemon's supported range
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Communication with daemon fails
+    /// - The hop count is out of range
+    /// - Not enough known peers have the required capabilities
+    pub async fn build_path(&self, required_caps: Vec<String>, hops: u8) -> Result<PathResponse> {
+        self.rpc_json::<PathResponse>(&RpcRequest {
+            id: None,
+            auth: self.auth_token.as_deref(),
+            req: Request::BuildPath {
+                required_caps: &required_caps,
+                hops,
+            },
+        })
+        .await
+    }
+
+    /// Run several raw requests (`{"op": ...}`) in one round-trip
+    ///
+    /// The daemon executes them in order and authorizes each one separately,
+    /// so the outer `Result` only covers the transport while every entry
+    /// carries its own outcome. The client's auth token is added to each
+    /// request that does not already carry one.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any request is not a JSON object
+    /// - Communication with daemon fails
+    /// - The daemon rejects the batch as a whole (e.g. too many requests)
+    pub async fn batch(
+        &self,
+        mut reqs: Vec<serde_json::Value>,
+    ) -> Result<Vec<Result<serde_json::Value>>> {
+        for req in &mut reqs {
+            let obj = req
+                .as_object_mut()
+                .ok_or_else(|| Error::protocol("batch request must be a JSON object"))?;
+            if let Some(token) = &self.auth_token {
+                obj.entry("auth").or_insert_with(|| token.clone().into());
+            }
+        }
+        let results = self
+            .rpc_json::<Vec<RpcResponseValue>>(&RpcRequest {
+                id: None,
+                auth: self.auth_token.as_deref(),
+                req: Request::Batch { requests: &reqs },
+            })
+            .await?;
+        Ok(results.into_iter().map(response_data).collect())
+    }
+
     /// Subscribe to daemon events of specific types
     ///
     /// # Arguments
@@ -211,104 +388,296 @@ impl DaemonClient {
         &self,
         types: Option<Vec<String>>,
     ) -> Result<broadcast::Receiver<Event>> {
-        let mut stream = connect(&self.cfg).await?;
+        let sub = Subscription {
+            cfg: self.cfg.clone(),
+            auth_token: self.auth_token.clone(),
+            types,
+            compression: self.event_compression,
+        };
+        let connect = async { sub.handshake(self.connect_throttled().await?).await };
+        let stream = timeout(Duration::from_millis(self.cfg.request_timeout_ms), connect)
+            .await
+            .map_err(|_| Error::Timeout)??;
+        // Now events stream follows line-delimited JSON
+        let (tx, rx) = broadcast::channel(128);
+        tokio::spawn(sub.run(stream, tx));
+        Ok(rx)
+    }
+
+    /// Connect unless a recent failure puts us inside the backoff window.
+    /// The window is at least `reconnect_min_interval_ms` and grows with the
+    /// reconnect module's exponential policy on consecutive failures.
+    async fn connect_throttled(&self) -> Result<DaemonStream> {
+        if let Some(at) = self.throttle.lock().unwrap().next_allowed {
+            let now = Instant::now();
+            if now < at {
+                return Err(Error::Backoff(at - now));
+            }
+        }
+        let res = match &self.reconnect {
+            Some(policy) => connect_with_retry(policy, || connect(&self.cfg)).await,
+            None => connect(&self.cfg).await,
+        };
+        let mut throttle = self.throttle.lock().unwrap();
+        match &res {
+            Ok(_) => *throttle = ConnectThrottle::default(),
+            Err(_) if self.cfg.reconnect_min_interval_ms > 0 => {
+                let min_ms = self.cfg.reconnect_min_interval_ms;
+                let delay = crate::reconnect::calculate_delay(
+                    throttle.failures,
+                    min_ms,
+                    MAX_RECONNECT_BACKOFF_MS.max(min_ms),
+                )
+                .max(Duration::from_millis(min_ms));
+                throttle.failures = throttle.failures.saturating_add(1);
+                throttle.next_allowed = Some(Instant::now() + delay);
+            }
+            Err(_) => {}
+        }
+        res
+    }
+
+    /// One request/response exchange, bounded as a whole by `request_timeout_ms`.
+    async fn rpc_json<T: for<'de> Deserialize<'de>>(&self, req: &RpcRequest<'_>) -> Result<T> {
+        let line = serde_json::to_string(req).map_err(|e| Error::Protocol(e.to_string()))? + "\n";
+        let deadline = Instant::now() + Duration::from_millis(self.cfg.request_timeout_ms);
+        let buf = self.round_trip(&line, deadline).await?;
+        decode_response(&buf)
+    }
+
+    /// Connect, send `line` and read the response line, or fail with
+    /// `Error::Timeout` at `deadline`. The connection is dropped either way.
+    async fn round_trip(&self, line: &str, deadline: Instant) -> Result<Vec<u8>> {
+        let exchange = async {
+            let mut stream = BufReader::new(self.connect_throttled().await?);
+            stream.get_mut().write_all(line.as_bytes()).await?;
+            stream.get_mut().flush().await?;
+            let mut buf = Vec::with_capacity(1024);
+            read_buffered_line(&mut stream, &mut buf).await?;
+            Ok::<_, Error>(buf)
+        };
+        tokio::time::timeout_at(deadline, exchange)
+            .await
+            .map_err(|_| Error::Timeout)?
+    }
+
+    /// Send a raw request object (`{"op": ...}`) and wait for its response,
+    /// bounding connect, write and read together by `deadline`.
+    ///
+    /// Returns `Error::Timeout` once the deadline passes; the in-flight
+    /// connection is dropped, so nothing lingers after the call returns.
+    /// The client's auth token is added unless `req` already carries one.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `req` is not a JSON object
+    /// - The deadline passes before a full response line arrives
+    /// - Communication with daemon fails or the daemon rejects the request
+    pub async fn request_with_deadline(
+        &self,
+        mut req: serde_json::Value,
+        deadline: Instant,
+    ) -> Result<serde_json::Value> {
+        let obj = req
+            .as_object_mut()
+            .ok_or_else(|| Error::protocol("request must be a JSON object"))?;
+        if let Some(token) = &self.auth_token {
+            obj.entry("auth").or_insert_with(|| token.clone().into());
+        }
+        let line = serde_json::to_string(&req).map_err(|e| Error::Protocol(e.to_string()))? + "\n";
+        let buf = self.round_trip(&line, deadline).await?;
+        decode_response(&buf)
+    }
+}
+
+/// Sent on an idle subscription; the daemon answers each with an empty line.
+const KEEPALIVE_PING: &[u8] = b"{\"op\":\"ping\"}\n";
+
+/// Everything needed to (re)establish an event subscription.
+struct Subscription {
+    cfg: SdkConfig,
+    auth_token: Option<String>,
+    types: Option<Vec<String>>,
+    compression: bool,
+}
+
+impl Subscription {
+    /// Send the subscribe request and consume the response line.
+    async fn handshake(&self, mut stream: DaemonStream) -> Result<BufReader<DaemonStream>> {
         let req = RpcRequest {
             id: None,
             auth: self.auth_token.as_deref(),
-            req: Request::SubscribeEvents { types },
+            req: Request::SubscribeEvents {
+                types: self.types.clone(),
+                compression: self.compression.then_some(EVENT_CODECS),
+            },
         };
         let line = serde_json::to_string(&req).map_err(|e| Error::Protocol(e.to_string()))? + "\n";
-        timeout(
-            Duration::from_millis(self.cfg.request_timeout_ms),
-            stream.write_all(line.as_bytes()),
-        )
-        .await
-        .map_err(|_| Error::Timeout)??;
-        timeout(
-            Duration::from_millis(self.cfg.request_timeout_ms),
-            stream.flush(),
-        )
-        .await
-        .map_err(|_| Error::Timeout)??;
-        // Drop first response line
+        let request_timeout = Duration::from_millis(self.cfg.request_timeout_ms);
+        timeout(request_timeout, stream.write_all(line.as_bytes()))
+            .await
+            .map_err(|_| Error::Timeout)??;
+        timeout(request_timeout, stream.flush())
+            .await
+            .map_err(|_| Error::Timeout)??;
+        // Drop first response line. Read through one buffer so event lines
+        // that arrive in the same segment as the response are not lost.
+        let mut stream = BufReader::new(stream);
         let mut buf = Vec::with_capacity(1024);
-        read_one_line_with_timeout(&mut stream, &mut buf, self.cfg.request_timeout_ms).await?;
-        // Now events stream follows line-delimited JSON
-        let (tx, rx) = broadcast::channel(128);
-        tokio::spawn(async move {
-            let mut stream_inner = stream; // move into task
-            let mut tmp = Vec::with_capacity(1024);
+        timeout(request_timeout, read_buffered_line(&mut stream, &mut buf))
+            .await
+            .map_err(|_| Error::Timeout)??;
+        Ok(stream)
+    }
+
+    /// Forward events until every receiver is gone. With keepalive enabled a
+    /// failed connection is replaced by a fresh subscription; otherwise the
+    /// task ends after reporting `events_stream_closed`.
+    async fn run(self, mut stream: BufReader<DaemonStream>, tx: broadcast::Sender<Event>) {
+        loop {
+            let err = self.pump(stream, &tx).await;
+            if tx.receiver_count() == 0 {
+                break;
+            }
+            if self.cfg.keepalive_interval_ms == 0 {
+                let _ = tx.send(system_event(format!("events_stream_closed:{err}")));
+                break;
+            }
+            let _ = tx.send(system_event(format!("events_stream_reconnecting:{err}")));
+            match self.reconnect(&tx).await {
+                Some(next) => stream = next,
+                None => break,
+            }
+        }
+    }
+
+    /// Forward events from one connection until it fails, pinging it while
+    /// idle and giving up once `keepalive_idle_timeout_ms` passes in silence.
+    async fn pump(&self, stream: BufReader<DaemonStream>, tx: &broadcast::Sender<Event>) -> Error {
+        let (reader, mut writer) = tokio::io::split(stream);
+        // Read lines on a separate task: a partially read line must survive
+        // the keepalive timer firing.
+        let (line_tx, mut lines) = mpsc::channel::<Result<Option<serde_json::Value>>>(16);
+        let reader_task = tokio::spawn(async move {
+            let mut reader = LineFramedReader::new(BufReader::new(reader));
             loop {
-                tmp.clear();
-                if let Err(e) = read_one_line(&mut stream_inner, &mut tmp).await {
-                    let _ = tx.send(Event {
-                        event_type: "system".into(),
-                        detail: format!("events_stream_closed:{e}"),
-                    });
+                let res = reader.next_value().await;
+                // A line that is not JSON is reported but does not end the stream
+                let failed = matches!(&res, Err(e) if !matches!(e, Error::Serde(_)));
+                if line_tx.send(res).await.is_err() || failed {
                     break;
                 }
-                if tmp.is_empty() {
-                    continue;
-                }
-                match serde_json::from_slice::<Event>(&tmp) {
-                    Ok(ev) => {
-                        if tx.send(ev).is_err() {
-                            break;
+            }
+        });
+
+        let keepalive = Duration::from_millis(self.cfg.keepalive_interval_ms);
+        let idle_timeout = Duration::from_millis(self.cfg.keepalive_idle_timeout_ms);
+        let request_timeout = Duration::from_millis(self.cfg.request_timeout_ms);
+        let mut ticker = tokio::time::interval((keepalive / 2).max(Duration::from_millis(1)));
+        let mut last_rx = Instant::now();
+        let mut last_ping = Instant::now();
+        let err = loop {
+            tokio::select! {
+                line = lines.recv() => {
+                    let event = match line {
+                        Some(Ok(value)) => value.map(decode_event),
+                        Some(Err(e @ Error::Serde(_))) => Some(Err(e)),
+                        Some(Err(e)) => break e,
+                        None => break Error::Disconnected,
+                    };
+                    last_rx = Instant::now();
+                    // Empty lines are keepalive replies
+                    if let Some(event) = event {
+                        let event = event
+                            .unwrap_or_else(|e| system_event(format!("events_decode_error:{e}")));
+                        if tx.send(event).is_err() {
+                            break Error::Disconnected;
                         }
                     }
-                    Err(e) => {
-                        if tx
-                            .send(Event {
-                                event_type: "system".into(),
-                                detail: format!("events_decode_error:{e}"),
-                            })
-                            .is_err()
-                        {
-                            break;
+                }
+                _ = ticker.tick(), if !keepalive.is_zero() => {
+                    // Every subscriber is gone; stop instead of pinging forever
+                    if tx.receiver_count() == 0 {
+                        break Error::Disconnected;
+                    }
+                    if !idle_timeout.is_zero() && last_rx.elapsed() >= idle_timeout {
+                        break Error::Timeout;
+                    }
+                    if last_rx.elapsed() >= keepalive && last_ping.elapsed() >= keepalive {
+                        let ping = async {
+                            writer.write_all(KEEPALIVE_PING).await?;
+                            writer.flush().await
+                        };
+                        match timeout(request_timeout, ping).await {
+                            Ok(Ok(())) => last_ping = Instant::now(),
+                            Ok(Err(e)) => break e.into(),
+                            Err(_) => break Error::Timeout,
                         }
                     }
                 }
             }
-        });
-        Ok(rx)
+        };
+        reader_task.abort();
+        err
     }
 
-    async fn rpc_json<T: for<'de> Deserialize<'de>>(&self, req: &RpcRequest<'_>) -> Result<T> {
-        let mut stream = connect(&self.cfg).await?;
-        let line = serde_json::to_string(req).map_err(|e| Error::Protocol(e.to_string()))? + "\n";
-        timeout(
-            Duration::from_millis(self.cfg.request_timeout_ms),
-            stream.write_all(line.as_bytes()),
-        )
-        .await
-        .map_err(|_| Error::Timeout)??;
-        timeout(
-            Duration::from_millis(self.cfg.request_timeout_ms),
-            stream.flush(),
-        )
-        .await
-        .map_err(|_| Error::Timeout)??;
-        let mut buf = Vec::with_capacity(1024);
-        read_one_line_with_timeout(&mut stream, &mut buf, self.cfg.request_timeout_ms).await?;
-        let resp: RpcResponseValue =
-            serde_json::from_slice(&buf).map_err(|e| Error::Protocol(e.to_string()))?;
-        if resp.ok {
-            // Optionally validate success code and note id for diagnostics
-            let _resp_id = resp.id.as_deref();
-            let _resp_code = resp.code;
-            let v = resp._data.ok_or_else(|| Error::protocol("missing _data"))?;
-            let t = serde_json::from_value(v).map_err(|e| Error::Protocol(e.to_string()))?;
-            Ok(t)
-        } else {
-            let code = resp.code;
-            let id_suffix = resp
-                .id
-                .as_deref()
-                .map(|s| format!(" id={s}"))
-                .unwrap_or_default();
-            let msg = resp.error.unwrap_or_else(|| "unknown error".into());
-            Err(Error::protocol(format!("{msg} (code={code}){id_suffix}")))
+    /// Resubscribe with the reconnect backoff until it succeeds or nobody listens.
+    async fn reconnect(&self, tx: &broadcast::Sender<Event>) -> Option<BufReader<DaemonStream>> {
+        let min_ms = match self.cfg.reconnect_min_interval_ms {
+            0 => DEFAULT_RESUBSCRIBE_DELAY_MS,
+            ms => ms,
+        };
+        let mut attempt = 0u32;
+        while tx.receiver_count() > 0 {
+            let delay = crate::reconnect::calculate_delay(
+                attempt,
+                min_ms,
+                MAX_RECONNECT_BACKOFF_MS.max(min_ms),
+            )
+            .max(Duration::from_millis(min_ms));
+            tokio::time::sleep(delay).await;
+            attempt = attempt.saturating_add(1);
+            if let Ok(stream) = connect(&self.cfg).await {
+                if let Ok(stream) = self.handshake(stream).await {
+                    return Some(stream);
+                }
+            }
         }
+        None
+    }
+}
+
+fn system_event(detail: String) -> Event {
+    Event {
+        event_type: "system".into(),
+        detail,
+    }
+}
+
+/// Unwrap a daemon response line into its data payload or an error.
+fn decode_response<T: for<'de> Deserialize<'de>>(buf: &[u8]) -> Result<T> {
+    let resp: RpcResponseValue =
+        serde_json::from_slice(buf).map_err(|e| Error::Protocol(e.to_string()))?;
+    let v = response_data(resp)?;
+    serde_json::from_value(v).map_err(|e| Error::Protocol(e.to_string()))
+}
+
+/// Data payload of a successful response, or the daemon's error.
+fn response_data(resp: RpcResponseValue) -> Result<serde_json::Value> {
+    if resp.ok {
+        // Optionally validate success code and note id for diagnostics
+        let _resp_id = resp.id.as_deref();
+        let _resp_code = resp.code;
+        resp._data.ok_or_else(|| Error::protocol("missing _data"))
+    } else {
+        let code = resp.code;
+        let id_suffix = resp
+            .id
+            .as_deref()
+            .map(|s| format!(" id={s}"))
+            .unwrap_or_default();
+        let msg = resp.error.unwrap_or_else(|| "unknown error".into());
+        Err(Error::protocol(format!("{msg} (code={code}){id_suffix}")))
     }
 }
 
@@ -366,75 +735,35 @@ async fn read_cookie_token() -> Option<String> {
     None
 }
 
-async fn read_one_line<R: AsyncRead + Unpin>(reader: &mut R, out: &mut Vec<u8>) -> Result<()> {
-    let mut tmp = [0u8; 256];
-    out.clear();
-    loop {
-        let n = reader
-            .read(&mut tmp)
-            .await
-            .map_err(|e| Error::Stream(e.to_string()))?;
-        if n == 0 {
-            break;
-        }
-        out.extend_from_slice(&tmp[..n]);
-        if out.contains(&b'\n') {
-            break;
-        }
-        if out.len() > 64 * 1024 {
-            return Err(Error::protocol("response too large"));
-        }
-    }
-    if let Some(pos) = memchr::memchr(b'\n', out) {
-        out.truncate(pos);
+/// Decode one event line, unwrapping it first if the daemon compressed it.
+fn decode_event(value: serde_json::Value) -> Result<Event> {
+    if value.get("compressed").is_none() {
+        return Ok(serde_json::from_value(value)?);
     }
-    // Trim a trailing CR if present (handle CRLF)
-    if out.last().copied() == Some(b'\r') {
-        out.pop();
+    let wrapped: CompressedLine = serde_json::from_value(value)?;
+    if wrapped.compressed != "deflate" {
+        return Err(Error::protocol(format!(
+            "unsupported event compression: {}",
+            wrapped.compressed
+        )));
     }
-    Ok(())
+    let deflated = STANDARD
+        .decode(wrapped.payload)
+        .map_err(|e| Error::Protocol(e.to_string()))?;
+    let line =
+        miniz_oxide::inflate::decompress_to_vec_with_limit(&deflated, MAX_DECOMPRESSED_EVENT)
+            .map_err(|_| Error::protocol("corrupt or oversized compressed event"))?;
+    Ok(serde_json::from_slice(&line)?)
 }
 
-async fn read_one_line_with_timeout<R: AsyncRead + Unpin>(
-    reader: &mut R,
-    out: &mut Vec<u8>,
-    timeout_ms: u64,
-) -> Result<()> {
-    let deadline = Duration::from_millis(timeout_ms);
-    let start = Instant::now();
-    out.clear();
-    let mut buf = [0u8; 256];
-    loop {
-        let remain = deadline.saturating_sub(start.elapsed());
-        if remain.is_zero() {
-            return Err(Error::Timeout);
-        }
-        let n = timeout(remain, reader.read(&mut buf))
-            .await
-            .map_err(|_| Error::Timeout)?
-            .map_err(|e| Error::Stream(e.to_string()))?;
-        if n == 0 {
-            break;
-        }
-        out.extend_from_slice(&buf[..n]);
-        if out.contains(&b'\n') {
-            break;
-        }
-        if out.len() > 64 * 1024 {
-            return Err(Error::protocol("response too large"));
-        }
-    }
-    if let Some(pos) = memchr::memchr(b'\n', out) {
-        out.truncate(pos);
-    }
-    if out.last().copied() == Some(b'\r') {
-        out.pop();
-    }
-    Ok(())
-}
+#[cfg(unix)]
+type DaemonStream = tokio::net::UnixStream;
+#[cfg(windows)]
+type DaemonStream = tokio::net::windows::named_pipe::NamedPipeClient;
 
 #[cfg(unix)]
 async fn connect(cfg: &SdkConfig) -> Result<tokio::net::UnixStream> {
+    cfg.validate_endpoint()?;
     let stream = tokio::net::UnixStream::connect(cfg.daemon_endpoint.clone()).await?;
     Ok(stream)
 }
@@ -442,15 +771,44 @@ async fn connect(cfg: &SdkConfig) -> Result<tokio::net::UnixStream> {
 #[cfg(windows)]
 async fn connect(cfg: &SdkConfig) -> Result<tokio::net::windows::named_pipe::NamedPipeClient> {
     use tokio::net::windows::named_pipe::ClientOptions;
+    cfg.validate_endpoint()?;
     let client = ClientOptions::new().open(cfg.daemon_endpoint.clone())?;
     Ok(client)
 }
 
+/// Run `attempt` until it connects or `policy` is exhausted. Only I/O errors
+/// are retried; anything else (e.g. a bad endpoint) fails immediately.
+async fn connect_with_retry<T, F, Fut>(policy: &ReconnectPolicy, mut attempt: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<T>>,
+{
+    let give_up = Instant::now() + policy.deadline;
+    let mut attempts = 0u32;
+    loop {
+        attempts += 1;
+        let err = match attempt().await {
+            Ok(v) => return Ok(v),
+            Err(e @ Error::Io(_)) => e,
+            Err(e) => return Err(e),
+        };
+        if attempts >= policy.max_attempts.max(1) {
+            return Err(err);
+        }
+        let delay = policy.delay(attempts);
+        if Instant::now() + delay > give_up {
+            return Err(err);
+        }
+        tracing::debug!(attempts, ?delay, "daemon connect failed, retrying");
+        tokio::time::sleep(delay).await;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use serde_json::json;
-    use tokio::io::{duplex, AsyncWriteExt};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
     #[test]
     fn request_serialization_shapes() {
@@ -463,6 +821,21 @@ mod tests {
         let s = serde_json::to_string(&req).unwrap();
         assert!(s.contains("\"op\":\"get_info\""));
 
+        let req = RpcRequest {
+            id: None,
+            auth: Some("t"),
+            req: Request::GetConfig,
+        };
+        let s = serde_json::to_string(&req).unwrap();
+        assert!(s.contains("\"op\":\"get_config\""));
+
+        let req = RpcRequest {
+            id: None,
+            auth: None,
+            req: Request::Health,
+        };
+        assert_eq!(serde_json::to_string(&req).unwrap(), "{\"op\":\"health\"}");
+
         let mut map = serde_json::Map::new();
         map.insert("log_level".into(), json!("debug"));
         let req = RpcRequest {
@@ -475,33 +848,6 @@ mod tests {
         assert!(s.contains("\"log_level\":"));
     }
 
-    #[tokio::test]
-    async fn read_one_line_handles_newline_and_truncation() -> Result<()> {
-        let (mut a, mut b) = duplex(64);
-        // Write two lines; reader should only read first line
-        tokio::spawn(async move {
-            let _ = b.write_all(b"{\"ok\":true}\n{\"ok\":false}\n").await;
-        });
-        let mut buf = Vec::new();
-        read_one_line(&mut a, &mut buf).await?;
-        let s = String::from_utf8(buf).unwrap();
-        assert_eq!(s, "{\"ok\":true}");
-        Ok(())
-    }
-
-    #[tokio::test]
-    async fn read_one_line_trims_crlf() -> Result<()> {
-        let (mut a, mut b) = duplex(64);
-        tokio::spawn(async move {
-            let _ = b.write_all(b"{\"ok\":true}\r\n").await;
-        });
-        let mut buf = Vec::new();
-        read_one_line(&mut a, &mut buf).await?;
-        let s = String::from_utf8(buf).unwrap();
-        assert_eq!(s, "{\"ok\":true}");
-        Ok(())
-    }
-
     #[test]
     fn with_token_ignores_empty_whitespace() {
         let c = DaemonClient::new(SdkConfig::default()).with_token("   \t\n");
@@ -536,4 +882,498 @@ mod tests {
         std::env::remove_var("NYX_DAEMON_COOKIE");
         Ok(())
     }
+
+    /// Accept one subscription, reply, then push `event` (compressed when
+    /// the client offered deflate). Returns the request line seen.
+    #[cfg(unix)]
+    async fn serve_one_event(
+        listener: tokio::net::UnixListener,
+        event: Vec<u8>,
+    ) -> serde_json::Value {
+        let (sock, _) = listener.accept().await.unwrap();
+        let mut sock = BufReader::new(sock);
+        let mut req = Vec::new();
+        read_buffered_line(&mut sock, &mut req).await.unwrap();
+        let req: serde_json::Value = serde_json::from_slice(&req).unwrap();
+        let offered = req["compression"]
+            .as_array()
+            .is_some_and(|c| c.iter().any(|v| v == "deflate"));
+        let line = if offered {
+            sock.write_all(b"{\"ok\":true,\"code\":0,\"data\":{\"compression\":\"deflate\"}}\n")
+                .await
+                .unwrap();
+            let deflated = miniz_oxide::deflate::compress_to_vec(&event, 6);
+            serde_json::to_vec(
+                &json!({"compressed": "deflate", "payload": STANDARD.encode(deflated)}),
+            )
+            .unwrap()
+        } else {
+            sock.write_all(b"{\"ok\":true,\"code\":0,\"data\":{}}\n")
+                .await
+                .unwrap();
+            event
+        };
+        sock.write_all(&line).await.unwrap();
+        sock.write_all(b"\n").await.unwrap();
+        sock.flush().await.unwrap();
+        req
+    }
+
+    #[cfg(unix)]
+    fn large_event() -> Event {
+        Event {
+            event_type: "system".into(),
+            detail: "config diff: log_level debug -> info; ".repeat(1000),
+        }
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn subscribe_events_decompresses_large_event() -> Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nyx.sock");
+        let listener = tokio::net::UnixListener::bind(&path)?;
+        let expected = large_event();
+        let server = tokio::spawn(serve_one_event(
+            listener,
+            serde_json::to_vec(&expected).unwrap(),
+        ));
+
+        let cfg = SdkConfig {
+            daemon_endpoint: path.to_string_lossy().into_owned(),
+            request_timeout_ms: 2000,
+            ..Default::default()
+        };
+        let mut rx = DaemonClient::new(cfg).subscribe_events(None).await?;
+        let got = timeout(Duration::from_secs(2), rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(got, expected);
+        let req = server.await.unwrap();
+        assert_eq!(req["compression"], json!(["deflate"]));
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn subscribe_events_plain_when_compression_disabled() -> Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nyx.sock");
+        let listener = tokio::net::UnixListener::bind(&path)?;
+        let expected = large_event();
+        let server = tokio::spawn(serve_one_event(
+            listener,
+            serde_json::to_vec(&expected).unwrap(),
+        ));
+
+        let cfg = SdkConfig {
+            daemon_endpoint: path.to_string_lossy().into_owned(),
+            request_timeout_ms: 2000,
+            ..Default::default()
+        };
+        let client = DaemonClient::new(cfg).with_event_compression(false);
+        let mut rx = client.subscribe_events(None).await?;
+        let got = timeout(Duration::from_secs(2), rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(got, expected);
+        let req = server.await.unwrap();
+        assert!(req.get("compression").is_none());
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn subscribe_events_decodes_dropped_marker() -> Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nyx.sock");
+        let listener = tokio::net::UnixListener::bind(&path)?;
+        // Same shape the daemon writes when a subscriber lags
+        let marker = br#"{"_ty":"events_dropped","_detail":"6"}"#.to_vec();
+        let server = tokio::spawn(serve_one_event(listener, marker));
+
+        let cfg = SdkConfig {
+            daemon_endpoint: path.to_string_lossy().into_owned(),
+            request_timeout_ms: 2000,
+            ..Default::default()
+        };
+        let mut rx = DaemonClient::new(cfg).subscribe_events(None).await?;
+        let got = timeout(Duration::from_secs(2), rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(got.event_type, "events_dropped");
+        assert_eq!(got.detail.parse::<u64>().ok(), Some(6));
+        server.await.unwrap();
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn request_with_deadline_times_out_on_slow_daemon() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nyx.sock");
+        let listener = tokio::net::UnixListener::bind(&path).unwrap();
+        let server = tokio::spawn(async move {
+            let (sock, _) = listener.accept().await.unwrap();
+            let mut sock = BufReader::new(sock);
+            let mut req = Vec::new();
+            read_buffered_line(&mut sock, &mut req).await.unwrap();
+            // Never answer; the client should give up and hang up on us
+            let mut rest = [0u8; 16];
+            let n = timeout(Duration::from_secs(2), sock.read(&mut rest))
+                .await
+                .expect("client kept the connection open past its deadline")
+                .unwrap();
+            (
+                serde_json::from_slice::<serde_json::Value>(&req).unwrap(),
+                n,
+            )
+        });
+
+        let cfg = SdkConfig {
+            daemon_endpoint: path.to_string_lossy().into_owned(),
+            request_timeout_ms: 5000,
+            ..Default::default()
+        };
+        let client = DaemonClient::new(cfg).with_token("tok");
+        let started = Instant::now();
+        let res = client
+            .request_with_deadline(
+                json!({"op": "get_info"}),
+                started + Duration::from_millis(150),
+            )
+            .await;
+        assert!(matches!(res, Err(Error::Timeout)), "{res:?}");
+        assert!(started.elapsed() < Duration::from_secs(1));
+
+        let (req, n) = server.await.unwrap();
+        assert_eq!(req["op"], "get_info");
+        assert_eq!(req["auth"], "tok");
+        assert_eq!(n, 0, "connection should be closed after the deadline");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn connect_attempts_throttled_while_daemon_down() {
+        let dir = tempfile::tempdir().unwrap();
+        let cfg = SdkConfig {
+            daemon_endpoint: dir
+                .path()
+                .join("absent.sock")
+                .to_string_lossy()
+                .into_owned(),
+            request_timeout_ms: 2000,
+            reconnect_min_interval_ms: 200,
+            ..Default::default()
+        };
+        let client = DaemonClient::new(cfg);
+
+        let mut attempts = 0;
+        for _ in 0..20 {
+            match client.get_info().await {
+                Err(Error::Backoff(wait)) => assert!(wait <= Duration::from_millis(200)),
+                Err(Error::Io(_)) => attempts += 1,
+                other => panic!("unexpected result: {other:?}"),
+            }
+        }
+        assert_eq!(attempts, 1, "only the first call may reach connect");
+
+        tokio::time::sleep(Duration::from_millis(220)).await;
+        assert!(matches!(client.get_info().await, Err(Error::Io(_))));
+        assert!(matches!(client.get_info().await, Err(Error::Backoff(_))));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn connect_attempts_not_throttled_by_default() {
+        let dir = tempfile::tempdir().unwrap();
+        let cfg = SdkConfig {
+            daemon_endpoint: dir
+                .path()
+                .join("absent.sock")
+                .to_string_lossy()
+                .into_owned(),
+            ..Default::default()
+        };
+        let client = DaemonClient::new(cfg);
+        for _ in 0..5 {
+            let res = client.get_info().await;
+            assert!(matches!(res, Err(Error::Io(_))), "{res:?}");
+        }
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn rpc_times_out_when_daemon_never_answers() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("silent.sock");
+        let listener = tokio::net::UnixListener::bind(&path).unwrap();
+        // Accept and hold connections open without ever replying
+        let server = tokio::spawn(async move {
+            let mut held = Vec::new();
+            while let Ok((sock, _)) = listener.accept().await {
+                held.push(sock);
+            }
+        });
+        let cfg = SdkConfig {
+            daemon_endpoint: path.to_string_lossy().into_owned(),
+            request_timeout_ms: 150,
+            ..Default::default()
+        };
+        let client = DaemonClient::new(cfg);
+
+        let started = Instant::now();
+        let res = tokio::time::timeout(Duration::from_secs(5), client.get_info())
+            .await
+            .expect("get_info hung past its request timeout");
+        assert!(matches!(res, Err(Error::Timeout)), "{res:?}");
+        assert!(started.elapsed() < Duration::from_secs(1));
+
+        let res = tokio::time::timeout(Duration::from_secs(5), client.batch(vec![]))
+            .await
+            .expect("batch hung past its request timeout");
+        assert!(matches!(res, Err(Error::Timeout)), "{res:?}");
+        let res = tokio::time::timeout(Duration::from_secs(5), client.subscribe_events(None))
+            .await
+            .expect("subscribe hung past its request timeout");
+        assert!(matches!(res, Err(Error::Timeout)));
+        server.abort();
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn reconnect_policy_retries_dead_endpoint_then_gives_up() {
+        let dir = tempfile::tempdir().unwrap();
+        let cfg = SdkConfig {
+            daemon_endpoint: dir.path().join("dead.sock").to_string_lossy().into_owned(),
+            ..Default::default()
+        };
+        let policy = ReconnectPolicy {
+            max_attempts: 4,
+            base_delay_ms: 5,
+            max_delay_ms: 20,
+            deadline: Duration::from_secs(10),
+        };
+        let attempts = std::sync::atomic::AtomicU32::new(0);
+        let res = connect_with_retry(&policy, || {
+            attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            connect(&cfg)
+        })
+        .await;
+        assert!(matches!(res, Err(Error::Io(_))));
+        assert_eq!(attempts.into_inner(), 4);
+
+        // A deadline shorter than any backoff stops after the first attempt.
+        let policy = ReconnectPolicy {
+            base_delay_ms: 1_000,
+            max_delay_ms: 1_000,
+            deadline: Duration::ZERO,
+            ..policy
+        };
+        let client = DaemonClient::new(cfg).with_reconnect(policy);
+        let started = Instant::now();
+        assert!(matches!(client.get_info().await, Err(Error::Io(_))));
+        assert!(started.elapsed() < Duration::from_millis(500));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn build_path_sends_caps_and_parses_hops() -> Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nyx.sock");
+        let listener = tokio::net::UnixListener::bind(&path)?;
+        let server = tokio::spawn(async move {
+            let (sock, _) = listener.accept().await.unwrap();
+            let mut sock = BufReader::new(sock);
+            let mut req = Vec::new();
+            read_buffered_line(&mut sock, &mut req).await.unwrap();
+            let resp = json!({"ok": true, "code": 0, "data": {
+                "path_id": "path_0",
+                "hops": [{"node_id": "ab", "address": "10.0.0.1:4433", "capabilities": ["pq"], "quality": 0.75}],
+                "quality": 0.75
+            }});
+            let mut line = serde_json::to_vec(&resp).unwrap();
+            line.push(b'\n');
+            sock.write_all(&line).await.unwrap();
+            sock.flush().await.unwrap();
+            serde_json::from_slice::<serde_json::Value>(&req).unwrap()
+        });
+
+        let cfg = SdkConfig {
+            daemon_endpoint: path.to_string_lossy().into_owned(),
+            request_timeout_ms: 2000,
+            ..Default::default()
+        };
+        let got = DaemonClient::new(cfg)
+            .build_path(vec!["pq".into()], 3)
+            .await?;
+        assert_eq!(got.path_id, "path_0");
+        assert_eq!(got.hops.len(), 1);
+        assert_eq!(got.hops[0].capabilities, vec!["pq".to_string()]);
+        let req = server.await.unwrap();
+        assert_eq!(req["op"], "build_path");
+        assert_eq!(req["required_caps"], json!(["pq"]));
+        assert_eq!(req["hops"], 3);
+        Ok(())
+    }
+
+    /// Fake daemon that accepts subscriptions, answers pings when `pong`
+    /// is set, and reports (connections accepted, pings seen).
+    #[cfg(unix)]
+    fn spawn_subscription_daemon(
+        listener: tokio::net::UnixListener,
+        pong: bool,
+    ) -> tokio::sync::watch::Receiver<(usize, usize)> {
+        let (stats_tx, stats_rx) = tokio::sync::watch::channel((0usize, 0usize));
+        tokio::spawn(async move {
+            while let Ok((sock, _)) = listener.accept().await {
+                stats_tx.send_modify(|s| s.0 += 1);
+                let stats_tx = stats_tx.clone();
+                tokio::spawn(async move {
+                    let mut sock = BufReader::new(sock);
+                    let mut line = Vec::new();
+                    read_buffered_line(&mut sock, &mut line).await.unwrap();
+                    sock.write_all(b"{\"ok\":true,\"code\":0,\"data\":{\"subscribed\":true}}\n")
+                        .await
+                        .unwrap();
+                    while read_buffered_line(&mut sock, &mut line).await.is_ok() {
+                        assert_eq!(line, &KEEPALIVE_PING[..KEEPALIVE_PING.len() - 1]);
+                        stats_tx.send_modify(|s| s.1 += 1);
+                        if pong {
+                            let _ = sock.write_all(b"\n").await;
+                        }
+                    }
+                });
+            }
+        });
+        stats_rx
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn keepalive_pings_keep_idle_subscription_alive() -> Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nyx.sock");
+        let stats = spawn_subscription_daemon(tokio::net::UnixListener::bind(&path)?, true);
+        let cfg = SdkConfig {
+            daemon_endpoint: path.to_string_lossy().into_owned(),
+            keepalive_interval_ms: 40,
+            keepalive_idle_timeout_ms: 150,
+            ..Default::default()
+        };
+        let mut rx = DaemonClient::new(cfg).subscribe_events(None).await?;
+
+        // Stay idle well past the idle timeout
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        let (connections, pings) = *stats.borrow();
+        assert_eq!(connections, 1, "subscription should not have reconnected");
+        assert!(pings >= 3, "expected periodic pings, saw {pings}");
+        assert!(matches!(
+            rx.try_recv(),
+            Err(broadcast::error::TryRecvError::Empty)
+        ));
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn keepalive_stops_once_every_receiver_is_dropped() -> Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nyx.sock");
+        let stats = spawn_subscription_daemon(tokio::net::UnixListener::bind(&path)?, true);
+        let cfg = SdkConfig {
+            daemon_endpoint: path.to_string_lossy().into_owned(),
+            keepalive_interval_ms: 20,
+            keepalive_idle_timeout_ms: 200,
+            ..Default::default()
+        };
+        let rx = DaemonClient::new(cfg).subscribe_events(None).await?;
+        drop(rx);
+
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        let pings = stats.borrow().1;
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        let (connections, later) = *stats.borrow();
+        assert_eq!(connections, 1, "nobody listens, so no resubscribe");
+        assert_eq!(later, pings, "pings continued without receivers");
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn keepalive_resubscribes_when_daemon_goes_silent() -> Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nyx.sock");
+        let mut stats = spawn_subscription_daemon(tokio::net::UnixListener::bind(&path)?, false);
+        let cfg = SdkConfig {
+            daemon_endpoint: path.to_string_lossy().into_owned(),
+            keepalive_interval_ms: 20,
+            keepalive_idle_timeout_ms: 80,
+            reconnect_min_interval_ms: 10,
+            ..Default::default()
+        };
+        let mut rx = DaemonClient::new(cfg).subscribe_events(None).await?;
+
+        let ev = timeout(Duration::from_secs(2), rx.recv())
+            .await
+            .map_err(|_| Error::Timeout)?
+            .unwrap();
+        assert!(
+            ev.detail.starts_with("events_stream_reconnecting:"),
+            "{ev:?}"
+        );
+        timeout(Duration::from_secs(2), stats.wait_for(|s| s.0 >= 2))
+            .await
+            .map_err(|_| Error::Timeout)?
+            .unwrap();
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn batch_returns_per_request_outcomes_in_order() -> Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nyx.sock");
+        let listener = tokio::net::UnixListener::bind(&path)?;
+        let server = tokio::spawn(async move {
+            let (sock, _) = listener.accept().await.unwrap();
+            let mut sock = BufReader::new(sock);
+            let mut req = Vec::new();
+            read_buffered_line(&mut sock, &mut req).await.unwrap();
+            let resp = json!({"ok": true, "code": 0, "data": [
+                {"ok": true, "code": 0, "data": {"node_id": "01"}},
+                {"ok": false, "code": 401, "error": "unauthorized"}
+            ]});
+            let mut line = serde_json::to_vec(&resp).unwrap();
+            line.push(b'\n');
+            sock.write_all(&line).await.unwrap();
+            sock.flush().await.unwrap();
+            serde_json::from_slice::<serde_json::Value>(&req).unwrap()
+        });
+
+        let cfg = SdkConfig {
+            daemon_endpoint: path.to_string_lossy().into_owned(),
+            request_timeout_ms: 2000,
+            ..Default::default()
+        };
+        let got = DaemonClient::new(cfg)
+            .with_token("tok")
+            .batch(vec![
+                json!({"op": "get_info"}),
+                json!({"op": "create_config_snapshot", "auth": "other", "description": null}),
+            ])
+            .await?;
+        assert_eq!(got.len(), 2);
+        assert_eq!(got[0].as_ref().unwrap()["node_id"], "01");
+        assert!(matches!(&got[1], Err(Error::Protocol(m)) if m.contains("code=401")));
+
+        let req = server.await.unwrap();
+        assert_eq!(req["op"], "batch");
+        assert_eq!(req["requests"][0]["auth"], "tok");
+        assert_eq!(req["requests"][1]["auth"], "other");
+        Ok(())
+    }
 }
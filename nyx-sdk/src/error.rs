@@ -4,7 +4,10 @@ use thiserror::Error as ThisError;
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
+/// Errors returned by the SDK. New variants may be added in minor
+/// releases, so matches need a wildcard arm.
 #[derive(Debug, ThisError)]
+#[non_exhaustive]
 pub enum Error {
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
@@ -22,6 +25,10 @@ pub enum Error {
     Disconnected,
     #[error("not found: {0}")]
     NotFound(&'static str),
+    /// Connection attempt suppressed because the daemon recently failed to
+    /// answer; retry after the given delay.
+    #[error("backing off, retry in {0:?}")]
+    Backoff(std::time::Duration),
     /// Unsupported required capability error (CLOSE 0x07)
     ///
     /// Returned when the peer requires a capability that this endpoint does not support.
@@ -93,6 +100,7 @@ mod tests {
             Error::Timeout,
             Error::Disconnected,
             Error::NotFound("test"),
+            Error::Backoff(std::time::Duration::from_millis(10)),
             Error::UnsupportedCapability(0x1234),
         ];
     }
@@ -4,6 +4,11 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Event {
+    /// Daemon event type; the daemon sends it as `_ty`.
+    #[serde(alias = "_ty")]
     pub event_type: String,
+    /// Free-form detail; the daemon sends it as `_detail`. For
+    /// `events_dropped` it holds the number of events lost.
+    #[serde(alias = "_detail")]
     pub detail: String,
 }
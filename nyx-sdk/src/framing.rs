@@ -0,0 +1,138 @@
+#![forbid(unsafe_code)]
+
+//! Newline-delimited JSON framing for the daemon IPC connection.
+//!
+//! Responses and subscription events are one JSON document per line,
+//! terminated by `\n` (a preceding `\r` is tolerated). An empty line is a
+//! keepalive reply and carries no document.
+
+use crate::error::{Error, Result};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt};
+
+/// Longest line accepted before the peer is treated as misbehaving.
+pub const MAX_LINE: u64 = 64 * 1024;
+
+/// Read one newline-terminated line from a buffered reader, keeping any
+/// bytes past the newline for the next call. EOF is reported as an error.
+pub(crate) async fn read_buffered_line<R: AsyncBufRead + Unpin>(
+    reader: &mut R,
+    out: &mut Vec<u8>,
+) -> Result<()> {
+    out.clear();
+    let n = (&mut *reader)
+        .take(MAX_LINE + 1)
+        .read_until(b'\n', out)
+        .await
+        .map_err(|e| Error::Stream(e.to_string()))?;
+    if n == 0 {
+        return Err(Error::Stream("connection closed".into()));
+    }
+    if out.last().copied() == Some(b'\n') {
+        out.pop();
+    } else if out.len() as u64 > MAX_LINE {
+        return Err(Error::protocol("response too large"));
+    }
+    if out.last().copied() == Some(b'\r') {
+        out.pop();
+    }
+    Ok(())
+}
+
+/// Yields one parsed JSON value per line of a byte stream.
+///
+/// Lines may arrive split across any number of reads; bytes past a newline
+/// stay buffered for the next call, so each line is parsed exactly once.
+pub struct LineFramedReader<R> {
+    inner: R,
+    line: Vec<u8>,
+}
+
+impl<R: AsyncBufRead + Unpin> LineFramedReader<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            line: Vec::with_capacity(1024),
+        }
+    }
+
+    /// Parse the next line. `Ok(None)` is an empty (keepalive) line.
+    ///
+    /// # Errors
+    /// - `Error::Serde` if the line is not valid JSON; the line is consumed
+    ///   and the reader stays usable
+    /// - `Error::Stream` on EOF or I/O failure, `Error::Protocol` if a line
+    ///   exceeds [`MAX_LINE`]; the connection should be dropped
+    pub async fn next_value(&mut self) -> Result<Option<serde_json::Value>> {
+        read_buffered_line(&mut self.inner, &mut self.line).await?;
+        if self.line.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(serde_json::from_slice(&self.line)?))
+    }
+
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{duplex, AsyncWriteExt, BufReader};
+
+    #[tokio::test]
+    async fn read_buffered_line_splits_lines_without_loss() -> Result<()> {
+        let (a, mut b) = duplex(64);
+        // Write two lines; each call should return exactly one of them
+        tokio::spawn(async move {
+            let _ = b.write_all(b"{\"ok\":true}\n{\"ok\":false}\n").await;
+        });
+        let mut a = BufReader::new(a);
+        let mut buf = Vec::new();
+        read_buffered_line(&mut a, &mut buf).await?;
+        assert_eq!(String::from_utf8(buf.clone()).unwrap(), "{\"ok\":true}");
+        read_buffered_line(&mut a, &mut buf).await?;
+        assert_eq!(String::from_utf8(buf.clone()).unwrap(), "{\"ok\":false}");
+        assert!(read_buffered_line(&mut a, &mut buf).await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_buffered_line_trims_crlf() -> Result<()> {
+        let (a, mut b) = duplex(64);
+        tokio::spawn(async move {
+            let _ = b.write_all(b"{\"ok\":true}\r\n").await;
+        });
+        let mut a = BufReader::new(a);
+        let mut buf = Vec::new();
+        read_buffered_line(&mut a, &mut buf).await?;
+        let s = String::from_utf8(buf).unwrap();
+        assert_eq!(s, "{\"ok\":true}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn values_split_across_reads_are_parsed_once() -> Result<()> {
+        let stream = tokio_test::io::Builder::new()
+            .read(b"{\"type\":\"sys")
+            .read(b"tem\",\"n\":1}\r")
+            .read(b"\n\n{\"n\":2}\n{\"n\"")
+            .read(b":3}\r\nnot json\n{\"n\":4}\n")
+            .build();
+        let mut reader = LineFramedReader::new(BufReader::new(stream));
+
+        let first = reader.next_value().await?.unwrap();
+        assert_eq!(first, serde_json::json!({"type": "system", "n": 1}));
+        assert_eq!(reader.next_value().await?, None, "keepalive line");
+        assert_eq!(reader.next_value().await?.unwrap()["n"], 2);
+        assert_eq!(reader.next_value().await?.unwrap()["n"], 3);
+        assert!(matches!(reader.next_value().await, Err(Error::Serde(_))));
+        assert_eq!(reader.next_value().await?.unwrap()["n"], 4);
+        assert!(matches!(reader.next_value().await, Err(Error::Stream(_))));
+        Ok(())
+    }
+}
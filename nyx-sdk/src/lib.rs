@@ -13,6 +13,7 @@ pub mod config;
 pub mod daemon;
 pub mod error;
 pub mod events;
+pub mod framing;
 pub mod proto;
 pub mod reconnect;
 pub mod retry;
@@ -23,4 +24,5 @@ pub use daemon::DaemonClient;
 pub use error::{Error, Result};
 pub use events::Event;
 pub use proto as api;
+pub use reconnect::ReconnectPolicy;
 pub use stream::NyxStream;
@@ -5,6 +5,9 @@
 
 use serde::{Deserialize, Serialize};
 
+/// Typed `code` of daemon responses; serializes to the legacy integers.
+pub use nyx_core::rpc::DaemonErrorCode;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ServiceInfo {
     pub version: String,
@@ -1,5 +1,67 @@
 #![forbid(unsafe_code)]
 
+use std::time::Duration;
+
+/// Retry schedule used by [`DaemonClient::with_reconnect`](crate::DaemonClient::with_reconnect)
+/// when connecting to the daemon fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReconnectPolicy {
+    /// Upper bound on connect attempts, including the first one.
+    pub max_attempts: u32,
+    /// Backoff cap for the first retry; doubles on each further retry.
+    pub base_delay_ms: u64,
+    /// Ceiling for the backoff cap.
+    pub max_delay_ms: u64,
+    /// Stop retrying once the next attempt would start after this much time
+    /// has passed since the first one.
+    pub deadline: Duration,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            base_delay_ms: 100,
+            max_delay_ms: 5_000,
+            deadline: Duration::from_secs(10),
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// Delay before retry number `retry` (1-based), with full jitter: uniform
+    /// in `0..=min(max_delay_ms, base_delay_ms * 2^(retry - 1))`.
+    #[must_use]
+    pub fn delay(&self, retry: u32) -> Duration {
+        let cap = backoff_ceiling_ms(
+            retry.saturating_sub(1),
+            self.base_delay_ms,
+            self.max_delay_ms,
+        );
+        Duration::from_millis(fastrand::u64(0..=cap))
+    }
+}
+
+/// Exponential backoff ceiling for 0-based `attempt`:
+/// `min(max_ms, base_ms * 2^attempt)`, saturating instead of overflowing.
+#[must_use]
+pub fn backoff_ceiling_ms(attempt: u32, base_ms: u64, max_ms: u64) -> u64 {
+    base_ms.saturating_mul(1u64 << attempt.min(63)).min(max_ms)
+}
+
+/// Single-attempt delay with equal jitter: uniform in the upper half of
+/// [`backoff_ceiling_ms`], so it is never zero unless the ceiling is.
+#[must_use]
+pub fn calculate_delay(attempt: u32, base_ms: u64, max_ms: u64) -> Duration {
+    let capped = backoff_ceiling_ms(attempt, base_ms, max_ms);
+    if capped == 0 {
+        return Duration::from_millis(0);
+    }
+    let half = (capped / 2).max(1);
+    let jitter = fastrand::u64(0..half);
+    Duration::from_millis(half + jitter)
+}
+
 #[cfg(feature = "reconnect")]
 pub mod retry_policy {
     use std::time::Duration;
@@ -13,23 +75,7 @@ pub mod retry_policy {
             .map(jitter) // Add jitter to prevent thundering herd
     }
 
-    /// Simple exponential backoff calculation for single attempt delays.
-    /// Used for compatibility with existing code patterns.
-    #[must_use]
-    pub fn calculate_delay(attempt: u32, base_ms: u64, max_ms: u64) -> Duration {
-        // Compute 2^attempt with a safe upper bound to avoid shifting by >= 64.
-        // Use saturating multiplication so large attempts cap at max_ms below.
-        let shift = attempt.min(63);
-        let pow = 1u64 << shift;
-        let raw = base_ms.saturating_mul(pow);
-        let capped = raw.min(max_ms);
-        if capped == 0 {
-            return Duration::from_millis(0);
-        }
-        let half = (capped / 2).max(1);
-        let jitter = fastrand::u64(0..half);
-        Duration::from_millis(half + jitter)
-    }
+    pub use super::calculate_delay;
 }
 
 #[cfg(not(feature = "reconnect"))]
@@ -41,11 +87,7 @@ pub mod retry_policy {
         std::iter::empty()
     }
 
-    /// No-op delay calculation when reconnect feature is disabled.
-    #[must_use]
-    pub fn calculate_delay(_attempt: u32, _base_ms: u64, _max_ms: u64) -> Duration {
-        Duration::from_millis(0)
-    }
+    pub use super::calculate_delay;
 }
 
 // Backward compatibility alias for existing code
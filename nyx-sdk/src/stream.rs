@@ -1,8 +1,47 @@
 #![forbid(unsafe_code)]
 
 use crate::error::{Error, Result};
-use bytes::Bytes;
+use bytes::{Buf, BufMut, Bytes, BytesMut};
 use nyx_stream::{pair, AsyncStream, AsyncStreamConfig};
+use std::{io::SeekFrom, path::Path, time::Duration};
+use tokio::{
+    fs::{File, OpenOptions},
+    io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt},
+    time::timeout,
+};
+
+/// Payload bytes carried by each file-transfer message.
+pub const FILE_CHUNK_SIZE: usize = 16 * 1024;
+
+/// `recv_file` gives up when no message arrives for this long.
+pub const FILE_TRANSFER_IDLE_TIMEOUT: Duration = Duration::from_secs(10);
+
+// File-transfer message tags (first byte of each stream message)
+const TAG_HEADER: u8 = 0x01; // total: u64 BE, offset: u64 BE
+const TAG_CHUNK: u8 = 0x02; // raw file bytes
+const TAG_TRAILER: u8 = 0x03; // blake3 hash of the whole file
+
+/// Progress of a file transfer, reported after every chunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransferProgress {
+    /// Bytes of the file present at the receiver, including a resumed prefix
+    pub position: u64,
+    /// Total file size in bytes
+    pub total: u64,
+}
+
+/// Outcome of a completed file transfer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileTransferSummary {
+    /// Offset the transfer resumed from (0 for a fresh transfer)
+    pub offset: u64,
+    /// Bytes moved over the stream in this call
+    pub bytes_sent: u64,
+    /// Total file size in bytes
+    pub total: u64,
+    /// Hex blake3 hash of the whole file
+    pub hash: String,
+}
 
 /// SDK wrapper for streams. Delegates to nyx-stream's AsyncStream, providing an adapter.
 #[derive(Clone)]
@@ -67,6 +106,187 @@ impl NyxStream {
     pub fn is_closed(&self) -> bool {
         self.inner.is_closed()
     }
+
+    /// Send the file at `path` to a peer running [`recv_file`](Self::recv_file),
+    /// starting at `offset` to resume a partial transfer.
+    ///
+    /// Chunks are paced by the stream's send window, so a slow receiver
+    /// slows the sender down instead of buffering the file in memory.
+    /// The trailer carries a blake3 hash of the whole file, which the
+    /// receiver checks before reporting success.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read, `offset` is past the end
+    /// of the file, or the stream fails.
+    pub async fn send_file(
+        &mut self,
+        path: impl AsRef<Path>,
+        offset: u64,
+        mut progress: impl FnMut(TransferProgress),
+    ) -> Result<FileTransferSummary> {
+        let mut file = File::open(path.as_ref()).await?;
+        let total = file.metadata().await?.len();
+        if offset > total {
+            return Err(Error::config(format!(
+                "resume offset {offset} is past the end of the file ({total} bytes)"
+            )));
+        }
+
+        let mut hasher = blake3::Hasher::new();
+        hash_prefix(&mut file, offset, &mut hasher).await?;
+
+        let mut header = BytesMut::with_capacity(17);
+        header.put_u8(TAG_HEADER);
+        header.put_u64(total);
+        header.put_u64(offset);
+        self.send(header.freeze()).await?;
+
+        let mut position = offset;
+        let mut buf = vec![0u8; FILE_CHUNK_SIZE];
+        while position < total {
+            let n = file.read(&mut buf).await?;
+            if n == 0 {
+                return Err(Error::Stream(format!(
+                    "file shrank during transfer at {position} of {total} bytes"
+                )));
+            }
+            hasher.update(&buf[..n]);
+            let mut chunk = BytesMut::with_capacity(n + 1);
+            chunk.put_u8(TAG_CHUNK);
+            chunk.put_slice(&buf[..n]);
+            self.send(chunk.freeze()).await?;
+            position += n as u64;
+            progress(TransferProgress { position, total });
+        }
+
+        let hash = hasher.finalize();
+        let mut trailer = BytesMut::with_capacity(33);
+        trailer.put_u8(TAG_TRAILER);
+        trailer.put_slice(hash.as_bytes());
+        self.send(trailer.freeze()).await?;
+
+        Ok(FileTransferSummary {
+            offset,
+            bytes_sent: total - offset,
+            total,
+            hash: hash.to_hex().to_string(),
+        })
+    }
+
+    /// Receive a file sent with [`send_file`](Self::send_file) into `path`.
+    ///
+    /// When the sender resumes from an offset, `path` must already hold at
+    /// least that many bytes; anything beyond the offset is overwritten.
+    /// On failure the bytes written so far are kept, so the transfer can be
+    /// resumed from the local file length.
+    ///
+    /// # Errors
+    /// Returns an error if the local file is shorter than the resume offset,
+    /// a message is malformed, the peer goes quiet for
+    /// [`FILE_TRANSFER_IDLE_TIMEOUT`], or the content hash does not match.
+    pub async fn recv_file(
+        &mut self,
+        path: impl AsRef<Path>,
+        mut progress: impl FnMut(TransferProgress),
+    ) -> Result<FileTransferSummary> {
+        let header = self.recv_transfer_message().await?;
+        let (total, offset) = match &header[..] {
+            [TAG_HEADER, rest @ ..] => {
+                let mut fields = rest;
+                match (fields.try_get_u64(), fields.try_get_u64()) {
+                    (Ok(total), Ok(offset)) if fields.is_empty() => (total, offset),
+                    _ => return Err(Error::protocol("malformed file-transfer header")),
+                }
+            }
+            _ => return Err(Error::protocol("expected file-transfer header")),
+        };
+        if offset > total {
+            return Err(Error::protocol("file-transfer offset past end of file"));
+        }
+
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path.as_ref())
+            .await?;
+        let local_len = file.metadata().await?.len();
+        if local_len < offset {
+            return Err(Error::Stream(format!(
+                "cannot resume at offset {offset}: local file has {local_len} bytes"
+            )));
+        }
+        file.set_len(offset).await?;
+
+        let mut hasher = blake3::Hasher::new();
+        hash_prefix(&mut file, offset, &mut hasher).await?;
+        file.seek(SeekFrom::Start(offset)).await?;
+
+        let mut position = offset;
+        let expected = loop {
+            let msg = self.recv_transfer_message().await?;
+            match &msg[..] {
+                [TAG_CHUNK, data @ ..] => {
+                    if position + data.len() as u64 > total {
+                        return Err(Error::protocol("file-transfer chunk past end of file"));
+                    }
+                    file.write_all(data).await?;
+                    hasher.update(data);
+                    position += data.len() as u64;
+                    progress(TransferProgress { position, total });
+                }
+                [TAG_TRAILER, hash @ ..] => match blake3::Hash::from_slice(hash) {
+                    Ok(hash) => break hash,
+                    Err(_) => return Err(Error::protocol("malformed file-transfer trailer")),
+                },
+                _ => return Err(Error::protocol("unexpected file-transfer message")),
+            }
+        };
+        file.flush().await?;
+        file.sync_all().await?;
+
+        if position != total {
+            return Err(Error::Stream(format!(
+                "transfer ended at {position} of {total} bytes"
+            )));
+        }
+        let hash = hasher.finalize();
+        if hash != expected {
+            return Err(Error::protocol("file-transfer content hash mismatch"));
+        }
+        Ok(FileTransferSummary {
+            offset,
+            bytes_sent: total - offset,
+            total,
+            hash: hash.to_hex().to_string(),
+        })
+    }
+
+    /// Wait for the next message, failing after [`FILE_TRANSFER_IDLE_TIMEOUT`] of silence.
+    async fn recv_transfer_message(&mut self) -> Result<Bytes> {
+        timeout(FILE_TRANSFER_IDLE_TIMEOUT, self.inner.recv_wait())
+            .await
+            .map_err(|_| Error::Timeout)?
+            .map_err(|e| Error::Stream(e.to_string()))
+    }
+}
+
+/// Feed the first `len` bytes of `file` into `hasher`, leaving the cursor at `len`.
+async fn hash_prefix(file: &mut File, len: u64, hasher: &mut blake3::Hasher) -> Result<()> {
+    file.seek(SeekFrom::Start(0)).await?;
+    let mut remaining = len;
+    let mut buf = vec![0u8; FILE_CHUNK_SIZE];
+    while remaining > 0 {
+        let want = remaining.min(buf.len() as u64) as usize;
+        let n = file.read(&mut buf[..want]).await?;
+        if n == 0 {
+            return Err(Error::Stream("file ended before resume offset".into()));
+        }
+        hasher.update(&buf[..n]);
+        remaining -= n as u64;
+    }
+    Ok(())
 }
 
 impl Default for NyxStream {
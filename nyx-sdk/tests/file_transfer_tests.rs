@@ -0,0 +1,78 @@
+use nyx_sdk::stream::TransferProgress;
+use nyx_sdk::NyxStream;
+
+fn random_bytes(len: usize) -> Vec<u8> {
+    let mut rng = fastrand::Rng::with_seed(0x5eed);
+    (0..len).map(|_| rng.u8(..)).collect()
+}
+
+#[tokio::test]
+async fn multi_megabyte_file_round_trips_with_matching_hash(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let dir = tempfile::tempdir()?;
+    let src = dir.path().join("src.bin");
+    let dst = dir.path().join("dst.bin");
+    let content = random_bytes(3 * 1024 * 1024 + 123);
+    std::fs::write(&src, &content)?;
+
+    let (mut tx, mut rx) = NyxStream::pair(1);
+    let receiver = tokio::spawn(async move {
+        let mut last = None;
+        let summary = rx.recv_file(&dst, |p| last = Some(p)).await;
+        (summary, last, dst)
+    });
+    let sent = tx.send_file(&src, 0, |_| {}).await?;
+    let (received, last, dst) = receiver.await?;
+    let received = received?;
+
+    assert_eq!(sent.total, content.len() as u64);
+    assert_eq!(received.hash, sent.hash);
+    assert_eq!(received.hash, blake3::hash(&content).to_hex().to_string());
+    assert_eq!(
+        last,
+        Some(TransferProgress {
+            position: sent.total,
+            total: sent.total
+        })
+    );
+    assert_eq!(std::fs::read(&dst)?, content);
+    Ok(())
+}
+
+#[tokio::test]
+async fn partial_transfer_resumes_from_offset() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = tempfile::tempdir()?;
+    let src = dir.path().join("src.bin");
+    let dst = dir.path().join("dst.bin");
+    let content = random_bytes(200 * 1024);
+    std::fs::write(&src, &content)?;
+    // Receiver already holds the first part from an interrupted transfer
+    let offset = 70 * 1024;
+    std::fs::write(&dst, &content[..offset])?;
+
+    let (mut tx, mut rx) = NyxStream::pair(1);
+    let receiver = tokio::spawn(async move { rx.recv_file(&dst, |_| {}).await.map(|s| (s, dst)) });
+    let sent = tx.send_file(&src, offset as u64, |_| {}).await?;
+    let (received, dst) = receiver.await??;
+
+    assert_eq!(sent.bytes_sent, (content.len() - offset) as u64);
+    assert_eq!(received.offset, offset as u64);
+    assert_eq!(received.hash, sent.hash);
+    assert_eq!(std::fs::read(&dst)?, content);
+    Ok(())
+}
+
+#[tokio::test]
+async fn short_header_is_a_protocol_error() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = tempfile::tempdir()?;
+    let dst = dir.path().join("dst.bin");
+
+    let (mut tx, mut rx) = NyxStream::pair(1);
+    let receiver = tokio::spawn(async move { rx.recv_file(&dst, |_| {}).await });
+    // Header tag followed by only part of the total/offset fields
+    tx.send(vec![0x01, 0, 0, 0, 0]).await?;
+
+    let err = receiver.await?.unwrap_err();
+    assert!(matches!(err, nyx_sdk::Error::Protocol(_)), "{err:?}");
+    Ok(())
+}
@@ -64,3 +64,16 @@ fn backoff_policy_exponential_with_jitter_bound_s() {
         assert!(d.as_millis() > 0);
     }
 }
+
+#[test]
+fn calculate_delay_backs_off_without_reconnect_feature_s() {
+    use nyx_sdk::reconnect::calculate_delay;
+    for _ in 0..32 {
+        let d = calculate_delay(3, 10, 10_000).as_millis() as u64;
+        assert!(
+            (40..=80).contains(&d),
+            "attempt 3 from 10ms lands in [40, 80]: {d}"
+        );
+        assert_eq!(calculate_delay(20, 10, 1_000).as_millis() as u64 / 500, 1);
+    }
+}
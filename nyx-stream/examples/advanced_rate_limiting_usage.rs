@@ -198,7 +198,7 @@ impl StreamManager {
 
                 Ok(data.len())
             }
-            TransmissionDecision::RateLimited => {
+            TransmissionDecision::RateLimited { .. } => {
                 // Update statistics
                 {
                     let mut stats = self.stats.lock().await;
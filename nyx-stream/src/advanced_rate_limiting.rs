@@ -119,8 +119,17 @@ impl PriorityTokenBucket {
     }
 
     /// Attempt to consume tokens for a specific traffic type
-    #[instrument(skip(self))]
     pub fn try_consume(&mut self, traffic_type: TrafficType, tokens: u64) -> bool {
+        self.consume(traffic_type, tokens).is_ok()
+    }
+
+    /// Like [`try_consume`](Self::try_consume), but reports which bucket denied the tokens
+    #[instrument(skip(self))]
+    pub fn consume(
+        &mut self,
+        traffic_type: TrafficType,
+        tokens: u64,
+    ) -> Result<(), RateLimitBucket> {
         self.update_buckets();
 
         // First check global limit
@@ -130,7 +139,7 @@ impl PriorityTokenBucket {
                 tokens = tokens,
                 "Global rate limit exceeded"
             );
-            return false;
+            return Err(RateLimitBucket::Global);
         }
 
         // Then check priority-specific limit
@@ -141,7 +150,7 @@ impl PriorityTokenBucket {
                     tokens = tokens,
                     "Tokens consumed successfully"
                 );
-                true
+                Ok(())
             } else {
                 // Refund global tokens since priority limit was hit
                 self.global_bucket.add_tokens(tokens);
@@ -150,14 +159,23 @@ impl PriorityTokenBucket {
                     tokens = tokens,
                     "Priority rate limit exceeded"
                 );
-                false
+                Err(RateLimitBucket::Traffic(traffic_type))
             }
         } else {
+            self.global_bucket.add_tokens(tokens);
             warn!(traffic_type = ?traffic_type, "Unknown traffic type");
-            false
+            Err(RateLimitBucket::Traffic(traffic_type))
         }
     }
 
+    /// Replace the bucket for `traffic_type` with one refilling at `rate`
+    /// bytes/s up to `burst` bytes. The new bucket starts full.
+    pub fn set_rate(&mut self, traffic_type: TrafficType, rate: u64, burst: u64) {
+        self.update_buckets();
+        self.buckets
+            .insert(traffic_type, TokenBucket::new(burst, rate));
+    }
+
     /// Update all token buckets based on elapsed time
     fn update_buckets(&mut self) {
         let now = Instant::now();
@@ -593,6 +611,27 @@ impl NyxRateLimiter {
         }
     }
 
+    /// Shape `traffic_type` independently of the weights in the config:
+    /// refill at `rate` bytes/s with bursts up to `burst` bytes.
+    /// The global bandwidth limit still applies on top.
+    pub fn set_rate_for(
+        &self,
+        traffic_type: TrafficType,
+        rate: f64,
+        burst: f64,
+    ) -> Result<(), RateLimitError> {
+        if !rate.is_finite() || rate < 0.0 || !burst.is_finite() || burst < 0.0 {
+            return Err(RateLimitError::Configuration(format!(
+                "invalid rate override for {traffic_type:?}: rate={rate}, burst={burst}"
+            )));
+        }
+        self.token_buckets
+            .lock()
+            .unwrap()
+            .set_rate(traffic_type, rate as u64, burst as u64);
+        Ok(())
+    }
+
     /// Check if a transmission is allowed
     #[instrument(skip(self))]
     pub async fn check_transmission(
@@ -616,9 +655,9 @@ impl NyxRateLimiter {
         // Check token bucket availability
         {
             let mut buckets = self.token_buckets.lock().unwrap();
-            if !buckets.try_consume(traffic_type, bytes as u64) {
+            if let Err(bucket) = buckets.consume(traffic_type, bytes as u64) {
                 self.update_stats(|stats| stats.rate_limited_count += 1);
-                return Ok(TransmissionDecision::RateLimited);
+                return Ok(TransmissionDecision::RateLimited { bucket });
             }
         }
 
@@ -758,12 +797,21 @@ pub enum TransmissionDecision {
     Allowed,
     /// Transmission should be delayed by the specified duration
     Delayed(Duration),
-    /// Transmission is blocked by rate limiting
-    RateLimited,
+    /// Transmission is blocked by rate limiting; `bucket` ran out of tokens
+    RateLimited { bucket: RateLimitBucket },
     /// Transmission is blocked by flow control
     FlowControlBlocked,
 }
 
+/// Token bucket that denied a transmission
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitBucket {
+    /// The global bandwidth limit
+    Global,
+    /// The bucket of a specific traffic type
+    Traffic(TrafficType),
+}
+
 /// Rate limiter error types
 #[derive(Debug, thiserror::Error)]
 pub enum RateLimitError {
@@ -922,7 +970,50 @@ mod tests {
             .check_transmission(1, 1, TrafficType::Normal, 250)
             .await
             .unwrap();
-        assert!(matches!(decision, TransmissionDecision::RateLimited));
+        assert!(matches!(
+            decision,
+            TransmissionDecision::RateLimited {
+                bucket: RateLimitBucket::Traffic(TrafficType::Normal)
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_per_traffic_type_override_isolates_buckets() {
+        let limiter = NyxRateLimiter::new(AdvancedFlowConfig::default());
+        limiter
+            .set_rate_for(TrafficType::Normal, 1000.0, 1000.0)
+            .unwrap();
+
+        // Data bucket holds exactly one 1000-byte burst
+        let decision = limiter
+            .check_transmission(1, 1, TrafficType::Normal, 1000)
+            .await
+            .unwrap();
+        assert!(matches!(decision, TransmissionDecision::Allowed));
+        let decision = limiter
+            .check_transmission(1, 1, TrafficType::Normal, 1000)
+            .await
+            .unwrap();
+        assert!(matches!(
+            decision,
+            TransmissionDecision::RateLimited {
+                bucket: RateLimitBucket::Traffic(TrafficType::Normal)
+            }
+        ));
+
+        // Control traffic is shaped by its own bucket and still passes
+        for _ in 0..3 {
+            let decision = limiter
+                .check_transmission(1, 0, TrafficType::Control, 1000)
+                .await
+                .unwrap();
+            assert!(matches!(decision, TransmissionDecision::Allowed));
+        }
+
+        assert!(limiter
+            .set_rate_for(TrafficType::Background, f64::NAN, 10.0)
+            .is_err());
     }
 
     #[tokio::test]
@@ -11,11 +11,17 @@ use crate::{
     multipath_dataplane::MultipathConfig,
 };
 use bytes::{Bytes, BytesMut};
-use std::{collections::BTreeMap, time::Duration};
+use std::{
+    collections::{BTreeMap, VecDeque},
+    sync::{Arc, Mutex},
+    task::{ready, Context, Poll},
+    time::Duration,
+};
 use tokio::{
-    sync::{mpsc, oneshot},
-    time::{sleep, Instant},
+    sync::{mpsc, oneshot, Notify, OwnedSemaphorePermit, Semaphore},
+    time::Instant,
 };
+use tokio_util::sync::PollSemaphore;
 
 /// Configuration for AsyncStream instances
 /// This struct provides comprehensive control over stream behavior including:
@@ -23,6 +29,7 @@ use tokio::{
 /// - Optional frame reordering for testing network conditions
 /// - Multipath routing configuration for load balancing and redundancy
 /// - Receiver buffer management to prevent memory exhaustion
+/// - Receive-window backpressure towards a peer whose reader is slow
 #[derive(Debug, Clone)]
 pub struct AsyncStreamConfig {
     pub stream_id: u32,
@@ -43,6 +50,10 @@ pub struct AsyncStreamConfig {
     pub cmix_config: Option<CmixConfig>,
     /// Optional multipath data plane configuration (LARMix++)
     pub multipath_dataplane_config: Option<MultipathConfig>,
+    /// Optional receive window (number of delivered-but-unread frames).
+    /// If Some(n), ACKs for frames beyond n are withheld until the reader drains,
+    /// which stalls the peer's writer once its send window is exhausted.
+    pub recv_window: Option<usize>,
 }
 
 impl Default for AsyncStreamConfig {
@@ -58,6 +69,7 @@ impl Default for AsyncStreamConfig {
             max_reorder_pending: Some(4096), // Increased from 2048 for better buffering
             cmix_config: None,               // Disabled by default
             multipath_dataplane_config: None, // Disabled by default
+            recv_window: None,               // Unbounded by default
         }
     }
 }
@@ -66,6 +78,7 @@ impl Default for AsyncStreamConfig {
 enum Cmd {
     Send {
         data: Bytes,
+        credit: OwnedSemaphorePermit,
         ack: oneshot::Sender<()>,
     },
     Recv {
@@ -85,9 +98,34 @@ enum LinkMsg {
     Close,
 }
 
-#[derive(Debug, Clone)]
+/// Handle to a stream endpoint.
+///
+/// Every unacknowledged frame holds one credit of the send window
+/// (`max_inflight`). Once the window is exhausted, [`AsyncStream::poll_ready`]
+/// returns `Poll::Pending` (and [`AsyncStream::send`] waits) until an ACK
+/// frees a credit. `send` additionally waits while the congestion window,
+/// which halves on every loss, is full, so fewer frames are in flight after
+/// a loss than `max_inflight` allows.
+#[derive(Debug)]
 pub struct AsyncStream {
     tx: mpsc::Sender<Cmd>,
+    credits: PollSemaphore,
+    reserved: Mutex<Option<OwnedSemaphorePermit>>,
+    /// Signalled by the endpoint task whenever the receive queue may have changed.
+    rx_ready: Arc<Notify>,
+}
+
+impl Clone for AsyncStream {
+    /// Clones share the stream and its send window; a credit reserved via
+    /// `poll_ready` stays with the original handle.
+    fn clone(&self) -> Self {
+        Self {
+            tx: self.tx.clone(),
+            credits: self.credits.clone(),
+            reserved: Mutex::new(None),
+            rx_ready: self.rx_ready.clone(),
+        }
+    }
 }
 
 impl AsyncStream {
@@ -97,15 +135,75 @@ impl AsyncStream {
         let (cmd_tx, cmd_rx) = mpsc::channel::<Cmd>(256); // Increased from 128 for better batching
         let (wire_tx, _wire_rx) = mpsc::channel::<LinkMsg>(2048); // Increased from 1024 for high-throughput
         let (_wire_back_tx, wire_back_rx) = mpsc::channel::<LinkMsg>(2048); // Matched for symmetry
-        tokio::spawn(endpoint_task(config, cmd_rx, wire_tx, wire_back_rx));
+        let credits = send_window(&config);
+        let rx_ready = Arc::new(Notify::new());
+        tokio::spawn(endpoint_task(
+            config,
+            cmd_rx,
+            wire_tx,
+            wire_back_rx,
+            credits.clone(),
+            rx_ready.clone(),
+        ));
+
+        AsyncStream::from_parts(cmd_tx, credits, rx_ready)
+    }
 
-        AsyncStream { tx: cmd_tx }
+    fn from_parts(tx: mpsc::Sender<Cmd>, credits: Arc<Semaphore>, rx_ready: Arc<Notify>) -> Self {
+        Self {
+            tx,
+            credits: PollSemaphore::new(credits),
+            reserved: Mutex::new(None),
+            rx_ready,
+        }
+    }
+
+    /// Poll for room in the send window, reserving one credit for the next
+    /// [`send`](Self::send) on this handle. Returns `Poll::Pending` while every
+    /// credit is held by an unacknowledged frame and wakes once one is freed.
+    pub fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        let reserved = self.reserved.get_mut().unwrap_or_else(|e| e.into_inner());
+        if reserved.is_none() {
+            match ready!(self.credits.poll_acquire(cx)) {
+                Some(permit) => *reserved = Some(permit),
+                None => return Poll::Ready(Err(Error::ChannelClosed)),
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    /// Wait until the send window has room; see [`poll_ready`](Self::poll_ready).
+    pub async fn ready(&mut self) -> Result<()> {
+        std::future::poll_fn(|cx| self.poll_ready(cx)).await
+    }
+
+    /// Number of frames that can currently be sent without waiting.
+    pub fn available_credits(&self) -> usize {
+        self.credits.available_permits()
     }
 
     pub async fn send(&self, data: Bytes) -> Result<()> {
+        let reserved = self
+            .reserved
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take();
+        let credit = match reserved {
+            Some(permit) => permit,
+            None => self
+                .credits
+                .clone_inner()
+                .acquire_owned()
+                .await
+                .map_err(|_| Error::ChannelClosed)?,
+        };
         let (tx, rx) = oneshot::channel();
         self.tx
-            .send(Cmd::Send { data, ack: tx })
+            .send(Cmd::Send {
+                data,
+                credit,
+                ack: tx,
+            })
             .await
             .map_err(|_| Error::ChannelClosed)?;
         rx.await.map_err(|_| Error::ChannelClosed)?;
@@ -121,6 +219,26 @@ impl AsyncStream {
         rx.await.map_err(|_| Error::ChannelClosed)
     }
 
+    /// Wait until a message is queued and return it.
+    ///
+    /// Unlike [`recv`](Self::recv), an empty queue does not yield `None`;
+    /// the call parks until the endpoint task queues data. It fails with
+    /// `ChannelClosed` once the endpoint has shut down. A peer that closes
+    /// without the local side closing leaves the call waiting, so callers
+    /// should bound it with a timeout.
+    pub async fn recv_wait(&self) -> Result<Bytes> {
+        loop {
+            // Register before checking so a message queued in between still wakes us
+            let notified = self.rx_ready.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+            if let Some(msg) = self.recv().await? {
+                return Ok(msg);
+            }
+            notified.await;
+        }
+    }
+
     /// Non-blocking receive: Returns Some if data is queued, None otherwise (or if stream is closed).
     /// This method enables efficient polling-based consumption patterns without blocking the async runtime.
     pub async fn try_recv(&self) -> Result<Option<Bytes>> {
@@ -171,20 +289,50 @@ pub fn pair(cfg_a: AsyncStreamConfig, mut cfg_b: AsyncStreamConfig) -> (AsyncStr
     let (wire_ab_tx, wire_ab_rx) = mpsc::channel::<LinkMsg>(1024);
     let (wire_ba_tx, wire_ba_rx) = mpsc::channel::<LinkMsg>(1024);
 
+    let credits_a = send_window(&cfg_a);
+    let credits_b = send_window(&cfg_b);
+    let rx_ready_a = Arc::new(Notify::new());
+    let rx_ready_b = Arc::new(Notify::new());
+
     tokio::spawn(endpoint_task(
         cfg_a,
         cmd_a_rx,
         wire_ab_tx.clone(),
         wire_ba_rx,
+        credits_a.clone(),
+        rx_ready_a.clone(),
     ));
     tokio::spawn(endpoint_task(
         cfg_b,
         cmd_b_rx,
         wire_ba_tx.clone(),
         wire_ab_rx,
+        credits_b.clone(),
+        rx_ready_b.clone(),
     ));
 
-    (AsyncStream { tx: cmd_a_tx }, AsyncStream { tx: cmd_b_tx })
+    (
+        AsyncStream::from_parts(cmd_a_tx, credits_a, rx_ready_a),
+        AsyncStream::from_parts(cmd_b_tx, credits_b, rx_ready_b),
+    )
+}
+
+/// Send-window credits: one per frame allowed in flight.
+fn send_window(config: &AsyncStreamConfig) -> Arc<Semaphore> {
+    Arc::new(Semaphore::new(config.max_inflight.max(1)))
+}
+
+/// A write waiting for room in the congestion window; close is queued in
+/// the same order so it never overtakes data.
+enum Outgoing {
+    Data {
+        data: Bytes,
+        credit: OwnedSemaphorePermit,
+        ack: oneshot::Sender<()>,
+    },
+    Close {
+        ack: oneshot::Sender<()>,
+    },
 }
 
 /// Internal tracking structure for transmitted frames awaiting acknowledgment.
@@ -196,6 +344,8 @@ struct TxEntry {
     last_sent: Instant,
     retries: u32,
     last_path: PathId,
+    /// Send-window credit, returned to the writer when the entry is acked.
+    _credit: OwnedSemaphorePermit,
 }
 
 async fn endpoint_task(
@@ -203,17 +353,23 @@ async fn endpoint_task(
     mut cmds: mpsc::Receiver<Cmd>,
     wire_tx: mpsc::Sender<LinkMsg>,
     mut wire_rx: mpsc::Receiver<LinkMsg>,
+    credits: Arc<Semaphore>,
+    rx_ready: Arc<Notify>,
 ) {
     let mut next_seq: u64 = 1;
     let mut inflight: BTreeMap<u64, TxEntry> = BTreeMap::new();
     let mut flow = FlowController::new(config.max_inflight, config.max_inflight * 4);
     let mut rtt = RttEstimator::new(config.retransmit_timeout);
-    let mut rx_queue: std::collections::VecDeque<Bytes> = Default::default();
+    let mut rx_queue: VecDeque<Bytes> = Default::default();
+    // ACKs withheld while rx_queue exceeds recv_window: (seq, path)
+    let mut deferred_acks: VecDeque<(u64, u8)> = VecDeque::new();
     let mut pending_rx: BTreeMap<u64, Bytes> = BTreeMap::new();
     let mut expected_rx_seq: u64 = 1;
     let mut closed_local = false;
     let mut closed_remote = false;
     let mut reorder_buf: Vec<(BytesMut, PathId)> = Vec::new();
+    // Writes accepted from the handle but not yet on the wire
+    let mut outbox: VecDeque<Outgoing> = VecDeque::new();
 
     // Initialize cMix integration if configured
     let cmix_manager = if let Some(cmix_config) = config.cmix_config.clone() {
@@ -241,7 +397,7 @@ async fn endpoint_task(
         .map(|s| s.retransmit_on_new_path)
         .unwrap_or(false);
 
-    // Non-blocking recv: no waiter queue; SDK polls via try_recv/recv loop
+    // recv/try_recv never block; recv_wait parks on rx_ready until data is queued
 
     // Periodic timer to check retransmit timeouts even if idle
     let mut rto_tick = tokio::time::interval(config.retransmit_timeout / 2);
@@ -294,113 +450,21 @@ async fn endpoint_task(
             // Commands first to avoid starvation
             Some(cmd) = cmds.recv() => {
                 match cmd {
-                    Cmd::Send { data, ack } => {
-                        // Early exit if stream is already closed locally
-                        if closed_local {
-                            let _ = ack.send(());
-                            continue;
-                        }
-
-                        // Apply backpressure by waiting if flow control window is full
-                        while !flow.can_send(inflight.len()) {
-                            sleep(Duration::from_millis(1)).await;
-                        }
-
-                        // Enforce maximum frame length limit if configured
-                        if let Some(limit) = config.max_frame_len {
-                            if data.len() > limit {
-                                let _ = ack.send(());
-                                continue;
-                            }
-                        }
-
-                        // Create data frame with monotonically increasing sequence number
-                        let frame = Frame::data(config.stream_id, next_seq, data);
-                        next_seq += 1;
-
-                        // Process frame through cMix if enabled
-                        if let Some(ref cmix_manager) = cmix_manager {
-                            if let Err(e) = cmix_manager.process_frame(frame.clone()).await {
-                                tracing::warn!("cMix processing failed for frame {}: {}", frame.header.seq, e);
-                                // Continue with normal processing even if cMix fails
-                            }
-                        }
-
-                        // Select optimal path for this frame (multipath load balancing)
-                        let selected_path = mpr.as_mut()
-                            .map(|s| s.pick_path())
-                            .unwrap_or(PathId(0));
-
-                        // Encode frame and handle optional reordering for network simulation
-                        let mut buf = BytesMut::new();
-                        if FrameCodec::encode(&frame, &mut buf).is_ok() {
-                            if let Some(n) = config.reorder_window {
-                                // Buffer frames and emit in reverse order for testing
-                                reorder_buf.push((buf, selected_path));
-                                if reorder_buf.len() >= n {
-                                    // Flush buffered frames in reverse order
-                                    while let Some((b, path)) = reorder_buf.pop() {
-                                        let _ = wire_tx.send(LinkMsg::Wire {
-                                            bytes: b,
-                                            path: path.0
-                                        }).await;
-                                    }
-                                }
-                            } else {
-                                // Direct transmission without reordering
-                                let _ = wire_tx.send(LinkMsg::Wire {
-                                    bytes: buf,
-                                    path: selected_path.0
-                                }).await;
-                            }
-                        }
-
-                        // Track frame for retransmission and acknowledgment handling
-                        inflight.insert(frame.header.seq, TxEntry {
-                            frame,
-                            last_sent: Instant::now(),
-                            retries: 0,
-                            last_path: selected_path
-                        });
-                        let _ = ack.send(());
+                    Cmd::Send { data, credit, ack } => {
+                        // Transmitted below once the congestion window has room
+                        outbox.push_back(Outgoing::Data { data, credit, ack });
                     }
-                    Cmd::Recv { reply } => {
-                        if let Some(b) = rx_queue.pop_front() {
-                            let _ = reply.send(Some(b));
-                        } else {
-                            let _ = reply.send(None);
-                        }
-                    }
-                    Cmd::TryRecv { reply } => {
-                        if let Some(b) = rx_queue.pop_front() {
-                            let _ = reply.send(Some(b));
-                        } else {
-                            let _ = reply.send(None);
-                        }
-                    }
-                    Cmd::Close { ack } => {
-                        if !closed_local {
-                            let close = Frame { header: FrameHeader { stream_id: config.stream_id, seq: next_seq, ty: FrameType::Close }, payload: vec![] };
-                            let mut buf = BytesMut::new();
-                            if FrameCodec::encode(&close, &mut buf).is_ok() {
-                                if config.reorder_window.is_some() {
-                                    // Flush any remaining buffered frames first in reverse
-                                    while let Some((b, path)) = reorder_buf.pop() {
-                                        let _ = wire_tx.send(LinkMsg::Wire { bytes: b, path: path.0 }).await;
-                                    }
-                                    let path = mpr.as_mut().map(|s| s.pick_path()).unwrap_or(PathId(0));
-                                    let _ = wire_tx.send(LinkMsg::Wire { bytes: buf, path: path.0 }).await;
-                                } else {
-                                    let path = mpr.as_mut().map(|s| s.pick_path()).unwrap_or(PathId(0));
-                                    let _ = wire_tx.send(LinkMsg::Wire { bytes: buf, path: path.0 }).await;
-                                }
+                    Cmd::Recv { reply } | Cmd::TryRecv { reply } => {
+                        let _ = reply.send(rx_queue.pop_front());
+                        // Reader made room: release ACKs now back inside the window
+                        if let Some(window) = config.recv_window {
+                            while rx_queue.len() < window + deferred_acks.len() {
+                                let Some((seq, path)) = deferred_acks.pop_front() else { break };
+                                send_ack(&wire_tx, config.stream_id, seq, path).await;
                             }
                         }
-                        // Send close across all paths to ensure peer sees it
-                        let _ = wire_tx.send(LinkMsg::Close).await;
-                        let _ = ack.send(());
-                        closed_local = true;
                     }
+                    Cmd::Close { ack } => outbox.push_back(Outgoing::Close { ack }),
                 }
             }
             _ = rto_tick.tick() => {
@@ -423,8 +487,16 @@ async fn endpoint_task(
 
                                 match frame.header.ty {
                                     FrameType::Data => {
+                                let seq = frame.header.seq;
+                                if seq < expected_rx_seq {
+                                    // Retransmitted duplicate: re-ack unless its ACK is being withheld
+                                    if !deferred_acks.iter().any(|&(s, _)| s == seq) {
+                                        send_ack(&wire_tx, config.stream_id, seq, path).await;
+                                    }
+                                    continue;
+                                }
                                 // Queue payload out-of-order and ack
-                                pending_rx.insert(frame.header.seq, Bytes::from(frame.payload));
+                                pending_rx.insert(seq, Bytes::from(frame.payload));
                                 // Optionally cap pending_rx size
                                 if let Some(cap) = config.max_reorder_pending {
                                     if pending_rx.len() > cap {
@@ -436,10 +508,15 @@ async fn endpoint_task(
                                     rx_queue.push_back(b);
                                     expected_rx_seq += 1;
                                 }
-                                // non-blocking receive: consumer will poll
-                                let ack = Frame { header: FrameHeader { stream_id: config.stream_id, seq: frame.header.seq, ty: FrameType::Ack }, payload: vec![] };
-                                let mut buf = BytesMut::new();
-                                if FrameCodec::encode(&ack, &mut buf).is_ok() { let _ = wire_tx.send(LinkMsg::Wire { bytes: buf, path }).await; }
+                                rx_ready.notify_waiters();
+                                let over_window = config.recv_window.is_some_and(|w| {
+                                    seq < expected_rx_seq && rx_queue.len() > w + deferred_acks.len()
+                                });
+                                if over_window {
+                                    deferred_acks.push_back((seq, path));
+                                } else {
+                                    send_ack(&wire_tx, config.stream_id, seq, path).await;
+                                }
                             }
                             FrameType::Ack => {
                                 // Slide window and grow
@@ -498,11 +575,164 @@ async fn endpoint_task(
             }
         }
 
+        // Transmit queued writes in order while the congestion window (which
+        // on_loss shrinks and on_ack grows) leaves room for more in flight
+        let closing = outbox.iter().any(|o| matches!(o, Outgoing::Close { .. }));
+        while let Some(next) = outbox.pop_front() {
+            match next {
+                Outgoing::Data { data, credit, ack } => {
+                    // Early exit if stream is already closed locally
+                    if closed_local {
+                        let _ = ack.send(());
+                        continue;
+                    }
+
+                    // Hold data while the window is full, unless a close is
+                    // queued behind it and must not wait on lost ACKs
+                    if !closing && !flow.can_send(inflight.len()) {
+                        outbox.push_front(Outgoing::Data { data, credit, ack });
+                        break;
+                    }
+
+                    // Enforce maximum frame length limit if configured
+                    if let Some(limit) = config.max_frame_len {
+                        if data.len() > limit {
+                            let _ = ack.send(());
+                            continue;
+                        }
+                    }
+
+                    // Create data frame with monotonically increasing sequence number
+                    let frame = Frame::data(config.stream_id, next_seq, data);
+                    next_seq += 1;
+
+                    // Process frame through cMix if enabled
+                    if let Some(ref cmix_manager) = cmix_manager {
+                        if let Err(e) = cmix_manager.process_frame(frame.clone()).await {
+                            tracing::warn!(
+                                "cMix processing failed for frame {}: {}",
+                                frame.header.seq,
+                                e
+                            );
+                            // Continue with normal processing even if cMix fails
+                        }
+                    }
+
+                    // Select optimal path for this frame (multipath load balancing)
+                    let selected_path = mpr.as_mut().map(|s| s.pick_path()).unwrap_or(PathId(0));
+
+                    // Encode frame and handle optional reordering for network simulation
+                    let mut buf = BytesMut::new();
+                    if FrameCodec::encode(&frame, &mut buf).is_ok() {
+                        if let Some(n) = config.reorder_window {
+                            // Buffer frames and emit in reverse order for testing
+                            reorder_buf.push((buf, selected_path));
+                            if reorder_buf.len() >= n {
+                                // Flush buffered frames in reverse order
+                                while let Some((b, path)) = reorder_buf.pop() {
+                                    let _ = wire_tx
+                                        .send(LinkMsg::Wire {
+                                            bytes: b,
+                                            path: path.0,
+                                        })
+                                        .await;
+                                }
+                            }
+                        } else {
+                            // Direct transmission without reordering
+                            let _ = wire_tx
+                                .send(LinkMsg::Wire {
+                                    bytes: buf,
+                                    path: selected_path.0,
+                                })
+                                .await;
+                        }
+                    }
+
+                    // Track frame for retransmission and acknowledgment handling
+                    inflight.insert(
+                        frame.header.seq,
+                        TxEntry {
+                            frame,
+                            last_sent: Instant::now(),
+                            retries: 0,
+                            last_path: selected_path,
+                            _credit: credit,
+                        },
+                    );
+                    let _ = ack.send(());
+                }
+                Outgoing::Close { ack } => {
+                    if !closed_local {
+                        let close = Frame {
+                            header: FrameHeader {
+                                stream_id: config.stream_id,
+                                seq: next_seq,
+                                ty: FrameType::Close,
+                            },
+                            payload: vec![],
+                        };
+                        let mut buf = BytesMut::new();
+                        if FrameCodec::encode(&close, &mut buf).is_ok() {
+                            if config.reorder_window.is_some() {
+                                // Flush any remaining buffered frames first in reverse
+                                while let Some((b, path)) = reorder_buf.pop() {
+                                    let _ = wire_tx
+                                        .send(LinkMsg::Wire {
+                                            bytes: b,
+                                            path: path.0,
+                                        })
+                                        .await;
+                                }
+                                let path = mpr.as_mut().map(|s| s.pick_path()).unwrap_or(PathId(0));
+                                let _ = wire_tx
+                                    .send(LinkMsg::Wire {
+                                        bytes: buf,
+                                        path: path.0,
+                                    })
+                                    .await;
+                            } else {
+                                let path = mpr.as_mut().map(|s| s.pick_path()).unwrap_or(PathId(0));
+                                let _ = wire_tx
+                                    .send(LinkMsg::Wire {
+                                        bytes: buf,
+                                        path: path.0,
+                                    })
+                                    .await;
+                            }
+                        }
+                    }
+                    // Send close across all paths to ensure peer sees it
+                    let _ = wire_tx.send(LinkMsg::Close).await;
+                    let _ = ack.send(());
+                    closed_local = true;
+                }
+            }
+        }
+
         if closed_local && closed_remote {
             break;
         }
     }
-    // non-blocking: nothing to wake
+    // Wake writers waiting on the send window and readers parked in
+    // recv_wait; both observe ChannelClosed
+    credits.close();
+    rx_ready.notify_waiters();
+}
+
+async fn send_ack(wire_tx: &mpsc::Sender<LinkMsg>, stream_id: u32, seq: u64, path: u8) {
+    let ack = Frame {
+        header: FrameHeader {
+            stream_id,
+            seq,
+            ty: FrameType::Ack,
+        },
+        payload: vec![],
+    };
+    let mut buf = BytesMut::new();
+    if FrameCodec::encode(&ack, &mut buf).is_ok() {
+        let _ = wire_tx.send(LinkMsg::Wire { bytes: buf, path }).await;
+    }
 }
 
 // For now we tag frames with a path id but share a single simulated link channel.
@@ -543,6 +773,89 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn writer_pends_until_slow_reader_drains() -> Result<(), Box<dyn std::error::Error>> {
+        let ca = AsyncStreamConfig {
+            max_inflight: 1,
+            ..Default::default()
+        };
+        let cb = AsyncStreamConfig {
+            recv_window: Some(1),
+            ..Default::default()
+        };
+        let (mut a, b) = pair(ca, cb);
+
+        // m0 fits the reader's window and is acked; m1 is buffered but its ACK withheld.
+        a.send(Bytes::from_static(b"m0")).await?;
+        tokio::time::timeout(Duration::from_secs(1), a.ready()).await??;
+        a.send(Bytes::from_static(b"m1")).await?;
+
+        // Window exhausted: the writer stays pending (across retransmit timeouts).
+        assert!(
+            tokio::time::timeout(Duration::from_millis(500), a.ready())
+                .await
+                .is_err(),
+            "writer should block while the reader is not draining"
+        );
+        assert_eq!(a.available_credits(), 0);
+
+        // Draining one frame releases the withheld ACK and wakes the writer.
+        assert_eq!(b.recv().await?.as_deref(), Some(&b"m0"[..]));
+        tokio::time::timeout(Duration::from_secs(1), a.ready()).await??;
+        assert_eq!(b.recv().await?.as_deref(), Some(&b"m1"[..]));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn loss_shrinks_what_may_be_in_flight() -> Result<(), Box<dyn std::error::Error>> {
+        let ca = AsyncStreamConfig {
+            max_inflight: 4,
+            ..Default::default()
+        };
+        let cb = AsyncStreamConfig {
+            recv_window: Some(1),
+            ..Default::default()
+        };
+        let (mut a, b) = pair(ca, cb);
+
+        // m1's ACK is withheld, so it times out repeatedly and halves the window to 1.
+        a.send(Bytes::from_static(b"m0")).await?;
+        tokio::time::timeout(Duration::from_secs(1), a.ready()).await??;
+        a.send(Bytes::from_static(b"m1")).await?;
+        tokio::time::sleep(Duration::from_millis(1000)).await;
+
+        // Credits remain, but the shrunken window keeps m2 off the wire.
+        assert_eq!(a.available_credits(), 3);
+        let writer = tokio::spawn(async move { a.send(Bytes::from_static(b"m2")).await });
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert!(
+            !writer.is_finished(),
+            "send must wait for the congestion window"
+        );
+
+        // Draining releases m1's ACK, which reopens the window for m2.
+        assert_eq!(b.recv().await?.as_deref(), Some(&b"m0"[..]));
+        tokio::time::timeout(Duration::from_secs(1), writer).await???;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn recv_wait_parks_until_data_arrives() -> Result<(), Box<dyn std::error::Error>> {
+        let (a, b) = pair(AsyncStreamConfig::default(), AsyncStreamConfig::default());
+        let reader = tokio::spawn(async move { b.recv_wait().await });
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(
+            !reader.is_finished(),
+            "empty queue must not resolve recv_wait"
+        );
+        a.send(Bytes::from_static(b"late")).await?;
+
+        let got = tokio::time::timeout(Duration::from_secs(1), reader).await???;
+        assert_eq!(&got[..], b"late");
+        Ok(())
+    }
+
     #[tokio::test]
     async fn close_propagates() -> Result<(), Box<dyn std::error::Error>> {
         let (a, b) = pair(AsyncStreamConfig::default(), AsyncStreamConfig::default());
@@ -139,6 +139,58 @@ pub fn negotiate(local_supported: &[u32], peer_caps: &[Capability]) -> Result<()
     Ok(())
 }
 
+/// Outcome of a dry-run negotiation, see [`preview`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NegotiationOutcome {
+    /// Capability ids advertised by both sides, in remote order
+    pub negotiated: Vec<u32>,
+    /// Ids the remote requires but we lack; we would close the session
+    pub unsupported_required: Vec<u32>,
+    /// Ids we require but the remote lacks; the remote would close the session
+    pub unsupported_by_peer: Vec<u32>,
+}
+
+impl NegotiationOutcome {
+    /// Whether both sides would accept the session
+    pub fn would_succeed(&self) -> bool {
+        self.unsupported_required.is_empty() && self.unsupported_by_peer.is_empty()
+    }
+
+    /// CLOSE reason we would send, identical to what a failed [`negotiate`]
+    /// produces via `build_close_unsupported_cap`
+    pub fn close_reason(&self) -> Option<Vec<u8>> {
+        self.unsupported_required
+            .first()
+            .map(|&id| crate::management::build_close_unsupported_cap(id))
+    }
+}
+
+/// Compute what negotiating `local` against `remote` would yield, without
+/// side effects. Unlike [`negotiate`], every unsupported required capability
+/// is reported, in both directions.
+pub fn preview(local: &[Capability], remote: &[Capability]) -> NegotiationOutcome {
+    let local_ids: HashSet<u32> = local.iter().map(|c| c.id).collect();
+    let remote_ids: HashSet<u32> = remote.iter().map(|c| c.id).collect();
+
+    NegotiationOutcome {
+        negotiated: remote
+            .iter()
+            .filter(|c| local_ids.contains(&c.id))
+            .map(|c| c.id)
+            .collect(),
+        unsupported_required: remote
+            .iter()
+            .filter(|c| c.is_required() && !local_ids.contains(&c.id))
+            .map(|c| c.id)
+            .collect(),
+        unsupported_by_peer: local
+            .iter()
+            .filter(|c| c.is_required() && !remote_ids.contains(&c.id))
+            .map(|c| c.id)
+            .collect(),
+    }
+}
+
 /// Get local capabilities that should be advertised to peers
 pub fn get_local_capabilities() -> Vec<Capability> {
     vec![
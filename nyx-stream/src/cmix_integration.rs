@@ -19,9 +19,10 @@ use nyx_mix::{
     cmix::{Batcher, CmixError, VerifiedBatch},
     vdf::{SecureVdf, VdfConfig, VdfError, VdfOutput},
 };
+use rand::{rngs::OsRng, Rng};
 use std::collections::{HashMap, VecDeque};
 use std::time::{Duration, Instant};
-use tokio::sync::{mpsc, RwLock};
+use tokio::sync::{mpsc, Mutex, RwLock};
 use tracing::{debug, info, warn};
 
 /// cMix integration errors
@@ -84,6 +85,10 @@ pub struct CmixConfig {
     pub vdf_config: VdfConfig,
     /// Enable accumulator proofs
     pub enable_accumulator_proofs: bool,
+    /// Random release delay applied to each batch, uniform in `[0, 2 * jitter]`
+    /// (mean `jitter`). Masks the otherwise predictable emission times of the
+    /// deterministic VDF. Zero disables jitter.
+    pub batch_jitter: Duration,
 }
 
 impl Default for CmixConfig {
@@ -97,6 +102,7 @@ impl Default for CmixConfig {
             network_timeout: Duration::from_secs(30),
             vdf_config: VdfConfig::default(),
             enable_accumulator_proofs: true,
+            batch_jitter: Duration::ZERO,
         }
     }
 }
@@ -116,6 +122,8 @@ pub struct BatchState {
     pub accumulator_witness: Option<Vec<u8>>,
     /// Frame contents
     pub frames: Vec<Frame>,
+    /// Time the batch was released for transmission (after jitter)
+    pub released_at: Option<Instant>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -153,6 +161,12 @@ pub struct CmixStats {
     pub errors: u64,
     /// Average batch processing time
     pub avg_batch_time: Duration,
+    /// Batches whose release time was jittered
+    pub jittered_batches: u64,
+    /// Signed offset of the most recent release from `ready + jitter`, in microseconds
+    pub last_batch_jitter_us: i64,
+    /// Sum of absolute jitter applied across batches
+    pub total_abs_jitter: Duration,
 }
 
 /// Main cMix integration manager
@@ -172,6 +186,9 @@ pub struct CmixIntegrationManager {
     frame_queue: RwLock<VecDeque<Frame>>,
     /// Statistics
     stats: RwLock<CmixStats>,
+    /// Latest release deadline handed out; later batches never get an
+    /// earlier one, so jitter cannot reorder them
+    last_release: Mutex<Option<Instant>>,
     /// Communication channels
     batch_sender: mpsc::UnboundedSender<VerifiedBatch>,
     #[allow(dead_code)]
@@ -218,6 +235,7 @@ impl CmixIntegrationManager {
             active_batches: RwLock::new(HashMap::new()),
             frame_queue: RwLock::new(VecDeque::new()),
             stats: RwLock::new(CmixStats::default()),
+            last_release: Mutex::new(None),
             batch_sender,
             frame_receiver: RwLock::new(None),
         })
@@ -301,6 +319,7 @@ impl CmixIntegrationManager {
             vdf_proof: None,
             accumulator_witness: None,
             frames: frames.clone(),
+            released_at: None,
         };
 
         // Store batch state
@@ -369,13 +388,65 @@ impl CmixIntegrationManager {
             }
         }
 
+        self.release_batch(batch_id, verified_batch).await;
+
+        info!("cMix batch {} ready for transmission", batch_id);
+        Ok(batch_id)
+    }
+
+    /// Wait out the batch's jittered release time, then hand it off for
+    /// transmission. With jitter `j` the deadline is `ready + j + u` for `u`
+    /// uniform in `[-j, j]`, i.e. uniform over `[ready, ready + 2j]`, moved up
+    /// to the previous batch's deadline if that is later. The deadline is
+    /// reserved under `last_release`, which is released before sleeping so
+    /// batches wait concurrently.
+    async fn release_batch(&self, batch_id: u64, verified_batch: VerifiedBatch) {
+        let jitter = self.config.batch_jitter;
+        let (release_at, offset_us) = {
+            let mut last_release = self.last_release.lock().await;
+            let ready = Instant::now();
+            let (release_at, offset_us) = if jitter.is_zero() {
+                (ready, 0i64)
+            } else {
+                let max_us = i64::try_from(jitter.as_micros()).unwrap_or(i64::MAX / 2);
+                let offset_us = OsRng.gen_range(-max_us..=max_us);
+                let nominal = ready + jitter;
+                let drawn = if offset_us >= 0 {
+                    nominal + Duration::from_micros(offset_us.unsigned_abs())
+                } else {
+                    nominal - Duration::from_micros(offset_us.unsigned_abs())
+                };
+                let release_at = last_release.map_or(drawn, |last| drawn.max(last));
+                let applied_us = if release_at >= nominal {
+                    i64::try_from((release_at - nominal).as_micros()).unwrap_or(i64::MAX)
+                } else {
+                    -i64::try_from((nominal - release_at).as_micros()).unwrap_or(i64::MAX)
+                };
+                (release_at, applied_us)
+            };
+            *last_release = Some(release_at);
+            (release_at, offset_us)
+        };
+
+        tokio::time::sleep_until(release_at.into()).await;
+
+        {
+            let mut batches = self.active_batches.write().await;
+            if let Some(batch_state) = batches.get_mut(&batch_id) {
+                batch_state.released_at = Some(release_at);
+            }
+        }
+        if !jitter.is_zero() {
+            let mut stats = self.stats.write().await;
+            stats.jittered_batches += 1;
+            stats.last_batch_jitter_us = offset_us;
+            stats.total_abs_jitter += Duration::from_micros(offset_us.unsigned_abs());
+        }
+
         // Send batch for transmission
         if self.batch_sender.send(verified_batch).is_err() {
             warn!("Failed to send batch {} for transmission", batch_id);
         }
-
-        info!("cMix batch {} ready for transmission", batch_id);
-        Ok(batch_id)
     }
 
     /// Generate accumulator proof for batch
@@ -754,6 +825,50 @@ mod tests {
         assert!(cmix_frame.accumulator_witness.is_some());
     }
 
+    #[tokio::test]
+    async fn test_batch_jitter_keeps_release_order() {
+        let jitter = Duration::from_millis(10);
+        let config = CmixConfig {
+            enabled: true,
+            batch_size: 1,
+            enable_accumulator_proofs: false,
+            batch_jitter: jitter,
+            ..Default::default()
+        };
+        let manager = CmixIntegrationManager::new(config).unwrap();
+
+        let batches = 12u64;
+        for i in 0..batches {
+            let frame = create_test_frame(format!("jitter {i}").into_bytes());
+            manager.process_frame(frame).await.unwrap();
+        }
+
+        let mut last = None;
+        for id in 1..=batches {
+            let released = manager
+                .get_batch_state(id)
+                .await
+                .unwrap()
+                .released_at
+                .unwrap();
+            if let Some(prev) = last {
+                assert!(
+                    released >= prev,
+                    "batch {id} released before its predecessor"
+                );
+            }
+            last = Some(released);
+        }
+
+        let stats = manager.stats().await;
+        assert_eq!(stats.jittered_batches, batches);
+        assert!(stats.last_batch_jitter_us.unsigned_abs() <= jitter.as_micros() as u64);
+        // Offsets are drawn from ±10ms; twelve of them summing to zero is
+        // vanishingly unlikely, so a non-zero total shows jitter was applied.
+        assert!(stats.total_abs_jitter > Duration::ZERO);
+        assert!(stats.total_abs_jitter <= jitter * batches as u32);
+    }
+
     #[tokio::test]
     async fn test_invalid_config() {
         let config = CmixConfig {
@@ -30,6 +30,42 @@ pub const MAX_EARLY_DATA_SIZE: usize = 64 * 1024;
 /// Maximum total early data per connection (1MB)
 pub const MAX_TOTAL_EARLY_DATA: usize = 1024 * 1024;
 
+/// Size limits applied by [`EarlyDataManager`] to 0-RTT data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EarlyDataConfig {
+    /// Largest single early data packet accepted
+    pub max_early_data_size: usize,
+    /// Largest cumulative early data accepted per session (until rekey)
+    pub max_total_early_data: usize,
+}
+
+impl Default for EarlyDataConfig {
+    fn default() -> Self {
+        Self {
+            max_early_data_size: MAX_EARLY_DATA_SIZE,
+            max_total_early_data: MAX_TOTAL_EARLY_DATA,
+        }
+    }
+}
+
+/// Early data rejected for exceeding an [`EarlyDataConfig`] limit
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, thiserror::Error)]
+pub enum EarlyDataLimitError {
+    /// A single packet is larger than `max_early_data_size`
+    #[error("packet size {size} exceeds maximum {max}")]
+    PacketTooLarge { size: usize, max: usize },
+    /// Accepting the packet would push the session past `max_total_early_data`
+    #[error("session total {received} + {size} exceeds maximum {max}")]
+    TotalExceeded {
+        received: usize,
+        size: usize,
+        max: usize,
+    },
+}
+
+/// Format version of [`AntiReplayWindow::export_state`] snapshots
+const ANTI_REPLAY_STATE_VERSION: u8 = 1;
+
 /// Direction identifiers for preventing nonce overlap in AEAD construction
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct DirectionId(pub u32);
@@ -258,6 +294,73 @@ impl AntiReplayWindow {
             last_reset: self.last_reset.map(|t| t.elapsed()),
         }
     }
+
+    /// Serialize the replay-relevant window state (CBOR) for persistence.
+    ///
+    /// Freshness requirements for callers persisting across reconnects:
+    /// - Export *after* the last nonce of the session was accepted (e.g. on
+    ///   session teardown). Nonces accepted after the snapshot are not
+    ///   covered and could be replayed once the snapshot is imported.
+    /// - A snapshot belongs to one key epoch. Discard it on rekey, since
+    ///   [`reset`](Self::reset) starts nonces from zero again.
+    /// - Only import into a window for the same direction.
+    pub fn export_state(&self) -> Result<Vec<u8>> {
+        let state = AntiReplayState {
+            version: ANTI_REPLAY_STATE_VERSION,
+            direction_id: self.direction_id,
+            window_base: self.window_base,
+            window_size: self.window_size,
+            seen_nonces: self.seen_nonces.iter().copied().collect(),
+        };
+        let mut out = Vec::new();
+        ciborium::ser::into_writer(&state, &mut out)?;
+        Ok(out)
+    }
+
+    /// Restore a snapshot produced by [`export_state`](Self::export_state).
+    ///
+    /// The snapshot is merged rather than overwriting: the window keeps the
+    /// higher base and the union of seen nonces, so importing can only make
+    /// replay protection stricter. This window's size is retained.
+    pub fn import_state(&mut self, bytes: &[u8]) -> Result<()> {
+        let state: AntiReplayState = ciborium::de::from_reader(bytes)?;
+        if state.version != ANTI_REPLAY_STATE_VERSION {
+            return Err(Error::Protocol(format!(
+                "Unsupported anti-replay state version {}",
+                state.version
+            )));
+        }
+        if state.direction_id != self.direction_id {
+            return Err(Error::Protocol(format!(
+                "Anti-replay state for direction {} cannot be imported into {}",
+                state.direction_id, self.direction_id
+            )));
+        }
+
+        self.window_base = self.window_base.max(state.window_base);
+        self.seen_nonces.extend(state.seen_nonces);
+        let cutoff = self.window_base.saturating_sub(self.window_size);
+        self.seen_nonces = self.seen_nonces.split_off(&cutoff);
+
+        info!(
+            direction = %self.direction_id,
+            window_base = self.window_base,
+            seen_count = self.seen_nonces.len(),
+            exported_window_size = state.window_size,
+            "Imported persisted anti-replay window state"
+        );
+        Ok(())
+    }
+}
+
+/// Persisted form of an [`AntiReplayWindow`]
+#[derive(Serialize, Deserialize)]
+struct AntiReplayState {
+    version: u8,
+    direction_id: DirectionId,
+    window_base: u64,
+    window_size: u64,
+    seen_nonces: Vec<u64>,
 }
 
 /// Statistics for anti-replay window telemetry
@@ -310,8 +413,8 @@ pub struct EarlyDataManager {
 }
 
 impl EarlyDataManager {
-    /// Create a new early data manager
-    pub fn new() -> Self {
+    /// Create a new early data manager enforcing the limits in `config`
+    pub fn new(config: EarlyDataConfig) -> Self {
         let mut windows = HashMap::new();
 
         // Create windows for both directions
@@ -332,8 +435,8 @@ impl EarlyDataManager {
             windows,
             state: EarlyDataState::Disabled,
             metrics: Arc::new(Mutex::new(EarlyDataMetrics::default())),
-            max_early_data_size: MAX_EARLY_DATA_SIZE,
-            max_total_early_data: MAX_TOTAL_EARLY_DATA,
+            max_early_data_size: config.max_early_data_size,
+            max_total_early_data: config.max_total_early_data,
             total_early_data_received: 0,
             session_start: Instant::now(),
         }
@@ -431,18 +534,13 @@ impl EarlyDataManager {
                 "SECURITY: Early data packet exceeds maximum allowed size"
             );
 
-            if let Ok(mut metrics) = self.metrics.lock() {
-                metrics.early_data_rejected_count += 1;
-                metrics
-                    .rejection_reasons
-                    .insert("oversized_packet".to_string());
-            }
-
-            return Err(Error::Protocol(format!(
-                "SECURITY: Early data packet size {} exceeds maximum {}",
-                data.len(),
-                self.max_early_data_size
-            )));
+            return Err(self.reject_over_limit(
+                "oversized_packet",
+                EarlyDataLimitError::PacketTooLarge {
+                    size: data.len(),
+                    max: self.max_early_data_size,
+                },
+            ));
         }
 
         // SECURITY: Validate total session early data
@@ -455,14 +553,14 @@ impl EarlyDataManager {
                 "SECURITY: Total early data would exceed session limit"
             );
 
-            if let Ok(mut metrics) = self.metrics.lock() {
-                metrics.early_data_rejected_count += 1;
-                metrics
-                    .rejection_reasons
-                    .insert("total_session_limit_exceeded".to_string());
-            }
-
-            return Ok(false);
+            return Err(self.reject_over_limit(
+                "total_session_limit_exceeded",
+                EarlyDataLimitError::TotalExceeded {
+                    received: self.total_early_data_received,
+                    size: data.len(),
+                    max: self.max_total_early_data,
+                },
+            ));
         }
 
         // SECURITY: Get and check anti-replay window
@@ -520,6 +618,17 @@ impl EarlyDataManager {
         Ok(true)
     }
 
+    /// Record a limit rejection in the metrics and turn it into an error
+    fn reject_over_limit(&self, reason: &str, err: EarlyDataLimitError) -> Error {
+        if let Ok(mut metrics) = self.metrics.lock() {
+            metrics.early_data_rejected_count += 1;
+            metrics.limit_rejected_count += 1;
+            metrics.rejection_reasons.insert(reason.to_string());
+            metrics.last_limit_error = Some(err.clone());
+        }
+        Error::EarlyDataLimit(err)
+    }
+
     /// Reset all anti-replay windows for rekey operation
     pub fn reset_for_rekey(&mut self) -> Result<()> {
         info!("SECURITY: Resetting all anti-replay windows for rekey operation");
@@ -592,7 +701,7 @@ impl EarlyDataManager {
 
 impl Default for EarlyDataManager {
     fn default() -> Self {
-        Self::new()
+        Self::new(EarlyDataConfig::default())
     }
 }
 
@@ -621,6 +730,12 @@ pub struct EarlyDataMetrics {
     /// Number of replay attacks detected and blocked
     pub replay_drops: u64,
 
+    /// Number of packets rejected for exceeding a size limit
+    pub limit_rejected_count: u64,
+
+    /// Most recent size limit violation
+    pub last_limit_error: Option<EarlyDataLimitError>,
+
     /// Number of times early data was enabled
     pub early_data_enabled_count: u64,
 
@@ -29,6 +29,8 @@ pub enum Error {
     StreamError(String),
     #[error("multipath error: {message}")]
     MultipathError { message: String },
+    #[error("early data: {0}")]
+    EarlyDataLimit(#[from] crate::early_data::EarlyDataLimitError),
 }
 
 impl Error {
@@ -12,7 +12,13 @@ use std::sync::{
 use tokio_util::codec::{Decoder, Encoder};
 
 /// Length-prefixed (u32 big-endian) + CBOR(Frame)
-pub struct FrameCodec;
+///
+/// A codec instance may carry its own frame-length cap (see [`FrameCodec::with_limit`]);
+/// without one it follows the global default.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FrameCodec {
+    limit: Option<usize>,
+}
 /// Safety cap to avoid pathological allocations/DoS via oversized frames
 pub const DEFAULT_MAX_FRAME_LEN: usize = 8 * 1024 * 1024; // 8 MiB
                                                           // Global, runtime-adjustable default limit. Initialized to DEFAULT_MAX_FRAME_LEN and can be
@@ -46,6 +52,24 @@ impl FrameCodec {
         default_max_frame_len()
     }
 
+    /// Codec with its own safety cap (bytes), independent of the global default.
+    /// Clamped to [1024, 64MiB] like the global.
+    pub fn with_limit(bytes: usize) -> Self {
+        Self {
+            limit: Some(clamp_limit(bytes)),
+        }
+    }
+
+    /// Override the safety cap for this codec only.
+    pub fn set_limit(&mut self, bytes: usize) {
+        self.limit = Some(clamp_limit(bytes));
+    }
+
+    /// Effective safety cap for this codec: its own limit, else the global default.
+    pub fn limit(&self) -> usize {
+        self.limit.unwrap_or_else(default_max_frame_len)
+    }
+
     /// Encode using the default safety cap (DEFAULT_MAX_FRAME_LEN).
     pub fn encode(frame: &Frame, dst: &mut BytesMut) -> Result<()> {
         Self::encode_with_limit(frame, dst, default_max_frame_len())
@@ -129,12 +153,23 @@ impl FrameCodec {
         let f = Frame::from_cbor(&data)?;
         Ok(Some(f))
     }
+
+    /// Incremental decode for callers feeding socket reads into one buffer.
+    ///
+    /// Returns `Ok(None)` until a whole frame is present, leaving the partial
+    /// bytes in `buf` for the next call. Once the length prefix is readable it
+    /// is checked against the configured limit, so oversize frames fail
+    /// before their body is buffered. Nothing is reserved from the claimed
+    /// length; the buffer grows only as bytes actually arrive.
+    pub fn decode_stream(&mut self, buf: &mut BytesMut) -> Result<Option<Frame>> {
+        Self::decode_with_limit(buf, self.limit())
+    }
 }
 
 impl Encoder<Frame> for FrameCodec {
     type Error = Error;
     fn encode(&mut self, item: Frame, dst: &mut BytesMut) -> core::result::Result<(), Self::Error> {
-        Self::encode(&item, dst)
+        Self::encode_with_limit(&item, dst, self.limit())
     }
 }
 
@@ -145,7 +180,7 @@ impl Decoder for FrameCodec {
         &mut self,
         src: &mut BytesMut,
     ) -> core::result::Result<Option<Self::Item>, Self::Error> {
-        Self::decode(src)
+        self.decode_stream(src)
     }
 }
 
@@ -222,6 +257,77 @@ mod test_s {
         Ok(())
     }
 
+    #[test]
+    fn decode_stream_byte_at_a_time() -> Result<()> {
+        let f = Frame::data(3, 9, &b"streamed payload"[..]);
+        let mut wire = BytesMut::new();
+        FrameCodec::encode(&f, &mut wire)?;
+        let mut codec = FrameCodec::default();
+        let mut acc = BytesMut::new();
+        for (i, byte) in wire.iter().enumerate() {
+            acc.put_u8(*byte);
+            let got = codec.decode_stream(&mut acc)?;
+            if i + 1 < wire.len() {
+                assert!(got.is_none(), "decoded early at byte {i}");
+                assert_eq!(acc.len(), i + 1);
+            } else {
+                let got = got.unwrap();
+                assert_eq!(got.header.stream_id, 3);
+                assert_eq!(got.header.seq, 9);
+                assert_eq!(got.payload, b"streamed payload");
+            }
+        }
+        assert!(acc.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn decode_stream_rejects_oversize_from_header() {
+        let mut codec = FrameCodec::default();
+        let mut acc = BytesMut::new();
+        let declared = (FrameCodec::default_limit() + 1) as u32;
+        for byte in declared.to_be_bytes() {
+            acc.put_u8(byte);
+            if acc.len() < 4 {
+                assert!(codec.decode_stream(&mut acc).unwrap().is_none());
+            }
+        }
+        let err = codec.decode_stream(&mut acc).unwrap_err();
+        match err {
+            Error::Protocol(msg) => assert!(msg.contains("exceeds maximum")),
+            _ => panic!("unexpected error: {err:?}"),
+        }
+    }
+
+    #[test]
+    fn instance_limits_are_independent() -> Result<(), Box<dyn std::error::Error>> {
+        let frame = Frame::data(1, 1, vec![0xAB; 4096]);
+        let mut wire = BytesMut::new();
+        FrameCodec::encode(&frame, &mut wire)?;
+
+        let mut untrusted = FrameCodec::with_limit(2048);
+        let mut trusted = FrameCodec::default();
+        trusted.set_limit(64 * 1024);
+        assert_eq!(untrusted.limit(), 2048);
+        assert_eq!(trusted.limit(), 64 * 1024);
+        assert_eq!(FrameCodec::default().limit(), FrameCodec::default_limit());
+
+        let mut buf = wire.clone();
+        let err = untrusted.decode_stream(&mut buf).unwrap_err();
+        assert!(matches!(err, Error::Protocol(ref m) if m.contains("exceeds maximum")));
+        let mut out = BytesMut::new();
+        assert!(Encoder::encode(&mut untrusted, frame.clone(), &mut out).is_err());
+
+        let mut buf = wire.clone();
+        let got = trusted
+            .decode_stream(&mut buf)?
+            .ok_or("Expected Some value")?;
+        assert_eq!(got.payload.len(), 4096);
+        Encoder::encode(&mut trusted, frame, &mut out)?;
+        assert_eq!(out, wire);
+        Ok(())
+    }
+
     use proptest::prelude::*;
     proptest! {
         #[test]
@@ -64,8 +64,8 @@ pub use advanced_path_selection::{
 pub use advanced_rate_limiting::{
     AdvancedFlowConfig, AdvancedFlowController, BackpressureCause, BackpressureController,
     BackpressureEvent, BucketStatus, FlowControlStatus, NyxRateLimiter, PriorityTokenBucket,
-    RateLimitError, RateLimiterStats, RateLimiterStatus, TokenBucket, TrafficType,
-    TransmissionDecision,
+    RateLimitBucket, RateLimitError, RateLimiterStats, RateLimiterStatus, TokenBucket,
+    TrafficType, TransmissionDecision,
 };
 pub use cmix_integration::{
     BatchProcessingState, BatchState, CmixConfig, CmixFrame, CmixIntegrationError,
@@ -93,11 +93,13 @@ pub use plugin_framework::{
 };
 
 pub use async_stream::{pair, AsyncStream, AsyncStreamConfig};
-pub use capability::{get_local_capabilities, negotiate, Capability, CapabilityError};
+pub use capability::{
+    get_local_capabilities, negotiate, Capability, CapabilityError, NegotiationOutcome,
+};
 pub use early_data::{
-    AntiReplayStats, AntiReplayWindow, DirectionId, EarlyDataManager, EarlyDataMetrics,
-    EarlyDataState, Nonce, NonceConstructor, SessionStats, ANTI_REPLAY_WINDOW_SIZE,
-    MAX_EARLY_DATA_SIZE, MAX_TOTAL_EARLY_DATA,
+    AntiReplayStats, AntiReplayWindow, DirectionId, EarlyDataConfig, EarlyDataLimitError,
+    EarlyDataManager, EarlyDataMetrics, EarlyDataState, Nonce, NonceConstructor, SessionStats,
+    ANTI_REPLAY_WINDOW_SIZE, MAX_EARLY_DATA_SIZE, MAX_TOTAL_EARLY_DATA,
 };
 pub use errors::{Error, Result};
 pub use extended_packet::{
@@ -13,6 +13,9 @@ pub struct PathMetric {
     pub weight: u32,
 }
 
+/// Default number of rounds a path may go unselected before it is forced.
+pub const DEFAULT_STARVATION_LIMIT: usize = 256;
+
 /// Ultra-high performance weighted scheduler with optimized data structures
 /// and minimal memory allocations for maximum throughput.
 #[derive(Debug)]
@@ -33,6 +36,11 @@ pub struct WeightedScheduler {
     // Cache for avoiding repeated calculations
     min_rtt_cache: f64,
     weights_dirty: bool,
+
+    // Starvation guard: selection round counter and last round each path was picked
+    round: u64,
+    last_selected: [u64; 16],
+    starvation_limit: usize,
 }
 
 impl WeightedScheduler {
@@ -50,6 +58,9 @@ impl WeightedScheduler {
             idx: 0,
             min_rtt_cache: f64::INFINITY,
             weights_dirty: true,
+            round: 0,
+            last_selected: [0; 16],
+            starvation_limit: DEFAULT_STARVATION_LIMIT,
         };
 
         // Populate with input paths (max 16 supported for optimal performance)
@@ -65,19 +76,49 @@ impl WeightedScheduler {
         scheduler
     }
 
+    /// Set the maximum number of rounds a path may go unselected.
+    ///
+    /// Low-weight paths can receive no ring slot at all under extreme weight
+    /// skew; once a path has waited `rounds` selections it is picked once
+    /// regardless of weight. `0` disables the guard.
+    pub fn set_starvation_limit(&mut self, rounds: usize) {
+        self.starvation_limit = rounds;
+    }
+
     /// Ultra-fast path selection with minimal branching
     #[inline(always)]
     pub fn next_path(&mut self) -> PathId {
         if self.ring_size == 0 {
             self.rebuild_ring_optimized();
         }
+        self.round += 1;
+
+        if let Some(i) = self.most_starved_path() {
+            self.last_selected[i] = self.round;
+            return self.path_ids[i];
+        }
 
         // Branchless modulo operation for power-of-2 ring sizes
         let path = self.ring[self.idx];
         self.idx = (self.idx + 1) % self.ring_size.max(1);
+        if let Some(i) = self.find_path_index(path) {
+            self.last_selected[i] = self.round;
+        }
         path
     }
 
+    /// Index of the longest-waiting path that has hit the starvation limit
+    #[inline(always)]
+    fn most_starved_path(&self) -> Option<usize> {
+        if self.starvation_limit == 0 {
+            return None;
+        }
+        let limit = self.starvation_limit as u64;
+        (0..self.active_paths as usize)
+            .filter(|&i| self.round - self.last_selected[i] >= limit)
+            .min_by_key(|&i| self.last_selected[i])
+    }
+
     /// High-performance RTT observation with optimized EWMA calculation
     pub fn observe_rtt(&mut self, path: PathId, sample: Duration) {
         // Find path index efficiently
@@ -364,6 +405,30 @@ mod test_s {
         assert!(c2 >= c1);
     }
 
+    #[test]
+    fn starved_path_selected_within_limit() {
+        let metric = |weight| PathMetric {
+            rtt: Duration::from_millis(10),
+            loss: 0.0,
+            weight,
+        };
+        let paths = vec![
+            (PathId(1), metric(10_000)),
+            (PathId(2), metric(10_000)),
+            (PathId(3), metric(1)),
+        ];
+        let mut s = WeightedScheduler::new(&paths);
+        s.set_starvation_limit(20);
+
+        let picks: Vec<_> = (0..200).map(|_| s.next_path().0).collect();
+        for window in picks.windows(20) {
+            assert!(window.contains(&3), "path 3 starved for 20 rounds");
+        }
+        // The guard only tops up the starved path; heavy paths still dominate
+        let c3 = picks.iter().filter(|&&p| p == 3).count();
+        assert!(c3 <= 200 / 20, "c3={c3}");
+    }
+
     #[test]
     fn observe_rtt_increases_weight_for_faster_path() {
         let paths = vec![
@@ -29,6 +29,7 @@ pub type ConnectionId = u32;
 
 /// Multipath configuration parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct MultipathConfig {
     /// Maximum number of concurrent paths
     pub max_paths: usize,
@@ -52,6 +53,19 @@ pub struct MultipathConfig {
     pub anti_replay_window_size: u32,
     /// Path failover timeout
     pub failover_timeout_ms: u64,
+    /// Reorder buffer sizing target as a multiple of the observed p95
+    /// occupancy; values above 1.0 leave headroom for bursts
+    pub reorder_target_p95_factor: f64,
+    /// Proportional gain of the reorder buffer size controller
+    pub reorder_pid_kp: f64,
+    /// Integral gain of the reorder buffer size controller
+    pub reorder_pid_ki: f64,
+    /// Derivative gain of the reorder buffer size controller
+    pub reorder_pid_kd: f64,
+    /// Lower bound for the adaptive reorder buffer size (frames)
+    pub reorder_min_size: usize,
+    /// Upper bound for the adaptive reorder buffer size (frames)
+    pub reorder_max_size: usize,
 }
 
 impl Default for MultipathConfig {
@@ -68,6 +82,12 @@ impl Default for MultipathConfig {
             enable_early_data: true,
             anti_replay_window_size: 1048576, // 2^20
             failover_timeout_ms: 10000,
+            reorder_target_p95_factor: 1.5,
+            reorder_pid_kp: 0.5,
+            reorder_pid_ki: 0.05,
+            reorder_pid_kd: 0.1,
+            reorder_min_size: 32,
+            reorder_max_size: 4096,
         }
     }
 }
@@ -394,20 +414,34 @@ impl PathScheduler {
         self.total_weight = self.paths.values().map(|p| p.weight).sum();
     }
 
-    /// Adjust hop count based on network conditions (LARMix++ algorithm)
+    /// Adjust hop count based on network conditions (LARMix++ algorithm).
+    /// The target comes from `nyx_mix::larmix::optimal_hops`; the path moves
+    /// at most one hop toward it per measurement, so a single outlier sample
+    /// cannot swing the path length across the whole range.
     fn adjust_hop_count(&self, metrics: &mut PathMetrics) {
-        // Increase hop count if conditions are poor
-        if (metrics.loss_rate > 0.1 || metrics.rtt_ms > 500.0)
-            && metrics.hop_count < self.config.max_hop_count
-        {
-            metrics.hop_count += 1;
+        let bound = |n: usize| u8::try_from(n).unwrap_or(u8::MAX);
+        let target = usize::from(nyx_mix::larmix::optimal_hops(
+            metrics.rtt_ms,
+            metrics.loss_rate,
+            bound(self.config.min_hop_count),
+            bound(self.config.max_hop_count),
+        ));
+        match metrics.hop_count.cmp(&target) {
+            std::cmp::Ordering::Less => metrics.hop_count += 1,
+            std::cmp::Ordering::Greater => metrics.hop_count -= 1,
+            std::cmp::Ordering::Equal => {}
         }
-        // Decrease hop count if conditions are good
-        else if metrics.loss_rate < 0.01
-            && metrics.rtt_ms < 100.0
-            && metrics.hop_count > self.config.min_hop_count
-        {
-            metrics.hop_count -= 1;
+    }
+
+    /// Force a path into `state` without touching its metrics.
+    /// Returns false if the path is unknown.
+    pub fn set_path_state(&mut self, path_id: PathId, state: PathState) -> bool {
+        match self.paths.get_mut(&path_id) {
+            Some(path) => {
+                path.state = state;
+                true
+            }
+            None => false,
         }
     }
 
@@ -450,8 +484,23 @@ pub struct ReorderingBuffer {
     timeout: Duration,
     /// Maximum buffer size
     max_buffer_size: usize,
+    /// Highest number of frames held at once (high-water mark)
+    peak_occupancy: usize,
+    /// Frames evicted out of order because the buffer was full
+    dropped_full: u64,
+    /// Frames discarded as duplicates or older than the delivery point
+    dropped_stale: u64,
+    /// Occupancy after each buffered frame since the last resize
+    occupancy_samples: VecDeque<usize>,
+    /// Overflow evictions since the last resize
+    overflows_since_resize: usize,
+    /// Adapts `max_buffer_size` to the observed occupancy when set
+    size_controller: Option<ReorderSizeController>,
 }
 
+/// Occupancy samples kept between two [`ReorderingBuffer::adapt_size`] calls
+const MAX_OCCUPANCY_SAMPLES: usize = 1024;
+
 impl ReorderingBuffer {
     /// Create new reordering buffer
     pub fn new(timeout_ms: u64, max_size: usize) -> Self {
@@ -460,9 +509,21 @@ impl ReorderingBuffer {
             next_expected_seq: 0,
             timeout: Duration::from_millis(timeout_ms),
             max_buffer_size: max_size,
+            peak_occupancy: 0,
+            dropped_full: 0,
+            dropped_stale: 0,
+            occupancy_samples: VecDeque::new(),
+            overflows_since_resize: 0,
+            size_controller: None,
         }
     }
 
+    /// Let `controller` resize the buffer on each [`Self::adapt_size`] call
+    pub fn with_size_controller(mut self, controller: ReorderSizeController) -> Self {
+        self.size_controller = Some(controller);
+        self
+    }
+
     /// Add frame to reordering buffer and return any deliverable frames
     pub fn add_frame(&mut self, frame: Frame, seq: u64) -> Vec<Frame> {
         let mut deliverable = Vec::new();
@@ -478,6 +539,12 @@ impl ReorderingBuffer {
                 self.next_expected_seq += 1;
             }
         } else if seq > self.next_expected_seq {
+            if self.buffer.contains_key(&seq) {
+                self.dropped_stale += 1;
+                tracing::debug!(seq, "reorder buffer: dropping duplicate frame");
+                return deliverable;
+            }
+
             // Buffer out-of-order frame
             let entry = ReorderEntry {
                 frame,
@@ -491,12 +558,26 @@ impl ReorderingBuffer {
                 if let Some((_, oldest)) = self.buffer.pop_first() {
                     // Deliver the oldest frame even if out of order
                     deliverable.push(oldest.frame);
+                    self.dropped_full += 1;
+                    self.overflows_since_resize += 1;
+                    tracing::warn!(
+                        max_size = self.max_buffer_size,
+                        "reorder buffer full: evicting oldest frame out of order"
+                    );
                 }
             }
 
             self.buffer.insert(seq, entry);
+            self.peak_occupancy = self.peak_occupancy.max(self.buffer.len());
+            if self.occupancy_samples.len() >= MAX_OCCUPANCY_SAMPLES {
+                self.occupancy_samples.pop_front();
+            }
+            self.occupancy_samples.push_back(self.buffer.len());
+        } else {
+            // Ignore duplicate or old frames (seq < next_expected_seq)
+            self.dropped_stale += 1;
+            tracing::debug!(seq, "reorder buffer: dropping stale frame");
         }
-        // Ignore duplicate or old frames (seq < next_expected_seq)
 
         deliverable
     }
@@ -522,6 +603,20 @@ impl ReorderingBuffer {
         timed_out
     }
 
+    /// Flush every buffered frame in sequence order, skipping over gaps,
+    /// and leave the buffer empty. Used on stream teardown so frames held
+    /// back for reordering are not lost; later frames at or below the last
+    /// drained sequence are treated as stale.
+    pub fn drain_in_order(&mut self) -> Vec<Frame> {
+        if let Some(&last) = self.buffer.keys().next_back() {
+            self.next_expected_seq = last + 1;
+        }
+        std::mem::take(&mut self.buffer)
+            .into_values()
+            .map(|entry| entry.frame)
+            .collect()
+    }
+
     /// Calculate dynamic buffer timeout based on RTT and jitter
     pub fn update_timeout(&mut self, rtt_diff_ms: f64, jitter_ms: f64) {
         let dynamic_timeout = (rtt_diff_ms + jitter_ms * 2.0).max(100.0);
@@ -532,6 +627,115 @@ impl ReorderingBuffer {
     pub fn get_stats(&self) -> (usize, u64, Duration) {
         (self.buffer.len(), self.next_expected_seq, self.timeout)
     }
+
+    /// Highest occupancy reached since creation. A peak equal to
+    /// `max_size` means the buffer overflowed and forced early delivery.
+    pub fn peak_occupancy(&self) -> usize {
+        self.peak_occupancy
+    }
+
+    /// Configured capacity
+    pub fn max_size(&self) -> usize {
+        self.max_buffer_size
+    }
+
+    /// Change the capacity; frames already buffered beyond it are kept
+    /// and drain through the normal overflow path.
+    pub fn set_max_size(&mut self, max_size: usize) {
+        self.max_buffer_size = max_size.max(1);
+    }
+
+    /// Frames pushed out of sequence because the buffer was full
+    pub fn dropped_full(&self) -> u64 {
+        self.dropped_full
+    }
+
+    /// Duplicate or already-delivered frames that were discarded
+    pub fn dropped_stale(&self) -> u64 {
+        self.dropped_stale
+    }
+
+    /// Resize from the p95 occupancy seen since the last call, if a size
+    /// controller is attached and any frame was buffered meanwhile.
+    /// Returns the capacity in effect afterwards.
+    pub fn adapt_size(&mut self) -> usize {
+        if self.occupancy_samples.is_empty() {
+            return self.max_buffer_size;
+        }
+        let Some(controller) = self.size_controller.as_mut() else {
+            return self.max_buffer_size;
+        };
+        let mut samples: Vec<usize> = self.occupancy_samples.drain(..).collect();
+        samples.sort_unstable();
+        let mut p95 = samples[(samples.len() * 95).div_ceil(100) - 1];
+        // Occupancy cannot exceed the capacity, so overflow means demand was
+        // higher than what the samples show
+        if self.overflows_since_resize > 0 {
+            p95 = p95.max(self.max_buffer_size + self.overflows_since_resize);
+            self.overflows_since_resize = 0;
+        }
+        let next = controller.update(self.max_buffer_size, p95);
+        self.set_max_size(next);
+        next
+    }
+}
+
+/// PID controller that sizes a reordering buffer from its observed p95
+/// occupancy, using the `reorder_*` tuning fields of [`MultipathConfig`]
+#[derive(Debug, Clone)]
+pub struct ReorderSizeController {
+    kp: f64,
+    ki: f64,
+    kd: f64,
+    target_factor: f64,
+    min_size: usize,
+    max_size: usize,
+    integral: f64,
+    prev_error: Option<f64>,
+}
+
+impl ReorderSizeController {
+    pub fn new(config: &MultipathConfig) -> Self {
+        let min_size = config.reorder_min_size.max(1);
+        Self {
+            kp: config.reorder_pid_kp,
+            ki: config.reorder_pid_ki,
+            kd: config.reorder_pid_kd,
+            target_factor: config.reorder_target_p95_factor,
+            min_size,
+            max_size: config.reorder_max_size.max(min_size),
+            integral: 0.0,
+            prev_error: None,
+        }
+    }
+
+    /// Bound `size` to the configured `[min, max]` range
+    pub fn clamp(&self, size: usize) -> usize {
+        size.clamp(self.min_size, self.max_size)
+    }
+
+    /// Next buffer capacity given the current one and the p95 occupancy
+    /// observed since the last update. Always within `[min, max]`.
+    pub fn update(&mut self, current_size: usize, p95_occupancy: usize) -> usize {
+        let target = p95_occupancy as f64 * self.target_factor;
+        let error = target - current_size as f64;
+
+        // Bound the integral term so a long overload can't wind it up
+        let limit = self.max_size as f64;
+        self.integral = (self.integral + error).clamp(-limit, limit);
+        let derivative = self.prev_error.map_or(0.0, |prev| error - prev);
+        self.prev_error = Some(error);
+
+        let next = current_size as f64
+            + self.kp * error
+            + self.ki * self.integral
+            + self.kd * derivative;
+        if !next.is_finite() {
+            return self.clamp(current_size);
+        }
+        next.round()
+            .clamp(self.min_size as f64, self.max_size as f64) as usize
+    }
 }
 
 /// Anti-replay window for early data protection
@@ -749,10 +953,12 @@ impl MultipathDataPlane {
         // Add to reordering buffer
         let mut buffers = self.reorder_buffers.write().await;
         let buffer = buffers.entry(connection_id).or_insert_with(|| {
+            let controller = ReorderSizeController::new(&self.config);
             ReorderingBuffer::new(
                 self.config.reorder_timeout_ms,
-                1000, // Max buffer size
+                controller.clamp(1000), // Initial max buffer size
             )
+            .with_size_controller(controller)
         });
 
         let deliverable_frames = buffer.add_frame(frame, sequence_number);
@@ -775,6 +981,7 @@ impl MultipathDataPlane {
         let mut result = HashMap::new();
 
         for (connection_id, buffer) in buffers.iter_mut() {
+            buffer.adapt_size();
             let timed_out = buffer.check_timeouts();
             if !timed_out.is_empty() {
                 let mut metrics = self.metrics.write().await;
@@ -929,6 +1136,123 @@ mod tests {
         assert_eq!(delivered.len(), 2); // Should deliver both frames
     }
 
+    #[test]
+    fn test_reordering_buffer_drain_in_order() {
+        let mut buffer = ReorderingBuffer::new(1000, 16);
+        let frame = |seq: u64| Frame {
+            header: FrameHeader {
+                stream_id: 1,
+                seq,
+                ty: FrameType::Data,
+            },
+            payload: vec![seq as u8],
+        };
+
+        assert_eq!(buffer.add_frame(frame(0), 0).len(), 1);
+        // Seq 1 and 4 never arrive; the rest arrive out of order.
+        for seq in [6, 3, 5, 2] {
+            assert!(buffer.add_frame(frame(seq), seq).is_empty());
+        }
+
+        let drained: Vec<u64> = buffer
+            .drain_in_order()
+            .into_iter()
+            .map(|f| f.header.seq)
+            .collect();
+        assert_eq!(drained, vec![2, 3, 5, 6]);
+        assert_eq!(buffer.get_stats().0, 0);
+        assert!(buffer.drain_in_order().is_empty());
+        // Frames at or below the drain point are stale afterwards
+        assert!(buffer.add_frame(frame(4), 4).is_empty());
+        assert_eq!(buffer.add_frame(frame(7), 7).len(), 1);
+    }
+
+    #[test]
+    fn test_reordering_buffer_peak_occupancy() {
+        let mut buffer = ReorderingBuffer::new(1000, 4);
+        assert_eq!(buffer.peak_occupancy(), 0);
+
+        // Sequence 0 never arrives, so everything queues up and overflows.
+        for seq in 1..=10u64 {
+            let frame = Frame {
+                header: FrameHeader {
+                    stream_id: 1,
+                    seq,
+                    ty: FrameType::Data,
+                },
+                payload: vec![seq as u8],
+            };
+            buffer.add_frame(frame, seq);
+            assert!(buffer.get_stats().0 <= 4);
+        }
+        assert_eq!(buffer.peak_occupancy(), buffer.max_size());
+    }
+
+    #[test]
+    fn test_reorder_pid_default_config_stays_in_bounds() {
+        let config = MultipathConfig::default();
+        let mut pid = ReorderSizeController::new(&config);
+        let mut buffer = ReorderingBuffer::new(config.reorder_timeout_ms, 1000);
+
+        // Steady p95 of 200 frames converges near 1.5x = 300
+        let mut size = buffer.max_size();
+        for _ in 0..200 {
+            size = pid.update(size, 200);
+            buffer.set_max_size(size);
+            assert!((config.reorder_min_size..=config.reorder_max_size).contains(&size));
+        }
+        assert!((290..=310).contains(&buffer.max_size()), "size={size}");
+
+        // Extreme occupancy clamps to the configured bounds
+        assert_eq!(pid.update(size, usize::MAX / 4), config.reorder_max_size);
+        let mut pid = ReorderSizeController::new(&config);
+        let mut size = config.reorder_max_size;
+        for _ in 0..50 {
+            size = pid.update(size, 0);
+        }
+        assert_eq!(size, config.reorder_min_size);
+    }
+
+    #[test]
+    fn test_reordering_buffer_adapts_size_to_occupancy() {
+        let config = MultipathConfig::default();
+        let frame = |seq: u64| Frame {
+            header: FrameHeader {
+                stream_id: 1,
+                seq,
+                ty: FrameType::Data,
+            },
+            payload: vec![],
+        };
+        let mut buffer = ReorderingBuffer::new(config.reorder_timeout_ms, 200)
+            .with_size_controller(ReorderSizeController::new(&config));
+
+        // Nothing buffered yet: the size is left alone
+        assert_eq!(buffer.adapt_size(), 200);
+
+        // Each round holds back 100 frames behind a gap, then fills it
+        let mut next = 0u64;
+        for _ in 0..100 {
+            for seq in next + 1..=next + 100 {
+                buffer.add_frame(frame(seq), seq);
+            }
+            assert_eq!(buffer.add_frame(frame(next), next).len(), 101);
+            next += 101;
+            buffer.adapt_size();
+        }
+        // Settles near 1.5x the p95 occupancy (95 frames)
+        assert!((130..=160).contains(&buffer.max_size()), "size={}", buffer.max_size());
+        assert_eq!(buffer.dropped_full(), 0);
+
+        // Overflowing a small buffer grows it past its old capacity
+        let mut buffer = ReorderingBuffer::new(config.reorder_timeout_ms, 50)
+            .with_size_controller(ReorderSizeController::new(&config));
+        for seq in 1..=100u64 {
+            buffer.add_frame(frame(seq), seq);
+        }
+        assert!(buffer.adapt_size() > 50);
+    }
+
     #[test]
     fn test_anti_replay_window() {
         let mut window = AntiReplayWindow::new(1024);
@@ -1025,4 +1349,38 @@ mod tests {
         scheduler.adjust_hop_count(&mut metrics);
         assert_eq!(metrics.hop_count, 4); // Should decrease due to good conditions
     }
+
+    #[test]
+    fn test_hop_count_adjustment_table() {
+        let scheduler = PathScheduler::new(MultipathConfig::default());
+        // (rtt_ms, loss_rate, hop_count before, hop_count after); min 3, max 7
+        let cases = [
+            (80.0, 0.005, 5, 4),
+            (80.0, 0.005, 3, 3),
+            (100.0, 0.005, 5, 4),
+            (150.0, 0.0, 5, 4),
+            (300.0, 0.0, 3, 4),
+            (300.0, 0.0, 5, 5),
+            (300.0, 0.0, 7, 6),
+            (450.0, 0.05, 5, 6),
+            (501.0, 0.0, 5, 6),
+            (80.0, 0.11, 5, 6),
+            (80.0, 0.11, 3, 4),
+            (600.0, 0.15, 7, 7),
+            (f64::NAN, 0.0, 6, 7),
+        ];
+        for (rtt_ms, loss_rate, before, after) in cases {
+            let mut metrics = PathMetrics {
+                rtt_ms,
+                loss_rate,
+                hop_count: before,
+                ..Default::default()
+            };
+            scheduler.adjust_hop_count(&mut metrics);
+            assert_eq!(
+                metrics.hop_count, after,
+                "rtt={rtt_ms} loss={loss_rate} hops={before}"
+            );
+        }
+    }
 }
@@ -41,7 +41,10 @@
 //! ```
 
 use crate::frame::{Frame, FrameType};
-use rand::{rngs::OsRng, Rng};
+use rand::{
+    rngs::{OsRng, StdRng},
+    Rng, RngCore, SeedableRng,
+};
 use std::{
     collections::VecDeque,
     time::{Duration, Instant},
@@ -59,6 +62,9 @@ pub const MIN_PADDING_SIZE: usize = 16;
 /// Maximum allowed delay for timing obfuscation
 pub const MAX_TIMING_DELAY: Duration = Duration::from_millis(100);
 
+/// Sliding window over which `max_overhead_ratio` is enforced
+pub const PADDING_BUDGET_WINDOW: Duration = Duration::from_secs(1);
+
 /// Padding-specific error types
 #[derive(Debug, Error)]
 pub enum PaddingError {
@@ -101,6 +107,13 @@ pub struct PaddingConfig {
     pub enable_dummy_traffic: bool,
     /// Dummy traffic rate (packets per second)
     pub dummy_traffic_rate: f32,
+    /// Ceiling on padding bytes per real byte over [`PADDING_BUDGET_WINDOW`].
+    /// Padding is reduced or skipped once the budget is spent
+    /// (default: unlimited).
+    pub max_overhead_ratio: f32,
+    /// Seed for reproducible padding decisions in tests. `None` (the default)
+    /// uses the OS CSPRNG; never set this in production.
+    pub deterministic_seed: Option<u64>,
 }
 
 impl Default for PaddingConfig {
@@ -116,6 +129,8 @@ impl Default for PaddingConfig {
             overhead_limit: 0.15, // 15% overhead limit
             enable_dummy_traffic: false,
             dummy_traffic_rate: 1.0,
+            max_overhead_ratio: f32::INFINITY,
+            deterministic_seed: None,
         }
     }
 }
@@ -186,6 +201,18 @@ impl PaddingConfig {
         self
     }
 
+    /// Cap padding at `ratio` padding bytes per real byte
+    pub fn max_overhead_ratio(mut self, ratio: f32) -> Self {
+        self.max_overhead_ratio = ratio;
+        self
+    }
+
+    /// Drive padding decisions from a seeded RNG (tests only)
+    pub fn deterministic_seed(mut self, seed: u64) -> Self {
+        self.deterministic_seed = Some(seed);
+        self
+    }
+
     /// Validate configuration parameters
     pub fn validate(&self) -> Result<(), PaddingError> {
         if self.target_packet_size < MIN_PADDING_SIZE {
@@ -224,6 +251,15 @@ impl PaddingConfig {
             });
         }
 
+        if self.max_overhead_ratio.is_nan() || self.max_overhead_ratio < 0.0 {
+            return Err(PaddingError::InvalidConfig {
+                reason: format!(
+                    "max_overhead_ratio must be non-negative, got {}",
+                    self.max_overhead_ratio
+                ),
+            });
+        }
+
         Ok(())
     }
 }
@@ -249,6 +285,10 @@ pub struct TrafficMetrics {
     pub dummy_packets: u32,
     /// Timing obfuscation events
     pub timing_obfuscations: u32,
+    /// Packets padded short of the target because the overhead budget ran out
+    pub budget_limited_packets: u64,
+    /// Padding bytes withheld by the overhead budget
+    pub padding_bytes_withheld: u64,
 }
 
 impl TrafficMetrics {
@@ -309,13 +349,106 @@ impl BurstDetector {
     }
 }
 
+/// Randomness behind padding bytes, delays and dummy traffic
+enum PaddingRng {
+    Os(OsRng),
+    Seeded(Box<StdRng>),
+}
+
+impl PaddingRng {
+    fn from_config(config: &PaddingConfig) -> Self {
+        match config.deterministic_seed {
+            Some(seed) => {
+                warn!("PaddingManager using deterministic seed; padding is predictable");
+                PaddingRng::Seeded(Box::new(StdRng::seed_from_u64(seed)))
+            }
+            None => PaddingRng::Os(OsRng),
+        }
+    }
+}
+
+impl RngCore for PaddingRng {
+    fn next_u32(&mut self) -> u32 {
+        match self {
+            PaddingRng::Os(r) => r.next_u32(),
+            PaddingRng::Seeded(r) => r.next_u32(),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match self {
+            PaddingRng::Os(r) => r.next_u64(),
+            PaddingRng::Seeded(r) => r.next_u64(),
+        }
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match self {
+            PaddingRng::Os(r) => r.fill_bytes(dest),
+            PaddingRng::Seeded(r) => r.fill_bytes(dest),
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        match self {
+            PaddingRng::Os(r) => r.try_fill_bytes(dest),
+            PaddingRng::Seeded(r) => r.try_fill_bytes(dest),
+        }
+    }
+}
+
+/// Sliding-window accounting of real vs. padding bytes
+#[derive(Debug)]
+struct PaddingBudget {
+    max_ratio: f32,
+    entries: VecDeque<(Instant, u64, u64)>, // (time, real bytes, padding bytes)
+    real: u64,
+    padding: u64,
+}
+
+impl PaddingBudget {
+    fn new(max_ratio: f32) -> Self {
+        Self {
+            max_ratio,
+            entries: VecDeque::new(),
+            real: 0,
+            padding: 0,
+        }
+    }
+
+    /// Padding bytes granted to a packet of `real` bytes that wants `wanted`
+    fn grant(&mut self, real: u64, wanted: u64) -> u64 {
+        let now = Instant::now();
+        while let Some(&(at, r, p)) = self.entries.front() {
+            if now.duration_since(at) < PADDING_BUDGET_WINDOW {
+                break;
+            }
+            self.entries.pop_front();
+            self.real -= r;
+            self.padding -= p;
+        }
+
+        let granted = if self.max_ratio.is_finite() {
+            let cap = (f64::from(self.max_ratio) * (self.real + real) as f64) as u64;
+            wanted.min(cap.saturating_sub(self.padding))
+        } else {
+            wanted
+        };
+        self.entries.push_back((now, real, granted));
+        self.real += real;
+        self.padding += granted;
+        granted
+    }
+}
+
 /// Main padding manager for traffic analysis resistance
 pub struct PaddingManager {
     config: PaddingConfig,
     metrics: TrafficMetrics,
     burst_detector: BurstDetector,
     last_packet_time: Option<Instant>,
-    rng: OsRng,
+    rng: PaddingRng,
+    budget: PaddingBudget,
 }
 
 impl PaddingManager {
@@ -330,12 +463,16 @@ impl PaddingManager {
             config.target_packet_size, config.enable_fixed_size, config.burst_protection
         );
 
+        let rng = PaddingRng::from_config(&config);
+        let budget = PaddingBudget::new(config.max_overhead_ratio);
+
         Ok(Self {
             config,
             metrics: TrafficMetrics::default(),
             burst_detector,
             last_packet_time: None,
-            rng: OsRng,
+            rng,
+            budget,
         })
     }
 
@@ -357,7 +494,17 @@ impl PaddingManager {
             });
         }
 
-        let padding_needed = self.config.target_packet_size - original_size;
+        let wanted = self.config.target_packet_size - original_size;
+        let padding_needed = self.budget.grant(original_size as u64, wanted as u64) as usize;
+        if padding_needed < wanted {
+            self.metrics.budget_limited_packets += 1;
+            self.metrics.padding_bytes_withheld += (wanted - padding_needed) as u64;
+            trace!(
+                "Overhead budget cut padding from {} to {} bytes",
+                wanted,
+                padding_needed
+            );
+        }
 
         if padding_needed > 0 {
             // Generate secure random padding
@@ -494,8 +641,12 @@ impl PaddingManager {
         new_config.validate()?;
 
         info!("Updating padding configuration");
+        if new_config.deterministic_seed != self.config.deterministic_seed {
+            self.rng = PaddingRng::from_config(&new_config);
+        }
         self.config = new_config;
         self.burst_detector = BurstDetector::new(self.config.burst_threshold);
+        self.budget.max_ratio = self.config.max_overhead_ratio;
 
         Ok(())
     }
@@ -506,6 +657,11 @@ impl PaddingManager {
         self.metrics = TrafficMetrics::default();
         self.burst_detector = BurstDetector::new(self.config.burst_threshold);
         self.last_packet_time = None;
+        self.budget = PaddingBudget::new(self.config.max_overhead_ratio);
+        if self.config.deterministic_seed.is_some() {
+            // Replay the same padding sequence after a reset
+            self.rng = PaddingRng::from_config(&self.config);
+        }
     }
 
     // Private helper methods
@@ -666,6 +822,50 @@ mod tests {
         assert_eq!(&padded_data[..original_data.len()], &original_data[..]);
     }
 
+    #[test]
+    async fn test_deterministic_seed_reproduces_padding() {
+        let frame = b"conformance frame".to_vec();
+        let pad_with = |seed| {
+            let config = PaddingConfig::new().deterministic_seed(seed);
+            let mut manager = PaddingManager::new(config).unwrap();
+            manager.pad_data(frame.clone()).unwrap()
+        };
+
+        assert_eq!(pad_with(7), pad_with(7));
+        assert_ne!(pad_with(7), pad_with(8));
+        assert_eq!(PaddingConfig::default().deterministic_seed, None);
+    }
+
+    #[test]
+    async fn test_overhead_budget_caps_padding_of_tiny_frames() {
+        let config = PaddingConfig::new().max_overhead_ratio(0.5);
+        let mut manager = PaddingManager::new(config).unwrap();
+
+        for i in 0..500u32 {
+            let frame = i.to_be_bytes().to_vec();
+            let padded = manager.pad_data(frame.clone()).unwrap();
+            assert_eq!(&padded[..frame.len()], &frame[..]);
+        }
+
+        let metrics = manager.metrics();
+        assert!(metrics.overhead_ratio > 0.0);
+        assert!(
+            metrics.overhead_ratio <= 0.5,
+            "overhead {} exceeds cap",
+            metrics.overhead_ratio
+        );
+        assert_eq!(metrics.budget_limited_packets, 500);
+        assert!(metrics.padding_bytes_withheld > 0);
+
+        // Without a cap the same frames are padded to the full target size
+        let mut unlimited = PaddingManager::new(PaddingConfig::new()).unwrap();
+        assert_eq!(unlimited.pad_data(vec![0; 4]).unwrap().len(), 1280);
+        assert!(PaddingConfig::new()
+            .max_overhead_ratio(-1.0)
+            .validate()
+            .is_err());
+    }
+
     #[test]
     async fn test_packet_too_large() {
         let config = PaddingConfig::new().target_packet_size(100);
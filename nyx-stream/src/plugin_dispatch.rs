@@ -345,13 +345,7 @@ impl PluginDispatcher {
         }
 
         // Check frame-specific permissions
-        let required_permission = match frame_type {
-            0x51 => Permission::Handshake,      // Handshake frame
-            0x52 => Permission::DataAccess,     // Data frame
-            0x53 => Permission::Control,        // Control frame
-            0x54 => Permission::ErrorReporting, // Error frame
-            _ => Permission::DataAccess,        // Default to data access
-        };
+        let required_permission = Permission::for_frame_type(frame_type);
 
         if !self
             .registry
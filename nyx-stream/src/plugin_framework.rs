@@ -19,6 +19,9 @@ use thiserror::Error;
 use tokio::sync::mpsc;
 use tracing::{error, info, warn};
 
+use crate::plugin::PluginId;
+use crate::plugin_registry::{Permission, PluginInfo, PluginRegistry};
+
 // Re-export for convenience
 pub use crate::errors::Error;
 pub use crate::frame::{Frame, FrameHeader, FrameType};
@@ -52,6 +55,13 @@ pub enum PluginError {
 
     #[error("Plugin timeout: {operation}")]
     Timeout { operation: String },
+
+    #[error("Plugin {id} lacks permission {permission:?} for {frame_type:?} frame")]
+    PermissionDenied {
+        id: u32,
+        frame_type: FrameType,
+        permission: Permission,
+    },
 }
 
 type PluginResult<T> = Result<T, PluginError>;
@@ -307,6 +317,8 @@ pub struct PluginManager {
     channels: RwLock<HashMap<u32, mpsc::Sender<PluginMessage>>>,
     /// Plugin load order (by priority)
     load_order: RwLock<Vec<u32>>,
+    /// Permissions granted per plugin, checked by `dispatch_checked`
+    permissions: PluginRegistry,
     /// Configuration
     config: PluginManagerConfig,
 }
@@ -356,6 +368,7 @@ impl PluginManager {
             required_capabilities: RwLock::new(HashSet::new()),
             channels: RwLock::new(HashMap::new()),
             load_order: RwLock::new(Vec::new()),
+            permissions: PluginRegistry::new(),
             config,
         }
     }
@@ -485,6 +498,7 @@ impl PluginManager {
             load_order.retain(|&id| id != plugin_id);
             channels.remove(&plugin_id);
         }
+        let _ = self.permissions.unregister(PluginId(plugin_id)).await;
 
         info!("Plugin {} unregistered", plugin_id);
         Ok(())
@@ -496,6 +510,79 @@ impl PluginManager {
         let header: PluginHeader = serde_cbor::from_slice(&frame.payload)
             .map_err(|e| PluginError::SerializationError(e.to_string()))?;
 
+        self.run_plugin(&header, frame).await
+    }
+
+    /// Grant permissions to a registered plugin, as used by
+    /// [`PluginDispatcher`](crate::plugin_dispatch::PluginDispatcher).
+    pub async fn grant_permissions(
+        &self,
+        plugin_id: u32,
+        permissions: impl IntoIterator<Item = Permission>,
+    ) -> PluginResult<()> {
+        if !self.plugins.read().unwrap().contains_key(&plugin_id) {
+            return Err(PluginError::PluginNotFound { id: plugin_id });
+        }
+        let id = PluginId(plugin_id);
+        if self.permissions.is_registered(id).await {
+            let _ = self.permissions.grant(id, permissions).await;
+        } else {
+            let name = format!("plugin-{plugin_id}");
+            let _ = self
+                .permissions
+                .register(PluginInfo::new(id, name, permissions))
+                .await;
+        }
+        Ok(())
+    }
+
+    /// Process a frame sent by `plugin_id`, refusing it unless the plugin has
+    /// been granted the [`Permission`] its frame type requires.
+    ///
+    /// The frame header must also name `plugin_id`, so a plugin cannot borrow
+    /// another plugin's grants by spoofing its id.
+    pub async fn dispatch_checked(
+        &self,
+        plugin_id: u32,
+        frame: &Frame,
+    ) -> PluginResult<Vec<Frame>> {
+        let header: PluginHeader = serde_cbor::from_slice(&frame.payload)
+            .map_err(|e| PluginError::SerializationError(e.to_string()))?;
+        if header.id != plugin_id {
+            return Err(PluginError::SecurityViolation(format!(
+                "plugin {plugin_id} sent frame for plugin {}",
+                header.id
+            )));
+        }
+        if !self.plugins.read().unwrap().contains_key(&plugin_id) {
+            return Err(PluginError::PluginNotFound { id: plugin_id });
+        }
+
+        // Frames outside the plugin range carry plugin data
+        let permission = match frame.header.ty {
+            FrameType::Custom(ty) => Permission::for_frame_type(ty),
+            _ => Permission::DataAccess,
+        };
+        if !self
+            .permissions
+            .has_permission(PluginId(plugin_id), permission)
+            .await
+        {
+            warn!(
+                "Plugin {} denied {:?} frame: missing {:?}",
+                plugin_id, frame.header.ty, permission
+            );
+            return Err(PluginError::PermissionDenied {
+                id: plugin_id,
+                frame_type: frame.header.ty,
+                permission,
+            });
+        }
+
+        self.run_plugin(&header, frame).await
+    }
+
+    async fn run_plugin(&self, header: &PluginHeader, frame: &Frame) -> PluginResult<Vec<Frame>> {
         let plugin_id = header.id;
 
         // Find and process with appropriate plugin
@@ -508,7 +595,7 @@ impl PluginManager {
             let mut plugins = self.plugins.write().unwrap();
             plugins.remove(&plugin_id).expect("plugin must exist")
         };
-        let res = instance.process_frame(&header, frame).await;
+        let res = instance.process_frame(header, frame).await;
         {
             let mut plugins = self.plugins.write().unwrap();
             plugins.insert(plugin_id, instance);
@@ -628,6 +715,17 @@ impl CapabilityNegotiator {
         Self { manager }
     }
 
+    /// Dry-run negotiation of protocol capabilities: reports what would be
+    /// agreed and which required capabilities would abort the session,
+    /// without touching plugin or negotiation state.
+    pub fn preview(
+        &self,
+        local: &[crate::capability::Capability],
+        remote: &[crate::capability::Capability],
+    ) -> crate::capability::NegotiationOutcome {
+        crate::capability::preview(local, remote)
+    }
+
     /// Create capability advertisement frame
     pub fn create_capability_frame(&self, stream_id: u32, seq: u64) -> PluginResult<Frame> {
         let capabilities = self.manager.get_capabilities();
@@ -876,4 +974,111 @@ mod tests {
         let result = manager.process_plugin_frame(&frame).await.unwrap();
         assert_eq!(result.len(), 1);
     }
+
+    #[test]
+    async fn test_preview_flags_unsupported_required_capability() {
+        use crate::capability::{Capability, CAP_CORE, CAP_PLUGIN_FRAMEWORK};
+        use crate::management::build_close_unsupported_cap;
+
+        let manager = Arc::new(PluginManager::new(PluginManagerConfig::default()));
+        manager
+            .register_plugin(Box::new(CompressionPlugin::new()))
+            .await
+            .unwrap();
+        let negotiator = CapabilityNegotiator::new(manager.clone());
+
+        let local = vec![
+            Capability::required(CAP_CORE, vec![]),
+            Capability::optional(CAP_PLUGIN_FRAMEWORK, vec![]),
+        ];
+        let remote = vec![
+            Capability::required(CAP_CORE, vec![]),
+            Capability::required(0x0999, vec![]),
+            Capability::optional(0x0777, vec![]),
+        ];
+        let outcome = negotiator.preview(&local, &remote);
+        assert_eq!(outcome.negotiated, vec![CAP_CORE]);
+        assert_eq!(outcome.unsupported_required, vec![0x0999]);
+        assert!(outcome.unsupported_by_peer.is_empty());
+        assert!(!outcome.would_succeed());
+        assert_eq!(
+            outcome.close_reason(),
+            Some(build_close_unsupported_cap(0x0999))
+        );
+
+        // Previewing must not alter registered plugin capabilities
+        assert_eq!(manager.get_capabilities().len(), 1);
+        assert!(negotiator.preview(&local, &local).would_succeed());
+    }
+
+    fn plugin_frame(
+        manager: &PluginManager,
+        plugin_id: u32,
+        plugin_type: PluginFrameType,
+    ) -> Frame {
+        let header = PluginHeader {
+            id: plugin_id,
+            flags: 0,
+            data: b"payload".to_vec(),
+        };
+        manager
+            .create_plugin_frame(1, 1, plugin_type, &header)
+            .unwrap()
+    }
+
+    #[test]
+    async fn test_dispatch_checked_allows_granted_capability() {
+        let manager = PluginManager::new(PluginManagerConfig::default());
+        let plugin_id = manager
+            .register_plugin(Box::new(CompressionPlugin::new()))
+            .await
+            .unwrap();
+        manager
+            .grant_permissions(plugin_id, [Permission::DataAccess])
+            .await
+            .unwrap();
+
+        let frame = plugin_frame(&manager, plugin_id, PluginFrameType::Data);
+        let result = manager.dispatch_checked(plugin_id, &frame).await.unwrap();
+        assert_eq!(result.len(), 1);
+
+        // Unlisted frame types fall back to DataAccess, as in PluginDispatcher
+        let frame = plugin_frame(&manager, plugin_id, PluginFrameType::Heartbeat);
+        assert!(manager.dispatch_checked(plugin_id, &frame).await.is_ok());
+    }
+
+    #[test]
+    async fn test_dispatch_checked_denies_missing_capability() {
+        let manager = PluginManager::new(PluginManagerConfig::default());
+        let plugin_id = manager
+            .register_plugin(Box::new(CompressionPlugin::new()))
+            .await
+            .unwrap();
+        manager
+            .grant_permissions(plugin_id, [Permission::DataAccess])
+            .await
+            .unwrap();
+
+        let frame = plugin_frame(&manager, plugin_id, PluginFrameType::Control);
+        let err = manager
+            .dispatch_checked(plugin_id, &frame)
+            .await
+            .unwrap_err();
+        match err {
+            PluginError::PermissionDenied { id, permission, .. } => {
+                assert_eq!(id, plugin_id);
+                assert_eq!(permission, Permission::Control);
+            }
+            e => panic!("expected PermissionDenied, got {e:?}"),
+        }
+        let stats = manager.get_plugin_statistics();
+        assert_eq!(stats[&plugin_id]["frames_processed"], 0);
+
+        // A frame naming another plugin is rejected outright
+        let frame = plugin_frame(&manager, plugin_id, PluginFrameType::Data);
+        assert!(matches!(
+            manager.dispatch_checked(plugin_id + 1, &frame).await,
+            Err(PluginError::SecurityViolation(_))
+        ));
+    }
 }
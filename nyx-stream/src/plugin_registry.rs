@@ -17,6 +17,19 @@ pub enum Permission {
     ErrorReporting,
 }
 
+impl Permission {
+    /// Permission a plugin needs to send a frame of type `frame_type`.
+    pub fn for_frame_type(frame_type: u8) -> Self {
+        match frame_type {
+            0x51 => Permission::Handshake,      // Handshake frame
+            0x52 => Permission::DataAccess,     // Data frame
+            0x53 => Permission::Control,        // Control frame
+            0x54 => Permission::ErrorReporting, // Error frame
+            _ => Permission::DataAccess,        // Default to data access
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PluginInfo {
     pub id: PluginId,
@@ -79,6 +92,17 @@ impl PluginRegistry {
             .unwrap_or(false)
     }
 
+    pub async fn grant(
+        &self,
+        id: PluginId,
+        permissions: impl IntoIterator<Item = Permission>,
+    ) -> Result<(), &'static str> {
+        let mut m = self.inner.write().await;
+        let info = m.get_mut(&id).ok_or("not registered")?;
+        info.permissions.extend(permissions);
+        Ok(())
+    }
+
     pub async fn count(&self) -> usize {
         let m = self.inner.read().await;
         m.len()
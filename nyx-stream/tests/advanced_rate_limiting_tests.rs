@@ -396,7 +396,7 @@ async fn test_nyx_rate_limiter_rate_limiting() {
                     .expect("Subsequent transmission check failed");
 
                 match decision {
-                    TransmissionDecision::RateLimited
+                    TransmissionDecision::RateLimited { .. }
                     | TransmissionDecision::FlowControlBlocked => {
                         limited = true;
                         break;
@@ -411,7 +411,7 @@ async fn test_nyx_rate_limiter_rate_limiting() {
             // Should eventually hit some form of limiting
             assert!(limited, "Expected some form of rate limiting to occur");
         }
-        TransmissionDecision::RateLimited | TransmissionDecision::FlowControlBlocked => {
+        TransmissionDecision::RateLimited { .. } | TransmissionDecision::FlowControlBlocked => {
             // Also acceptable - very aggressive rate limiting
         }
         _ => panic!("Unexpected decision for first transmission: {decision:?}"),
@@ -509,7 +509,7 @@ async fn test_nyx_rate_limiter_priority_handling() {
     // The important thing is that the system processes the request
     match decision {
         TransmissionDecision::Allowed
-        | TransmissionDecision::RateLimited
+        | TransmissionDecision::RateLimited { .. }
         | TransmissionDecision::FlowControlBlocked => {
             // All are acceptable outcomes - priority affects bucket sizing and allocation
         }
@@ -741,7 +741,7 @@ async fn test_integration_realistic_scenario() {
                             );
                         }
                     }
-                    TransmissionDecision::RateLimited
+                    TransmissionDecision::RateLimited { .. }
                     | TransmissionDecision::FlowControlBlocked => {
                         total_blocked += 1;
                     }
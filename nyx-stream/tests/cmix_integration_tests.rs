@@ -57,6 +57,7 @@ async fn test_cmix_manager_basic_functionality() -> Result<(), Box<dyn std::erro
             __fast_verification: true,
         },
         enable_accumulator_proofs: true,
+        batch_jitter: Duration::ZERO,
     };
 
     let manager = CmixIntegrationManager::new(config)?;
@@ -92,6 +93,7 @@ async fn test_cmix_frame_batching() -> Result<(), Box<dyn std::error::Error>> {
             __fast_verification: true,
         },
         enable_accumulator_proofs: true,
+        batch_jitter: Duration::ZERO,
     };
 
     let manager = CmixIntegrationManager::new(config)?;
@@ -147,6 +149,7 @@ async fn test_cmix_vdf_integration() -> Result<(), Box<dyn std::error::Error>> {
             __fast_verification: true,
         },
         enable_accumulator_proofs: true,
+        batch_jitter: Duration::ZERO,
     };
 
     let manager = CmixIntegrationManager::new(config)?;
@@ -195,6 +198,7 @@ async fn test_cmix_accumulator_proofs() -> Result<(), Box<dyn std::error::Error>
             __fast_verification: true,
         },
         enable_accumulator_proofs: true,
+        batch_jitter: Duration::ZERO,
     };
 
     let manager = CmixIntegrationManager::new(config)?;
@@ -246,6 +250,7 @@ async fn test_cmix_force_flush() -> Result<(), Box<dyn std::error::Error>> {
             __fast_verification: true,
         },
         enable_accumulator_proofs: false, // Disable for faster testing
+        batch_jitter: Duration::ZERO,
     };
 
     let manager = CmixIntegrationManager::new(config)?;
@@ -293,6 +298,7 @@ async fn test_cmix_async_stream_integration() -> Result<(), Box<dyn std::error::
             __fast_verification: true,
         },
         enable_accumulator_proofs: false,
+        batch_jitter: Duration::ZERO,
     };
 
     let stream_config = AsyncStreamConfig {
@@ -335,6 +341,7 @@ async fn test_cmix_batch_timeout() -> Result<(), Box<dyn std::error::Error>> {
             __fast_verification: true,
         },
         enable_accumulator_proofs: false,
+        batch_jitter: Duration::ZERO,
     };
 
     let manager = CmixIntegrationManager::new(config)?;
@@ -403,6 +410,7 @@ async fn test_cmix_performance_under_load() -> Result<(), Box<dyn std::error::Er
             __fast_verification: true,
         },
         enable_accumulator_proofs: false, // Disabled for speed
+        batch_jitter: Duration::ZERO,
     };
 
     let manager = CmixIntegrationManager::new(config)?;
@@ -14,7 +14,7 @@
 //! requirements from Nyx Protocol v1.0 specification.
 
 use nyx_stream::early_data::*;
-use nyx_stream::errors::Result;
+use nyx_stream::errors::{Error, Result};
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::Duration;
@@ -196,7 +196,7 @@ fn test_anti_replay_window_reset() {
 
 #[test]
 fn test_early_data_manager_lifecycle() -> Result<()> {
-    let mut manager = EarlyDataManager::new();
+    let mut manager = EarlyDataManager::default();
 
     // Initial state should be disabled
     assert_eq!(manager.state(), EarlyDataState::Disabled);
@@ -220,7 +220,7 @@ fn test_early_data_manager_lifecycle() -> Result<()> {
 
 #[test]
 fn test_early_data_validation_basic() -> Result<()> {
-    let mut manager = EarlyDataManager::new();
+    let mut manager = EarlyDataManager::default();
     let direction = DirectionId::CLIENT_TO_SERVER;
     let test_data = b"Hello, early data world!";
 
@@ -246,7 +246,7 @@ fn test_early_data_validation_basic() -> Result<()> {
 
 #[test]
 fn test_early_data_size_limits() -> Result<()> {
-    let mut manager = EarlyDataManager::new();
+    let mut manager = EarlyDataManager::default();
     manager.enable_early_data()?;
 
     let direction = DirectionId::CLIENT_TO_SERVER;
@@ -266,7 +266,7 @@ fn test_early_data_size_limits() -> Result<()> {
 
 #[test]
 fn test_early_data_total_session_limits() -> Result<()> {
-    let mut manager = EarlyDataManager::new();
+    let mut manager = EarlyDataManager::default();
     manager.enable_early_data()?;
 
     let direction = DirectionId::CLIENT_TO_SERVER;
@@ -292,15 +292,75 @@ fn test_early_data_total_session_limits() -> Result<()> {
 
     // This chunk should exceed the total limit
     let result =
-        manager.validate_early_data(direction, Nonce::new(max_chunks as u64 + 2), &data_chunk)?;
-    assert!(!result); // Should be rejected due to total limit
+        manager.validate_early_data(direction, Nonce::new(max_chunks as u64 + 2), &data_chunk);
+    assert!(result.is_err()); // Should be rejected due to total limit
+
+    Ok(())
+}
+
+#[test]
+fn test_early_data_config_per_message_limit() -> Result<()> {
+    let config = EarlyDataConfig {
+        max_early_data_size: 100,
+        ..Default::default()
+    };
+    let mut manager = EarlyDataManager::new(config);
+    manager.enable_early_data()?;
+    let direction = DirectionId::CLIENT_TO_SERVER;
+
+    assert!(manager.validate_early_data(direction, Nonce::new(1), &[0u8; 100])?);
+    let err = manager
+        .validate_early_data(direction, Nonce::new(2), &[0u8; 101])
+        .unwrap_err();
+    let expected = EarlyDataLimitError::PacketTooLarge {
+        size: 101,
+        max: 100,
+    };
+    assert!(matches!(&err, Error::EarlyDataLimit(e) if *e == expected));
+
+    let metrics = manager.metrics()?;
+    assert_eq!(metrics.limit_rejected_count, 1);
+    assert_eq!(metrics.last_limit_error, Some(expected));
+    assert_eq!(manager.session_stats().max_early_data_size, 100);
+
+    Ok(())
+}
+
+#[test]
+fn test_early_data_config_cumulative_limit() -> Result<()> {
+    let config = EarlyDataConfig {
+        max_early_data_size: 100,
+        max_total_early_data: 250,
+    };
+    let mut manager = EarlyDataManager::new(config);
+    manager.enable_early_data()?;
+    let direction = DirectionId::CLIENT_TO_SERVER;
+
+    assert!(manager.validate_early_data(direction, Nonce::new(1), &[0u8; 100])?);
+    assert!(manager.validate_early_data(direction, Nonce::new(2), &[0u8; 100])?);
+    let err = manager
+        .validate_early_data(direction, Nonce::new(3), &[0u8; 100])
+        .unwrap_err();
+    let expected = EarlyDataLimitError::TotalExceeded {
+        received: 200,
+        size: 100,
+        max: 250,
+    };
+    assert!(matches!(&err, Error::EarlyDataLimit(e) if *e == expected));
+    // Smaller data that still fits is accepted.
+    assert!(manager.validate_early_data(direction, Nonce::new(3), &[0u8; 50])?);
+
+    let metrics = manager.metrics()?;
+    assert_eq!(metrics.limit_rejected_count, 1);
+    assert_eq!(metrics.last_limit_error, Some(expected));
+    assert_eq!(metrics.total_early_data_bytes, 250);
 
     Ok(())
 }
 
 #[test]
 fn test_early_data_security_disable() -> Result<()> {
-    let mut manager = EarlyDataManager::new();
+    let mut manager = EarlyDataManager::default();
 
     // Enable early data first
     manager.enable_early_data()?;
@@ -325,7 +385,7 @@ fn test_early_data_security_disable() -> Result<()> {
 
 #[test]
 fn test_early_data_rekey_operation() -> Result<()> {
-    let mut manager = EarlyDataManager::new();
+    let mut manager = EarlyDataManager::default();
     manager.enable_early_data()?;
 
     let direction = DirectionId::CLIENT_TO_SERVER;
@@ -352,7 +412,7 @@ fn test_early_data_rekey_operation() -> Result<()> {
 
 #[test]
 fn test_early_data_bidirectional() -> Result<()> {
-    let mut manager = EarlyDataManager::new();
+    let mut manager = EarlyDataManager::default();
     manager.enable_early_data()?;
 
     let client_dir = DirectionId::CLIENT_TO_SERVER;
@@ -407,7 +467,7 @@ fn test_nonce_constructor_utilities() -> Result<()> {
 
 #[test]
 fn test_early_data_metrics_collection() -> Result<()> {
-    let mut manager = EarlyDataManager::new();
+    let mut manager = EarlyDataManager::default();
     let direction = DirectionId::CLIENT_TO_SERVER;
     let test_data = b"metrics test data";
 
@@ -449,7 +509,7 @@ fn test_early_data_metrics_collection() -> Result<()> {
 
 #[test]
 fn test_session_statistics() -> Result<()> {
-    let mut manager = EarlyDataManager::new();
+    let mut manager = EarlyDataManager::default();
     let direction = DirectionId::CLIENT_TO_SERVER;
     let test_data = b"session stats test";
 
@@ -475,7 +535,7 @@ fn test_session_statistics() -> Result<()> {
 
 #[test]
 fn test_window_statistics_collection() -> Result<()> {
-    let mut manager = EarlyDataManager::new();
+    let mut manager = EarlyDataManager::default();
     manager.enable_early_data()?;
 
     let client_dir = DirectionId::CLIENT_TO_SERVER;
@@ -512,7 +572,7 @@ fn test_window_statistics_collection() -> Result<()> {
 
 #[test]
 fn test_concurrent_access() -> Result<()> {
-    let manager = Arc::new(Mutex::new(EarlyDataManager::new()));
+    let manager = Arc::new(Mutex::new(EarlyDataManager::default()));
     let direction = DirectionId::CLIENT_TO_SERVER;
     let test_data = b"concurrent test";
 
@@ -577,7 +637,7 @@ fn test_edge_case_window_boundaries() {
 
 #[test]
 fn test_comprehensive_rejection_reasons() -> Result<()> {
-    let mut manager = EarlyDataManager::new();
+    let mut manager = EarlyDataManager::default();
     let direction = DirectionId::CLIENT_TO_SERVER;
     let test_data = b"rejection test";
 
@@ -607,3 +667,23 @@ fn test_comprehensive_rejection_reasons() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_anti_replay_state_survives_reconnect() {
+    let direction = DirectionId::CLIENT_TO_SERVER;
+    let mut window = AntiReplayWindow::new(direction);
+    assert!(window.check_and_update(Nonce::new(42)));
+    let state = window.export_state().unwrap();
+
+    // A fresh window for the reconnected session would accept the old nonce...
+    let mut restored = AntiReplayWindow::new(direction);
+    // ...but not once the persisted state is imported.
+    restored.import_state(&state).unwrap();
+    assert!(!restored.check_and_update(Nonce::new(42)));
+    assert!(restored.check_and_update(Nonce::new(43)));
+    assert_eq!(restored.stats().window_base, 43);
+
+    // State is bound to its direction.
+    let mut other = AntiReplayWindow::new(DirectionId::SERVER_TO_CLIENT);
+    assert!(other.import_state(&state).is_err());
+}
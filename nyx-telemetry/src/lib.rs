@@ -58,9 +58,7 @@ mod opentelemetry_integration {
         // No-op when otlp feature is disabled
     }
 }
-#[cfg(feature = "otlp")]
-mod otlp;
-mod sampling;
+pub mod sampling;
 
 // --- Public API surface kept stable -------------------------------------------
 
@@ -82,6 +80,19 @@ pub fn record_counter(name: &str, v: u64) {
     self::metrics::record_counter(name, v)
 }
 
+/// Set an IntGauge to the provided value. The gauge is lazily created and
+/// registered to the shared Prometheus registry upon first use.
+pub fn set_gauge(name: &str, v: i64) {
+    self::metrics::set_gauge(name, v)
+}
+
+/// Drop all counters and gauges from the shared registry (feature = "test_util").
+/// Lets tests in the same process start from a clean registry.
+#[cfg(any(test, feature = "test_util"))]
+pub fn reset_metrics() {
+    self::metrics::reset_metrics()
+}
+
 /// Dump Prometheus metrics in text exposition format.
 pub fn dump_prometheus() -> String {
     // Access the global registry directly since module path resolution is problematic
@@ -112,6 +123,16 @@ pub fn warp_metrics_filter(
     self::metrics::warp_metrics_filter()
 }
 
+/// Same as [`warp_metrics_filter`], optionally requiring a bearer token.
+/// `None` keeps the endpoint unauthenticated; with `Some(token)` requests
+/// lacking `Authorization: Bearer <token>` are answered with 401.
+#[cfg(feature = "prometheus")]
+pub fn warp_metrics_filter_with_auth(
+    token: Option<String>,
+) -> impl warp::Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    self::metrics::warp_metrics_filter_with_auth(token)
+}
+
 /// Start a standalone Prometheus metrics HTTP server on the given address.
 /// Returns a guard that will gracefully stop the server when dropped.
 #[cfg(feature = "prometheus")]
@@ -121,10 +142,23 @@ pub async fn start_metrics_http_server(
     self::metrics::start_http_server(addr).await
 }
 
+/// Like [`start_metrics_http_server`], but falls back to an ephemeral port when
+/// `preferred` cannot be bound. Check the guard's `addr()` for the port in use.
+#[cfg(feature = "prometheus")]
+pub async fn start_metrics_http_server_or_ephemeral(
+    preferred: std::net::SocketAddr,
+) -> Result<metrics::MetricsHttpServerGuard> {
+    self::metrics::start_http_server_or_ephemeral(preferred).await
+}
+
 // Small self-check
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::Mutex;
+
+    // Tests touching the shared registry must not interleave with a reset.
+    static REGISTRY_LOCK: Mutex<()> = Mutex::new(());
 
     #[test]
     fn initnoop() -> Result<()> {
@@ -134,6 +168,7 @@ mod tests {
 
     #[test]
     fn counter_and_dump_smoke() -> Result<()> {
+        let _guard = REGISTRY_LOCK.lock().unwrap_or_else(|e| e.into_inner());
         init(&Config {
             exporter: Exporter::Prometheus,
             servicename: None,
@@ -143,4 +178,26 @@ mod tests {
         assert!(out.contains("unit_counter"));
         Ok(())
     }
+
+    #[test]
+    fn reset_metrics_clears_counters() {
+        let _guard = REGISTRY_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        record_counter("reset_probe_counter", 2);
+        assert!(dump_prometheus().contains("reset_probe_counter 2"));
+        reset_metrics();
+        assert!(!dump_prometheus().contains("reset_probe_counter"));
+        // Re-recording after a reset starts from zero.
+        record_counter("reset_probe_counter", 1);
+        assert!(dump_prometheus().contains("reset_probe_counter 1"));
+    }
+
+    #[test]
+    fn gauge_set_and_reset() {
+        let _guard = REGISTRY_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        set_gauge("unit_gauge", 7);
+        set_gauge("unit_gauge", 3);
+        assert!(dump_prometheus().contains("unit_gauge 3"));
+        reset_metrics();
+        assert!(!dump_prometheus().contains("unit_gauge"));
+    }
 }
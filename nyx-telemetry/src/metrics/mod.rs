@@ -1,6 +1,6 @@
 use once_cell::sync::Lazy;
 use prometheus::Encoder;
-use prometheus::{IntCounter, Registry, TextEncoder};
+use prometheus::{IntCounter, IntGauge, Registry, TextEncoder};
 /// Metrics utilities and Prometheus exposition with robust error handling.
 use std::collections::HashMap;
 use std::sync::Mutex;
@@ -13,6 +13,46 @@ pub(crate) static REGISTRY: Lazy<Registry> = Lazy::new(Registry::new);
 static COUNTERS: Lazy<Mutex<HashMap<String, IntCounter>>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
 
+/// Thread-safe storage for dynamically created gauge metrics
+static GAUGES: Lazy<Mutex<HashMap<String, IntGauge>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Set an IntGauge to `v`, creating and registering it on first use.
+///
+/// Gauges whose name cannot be registered (invalid name or a clash with an
+/// existing metric of another type) are still cached but not exported.
+pub fn set_gauge(name: &str, v: i64) {
+    let mut map = match GAUGES.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => {
+            tracing::warn!(
+                metricname = name,
+                "Gauge mutex was poisoned, attempting recovery"
+            );
+            poisoned.into_inner()
+        }
+    };
+    if let Some(gauge) = map.get(name) {
+        gauge.set(v);
+        return;
+    }
+    match IntGauge::new(name, format!("Nyx protocol gauge: {name}")) {
+        Ok(gauge) => {
+            if let Err(reg_error) = REGISTRY.register(Box::new(gauge.clone())) {
+                tracing::debug!(
+                    metricname = name,
+                    error = %reg_error,
+                    "Gauge registration failed (likely already registered)"
+                );
+            }
+            gauge.set(v);
+            map.insert(name.to_string(), gauge);
+        }
+        Err(gauge_error) => {
+            tracing::error!(metricname = name, error = %gauge_error, "Failed to create gauge");
+        }
+    }
+}
+
 /// Record a value into an IntCounter, creating and registering it on first use
 ///
 /// This function handles counter creation, registration, and value updates in a
@@ -138,29 +178,93 @@ pub fn record_counter(name: &str, v: u64) {
     }
 }
 
+/// Unregister every cached counter and gauge and clear the caches so the next
+/// `record_counter`/`set_gauge` starts from zero. Only for test isolation: the global
+/// registry otherwise leaks state between tests sharing a process.
+#[cfg(any(test, feature = "test_util"))]
+pub fn reset_metrics() {
+    let mut map = match COUNTERS.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    for (name, counter) in map.drain() {
+        if let Err(e) = REGISTRY.unregister(Box::new(counter)) {
+            tracing::debug!(metricname = %name, error = %e, "Counter was not registered");
+        }
+    }
+    drop(map);
+    let mut gauges = match GAUGES.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    for (name, gauge) in gauges.drain() {
+        if let Err(e) = REGISTRY.unregister(Box::new(gauge)) {
+            tracing::debug!(metricname = %name, error = %e, "Gauge was not registered");
+        }
+    }
+}
+
 #[cfg(feature = "prometheus")]
 use warp::{Filter, Rejection, Reply};
 
 /// Provide a Warp filter that serves "/metrics" with text exposition.
 #[cfg(feature = "prometheus")]
 pub fn warp_metrics_filter() -> impl Filter<Extract = (impl Reply,), Error = Rejection> + Clone {
-    warp::path("metrics").and(warp::get()).map(|| {
-        // Inline implementation to avoid scope issues
-        let metric_families = REGISTRY.gather();
-        let encoder = TextEncoder::new();
-        let mut buffer = Vec::new();
-        let metrics_text = match encoder.encode(&metric_families, &mut buffer) {
-            Ok(()) => String::from_utf8(buffer).unwrap_or_else(|_| {
-                String::from("# Prometheus metrics export failed: UTF-8 conversion error\n")
-            }),
-            Err(_) => String::from("# Prometheus metrics export failed: encoding error\n"),
-        };
-        warp::reply::with_header(
-            metrics_text,
-            "content-type",
-            "text/plain; version=0.0.4; charset=utf-8",
-        )
-    })
+    warp_metrics_filter_with_auth(None)
+}
+
+/// Like [`warp_metrics_filter`], but when `token` is set every scrape must carry
+/// `Authorization: Bearer <token>`; anything else gets 401.
+#[cfg(feature = "prometheus")]
+pub fn warp_metrics_filter_with_auth(
+    token: Option<String>,
+) -> impl Filter<Extract = (impl Reply,), Error = Rejection> + Clone {
+    warp::path("metrics")
+        .and(warp::get())
+        .and(warp::header::optional::<String>("authorization"))
+        .map(move |auth: Option<String>| {
+            if let Some(expected) = token.as_deref() {
+                let presented = auth.as_deref().and_then(|h| h.strip_prefix("Bearer "));
+                if !presented.is_some_and(|p| tokens_match(p.as_bytes(), expected.as_bytes())) {
+                    return warp::reply::with_header(
+                        warp::reply::with_status(
+                            String::from("Unauthorized\n"),
+                            warp::http::StatusCode::UNAUTHORIZED,
+                        ),
+                        "www-authenticate",
+                        "Bearer",
+                    )
+                    .into_response();
+                }
+            }
+            // Inline implementation to avoid scope issues
+            let metric_families = REGISTRY.gather();
+            let encoder = TextEncoder::new();
+            let mut buffer = Vec::new();
+            let metrics_text = match encoder.encode(&metric_families, &mut buffer) {
+                Ok(()) => String::from_utf8(buffer).unwrap_or_else(|_| {
+                    String::from("# Prometheus metrics export failed: UTF-8 conversion error\n")
+                }),
+                Err(_) => String::from("# Prometheus metrics export failed: encoding error\n"),
+            };
+            warp::reply::with_header(
+                metrics_text,
+                "content-type",
+                "text/plain; version=0.0.4; charset=utf-8",
+            )
+            .into_response()
+        })
+}
+
+/// Compare tokens via their SHA-256 digests so neither the differing byte
+/// nor the expected token's length shows up in the comparison time.
+#[cfg(feature = "prometheus")]
+fn tokens_match(presented: &[u8], expected: &[u8]) -> bool {
+    use sha2::{Digest, Sha256};
+    use subtle::ConstantTimeEq;
+    Sha256::digest(presented)
+        .ct_eq(&Sha256::digest(expected))
+        .into()
 }
 
 /// A guard that stops the metrics HTTP server when dropped.
@@ -192,7 +296,8 @@ impl Drop for MetricsHttpServerGuard {
     }
 }
 
-/// Start a background Warp server that serves `/metrics` on the given address.
+/// Start a background server that serves `/metrics` (and `/healthz` for
+/// liveness probes) on the given address.
 #[cfg(feature = "prometheus")]
 pub async fn start_http_server(
     addr: std::net::SocketAddr,
@@ -232,6 +337,9 @@ pub async fn start_http_server(
                     hyper::header::HeaderValue::from_static("text/plain; version=0.0.4"),
                 );
                 Ok::<_, hyper::Error>(resp)
+            } else if req.method() == hyper::Method::GET && req.uri().path() == "/healthz" {
+                // Liveness probe: the server answering at all is the signal.
+                Ok::<_, hyper::Error>(Response::new(Body::from("ok\n")))
             } else {
                 let mut resp = Response::new(Body::from("Not Found"));
                 *resp.status_mut() = StatusCode::NOT_FOUND;
@@ -267,3 +375,31 @@ pub async fn start_http_server(
         addr: bound_addr,
     })
 }
+
+/// Start the metrics server on `preferred`, falling back to an ephemeral port
+/// on the same interface if that fails (typically because the port is in use).
+/// The returned guard's `addr()` reports the address actually bound.
+#[cfg(feature = "prometheus")]
+pub async fn start_http_server_or_ephemeral(
+    preferred: std::net::SocketAddr,
+) -> crate::Result<MetricsHttpServerGuard> {
+    match start_http_server(preferred).await {
+        Ok(guard) => {
+            tracing::info!(addr = %guard.addr(), "metrics server bound to preferred address");
+            Ok(guard)
+        }
+        Err(e) if preferred.port() != 0 => {
+            let mut fallback = preferred;
+            fallback.set_port(0);
+            tracing::warn!(
+                preferred = %preferred,
+                error = %e,
+                "metrics server could not bind preferred address, falling back to an ephemeral port"
+            );
+            let guard = start_http_server(fallback).await?;
+            tracing::info!(addr = %guard.addr(), "metrics server bound to ephemeral address");
+            Ok(guard)
+        }
+        Err(e) => Err(e),
+    }
+}
@@ -3,6 +3,7 @@
 //! This module provides OTLP exporter functionality for sending telemetry data
 //! to OpenTelemetry collectors via gRPC or HTTP protocols.
 
+use crate::sampling::MemoryPressurePolicy;
 use crate::{Error, Result};
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -20,6 +21,8 @@ const DEFAULT_BATCH_TIMEOUT: Duration = Duration::from_secs(5);
 const DEFAULT_MAX_RETRIES: u32 = 3;
 /// Retry backoff base duration
 const DEFAULT_RETRY_BACKOFF: Duration = Duration::from_millis(100);
+/// Upper bound for the exponential retry backoff
+const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(30);
 
 /// OTLP configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -140,6 +143,37 @@ pub struct OtlpExporter {
     stats: Arc<RwLock<ExportStats>>,
     /// Shutdown signal
     shutdown_tx: Option<mpsc::Sender<()>>,
+    /// Optional memory-pressure sampling applied before queueing spans
+    sampling: Option<PressureSampling>,
+}
+
+/// Memory-pressure policy plus how often its memory probe is re-run
+struct PressureSampling {
+    policy: Arc<MemoryPressurePolicy>,
+    refresh_every: Duration,
+    last_refresh: std::sync::Mutex<Option<Instant>>,
+}
+
+impl PressureSampling {
+    /// Re-probe memory when the last reading is stale, then roll the dice
+    fn admit(&self) -> bool {
+        let now = Instant::now();
+        let stale = {
+            let mut last = self
+                .last_refresh
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            let stale = last.is_none_or(|at| now.duration_since(at) >= self.refresh_every);
+            if stale {
+                *last = Some(now);
+            }
+            stale
+        };
+        if stale {
+            self.policy.refresh();
+        }
+        self.policy.should_sample()
+    }
 }
 
 /// Export statistics
@@ -155,6 +189,12 @@ pub struct ExportStats {
     pub batches_sent: u64,
     /// Total retries performed
     pub retries_performed: u64,
+    /// Batches dropped after a permanent failure or exhausted retries
+    pub dead_lettered_batches: u64,
+    /// Spans contained in dead-lettered batches
+    pub spans_dead_lettered: u64,
+    /// Spans dropped by the memory-pressure sampling policy
+    pub spans_sampled_out: u64,
     /// Last export time
     pub last_export: Option<SystemTime>,
     /// Export errors
@@ -191,11 +231,35 @@ impl OtlpExporter {
             span_sender,
             stats,
             shutdown_tx: Some(shutdown_tx),
+            sampling: None,
         })
     }
 
+    /// Drop spans according to `policy` before they are queued, probing
+    /// memory at most once per `refresh_every`
+    pub fn with_memory_pressure(
+        mut self,
+        policy: Arc<MemoryPressurePolicy>,
+        refresh_every: Duration,
+    ) -> Self {
+        self.sampling = Some(PressureSampling {
+            policy,
+            refresh_every,
+            last_refresh: std::sync::Mutex::new(None),
+        });
+        self
+    }
+
     /// Export a span
+    ///
+    /// Under memory pressure the span may be sampled out; that is not an error.
     pub async fn export_span(&self, span: Span) -> Result<()> {
+        if let Some(sampling) = &self.sampling {
+            if !sampling.admit() {
+                self.stats.write().await.spans_sampled_out += 1;
+                return Ok(());
+            }
+        }
         {
             let mut stats = self.stats.write().await;
             stats.spans_received += 1;
@@ -365,14 +429,19 @@ impl ExportTask {
             Err(e) => {
                 let mut stats = self.stats.write().await;
                 stats.export_failures += 1;
+                stats.dead_lettered_batches += 1;
+                stats.spans_dead_lettered += batch.spans.len() as u64;
                 stats.last_error = Some(e.to_string());
-                error!("Failed to export batch: {}", e);
+                error!("Dead-lettering batch of {} spans: {}", batch.spans.len(), e);
             }
         }
 
         self.last_export = Instant::now();
     }
 
+    /// Export with bounded exponential backoff. Only transient failures
+    /// (connect errors, timeouts, 408/429/5xx) are retried; anything else is
+    /// returned immediately so the caller can dead-letter the batch.
     async fn export_batch_with_retry(&mut self, batch: &ExportBatch) -> Result<()> {
         let mut retry_count = 0;
         let mut backoff = self.config.retry_backoff;
@@ -380,7 +449,8 @@ impl ExportTask {
         loop {
             match self.export_batch(batch).await {
                 Ok(_) => return Ok(()),
-                Err(e) => {
+                Err(ExportFailure::Permanent(e)) => return Err(e),
+                Err(ExportFailure::Transient(e)) => {
                     retry_count += 1;
                     if retry_count > self.config.max_retries {
                         return Err(e);
@@ -393,20 +463,20 @@ impl ExportTask {
 
                     warn!("Export attempt {} failed, retrying in {:?}: {}", retry_count, backoff, e);
                     sleep(backoff).await;
-                    backoff = std::cmp::min(backoff * 2, Duration::from_secs(30));
+                    backoff = std::cmp::min(backoff * 2, MAX_RETRY_BACKOFF);
                 }
             }
         }
     }
 
-    async fn export_batch(&self, batch: &ExportBatch) -> Result<()> {
+    async fn export_batch(&self, batch: &ExportBatch) -> std::result::Result<(), ExportFailure> {
         let url = match self.config.protocol {
             OtlpProtocol::Grpc => format!("{}/v1/traces", self.config.endpoint),
             OtlpProtocol::Http => format!("{}/v1/traces", self.config.endpoint),
         };
 
         let body = serde_json::to_vec(batch)
-            .map_err(|e| Error::Init(format!("Failed to serialize batch: {}", e)))?;
+            .map_err(|e| ExportFailure::Permanent(Error::Init(format!("Failed to serialize batch: {}", e))))?;
 
         let mut request = self.client
             .post(&url)
@@ -425,8 +495,8 @@ impl ExportTask {
 
         let response = timeout(self.config.timeout, request.body(body).send())
             .await
-            .map_err(|_| Error::Init("Request timeout".to_string()))?
-            .map_err(|e| Error::Init(format!("Request failed: {}", e)))?;
+            .map_err(|_| ExportFailure::Transient(Error::Init("Request timeout".to_string())))?
+            .map_err(|e| ExportFailure::Transient(Error::Init(format!("Request failed: {}", e))))?;
 
         if response.status().is_success() {
             Ok(())
@@ -436,11 +506,27 @@ impl ExportTask {
                 .text()
                 .await
                 .unwrap_or_else(|_| "Failed to read response body".to_string());
-            Err(Error::Init(format!("Export failed with status {}: {}", status, body)))
+            let err = Error::Init(format!("Export failed with status {}: {}", status, body));
+            if status.is_server_error()
+                || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+                || status == reqwest::StatusCode::REQUEST_TIMEOUT
+            {
+                Err(ExportFailure::Transient(err))
+            } else {
+                Err(ExportFailure::Permanent(err))
+            }
         }
     }
 }
 
+/// Outcome of a failed export attempt
+enum ExportFailure {
+    /// Collector unavailable or overloaded; worth retrying
+    Transient(Error),
+    /// Collector rejected the batch; retrying would not help
+    Permanent(Error),
+}
+
 /// Utility functions for span creation
 pub mod utils {
     use super::*;
@@ -589,6 +675,40 @@ mod tests {
         exporter.shutdown().await.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_memory_pressure_drops_spans_until_recovery() {
+        use std::sync::atomic::{AtomicU64, Ordering};
+        const MIB: u64 = 1024 * 1024;
+        let injected = Arc::new(AtomicU64::new(900 * MIB));
+        let probe = Arc::clone(&injected);
+        let policy = Arc::new(MemoryPressurePolicy::with_probe(
+            1.0,
+            500 * MIB,
+            800 * MIB,
+            move || probe.load(Ordering::Relaxed),
+        ));
+        let mut exporter = OtlpExporter::new(create_test_config())
+            .await
+            .unwrap()
+            .with_memory_pressure(policy, Duration::ZERO);
+        let span = || utils::create_span("t".into(), "s".into(), "pressure".into(), None);
+
+        for _ in 0..3 {
+            exporter.export_span(span()).await.unwrap();
+        }
+        let stats = exporter.stats().await;
+        assert_eq!(stats.spans_sampled_out, 3);
+        assert_eq!(stats.spans_received, 0);
+
+        injected.store(100 * MIB, Ordering::Relaxed);
+        exporter.export_span(span()).await.unwrap();
+        let stats = exporter.stats().await;
+        assert_eq!(stats.spans_sampled_out, 3);
+        assert_eq!(stats.spans_received, 1);
+
+        exporter.shutdown().await.unwrap();
+    }
+
     #[tokio::test]
     async fn test_force_flush() {
         let config = create_test_config();
@@ -708,6 +828,9 @@ mod tests {
         assert_eq!(stats.export_failures, 0);
         assert_eq!(stats.batches_sent, 0);
         assert_eq!(stats.retries_performed, 0);
+        assert_eq!(stats.dead_lettered_batches, 0);
+        assert_eq!(stats.spans_dead_lettered, 0);
+        assert_eq!(stats.spans_sampled_out, 0);
         assert!(stats.last_export.is_none());
         assert!(stats.last_error.is_none());
     }
@@ -1,10 +1,9 @@
 //! Simple probabilistic sampling helper_s.
 
 use rand::Rng;
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
 
 /// Return true with probability p in [0.0, 1.0].
-// Thi_s helper i_s primarily used in test_s; allow dead code in library build_s.
-#[allow(dead_code)]
 pub fn sample(p: f64) -> bool {
     if !(0.0..=1.0).contains(&p) {
         return false;
@@ -19,9 +18,160 @@ pub fn sample(p: f64) -> bool {
     rng.gen::<f64>() < p
 }
 
+/// Resident memory of the current process in bytes (0 if unavailable).
+pub fn process_memory_bytes() -> u64 {
+    let Ok(pid) = sysinfo::get_current_pid() else {
+        return 0;
+    };
+    let mut sys = sysinfo::System::new();
+    if !sys.refresh_process(pid) {
+        return 0;
+    }
+    sys.process(pid).map(|p| p.memory()).unwrap_or(0)
+}
+
+/// Memory pressure level tracked by [`MemoryPressurePolicy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PressureLevel {
+    /// Below the soft limit: sample at the base rate.
+    Normal,
+    /// Above the soft limit: sample at a reduced rate.
+    Elevated,
+    /// Above the hard limit: span creation is suspended.
+    Critical,
+}
+
+/// Sampling policy that backs off when process memory is high.
+///
+/// Attach it to an exporter with `otlp::OtlpExporter::with_memory_pressure`.
+///
+/// Above `soft_limit_bytes` the base rate is multiplied by `reduced_factor`;
+/// above `hard_limit_bytes` sampling is suspended. A level is only left once
+/// memory falls below its limit by `recovery_margin` (a fraction of the
+/// limit), so the rate does not flap around a threshold.
+pub struct MemoryPressurePolicy {
+    base_rate: f64,
+    reduced_factor: f64,
+    soft_limit_bytes: u64,
+    hard_limit_bytes: u64,
+    recovery_margin: f64,
+    probe: Box<dyn Fn() -> u64 + Send + Sync>,
+    level: AtomicU8,
+    last_observed: AtomicU64,
+}
+
+impl std::fmt::Debug for MemoryPressurePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MemoryPressurePolicy")
+            .field("base_rate", &self.base_rate)
+            .field("reduced_factor", &self.reduced_factor)
+            .field("soft_limit_bytes", &self.soft_limit_bytes)
+            .field("hard_limit_bytes", &self.hard_limit_bytes)
+            .field("level", &self.level())
+            .finish()
+    }
+}
+
+impl MemoryPressurePolicy {
+    /// Create a policy probing process RSS via [`process_memory_bytes`].
+    pub fn new(base_rate: f64, soft_limit_bytes: u64, hard_limit_bytes: u64) -> Self {
+        Self::with_probe(
+            base_rate,
+            soft_limit_bytes,
+            hard_limit_bytes,
+            process_memory_bytes,
+        )
+    }
+
+    /// Create a policy with a custom memory probe (useful for tests).
+    pub fn with_probe<F>(
+        base_rate: f64,
+        soft_limit_bytes: u64,
+        hard_limit_bytes: u64,
+        probe: F,
+    ) -> Self
+    where
+        F: Fn() -> u64 + Send + Sync + 'static,
+    {
+        Self {
+            base_rate: base_rate.clamp(0.0, 1.0),
+            reduced_factor: 0.1,
+            soft_limit_bytes,
+            hard_limit_bytes: hard_limit_bytes.max(soft_limit_bytes),
+            recovery_margin: 0.1,
+            probe: Box::new(probe),
+            level: AtomicU8::new(PressureLevel::Normal as u8),
+            last_observed: AtomicU64::new(0),
+        }
+    }
+
+    /// Override the rate multiplier applied at [`PressureLevel::Elevated`].
+    pub fn with_reduced_factor(mut self, factor: f64) -> Self {
+        self.reduced_factor = factor.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Override the hysteresis margin (fraction of a limit, 0.0..=1.0).
+    pub fn with_recovery_margin(mut self, margin: f64) -> Self {
+        self.recovery_margin = margin.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Probe memory and update the pressure level.
+    pub fn refresh(&self) -> PressureLevel {
+        self.observe((self.probe)())
+    }
+
+    /// Update the pressure level from an externally measured value.
+    pub fn observe(&self, used_bytes: u64) -> PressureLevel {
+        self.last_observed.store(used_bytes, Ordering::Relaxed);
+        let below = |limit: u64| (used_bytes as f64) < limit as f64 * (1.0 - self.recovery_margin);
+        let next = match self.level() {
+            _ if used_bytes > self.hard_limit_bytes => PressureLevel::Critical,
+            PressureLevel::Critical if !below(self.hard_limit_bytes) => PressureLevel::Critical,
+            _ if used_bytes > self.soft_limit_bytes => PressureLevel::Elevated,
+            PressureLevel::Critical | PressureLevel::Elevated if !below(self.soft_limit_bytes) => {
+                PressureLevel::Elevated
+            }
+            _ => PressureLevel::Normal,
+        };
+        self.level.store(next as u8, Ordering::Relaxed);
+        next
+    }
+
+    /// Current pressure level.
+    pub fn level(&self) -> PressureLevel {
+        match self.level.load(Ordering::Relaxed) {
+            x if x == PressureLevel::Critical as u8 => PressureLevel::Critical,
+            x if x == PressureLevel::Elevated as u8 => PressureLevel::Elevated,
+            _ => PressureLevel::Normal,
+        }
+    }
+
+    /// Last memory reading passed to [`observe`](Self::observe), in bytes.
+    pub fn last_observed_bytes(&self) -> u64 {
+        self.last_observed.load(Ordering::Relaxed)
+    }
+
+    /// Sampling probability at the current pressure level.
+    pub fn effective_rate(&self) -> f64 {
+        match self.level() {
+            PressureLevel::Normal => self.base_rate,
+            PressureLevel::Elevated => self.base_rate * self.reduced_factor,
+            PressureLevel::Critical => 0.0,
+        }
+    }
+
+    /// Decide whether to create a span, using the last observed pressure level.
+    pub fn should_sample(&self) -> bool {
+        sample(self.effective_rate())
+    }
+}
+
 #[cfg(test)]
 mod test_s {
     use super::*;
+    use std::sync::Arc;
 
     #[test]
     fn bound_s() {
@@ -29,4 +179,52 @@ mod test_s {
         assert!(!sample(0.0));
         assert!(sample(1.0));
     }
+
+    #[test]
+    fn memory_pressure_reduces_and_restores_rate() {
+        const MIB: u64 = 1024 * 1024;
+        let injected = Arc::new(AtomicU64::new(100 * MIB));
+        let probe = Arc::clone(&injected);
+        let policy = MemoryPressurePolicy::with_probe(1.0, 500 * MIB, 800 * MIB, move || {
+            probe.load(Ordering::Relaxed)
+        });
+
+        assert_eq!(policy.refresh(), PressureLevel::Normal);
+        assert_eq!(policy.effective_rate(), 1.0);
+        assert!(policy.should_sample());
+
+        injected.store(600 * MIB, Ordering::Relaxed);
+        assert_eq!(policy.refresh(), PressureLevel::Elevated);
+        assert!(policy.effective_rate() < 1.0);
+        assert!(policy.effective_rate() > 0.0);
+
+        injected.store(900 * MIB, Ordering::Relaxed);
+        assert_eq!(policy.refresh(), PressureLevel::Critical);
+        assert_eq!(policy.effective_rate(), 0.0);
+        assert!(!policy.should_sample());
+
+        // Just under the hard limit is within the hysteresis band: stay suspended
+        injected.store(790 * MIB, Ordering::Relaxed);
+        assert_eq!(policy.refresh(), PressureLevel::Critical);
+
+        injected.store(480 * MIB, Ordering::Relaxed);
+        assert_eq!(policy.refresh(), PressureLevel::Elevated);
+
+        injected.store(200 * MIB, Ordering::Relaxed);
+        assert_eq!(policy.refresh(), PressureLevel::Normal);
+        assert_eq!(policy.effective_rate(), 1.0);
+        assert_eq!(policy.last_observed_bytes(), 200 * MIB);
+    }
+
+    #[test]
+    fn process_memory_probe_is_nonzero() {
+        // RSS is always available on supported platforms
+        if cfg!(any(
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "windows"
+        )) {
+            assert!(process_memory_bytes() > 0);
+        }
+    }
 }
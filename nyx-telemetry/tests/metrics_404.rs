@@ -14,3 +14,27 @@ async fn non_metrics_path_404() -> anyhow::Result<()> {
     assert!(resp.is_err(), "expected 404 error, got: {resp:?}");
     Ok(())
 }
+
+#[tokio::test]
+async fn healthz_ok_alongside_404() -> anyhow::Result<()> {
+    let guard = nyx_telemetry::start_metrics_http_server("127.0.0.1:0".parse()?).await?;
+    let base = format!("http://{}", guard.addr());
+    let (health, unknown) = tokio::task::spawn_blocking(move || {
+        let agent = ureq::AgentBuilder::new()
+            .timeout(std::time::Duration::from_millis(800))
+            .build();
+        let health = agent
+            .get(&format!("{base}/healthz"))
+            .call()
+            .map(|r| r.status());
+        let unknown = match agent.get(&format!("{base}/unknown")).call() {
+            Err(ureq::Error::Status(code, _)) => Some(code),
+            _ => None,
+        };
+        (health, unknown)
+    })
+    .await?;
+    assert_eq!(health?, 200);
+    assert_eq!(unknown, Some(404));
+    Ok(())
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "prometheus")]
+
+#[tokio::test]
+async fn metrics_filter_enforces_bearer_token() -> anyhow::Result<()> {
+    nyx_telemetry::record_counter("nyx_auth_test_counter", 1);
+    let filter = nyx_telemetry::warp_metrics_filter_with_auth(Some("s3cret".into()));
+
+    let resp = warp::test::request()
+        .method("GET")
+        .path("/metrics")
+        .header("authorization", "Bearer wrong")
+        .reply(&filter)
+        .await;
+    assert_eq!(resp.status(), 401);
+    assert!(!String::from_utf8(resp.body().to_vec())?.contains("nyx_auth_test_counter"));
+
+    let resp = warp::test::request()
+        .method("GET")
+        .path("/metrics")
+        .reply(&filter)
+        .await;
+    assert_eq!(resp.status(), 401);
+
+    let resp = warp::test::request()
+        .method("GET")
+        .path("/metrics")
+        .header("authorization", "Bearer s3cret")
+        .reply(&filter)
+        .await;
+    assert_eq!(resp.status(), 200);
+    let body = String::from_utf8(resp.body().to_vec())?;
+    assert!(body.contains("nyx_auth_test_counter"), "body: {body}");
+    Ok(())
+}
+
+#[tokio::test]
+async fn metrics_filter_without_auth_stays_open() -> anyhow::Result<()> {
+    nyx_telemetry::record_counter("nyx_open_test_counter", 1);
+    let filter = nyx_telemetry::warp_metrics_filter_with_auth(None);
+    let resp = warp::test::request()
+        .method("GET")
+        .path("/metrics")
+        .reply(&filter)
+        .await;
+    assert_eq!(resp.status(), 200);
+    let body = String::from_utf8(resp.body().to_vec())?;
+    assert!(body.contains("nyx_open_test_counter"), "body: {body}");
+    Ok(())
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "prometheus")]
+
+#[tokio::test]
+async fn falls_back_to_ephemeral_port_when_preferred_is_taken() -> anyhow::Result<()> {
+    nyx_telemetry::init(&nyx_telemetry::Config::default())?;
+    nyx_telemetry::record_counter("nyx_port_fallback_counter", 1);
+
+    // Occupy the preferred port for the duration of the test.
+    let blocker = std::net::TcpListener::bind("127.0.0.1:0")?;
+    let preferred = blocker.local_addr()?;
+
+    let guard = nyx_telemetry::start_metrics_http_server_or_ephemeral(preferred).await?;
+    assert_eq!(guard.addr().ip(), preferred.ip());
+    assert_ne!(guard.addr().port(), preferred.port());
+    assert_ne!(guard.addr().port(), 0);
+
+    let url = format!("http://{}/metrics", guard.addr());
+    let body = tokio::task::spawn_blocking(move || -> anyhow::Result<String> {
+        Ok(ureq::get(&url)
+            .timeout(std::time::Duration::from_secs(2))
+            .call()?
+            .into_string()?)
+    })
+    .await??;
+    assert!(body.contains("nyx_port_fallback_counter"), "body: {body}");
+    drop(blocker);
+    Ok(())
+}
+
+#[tokio::test]
+async fn uses_preferred_port_when_free() -> anyhow::Result<()> {
+    let probe = std::net::TcpListener::bind("127.0.0.1:0")?;
+    let preferred = probe.local_addr()?;
+    drop(probe);
+
+    let guard = nyx_telemetry::start_metrics_http_server_or_ephemeral(preferred).await?;
+    assert_eq!(guard.addr(), preferred);
+    Ok(())
+}
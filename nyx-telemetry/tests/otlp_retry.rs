@@ -0,0 +1,103 @@
+#![cfg(feature = "otlp")]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use nyx_telemetry::otlp::{utils, OtlpConfig, OtlpExporter, OtlpProtocol};
+
+/// Minimal collector answering each request with the next status from
+/// `statuses` (the last one repeats) and recording request bodies.
+fn spawn_collector(statuses: Vec<u16>) -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind collector");
+    let addr = listener.local_addr().expect("collector addr");
+    let bodies = Arc::new(Mutex::new(Vec::new()));
+    let seen = bodies.clone();
+    std::thread::spawn(move || {
+        for (i, stream) in listener.incoming().enumerate() {
+            let Ok(mut stream) = stream else { break };
+            let mut reader = BufReader::new(stream.try_clone().expect("clone stream"));
+            let mut content_length = 0usize;
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+                    break;
+                }
+                if let Some(v) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    content_length = v.trim().parse().unwrap_or(0);
+                }
+            }
+            let mut body = vec![0u8; content_length];
+            let _ = reader.read_exact(&mut body);
+            seen.lock()
+                .expect("bodies lock")
+                .push(String::from_utf8_lossy(&body).into_owned());
+            let status = statuses[i.min(statuses.len() - 1)];
+            let _ = write!(
+                stream,
+                "HTTP/1.1 {status} X\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+            );
+        }
+    });
+    (format!("http://{addr}"), bodies)
+}
+
+fn config(endpoint: String) -> OtlpConfig {
+    OtlpConfig {
+        endpoint,
+        protocol: OtlpProtocol::Http,
+        max_batch_size: 1,
+        batch_timeout: Duration::from_millis(50),
+        max_retries: 3,
+        retry_backoff: Duration::from_millis(10),
+        timeout: Duration::from_secs(2),
+        ..OtlpConfig::default()
+    }
+}
+
+async fn export_one(endpoint: String, name: &str) -> nyx_telemetry::otlp::ExportStats {
+    let mut exporter = OtlpExporter::new(config(endpoint)).await.expect("exporter");
+    let span = utils::create_span(
+        utils::generate_trace_id(),
+        utils::generate_span_id(),
+        name.to_string(),
+        None,
+    );
+    exporter.export_span(span).await.expect("export span");
+    for _ in 0..100 {
+        let stats = exporter.stats().await;
+        if stats.spans_exported + stats.spans_dead_lettered > 0 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    let stats = exporter.stats().await;
+    exporter.shutdown().await.expect("shutdown");
+    stats
+}
+
+#[tokio::test]
+async fn transient_failure_is_retried_until_exported() {
+    let (endpoint, bodies) = spawn_collector(vec![503, 200]);
+    let stats = export_one(endpoint, "retried_span").await;
+
+    assert_eq!(stats.spans_exported, 1);
+    assert_eq!(stats.retries_performed, 1);
+    assert_eq!(stats.dead_lettered_batches, 0);
+    let bodies = bodies.lock().expect("bodies lock");
+    assert_eq!(bodies.len(), 2);
+    assert!(bodies[1].contains("retried_span"));
+}
+
+#[tokio::test]
+async fn permanent_failure_is_dead_lettered_without_retry() {
+    let (endpoint, bodies) = spawn_collector(vec![400]);
+    let stats = export_one(endpoint, "rejected_span").await;
+
+    assert_eq!(stats.spans_exported, 0);
+    assert_eq!(stats.retries_performed, 0);
+    assert_eq!(stats.dead_lettered_batches, 1);
+    assert_eq!(stats.spans_dead_lettered, 1);
+    assert_eq!(bodies.lock().expect("bodies lock").len(), 1);
+}
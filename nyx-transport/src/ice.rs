@@ -54,6 +54,41 @@ pub enum CandidateType {
     Relay,
 }
 
+impl CandidateType {
+    /// Recommended type preference (RFC 8445 §5.1.2.2)
+    pub fn type_preference(self) -> u8 {
+        match self {
+            CandidateType::Host => 126,
+            CandidateType::PeerReflexive => 110,
+            CandidateType::ServerReflexive => 100,
+            CandidateType::Relay => 0,
+        }
+    }
+}
+
+/// Candidate priority per RFC 8445 §5.1.2.1:
+/// `2^24 * type_pref + 2^8 * local_pref + (256 - component)`.
+///
+/// Components are numbered from 1; 0 is treated as 1.
+pub fn candidate_priority(type_pref: u8, local_pref: u16, component: u8) -> u32 {
+    (u32::from(type_pref) << 24)
+        | (u32::from(local_pref) << 8)
+        | (256 - u32::from(component.max(1)))
+}
+
+/// Local preference: IPv4 is preferred slightly over IPv6.
+fn local_preference(ip: IpAddr) -> u16 {
+    match ip {
+        IpAddr::V4(_) => 65535,
+        IpAddr::V6(_) => 65534,
+    }
+}
+
+/// Order candidates highest priority first; ties keep their gathering order.
+pub fn sort_by_priority(candidates: &mut [Candidate]) {
+    candidates.sort_by_key(|c| std::cmp::Reverse(c.priority));
+}
+
 /// ICE transport protocol
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Transport {
@@ -331,6 +366,8 @@ impl IceAgent {
         // Gather relay candidates using TURN
         self.gather_relay_candidates().await?;
 
+        sort_by_priority(&mut self.local_candidates.write().await);
+
         self.transition_to_state(IceAgentState::Complete).await?;
         Ok(())
     }
@@ -723,22 +760,8 @@ impl IceAgent {
     }
 
     fn calculate_priority(&self, candidate_type: CandidateType, ip: IpAddr) -> u32 {
-        let type_preference = match candidate_type {
-            CandidateType::Host => 126,
-            CandidateType::PeerReflexive => 110,
-            CandidateType::ServerReflexive => 100,
-            CandidateType::Relay => 0,
-        };
-
-        let local_preference = match ip {
-            IpAddr::V4(_) => 65535,
-            IpAddr::V6(_) => 65534,
-        };
-
         // Component ID is always 1 for single component
-        let component_id = 1;
-
-        (type_preference << 24) | (local_preference << 8) | (256 - component_id)
+        candidate_priority(candidate_type.type_preference(), local_preference(ip), 1)
     }
 
     fn calculate_pair_priority(&self, local: &Candidate, remote: &Candidate) -> u64 {
@@ -774,7 +797,7 @@ impl Candidate {
             foundation: format!("host-{address}"),
             component_id,
             transport: Transport::Udp,
-            priority: Self::calculate_host_priority(address.ip()),
+            priority: Self::calculate_priority(CandidateType::Host, component_id, address.ip()),
             address,
             candidate_type: CandidateType::Host,
             related_address: None,
@@ -792,7 +815,11 @@ impl Candidate {
             foundation: format!("srflx-{address}"),
             component_id,
             transport: Transport::Udp,
-            priority: Self::calculate_srflx_priority(address.ip()),
+            priority: Self::calculate_priority(
+                CandidateType::ServerReflexive,
+                component_id,
+                address.ip(),
+            ),
             address,
             candidate_type: CandidateType::ServerReflexive,
             related_address: Some(related),
@@ -806,7 +833,7 @@ impl Candidate {
             foundation: format!("relay-{address}"),
             component_id,
             transport: Transport::Udp,
-            priority: Self::calculate_relay_priority(address.ip()),
+            priority: Self::calculate_priority(CandidateType::Relay, component_id, address.ip()),
             address,
             candidate_type: CandidateType::Relay,
             related_address: Some(related),
@@ -814,31 +841,10 @@ impl Candidate {
         }
     }
 
-    fn calculate_host_priority(ip: IpAddr) -> u32 {
-        let type_preference = 126u32;
-        let local_preference = match ip {
-            IpAddr::V4(_) => 65535,
-            IpAddr::V6(_) => 65534,
-        };
-        (type_preference << 24) | (local_preference << 8) | 255
-    }
-
-    fn calculate_srflx_priority(ip: IpAddr) -> u32 {
-        let type_preference = 100u32;
-        let local_preference = match ip {
-            IpAddr::V4(_) => 65535,
-            IpAddr::V6(_) => 65534,
-        };
-        (type_preference << 24) | (local_preference << 8) | 255
-    }
-
-    fn calculate_relay_priority(ip: IpAddr) -> u32 {
-        let type_preference = 0u32;
-        let local_preference = match ip {
-            IpAddr::V4(_) => 65535,
-            IpAddr::V6(_) => 65534,
-        };
-        (type_preference << 24) | (local_preference << 8) | 255
+    fn calculate_priority(candidate_type: CandidateType, component_id: u32, ip: IpAddr) -> u32 {
+        let type_pref = candidate_type.type_preference();
+        let component = u8::try_from(component_id).unwrap_or(u8::MAX);
+        candidate_priority(type_pref, local_preference(ip), component)
     }
 }
 
@@ -864,6 +870,50 @@ mod tests {
         assert!(host_candidate.priority > 0);
     }
 
+    #[test]
+    fn candidate_priority_matches_rfc_values() {
+        // Component 1 with the maximum local preference
+        assert_eq!(candidate_priority(126, 65535, 1), 2_130_706_431);
+        assert_eq!(candidate_priority(110, 65535, 1), 1_862_270_975);
+        assert_eq!(candidate_priority(100, 65535, 1), 1_694_498_815);
+        assert_eq!(candidate_priority(0, 65535, 1), 16_777_215);
+        // Component 2 ranks just below component 1
+        assert_eq!(candidate_priority(126, 65535, 2), 2_130_706_430);
+
+        let ip: SocketAddr = "192.0.2.1:5000".parse().unwrap();
+        assert_eq!(Candidate::new_host(1, ip).priority, 2_130_706_431);
+        assert_eq!(
+            Candidate::new_server_reflexive(1, ip, ip).priority,
+            1_694_498_815
+        );
+        assert_eq!(Candidate::new_relay(1, ip, ip).priority, 16_777_215);
+    }
+
+    #[test]
+    fn host_candidates_sort_ahead_of_relayed() {
+        let addr: SocketAddr = "192.0.2.1:5000".parse().unwrap();
+        let mut candidates = vec![
+            Candidate::new_relay(1, addr, addr),
+            Candidate::new_server_reflexive(1, addr, addr),
+            Candidate::new_host(1, "[2001:db8::1]:5000".parse().unwrap()),
+            Candidate::new_host(1, addr),
+        ];
+        sort_by_priority(&mut candidates);
+        let order: Vec<_> = candidates
+            .iter()
+            .map(|c| (c.candidate_type, c.address.is_ipv4()))
+            .collect();
+        assert_eq!(
+            order,
+            [
+                (CandidateType::Host, true),
+                (CandidateType::Host, false),
+                (CandidateType::ServerReflexive, true),
+                (CandidateType::Relay, true),
+            ]
+        );
+    }
+
     #[tokio::test]
     async fn test_candidate_pair_formation() {
         // Add timeout to prevent hanging
@@ -412,10 +412,26 @@ impl Default for TransportRequirements {
     }
 }
 
+/// Notifications published by [`TransportManager`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransportEvent {
+    /// The active flow moved to another transport without a new session
+    Migrated {
+        from: TransportKind,
+        to: TransportKind,
+    },
+}
+
+/// Buffered events per subscriber before the oldest are dropped
+const TRANSPORT_EVENT_CAPACITY: usize = 16;
+
 /// High-level transport manager for handling multiple protocols
 pub struct TransportManager {
     capabilities: TransportCapabilities,
     preferred_transports: Vec<TransportKind>,
+    requirements: TransportRequirements,
+    active: Option<TransportKind>,
+    events: tokio::sync::broadcast::Sender<TransportEvent>,
 }
 
 impl TransportManager {
@@ -424,9 +440,14 @@ impl TransportManager {
         let capabilities = detect_capabilities();
         let preferred_transports = Self::determine_preferred_transports(&capabilities);
 
+        let (events, _) = tokio::sync::broadcast::channel(TRANSPORT_EVENT_CAPACITY);
+
         Self {
             capabilities,
             preferred_transports,
+            requirements: TransportRequirements::default(),
+            active: None,
+            events,
         }
     }
 
@@ -448,6 +469,60 @@ impl TransportManager {
             .copied()
     }
 
+    /// Select a transport for `requirements` and make it the active one
+    pub fn establish(&mut self, requirements: TransportRequirements) -> Result<TransportKind> {
+        let kind = self
+            .select_transport(&requirements)
+            .ok_or_else(|| Error::Msg("no transport meets the requirements".into()))?;
+        self.requirements = requirements;
+        self.active = Some(kind);
+        Ok(kind)
+    }
+
+    /// Transport carrying the current flow, if one was established
+    pub fn active_transport(&self) -> Option<TransportKind> {
+        self.active
+    }
+
+    /// Requirements the active flow was established (or last updated) with
+    pub fn requirements(&self) -> &TransportRequirements {
+        &self.requirements
+    }
+
+    /// Update the requirements, e.g. after a network change; checked on the next migration
+    pub fn set_requirements(&mut self, requirements: TransportRequirements) {
+        self.requirements = requirements;
+    }
+
+    /// Receive [`TransportEvent`]s published after this call
+    pub fn subscribe(&self) -> tokio::sync::broadcast::Receiver<TransportEvent> {
+        self.events.subscribe()
+    }
+
+    /// Move the active flow from `from` to `to` without tearing down the session
+    ///
+    /// `to` must be available and meet the current requirements. On success a
+    /// [`TransportEvent::Migrated`] is published; on failure nothing changes.
+    pub fn migrate(&mut self, from: TransportKind, to: TransportKind) -> Result<()> {
+        if self.active != Some(from) {
+            return Err(Error::Msg(format!(
+                "cannot migrate from {from:?}: active transport is {:?}",
+                self.active
+            )));
+        }
+        if !self.preferred_transports.contains(&to)
+            || !self.transport_meets_requirements(to, &self.requirements)
+        {
+            return Err(Error::Msg(format!(
+                "{to:?} does not meet the current transport requirements"
+            )));
+        }
+        self.active = Some(to);
+        // No subscribers is not an error
+        let _ = self.events.send(TransportEvent::Migrated { from, to });
+        Ok(())
+    }
+
     /// Check if transport meets requirements
     fn transport_meets_requirements(
         &self,
@@ -550,6 +625,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn migrate_rechecks_requirements_and_emits_event() {
+        let mut manager = TransportManager::new();
+        let mut events = manager.subscribe();
+        let (udp, tcp) = (TransportKind::Udp, TransportKind::Tcp);
+        let kind = manager.establish(TransportRequirements::default()).unwrap();
+        assert_eq!(kind, udp);
+
+        // TCP does not satisfy the unreliable-datagram requirements
+        assert!(manager.migrate(udp, tcp).is_err());
+        assert_eq!(manager.active_transport(), Some(udp));
+        assert!(events.try_recv().is_err());
+
+        // After the network change the flow needs reliability; TCP now qualifies
+        manager.set_requirements(TransportRequirements {
+            requires_reliability: true,
+            allows_unreliable: false,
+            ..Default::default()
+        });
+        manager.migrate(udp, tcp).unwrap();
+        assert_eq!(manager.active_transport(), Some(tcp));
+        assert_eq!(
+            events.try_recv().unwrap(),
+            TransportEvent::Migrated { from: udp, to: tcp }
+        );
+
+        // The source must be the active transport
+        assert!(manager.migrate(udp, tcp).is_err());
+    }
+
     #[test]
     fn udp_send_recv_roundtrip() -> std::result::Result<(), Box<dyn std::error::Error>> {
         let mut a = UdpEndpoint::bind_loopback()?;
@@ -47,6 +47,9 @@ pub const MAX_CONCURRENT_VALIDATIONS: usize = 8;
 /// Path validation retry attempt_s
 pub const PATH_VALIDATION_RETRIES: u32 = 3;
 
+/// Outstanding challenges kept per peer; older ones are dropped first
+pub const MAX_OUTSTANDING_CHALLENGES_PER_PEER: usize = 4;
+
 /// Poll interval to check for cancellation during wait loop_s
 const VALIDATION_POLL_INTERVAL: Duration = Duration::from_millis(100);
 
@@ -198,7 +201,7 @@ impl PathChallenge {
 /// Comprehensive path validation manager
 pub struct PathValidator {
     local_socket: Arc<UdpSocket>,
-    active_challenges: Arc<Mutex<HashMap<String, PathChallenge>>>,
+    challenges: Arc<Mutex<PathChallengeTracker>>,
     path_metrics: Arc<Mutex<HashMap<SocketAddr, PathMetrics>>>,
     validation_timeout: Duration,
     max_retries: u32,
@@ -218,7 +221,9 @@ impl PathValidator {
 
         Ok(Self {
             local_socket: Arc::new(socket),
-            active_challenges: Arc::new(Mutex::new(HashMap::new())),
+            challenges: Arc::new(Mutex::new(PathChallengeTracker::new(
+                DEFAULT_PATH_VALIDATION_TIMEOUT,
+            ))),
             path_metrics: Arc::new(Mutex::new(HashMap::new())),
             validation_timeout: DEFAULT_PATH_VALIDATION_TIMEOUT,
             max_retries: PATH_VALIDATION_RETRIES,
@@ -238,7 +243,7 @@ impl PathValidator {
 
         Ok(Self {
             local_socket: Arc::new(socket),
-            active_challenges: Arc::new(Mutex::new(HashMap::new())),
+            challenges: Arc::new(Mutex::new(PathChallengeTracker::new(timeout))),
             path_metrics: Arc::new(Mutex::new(HashMap::new())),
             validation_timeout: timeout,
             max_retries: PATH_VALIDATION_RETRIES,
@@ -261,7 +266,7 @@ impl PathValidator {
             .map_err(|e| Error::Msg(format!("Failed to bind path validator socket: {e}")))?;
         Ok(Self {
             local_socket: Arc::new(socket),
-            active_challenges: Arc::new(Mutex::new(HashMap::new())),
+            challenges: Arc::new(Mutex::new(PathChallengeTracker::new(timeout))),
             path_metrics: Arc::new(Mutex::new(HashMap::new())),
             validation_timeout: timeout,
             max_retries,
@@ -288,14 +293,13 @@ impl PathValidator {
                 }
             };
             challenge.attempt = attempt;
-            let token_key = challenge.token_hex();
 
-            // Store the challenge (replace any previous for same token)
-            {
-                let mut challenges =
-                    safe_mutex_lock(&self.active_challenges, "path_challenge_receive")?;
-                challenges.insert(token_key.clone(), challenge.clone());
-            }
+            // Track the token so only one in-time response from the target validates it
+            safe_mutex_lock(&self.challenges, "path_challenge_issue")?.track(
+                target_addr,
+                challenge.token,
+                challenge.sent_at,
+            );
 
             // Send PATH_CHALLENGE frame
             self.send_path_challenge(&challenge).await?;
@@ -303,7 +307,7 @@ impl PathValidator {
             // Compute deadline and wait
             let deadline = Instant::now() + self.validation_timeout;
             match self
-                .wait_for_path_response(&token_key, challenge.sent_at, deadline)
+                .wait_for_path_response(&challenge.token, deadline)
                 .await
             {
                 Ok(metrics) => {
@@ -316,13 +320,11 @@ impl PathValidator {
                         m.validation_count = 1;
                         path_metrics.insert(target_addr, m.clone());
                     }
-                    // Clean up
-                    self.cleanup_challenge(&token_key);
                     return Ok(metrics);
                 }
                 Err(e) => {
                     // Clean up this challenge and retry if attempts remain
-                    self.cleanup_challenge(&token_key);
+                    self.cleanup_challenge(target_addr, &challenge.token);
                     last_err = Some(e);
                     if attempt < self.max_retries {
                         // brief backoff before retry
@@ -367,8 +369,7 @@ impl PathValidator {
     /// Wait for PATH_RESPONSE for a specific challenge
     async fn wait_for_path_response(
         &self,
-        token_key: &str,
-        sent_at: Instant,
+        token: &[u8; PATH_CHALLENGE_TOKEN_SIZE],
         deadline: Instant,
     ) -> Result<PathMetrics> {
         let mut buffer = [0u8; 1024];
@@ -390,7 +391,7 @@ impl PathValidator {
             match timeout(recv_timeout, self.local_socket.recv_from(&mut buffer)).await {
                 Ok(Ok((len, from_addr))) => {
                     if let Some(metrics) = self
-                        .process_received_frame(&buffer[..len], from_addr, token_key, sent_at)
+                        .process_received_frame(&buffer[..len], from_addr, token)
                         .await?
                     {
                         return Ok(metrics);
@@ -442,8 +443,7 @@ impl PathValidator {
         &self,
         _data: &[u8],
         from_addr: SocketAddr,
-        expected_token_key: &str,
-        start_time: Instant,
+        expected_token: &[u8; PATH_CHALLENGE_TOKEN_SIZE],
     ) -> Result<Option<PathMetrics>> {
         if _data.len() < 1 + PATH_CHALLENGE_TOKEN_SIZE {
             return Ok(None);
@@ -452,29 +452,18 @@ impl PathValidator {
         let frame_type = _data[0];
         if frame_type == PATH_RESPONSE_FRAME_TYPE {
             let received_token = &_data[1..1 + PATH_CHALLENGE_TOKEN_SIZE];
-            let received_token_key = hex::encode(received_token);
-
-            if received_token_key == expected_token_key {
-                // Verify that the response comes from the originally challenged address.
-                let from_expected_addr = {
-                    let challenges =
-                        safe_mutex_lock(&self.active_challenges, "active_challenges_operation")?;
-                    match challenges.get(expected_token_key) {
-                        Some(c) => c.target_addr == from_addr,
-                        None => {
-                            eprintln!(
-                                "No challenge found for expected_token_key: {expected_token_key}",
-                            );
-                            false
-                        }
+
+            if received_token == expected_token {
+                // Only the challenged address may answer, once, within the TTL;
+                // anything else is a possible spoof, reflection or replay.
+                let check = safe_mutex_lock(&self.challenges, "path_challenge_verify")?
+                    .verify(from_addr, expected_token);
+                let rtt = match check {
+                    PathResponseCheck::Validated(rtt) => rtt,
+                    PathResponseCheck::Expired | PathResponseCheck::UnknownNonce => {
+                        return Ok(None)
                     }
                 };
-                if !from_expected_addr {
-                    // Ignore responses from unexpected addresses (possible spoof/reflection)
-                    return Ok(None);
-                }
-                // Valid PATH_RESPONSE received
-                let rtt = start_time.elapsed();
 
                 let metrics = PathMetrics {
                     round_trip_time: rtt,
@@ -522,12 +511,10 @@ impl PathValidator {
         (bytes_per_second as u64).max(1000) // Minimum 1 KB/s
     }
 
-    /// Cleanup expired or completed challenge
-    fn cleanup_challenge(&self, token_key: &str) {
-        if let Ok(mut challenges) =
-            safe_mutex_lock(&self.active_challenges, "active_challenges_operation")
-        {
-            challenges.remove(token_key);
+    /// Forget a challenge that went unanswered
+    fn cleanup_challenge(&self, peer: SocketAddr, token: &[u8; PATH_CHALLENGE_TOKEN_SIZE]) {
+        if let Ok(mut challenges) = safe_mutex_lock(&self.challenges, "path_challenge_cleanup") {
+            challenges.forget(peer, token);
         }
     }
 
@@ -582,7 +569,7 @@ impl PathValidator {
     fn clone_for_validation(&self) -> Self {
         Self {
             local_socket: Arc::clone(&self.local_socket),
-            active_challenges: Arc::clone(&self.active_challenges),
+            challenges: Arc::clone(&self.challenges),
             path_metrics: Arc::clone(&self.path_metrics),
             validation_timeout: self.validation_timeout,
             max_retries: self.max_retries,
@@ -599,10 +586,8 @@ impl PathValidator {
         let now = Instant::now();
 
         // Cleanup expired challenges
-        if let Ok(mut challenges) =
-            safe_mutex_lock(&self.active_challenges, "active_challenges_operation")
-        {
-            challenges.retain(|_, challenge| !challenge.is_timed_out(self.validation_timeout * 2));
+        if let Ok(mut challenges) = safe_mutex_lock(&self.challenges, "path_challenge_cleanup") {
+            challenges.purge_expired();
         }
 
         // Cleanup old metrics (older than 1 hour)
@@ -657,6 +642,119 @@ pub async fn validate_path_comprehensive(
     validator.validate_path(target_addr).await
 }
 
+// ==================== Challenge Expiry ====================
+
+/// Outcome of checking a PATH_RESPONSE nonce
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathResponseCheck {
+    /// Matched an outstanding challenge within its TTL; carries the RTT
+    Validated(Duration),
+    /// Matched a challenge whose TTL had already elapsed
+    Expired,
+    /// No outstanding challenge to this peer carries the nonce
+    UnknownNonce,
+}
+
+/// Transport-independent PATH_CHALLENGE bookkeeping with nonce expiry
+///
+/// Issues random 16-byte nonces per peer and accepts a response only if it
+/// echoes an outstanding nonce for the same peer within the TTL. Each nonce
+/// is consumed on first use, so a replayed PATH_RESPONSE cannot validate a
+/// path again.
+#[derive(Debug)]
+pub struct PathChallengeTracker {
+    ttl: Duration,
+    outstanding: HashMap<SocketAddr, Vec<([u8; PATH_CHALLENGE_TOKEN_SIZE], Instant)>>,
+}
+
+impl PathChallengeTracker {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            outstanding: HashMap::new(),
+        }
+    }
+
+    /// Create a challenge nonce for `peer` and remember when it was issued
+    pub fn issue(&mut self, peer: SocketAddr) -> [u8; PATH_CHALLENGE_TOKEN_SIZE] {
+        self.issue_at(peer, Instant::now())
+    }
+
+    /// Check a PATH_RESPONSE nonce received from `peer`
+    pub fn verify(
+        &mut self,
+        peer: SocketAddr,
+        nonce: &[u8; PATH_CHALLENGE_TOKEN_SIZE],
+    ) -> PathResponseCheck {
+        self.verify_at(peer, nonce, Instant::now())
+    }
+
+    /// Number of challenges awaiting a response from `peer`
+    pub fn outstanding(&self, peer: &SocketAddr) -> usize {
+        self.outstanding.get(peer).map_or(0, Vec::len)
+    }
+
+    /// Drop every challenge whose TTL has elapsed
+    pub fn purge_expired(&mut self) {
+        let now = Instant::now();
+        let ttl = self.ttl;
+        self.outstanding.retain(|_, pending| {
+            pending.retain(|(_, issued)| now.duration_since(*issued) <= ttl);
+            !pending.is_empty()
+        });
+    }
+
+    fn issue_at(&mut self, peer: SocketAddr, now: Instant) -> [u8; PATH_CHALLENGE_TOKEN_SIZE] {
+        let mut nonce = [0u8; PATH_CHALLENGE_TOKEN_SIZE];
+        OsRng.fill_bytes(&mut nonce);
+        self.track(peer, nonce, now);
+        nonce
+    }
+
+    /// Remember a nonce generated elsewhere (e.g. by [`PathChallenge::new`])
+    fn track(&mut self, peer: SocketAddr, nonce: [u8; PATH_CHALLENGE_TOKEN_SIZE], issued: Instant) {
+        let pending = self.outstanding.entry(peer).or_default();
+        if pending.len() >= MAX_OUTSTANDING_CHALLENGES_PER_PEER {
+            pending.remove(0);
+        }
+        pending.push((nonce, issued));
+    }
+
+    /// Drop an outstanding nonce without validating it
+    fn forget(&mut self, peer: SocketAddr, nonce: &[u8; PATH_CHALLENGE_TOKEN_SIZE]) {
+        if let Some(pending) = self.outstanding.get_mut(&peer) {
+            pending.retain(|(n, _)| n != nonce);
+            if pending.is_empty() {
+                self.outstanding.remove(&peer);
+            }
+        }
+    }
+
+    fn verify_at(
+        &mut self,
+        peer: SocketAddr,
+        nonce: &[u8; PATH_CHALLENGE_TOKEN_SIZE],
+        now: Instant,
+    ) -> PathResponseCheck {
+        let Some(pending) = self.outstanding.get_mut(&peer) else {
+            return PathResponseCheck::UnknownNonce;
+        };
+        let Some(pos) = pending.iter().position(|(n, _)| n == nonce) else {
+            return PathResponseCheck::UnknownNonce;
+        };
+        let (_, issued) = pending.remove(pos);
+        if pending.is_empty() {
+            self.outstanding.remove(&peer);
+        }
+        let elapsed = now.saturating_duration_since(issued);
+        if elapsed > self.ttl {
+            PathResponseCheck::Expired
+        } else {
+            PathResponseCheck::Validated(elapsed)
+        }
+    }
+}
+
 // ==================== Active Probing Infrastructure ====================
 
 /// Probe result with detailed measurements
@@ -991,6 +1089,136 @@ pub async fn validate_bidirectional_path(
 mod tests {
     use super::*;
 
+    const PEER: &str = "192.0.2.10:4433";
+
+    #[test]
+    fn challenge_response_within_ttl_validates_once() {
+        let peer: SocketAddr = PEER.parse().unwrap();
+        let mut tracker = PathChallengeTracker::new(Duration::from_secs(3));
+        let t0 = Instant::now();
+        let nonce = tracker.issue_at(peer, t0);
+        assert_eq!(tracker.outstanding(&peer), 1);
+
+        let rtt = Duration::from_millis(40);
+        assert_eq!(
+            tracker.verify_at(peer, &nonce, t0 + rtt),
+            PathResponseCheck::Validated(rtt)
+        );
+        // Consumed: a replay of the same response is rejected
+        assert_eq!(
+            tracker.verify_at(peer, &nonce, t0 + rtt),
+            PathResponseCheck::UnknownNonce
+        );
+        assert_eq!(tracker.outstanding(&peer), 0);
+    }
+
+    #[test]
+    fn challenge_response_after_ttl_is_rejected() {
+        let peer: SocketAddr = PEER.parse().unwrap();
+        let mut tracker = PathChallengeTracker::new(Duration::from_secs(3));
+        let t0 = Instant::now();
+        let nonce = tracker.issue_at(peer, t0);
+
+        assert_eq!(
+            tracker.verify_at(peer, &nonce, t0 + Duration::from_secs(4)),
+            PathResponseCheck::Expired
+        );
+        assert_eq!(tracker.outstanding(&peer), 0);
+    }
+
+    #[test]
+    fn challenge_response_with_wrong_nonce_or_peer_is_rejected() {
+        let peer: SocketAddr = PEER.parse().unwrap();
+        let other: SocketAddr = "192.0.2.11:4433".parse().unwrap();
+        let mut tracker = PathChallengeTracker::new(Duration::from_secs(3));
+        let t0 = Instant::now();
+        let nonce = tracker.issue_at(peer, t0);
+
+        let mut wrong = nonce;
+        wrong[0] ^= 0xff;
+        assert_eq!(
+            tracker.verify_at(peer, &wrong, t0),
+            PathResponseCheck::UnknownNonce
+        );
+        assert_eq!(
+            tracker.verify_at(other, &nonce, t0),
+            PathResponseCheck::UnknownNonce
+        );
+        // The genuine challenge is still outstanding
+        assert_eq!(tracker.outstanding(&peer), 1);
+    }
+
+    #[tokio::test]
+    async fn validator_accepts_response_once_and_only_from_target() -> Result<()> {
+        let validator = PathValidator::new("127.0.0.1:0".parse().unwrap()).await?;
+        let peer: SocketAddr = PEER.parse().unwrap();
+        let other: SocketAddr = "192.0.2.11:4433".parse().unwrap();
+        let challenge = PathChallenge::new(peer).expect("valid peer");
+        validator
+            .challenges
+            .lock()
+            .unwrap()
+            .track(peer, challenge.token, challenge.sent_at);
+
+        let mut response = vec![PATH_RESPONSE_FRAME_TYPE];
+        response.extend_from_slice(&challenge.token);
+        let from_other = validator
+            .process_received_frame(&response, other, &challenge.token)
+            .await?;
+        assert!(from_other.is_none());
+        let first = validator
+            .process_received_frame(&response, peer, &challenge.token)
+            .await?;
+        assert!(first.is_some());
+        // A replayed PATH_RESPONSE no longer matches an outstanding challenge
+        let replay = validator
+            .process_received_frame(&response, peer, &challenge.token)
+            .await?;
+        assert!(replay.is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn validator_rejects_response_after_ttl() -> Result<()> {
+        let ttl = Duration::from_millis(20);
+        let validator =
+            PathValidator::new_with_timeout("127.0.0.1:0".parse().unwrap(), ttl).await?;
+        let peer: SocketAddr = PEER.parse().unwrap();
+        let challenge = PathChallenge::new(peer).expect("valid peer");
+        validator
+            .challenges
+            .lock()
+            .unwrap()
+            .track(peer, challenge.token, challenge.sent_at);
+
+        tokio::time::sleep(ttl * 2).await;
+        let mut response = vec![PATH_RESPONSE_FRAME_TYPE];
+        response.extend_from_slice(&challenge.token);
+        let late = validator
+            .process_received_frame(&response, peer, &challenge.token)
+            .await?;
+        assert!(late.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn challenge_tracker_bounds_outstanding_per_peer() {
+        let peer: SocketAddr = PEER.parse().unwrap();
+        let mut tracker = PathChallengeTracker::new(Duration::from_secs(3));
+        let first = tracker.issue(peer);
+        for _ in 0..MAX_OUTSTANDING_CHALLENGES_PER_PEER {
+            tracker.issue(peer);
+        }
+        assert_eq!(
+            tracker.outstanding(&peer),
+            MAX_OUTSTANDING_CHALLENGES_PER_PEER
+        );
+        assert_eq!(
+            tracker.verify(peer, &first),
+            PathResponseCheck::UnknownNonce
+        );
+    }
+
     #[test]
     fn parse_ipv4_literal() -> std::result::Result<(), Box<dyn std::error::Error>> {
         let addr = validate_host_port("127.0.0.1", 8080)?;
@@ -22,7 +22,7 @@ use hmac::{Hmac, Mac};
 use sha1::Sha1;
 use thiserror::Error;
 use tokio::net::UdpSocket;
-use tokio::time::timeout;
+use tokio::time::{timeout, timeout_at, Instant};
 
 /// STUN/TURN protocol errors
 #[derive(Error, Debug, Clone, PartialEq, Eq)]
@@ -54,6 +54,15 @@ pub type StunResult<T> = Result<T, StunError>;
 /// STUN message magic cookie
 const MAGIC_COOKIE: u32 = 0x2112A442;
 
+/// Initial retransmission timeout for binding requests (RFC 5389 §7.2.1)
+pub const DEFAULT_RTO: Duration = Duration::from_millis(500);
+
+/// Binding request transmissions before giving up (Rc, RFC 5389 §7.2.1)
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 7;
+
+/// After the last transmission, wait this many initial RTOs (Rm)
+const FINAL_WAIT_FACTOR: u32 = 16;
+
 /// STUN message types
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u16)]
@@ -519,6 +528,8 @@ fn decode_xor_address(data: &[u8], transaction_id: &[u8; 12]) -> StunResult<Sock
 pub struct StunClient {
     socket: Arc<UdpSocket>,
     timeout_duration: Duration,
+    rto: Duration,
+    max_attempts: u32,
 }
 
 impl StunClient {
@@ -531,9 +542,97 @@ impl StunClient {
         Ok(Self {
             socket: Arc::new(socket),
             timeout_duration: Duration::from_secs(5),
+            rto: DEFAULT_RTO,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
         })
     }
 
+    /// Override the retransmission schedule used by [`Self::get_mapped_address`]
+    pub fn with_retransmission(mut self, rto: Duration, max_attempts: u32) -> Self {
+        self.rto = rto;
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Local address the client sends from
+    pub fn local_addr(&self) -> StunResult<SocketAddr> {
+        self.socket
+            .local_addr()
+            .map_err(|e| StunError::NetworkError(e.to_string()))
+    }
+
+    /// Discover the external address with RFC 5389 retransmission.
+    ///
+    /// The same request is resent after RTO, 2*RTO, 4*RTO, ... until
+    /// `max_attempts` transmissions; after the last one the client waits
+    /// 16*RTO. `timeout` caps the whole exchange. Datagrams from other
+    /// peers or for other transactions are ignored.
+    pub async fn get_mapped_address(
+        &self,
+        server: SocketAddr,
+        timeout: Duration,
+    ) -> StunResult<SocketAddr> {
+        let request = StunMessage::new(MessageType::BindingRequest);
+        let request_bytes = request.encode()?;
+        let deadline = Instant::now() + timeout;
+        let mut rto = self.rto;
+        let mut buf = vec![0u8; 1500];
+
+        for attempt in 1..=self.max_attempts {
+            self.socket
+                .send_to(&request_bytes, server)
+                .await
+                .map_err(|e| StunError::NetworkError(e.to_string()))?;
+            let wait = if attempt == self.max_attempts {
+                self.rto * FINAL_WAIT_FACTOR
+            } else {
+                rto
+            };
+            let attempt_deadline = (Instant::now() + wait).min(deadline);
+            if let Some(addr) = self
+                .await_binding_response(&request, server, attempt_deadline, &mut buf)
+                .await?
+            {
+                return Ok(addr);
+            }
+            if Instant::now() >= deadline {
+                break;
+            }
+            rto *= 2;
+        }
+        Err(StunError::Timeout)
+    }
+
+    /// Wait until `deadline` for the response to `request`; `None` on timeout.
+    async fn await_binding_response(
+        &self,
+        request: &StunMessage,
+        server: SocketAddr,
+        deadline: Instant,
+        buf: &mut [u8],
+    ) -> StunResult<Option<SocketAddr>> {
+        loop {
+            let (len, from) = match timeout_at(deadline, self.socket.recv_from(buf)).await {
+                Err(_) => return Ok(None),
+                Ok(Err(e)) => return Err(StunError::NetworkError(e.to_string())),
+                Ok(Ok(received)) => received,
+            };
+            if from != server {
+                continue;
+            }
+            let Ok(response) = StunMessage::decode(&buf[..len]) else {
+                continue;
+            };
+            if response.header.transaction_id != request.header.transaction_id {
+                continue;
+            }
+            return response
+                .get_xor_mapped_address()?
+                .ok_or_else(|| StunError::ParseError("No XOR-MAPPED-ADDRESS in response".into()))
+                .map(Some);
+        }
+    }
+
     /// Perform STUN binding request to discover external address
     pub async fn binding_request(&self, server: SocketAddr) -> StunResult<SocketAddr> {
         let request = StunMessage::new(MessageType::BindingRequest);
@@ -767,4 +866,66 @@ mod tests {
         // Wrong password should fail
         assert!(!msg.verify_message_integrity("wrong-password").unwrap());
     }
+
+    #[tokio::test]
+    async fn get_mapped_address_retransmits_until_answered() {
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let server_addr = server.local_addr().unwrap();
+        // Drops the first request so the answer comes from a retransmission
+        let responder = tokio::spawn(async move {
+            let mut buf = [0u8; 1500];
+            let mut seen = 0;
+            loop {
+                let (len, from) = server.recv_from(&mut buf).await.unwrap();
+                seen += 1;
+                if seen == 1 {
+                    continue;
+                }
+                let request = StunMessage::decode(&buf[..len]).unwrap();
+                let mut response = StunMessage::new(MessageType::BindingResponse);
+                response.header.transaction_id = request.header.transaction_id;
+                response.add_xor_mapped_address(from);
+                let bytes = response.encode().unwrap();
+                server.send_to(&bytes, from).await.unwrap();
+                return seen;
+            }
+        });
+
+        let client = StunClient::new("127.0.0.1:0".parse().unwrap())
+            .await
+            .unwrap()
+            .with_retransmission(Duration::from_millis(20), 7);
+        let mapped = client
+            .get_mapped_address(server_addr, Duration::from_secs(5))
+            .await
+            .unwrap();
+
+        assert_eq!(mapped, client.local_addr().unwrap());
+        assert_eq!(responder.await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn get_mapped_address_gives_up_after_max_attempts() {
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let server_addr = server.local_addr().unwrap();
+        let client = StunClient::new("127.0.0.1:0".parse().unwrap())
+            .await
+            .unwrap()
+            .with_retransmission(Duration::from_millis(10), 3);
+
+        // Sends at 0, 10 and 30ms, then waits 16 * 10ms after the last one
+        let started = std::time::Instant::now();
+        let res = client
+            .get_mapped_address(server_addr, Duration::from_secs(5))
+            .await;
+        assert_eq!(res, Err(StunError::Timeout));
+        assert!(started.elapsed() >= Duration::from_millis(190));
+
+        let mut buf = [0u8; 1500];
+        let mut received = 0;
+        while let Ok(Ok(_)) = timeout(Duration::from_millis(50), server.recv_from(&mut buf)).await {
+            received += 1;
+        }
+        assert_eq!(received, 3);
+    }
 }
@@ -8,6 +8,9 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::net::UdpSocket;
 
+/// Client side of the binding exchange, including RFC 5389 retransmission
+pub use crate::stun::StunClient;
+
 /// STUN error types
 #[derive(thiserror::Error, Debug)]
 pub enum StunError {
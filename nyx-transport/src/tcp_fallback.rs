@@ -1,11 +1,13 @@
 //! Optimized TCP fallback helpers for reliable transport
 //!
 //! Provides high-performance TCP connection management with connection pooling,
-//! keep-alive optimization, and automatic retry logic.
+//! keep-alive optimization, and automatic retry logic, plus [`DatagramFramer`]
+//! for carrying datagrams over the stream with their boundaries intact.
 
 use crate::{Error, Result};
 use socket2::SockRef; // Safe socket operations
 use std::collections::HashMap;
+use std::io::{ErrorKind, Read, Write};
 use std::net::{SocketAddr, TcpStream};
 use std::sync::{
     atomic::{AtomicU64, Ordering},
@@ -238,6 +240,86 @@ pub fn try_connect(addr: SocketAddr, timeout: Duration) -> Result<bool> {
     Ok(true)
 }
 
+/// Largest datagram a [`DatagramFramer`] can carry (16-bit length prefix)
+pub const MAX_FRAMED_DATAGRAM_LEN: usize = u16::MAX as usize;
+
+/// Carries datagrams over a TCP stream, preserving their boundaries
+///
+/// Each datagram is sent as a 2-byte big-endian length followed by the
+/// payload (RFC 4571 framing), and read back as exactly that payload.
+/// Frames longer than the configured limit are refused in both directions.
+pub struct DatagramFramer<S> {
+    stream: S,
+    max_len: usize,
+}
+
+impl<S> DatagramFramer<S> {
+    /// Frame `stream` with the largest limit the prefix allows
+    pub fn new(stream: S) -> Self {
+        Self::with_max_len(stream, MAX_FRAMED_DATAGRAM_LEN)
+    }
+
+    /// Frame `stream`, rejecting datagrams longer than `max_len` bytes
+    pub fn with_max_len(stream: S, max_len: usize) -> Self {
+        Self {
+            stream,
+            max_len: max_len.min(MAX_FRAMED_DATAGRAM_LEN),
+        }
+    }
+
+    pub fn get_ref(&self) -> &S {
+        &self.stream
+    }
+
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+}
+
+impl<S: Write> DatagramFramer<S> {
+    /// Send one datagram as a single frame
+    pub fn send(&mut self, datagram: &[u8]) -> Result<()> {
+        if datagram.len() > self.max_len {
+            return Err(Error::Msg(format!(
+                "datagram of {} bytes exceeds frame limit {}",
+                datagram.len(),
+                self.max_len
+            )));
+        }
+        // Prefix and payload in one write so the frame is not split by Nagle
+        let mut frame = Vec::with_capacity(2 + datagram.len());
+        frame.extend_from_slice(&(datagram.len() as u16).to_be_bytes());
+        frame.extend_from_slice(datagram);
+        self.stream.write_all(&frame)?;
+        self.stream.flush()?;
+        TCP_BYTES_SENT.fetch_add(frame.len() as u64, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+impl<S: Read> DatagramFramer<S> {
+    /// Read the next datagram; `Ok(None)` when the peer closed between frames
+    pub fn recv(&mut self) -> Result<Option<Vec<u8>>> {
+        let mut prefix = [0u8; 2];
+        match self.stream.read_exact(&mut prefix) {
+            Ok(()) => {}
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e.into()),
+        }
+        let len = usize::from(u16::from_be_bytes(prefix));
+        if len > self.max_len {
+            return Err(Error::Msg(format!(
+                "incoming frame of {len} bytes exceeds frame limit {}",
+                self.max_len
+            )));
+        }
+        let mut datagram = vec![0u8; len];
+        self.stream.read_exact(&mut datagram)?;
+        TCP_BYTES_RECEIVED.fetch_add(2 + len as u64, Ordering::Relaxed);
+        Ok(Some(datagram))
+    }
+}
+
 /// Create a default connection pool with reasonable settings
 pub fn create_default_pool() -> TcpConnectionPool {
     TcpConnectionPool::new(
@@ -252,6 +334,45 @@ mod tests {
     use super::*;
     use std::net::TcpListener;
 
+    #[test]
+    fn datagram_framer_preserves_boundaries() -> std::result::Result<(), Box<dyn std::error::Error>>
+    {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        let datagrams = [vec![0x01], vec![0xAB; 1280], vec![0x5C; 4000]];
+        let to_send = datagrams.clone();
+        let sender = std::thread::spawn(move || -> Result<()> {
+            let mut framer = DatagramFramer::new(TcpStream::connect(addr)?);
+            for d in &to_send {
+                framer.send(d)?;
+            }
+            Ok(())
+        });
+
+        let (stream, _) = listener.accept()?;
+        let mut framer = DatagramFramer::new(stream);
+        for expected in &datagrams {
+            assert_eq!(framer.recv()?.as_ref(), Some(expected));
+        }
+        sender.join().expect("sender thread")?;
+        assert_eq!(framer.recv()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn datagram_framer_enforces_frame_limit() {
+        let mut out = DatagramFramer::with_max_len(Vec::new(), 1280);
+        assert!(out.send(&[0u8; 1281]).is_err());
+        out.send(&[7u8; 1280]).unwrap();
+        assert_eq!(out.get_ref().len(), 2 + 1280);
+
+        // A peer announcing an oversized frame is refused before reading it
+        let mut wire = 2000u16.to_be_bytes().to_vec();
+        wire.extend_from_slice(&[0u8; 2000]);
+        let mut input = DatagramFramer::with_max_len(wire.as_slice(), 1280);
+        assert!(input.recv().is_err());
+    }
+
     #[test]
     fn can_connect_localhost() -> std::result::Result<(), Box<dyn std::error::Error>> {
         let listener = TcpListener::bind("127.0.0.1:0")?;
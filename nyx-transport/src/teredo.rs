@@ -11,9 +11,9 @@ use std::collections::HashMap;
 use std::fmt;
 use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6, UdpSocket};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tokio::net::UdpSocket as TokioUdpSocket;
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, RwLock};
 use thiserror::Error;
 use bytes::{Bytes, BytesMut, BufMut};
 
@@ -37,6 +37,8 @@ pub enum TeredoError {
     Io(#[from] std::io::Error),
     #[error("No suitable address found")]
     NoSuitableAddress,
+    #[error("No Teredo server responded")]
+    NoResponsiveServer,
 }
 
 pub type TeredoResult<T> = Result<T, TeredoError>;
@@ -439,16 +441,8 @@ impl TeredoTunnel {
     /// 
     /// RFC 4380 §5.2.1: Client sends Router Solicitation to discover the server
     pub async fn establish(&self) -> TeredoResult<()> {
-        // Build ICMPv6 Router Solicitation packet (simplified)
-        // Type=133 (Router Solicitation), Code=0, Checksum, Reserved
-        let mut rs_packet = BytesMut::with_capacity(64);
-        rs_packet.put_u8(133); // ICMPv6 Type: Router Solicitation
-        rs_packet.put_u8(0);   // Code
-        rs_packet.put_u16(0);  // Checksum (computed later)
-        rs_packet.put_u32(0);  // Reserved
-        
         // Encapsulate in minimal IPv6 header for Teredo
-        let encapsulated = self.encapsulate_packet(rs_packet.freeze(), None)?;
+        let encapsulated = self.encapsulate_packet(router_solicitation(), None)?;
         
         // Send to Teredo server
         self.local_socket.send_to(&encapsulated, self.server_addr).await?;
@@ -472,21 +466,7 @@ impl TeredoTunnel {
         ipv6_packet: Bytes,
         origin: Option<SocketAddrV4>,
     ) -> TeredoResult<Bytes> {
-        let mut buffer = BytesMut::with_capacity(8 + ipv6_packet.len());
-        
-        // Add origin indication if present (RFC 4380 §5.1.1)
-        if let Some(origin_addr) = origin {
-            buffer.put_u16(0x0001); // Indicator type
-            buffer.put_u16(0x0000); // Reserved
-            let origin_octets = origin_addr.ip().octets();
-            buffer.put_slice(&origin_octets);
-            buffer.put_u16(origin_addr.port());
-        }
-        
-        // Append IPv6 packet
-        buffer.put_slice(&ipv6_packet);
-        
-        Ok(buffer.freeze())
+        Ok(encapsulate(&ipv6_packet, origin))
     }
 
     /// Decapsulate a received Teredo packet (RFC 4380 §5.1)
@@ -502,36 +482,7 @@ impl TeredoTunnel {
         packet: Bytes,
         _from: SocketAddrV4,
     ) -> TeredoResult<TeredoPacket> {
-        if packet.len() < 40 {
-            return Err(TeredoError::DecapsulationFailed(
-                "Packet too short".to_string()
-            ));
-        }
-        
-        let mut offset = 0;
-        let mut origin = None;
-        
-        // Check for origin indication (first 2 bytes = 0x0001)
-        if packet.len() >= 8 && packet[0] == 0x00 && packet[1] == 0x01 {
-            // Parse origin indication
-            offset = 2; // Skip indicator type
-            offset += 2; // Skip reserved
-            let origin_ip = Ipv4Addr::new(
-                packet[offset], packet[offset+1], packet[offset+2], packet[offset+3]
-            );
-            offset += 4;
-            let origin_port = u16::from_be_bytes([packet[offset], packet[offset+1]]);
-            offset += 2;
-            origin = Some(SocketAddrV4::new(origin_ip, origin_port));
-        }
-        
-        // Extract IPv6 packet
-        let ipv6_payload = packet.slice(offset..);
-        
-        Ok(TeredoPacket {
-            origin,
-            ipv6_payload,
-        })
+        decapsulate(packet)
     }
 
     /// Send an IPv6 packet through the Teredo tunnel
@@ -612,6 +563,228 @@ impl TeredoTunnel {
     }
 }
 
+/// Encapsulate an IPv6 packet for IPv4/UDP transmission (RFC 4380 §5.1)
+fn encapsulate(ipv6_packet: &[u8], origin: Option<SocketAddrV4>) -> Bytes {
+    let mut buffer = BytesMut::with_capacity(8 + ipv6_packet.len());
+    
+    // Add origin indication if present (RFC 4380 §5.1.1)
+    if let Some(origin_addr) = origin {
+        buffer.put_u16(0x0001); // Indicator type
+        buffer.put_u16(0x0000); // Reserved
+        let origin_octets = origin_addr.ip().octets();
+        buffer.put_slice(&origin_octets);
+        buffer.put_u16(origin_addr.port());
+    }
+    
+    // Append IPv6 packet
+    buffer.put_slice(ipv6_packet);
+    
+    buffer.freeze()
+}
+
+/// Parse a received Teredo datagram (RFC 4380 §5.1)
+fn decapsulate(packet: Bytes) -> TeredoResult<TeredoPacket> {
+    if packet.len() < 40 {
+        return Err(TeredoError::DecapsulationFailed(
+            "Packet too short".to_string()
+        ));
+    }
+    
+    let mut offset = 0;
+    let mut origin = None;
+    
+    // Check for origin indication (first 2 bytes = 0x0001)
+    if packet.len() >= 8 && packet[0] == 0x00 && packet[1] == 0x01 {
+        // Parse origin indication
+        offset = 2; // Skip indicator type
+        offset += 2; // Skip reserved
+        let origin_ip = Ipv4Addr::new(
+            packet[offset], packet[offset+1], packet[offset+2], packet[offset+3]
+        );
+        offset += 4;
+        let origin_port = u16::from_be_bytes([packet[offset], packet[offset+1]]);
+        offset += 2;
+        origin = Some(SocketAddrV4::new(origin_ip, origin_port));
+    }
+    
+    // Extract IPv6 packet
+    let ipv6_payload = packet.slice(offset..);
+    
+    Ok(TeredoPacket {
+        origin,
+        ipv6_payload,
+    })
+}
+
+/// Build an ICMPv6 Router Solicitation packet (simplified)
+fn router_solicitation() -> Bytes {
+    // Type=133 (Router Solicitation), Code=0, Checksum, Reserved
+    let mut rs_packet = BytesMut::with_capacity(64);
+    rs_packet.put_u8(133); // ICMPv6 Type: Router Solicitation
+    rs_packet.put_u8(0); // Code
+    rs_packet.put_u16(0); // Checksum (computed later)
+    rs_packet.put_u32(0); // Reserved
+    rs_packet.freeze()
+}
+
+/// Whether a received datagram is an encapsulated ICMPv6 Router Advertisement
+fn is_router_advertisement(datagram: &[u8]) -> bool {
+    const ICMPV6_NEXT_HEADER: u8 = 58;
+    const ROUTER_ADVERTISEMENT: u8 = 134;
+    match decapsulate(Bytes::copy_from_slice(datagram)) {
+        Ok(packet) => {
+            let ipv6 = &packet.ipv6_payload;
+            ipv6.len() > 40
+                && ipv6[0] >> 4 == 6
+                && ipv6[6] == ICMPV6_NEXT_HEADER
+                && ipv6[40] == ROUTER_ADVERTISEMENT
+        }
+        Err(_) => false,
+    }
+}
+
+/// How long [`TeredoClient`] waits for servers to answer a probe
+pub const DEFAULT_PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Teredo client choosing among several servers
+///
+/// Every server is probed with an encapsulated Router Solicitation and the
+/// first to answer with a Router Advertisement (the lowest-latency one) is
+/// selected. A failed [`TeredoClient::send`] or an unanswered
+/// [`TeredoClient::check_selected`] fails over to another server;
+/// [`TeredoClient::spawn_monitor`] runs the latter periodically.
+pub struct TeredoClient {
+    /// Local IPv4 socket used for probes and traffic
+    socket: TokioUdpSocket,
+    /// Candidate Teredo servers
+    servers: Vec<SocketAddr>,
+    /// How long to wait for probe answers
+    probe_timeout: Duration,
+    /// Currently selected server
+    selected: RwLock<Option<SocketAddr>>,
+    /// Serializes probes so concurrent ones do not steal each other's answers
+    probing: Mutex<()>,
+}
+
+impl TeredoClient {
+    /// Probe `servers` and select the fastest responder
+    pub async fn with_servers(servers: Vec<SocketAddr>) -> TeredoResult<Self> {
+        Self::with_servers_and_timeout(servers, DEFAULT_PROBE_TIMEOUT).await
+    }
+
+    /// Like [`Self::with_servers`], waiting `probe_timeout` for answers
+    pub async fn with_servers_and_timeout(
+        servers: Vec<SocketAddr>,
+        probe_timeout: Duration,
+    ) -> TeredoResult<Self> {
+        let socket = TokioUdpSocket::bind(SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 0)).await?;
+        let client = Self {
+            socket,
+            servers,
+            probe_timeout,
+            selected: RwLock::new(None),
+            probing: Mutex::new(()),
+        };
+        client.reselect().await?;
+        Ok(client)
+    }
+
+    /// Server currently in use, if any responded to the last probe
+    pub async fn selected_server(&self) -> Option<SocketAddr> {
+        *self.selected.read().await
+    }
+
+    /// Probe every server again and select the fastest responder
+    ///
+    /// The previous selection stays readable while probing and is cleared
+    /// when no server answers.
+    pub async fn reselect(&self) -> TeredoResult<SocketAddr> {
+        let _probing = self.probing.lock().await;
+        let best = self
+            .probe_servers(&self.servers)
+            .await
+            .into_iter()
+            .min_by_key(|(_, rtt)| *rtt)
+            .map(|(server, _)| server);
+        *self.selected.write().await = best;
+        best.ok_or(TeredoError::NoResponsiveServer)
+    }
+
+    /// Probe the selected server and fail over if it no longer answers
+    pub async fn check_selected(&self) -> TeredoResult<SocketAddr> {
+        if let Some(current) = self.selected_server().await {
+            let answered = {
+                let _probing = self.probing.lock().await;
+                !self.probe_servers(&[current]).await.is_empty()
+            };
+            if answered {
+                return Ok(current);
+            }
+        }
+        self.reselect().await
+    }
+
+    /// Encapsulate `ipv6_packet` and send it to the selected server
+    ///
+    /// A send error triggers a reselection and one retry on the new server.
+    pub async fn send(&self, ipv6_packet: &[u8]) -> TeredoResult<()> {
+        let packet = encapsulate(ipv6_packet, None);
+        let server = match self.selected_server().await {
+            Some(server) => server,
+            None => self.reselect().await?,
+        };
+        if self.socket.send_to(&packet, server).await.is_ok() {
+            return Ok(());
+        }
+        let server = self.reselect().await?;
+        self.socket.send_to(&packet, server).await?;
+        Ok(())
+    }
+
+    /// Run [`Self::check_selected`] every `interval` in the background
+    pub fn spawn_monitor(self: Arc<Self>, interval: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                // Keep watching even when every server is down for now
+                let _ = self.check_selected().await;
+            }
+        })
+    }
+
+    /// Round-trip time of each of `servers` answering within the probe timeout
+    async fn probe_servers(&self, servers: &[SocketAddr]) -> Vec<(SocketAddr, Duration)> {
+        let probe = encapsulate(&router_solicitation(), None);
+        let started = Instant::now();
+        for server in servers {
+            // A failed send just leaves that server unanswered
+            let _ = self.socket.send_to(&probe, server).await;
+        }
+
+        let deadline = tokio::time::Instant::from_std(started + self.probe_timeout);
+        let mut answered: Vec<(SocketAddr, Duration)> = Vec::new();
+        let mut buf = [0u8; 1500];
+        while answered.len() < servers.len() {
+            match tokio::time::timeout_at(deadline, self.socket.recv_from(&mut buf)).await {
+                Ok(Ok((len, from))) => {
+                    if servers.contains(&from)
+                        && is_router_advertisement(&buf[..len])
+                        && !answered.iter().any(|(s, _)| *s == from)
+                    {
+                        answered.push((from, started.elapsed()));
+                    }
+                }
+                // e.g. port unreachable reported for a dead server
+                Ok(Err(_)) => continue,
+                Err(_) => break,
+            }
+        }
+        answered
+    }
+}
+
 // ==================== RFC 6724 Address Selection ====================
 
 /// RFC 6724 address selection policy entry
@@ -1114,4 +1287,106 @@ mod tests {
         let back = convert_socket_addr(converted);
         assert_eq!(back, v4_addr);
     }
+
+    /// Encapsulated ICMPv6 Router Advertisement as a Teredo server sends it
+    fn router_advertisement() -> Vec<u8> {
+        let mut packet = vec![0u8; 56];
+        packet[0] = 0x60; // IPv6
+        packet[4..6].copy_from_slice(&16u16.to_be_bytes());
+        packet[6] = 58; // Next header: ICMPv6
+        packet[7] = 255; // Hop limit
+        packet[40] = 134; // ICMPv6 Type: Router Advertisement
+        packet
+    }
+
+    /// Stub Teredo server answering every Router Solicitation with `reply`
+    async fn stub_server_with(reply: Vec<u8>) -> (SocketAddr, tokio::task::JoinHandle<()>) {
+        let socket = TokioUdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let addr = socket.local_addr().unwrap();
+        let task = tokio::spawn(async move {
+            let mut buf = [0u8; 1500];
+            while let Ok((len, from)) = socket.recv_from(&mut buf).await {
+                if buf[..len].first() == Some(&133) {
+                    let _ = socket.send_to(&reply, from).await;
+                }
+            }
+        });
+        (addr, task)
+    }
+
+    async fn stub_server() -> SocketAddr {
+        stub_server_with(router_advertisement()).await.0
+    }
+
+    #[tokio::test]
+    async fn test_client_fails_over_to_responsive_server() {
+        // Bound but never answers
+        let dead = TokioUdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let dead_addr = dead.local_addr().unwrap();
+        let live_addr = stub_server().await;
+
+        let client = TeredoClient::with_servers_and_timeout(
+            vec![dead_addr, live_addr],
+            Duration::from_millis(300),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(client.selected_server().await, Some(live_addr));
+        assert_eq!(client.reselect().await.unwrap(), live_addr);
+    }
+
+    #[tokio::test]
+    async fn test_client_without_responsive_server() {
+        let dead = TokioUdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let res = TeredoClient::with_servers_and_timeout(
+            vec![dead.local_addr().unwrap()],
+            Duration::from_millis(100),
+        )
+        .await;
+
+        assert!(matches!(res, Err(TeredoError::NoResponsiveServer)));
+    }
+
+    #[tokio::test]
+    async fn test_client_ignores_non_advertisement_replies() {
+        let (chatty, _task) = stub_server_with(b"router-advertisement".to_vec()).await;
+        let res = TeredoClient::with_servers_and_timeout(
+            vec![chatty],
+            Duration::from_millis(100),
+        )
+        .await;
+
+        assert!(matches!(res, Err(TeredoError::NoResponsiveServer)));
+    }
+
+    #[tokio::test]
+    async fn test_client_switches_when_selected_server_dies() {
+        let (first, first_task) = stub_server_with(router_advertisement()).await;
+        let (second, second_task) = stub_server_with(router_advertisement()).await;
+        let client = Arc::new(
+            TeredoClient::with_servers_and_timeout(
+                vec![first, second],
+                Duration::from_millis(200),
+            )
+            .await
+            .unwrap(),
+        );
+        let selected = client.selected_server().await.unwrap();
+        let (other, selected_task) = if selected == first {
+            (second, first_task)
+        } else {
+            (first, second_task)
+        };
+        let monitor = client.clone().spawn_monitor(Duration::from_millis(50));
+
+        selected_task.abort();
+        let deadline = Instant::now() + Duration::from_secs(3);
+        while client.selected_server().await != Some(other) && Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        monitor.abort();
+
+        assert_eq!(client.selected_server().await, Some(other));
+    }
 }